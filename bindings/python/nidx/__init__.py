@@ -7,12 +7,84 @@ from ._nidx import (
     NidInfo,
     NidInvalidDateError,
     albania,
+    australia,
+    austria,
+    bahrain,
+    belarus,
+    botswana,
+    chile,
+    colombia,
+    costa_rica,
+    cyprus,
+    czechia,
+    ecuador,
+    egypt,
+    finland,
+    france,
+    georgia,
+    germany,
+    ireland,
+    italy,
+    kenya,
     kosovo,
+    latvia,
+    malaysia,
+    north_macedonia,
+    peru,
+    poland,
+    portugal,
+    russia,
+    serbia,
+    south_korea,
+    spain,
+    switzerland,
+    turkey,
+    uae,
+    ukraine,
+    venezuela,
+    vietnam,
+    zimbabwe,
 )
 
 __all__ = [
     "albania",
+    "australia",
+    "austria",
+    "bahrain",
+    "belarus",
+    "botswana",
+    "chile",
+    "colombia",
+    "costa_rica",
+    "cyprus",
+    "czechia",
+    "ecuador",
+    "egypt",
+    "finland",
+    "france",
+    "georgia",
+    "germany",
+    "ireland",
+    "italy",
+    "kenya",
     "kosovo",
+    "latvia",
+    "malaysia",
+    "north_macedonia",
+    "peru",
+    "poland",
+    "portugal",
+    "russia",
+    "serbia",
+    "south_korea",
+    "spain",
+    "switzerland",
+    "turkey",
+    "uae",
+    "ukraine",
+    "venezuela",
+    "vietnam",
+    "zimbabwe",
     "NidInfo",
     "NidError",
     "NidFormatError",
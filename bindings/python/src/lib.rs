@@ -132,6 +132,950 @@ fn albania_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
     Ok(m)
 }
 
+/// Submodule for Serbia JMBG operations.
+fn serbia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "serbia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::serbia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::serbia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::serbia::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::serbia::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::serbia::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for North Macedonia EMBG operations.
+fn north_macedonia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "north_macedonia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::north_macedonia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::north_macedonia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::north_macedonia::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::north_macedonia::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::north_macedonia::NidError::InvalidDate(_) => {
+                    NidInvalidDateError::new_err(msg)
+                }
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Italy codice fiscale operations.
+fn italy_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "italy")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::italy::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::italy::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::italy::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::italy::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::italy::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Spain DNI/NIE operations.
+fn spain_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "spain")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::spain::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::spain::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::spain::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::spain::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Portugal Cartão de Cidadão number operations.
+fn portugal_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "portugal")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::portugal::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::portugal::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::portugal::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::portugal::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for France NIR operations.
+fn france_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "france")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::france::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::france::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::france::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::france::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::france::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Germany IdNr operations.
+fn germany_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "germany")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::germany::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::germany::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::germany::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::germany::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Switzerland AHV number operations.
+fn switzerland_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "switzerland")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::switzerland::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::switzerland::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::switzerland::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::switzerland::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Austria social insurance number operations.
+fn austria_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "austria")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::austria::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::austria::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::austria::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::austria::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::austria::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Poland PESEL operations.
+fn poland_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "poland")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::poland::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::poland::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::poland::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::poland::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::poland::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Czechia birth number operations.
+fn czechia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "czechia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::czechia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::czechia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::czechia::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::czechia::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::czechia::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Ukraine RNTRC operations.
+fn ukraine_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "ukraine")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::ukraine::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::ukraine::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::ukraine::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::ukraine::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Turkey TCKN operations.
+fn turkey_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "turkey")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::turkey::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::turkey::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::turkey::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::turkey::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Finland HETU operations.
+fn finland_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "finland")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::finland::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::finland::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::finland::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::finland::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::finland::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Latvia personal code operations.
+fn latvia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "latvia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::latvia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::latvia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::latvia::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::latvia::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::latvia::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Ireland PPSN operations.
+fn ireland_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "ireland")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::ireland::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::ireland::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::ireland::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::ireland::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Chile RUN operations.
+fn chile_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "chile")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::chile::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::chile::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::chile::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::chile::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for South Korea RRN operations.
+fn south_korea_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "south_korea")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::south_korea::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::south_korea::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::south_korea::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::south_korea::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::south_korea::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Malaysia NRIC operations.
+fn malaysia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "malaysia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::malaysia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::malaysia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::malaysia::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::malaysia::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Egypt national ID number operations.
+fn egypt_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "egypt")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::egypt::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::egypt::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::egypt::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::egypt::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::egypt::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Australia TFN operations.
+fn australia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "australia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::australia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::australia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::australia::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::australia::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Belarus identification number operations.
+fn belarus_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "belarus")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::belarus::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::belarus::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::belarus::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::belarus::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::belarus::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Russia taxpayer number operations.
+fn russia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "russia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::russia::inn::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::russia::inn::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::russia::inn::InnError::Format(_) => NidFormatError::new_err(msg),
+                nidx::russia::inn::InnError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Georgia personal number operations.
+fn georgia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "georgia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::georgia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::georgia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::georgia::NidError::Format(_) => NidFormatError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Cyprus identity number operations.
+fn cyprus_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "cyprus")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::cyprus::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::cyprus::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::cyprus::NidError::Format(_) => NidFormatError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for United Arab Emirates Emirates ID operations.
+fn uae_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "uae")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::uae::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::uae::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::uae::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::uae::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Bahrain CPR number operations.
+fn bahrain_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "bahrain")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::bahrain::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::bahrain::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::bahrain::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::bahrain::NidError::Checksum => NidChecksumError::new_err(msg),
+                nidx::bahrain::NidError::InvalidDate(_) => NidInvalidDateError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Kenya national ID number operations.
+fn kenya_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "kenya")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::kenya::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::kenya::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::kenya::NidError::Format(_) => NidFormatError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Zimbabwe registration number operations.
+fn zimbabwe_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "zimbabwe")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::zimbabwe::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::zimbabwe::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::zimbabwe::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::zimbabwe::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Botswana Omang number operations.
+fn botswana_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "botswana")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::botswana::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::botswana::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::botswana::NidError::Format(_) => NidFormatError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Peru DNI operations.
+fn peru_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "peru")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::peru::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::peru::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::peru::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::peru::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Ecuador cédula operations.
+fn ecuador_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "ecuador")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::ecuador::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::ecuador::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::ecuador::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::ecuador::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Venezuela cédula operations.
+fn venezuela_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "venezuela")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::venezuela::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::venezuela::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::venezuela::NidError::Format(_) => NidFormatError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Colombia identity number operations.
+fn colombia_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "colombia")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::colombia::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::colombia::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::colombia::NidError::Format(_) => NidFormatError::new_err(msg),
+                nidx::colombia::NidError::Checksum => NidChecksumError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Costa Rica identity number operations.
+fn costa_rica_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "costa_rica")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::costa_rica::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::costa_rica::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::costa_rica::NidError::Format(_) => NidFormatError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
+/// Submodule for Vietnam citizen identity number operations.
+fn vietnam_module(py: Python<'_>) -> PyResult<Bound<'_, PyModule>> {
+    let m = PyModule::new(py, "vietnam")?;
+
+    #[pyfunction]
+    fn is_valid(nid: &str) -> bool {
+        nidx::vietnam::is_valid(nid)
+    }
+
+    #[pyfunction]
+    fn validate(nid: &str) -> PyResult<()> {
+        nidx::vietnam::validate(nid).map_err(|e| {
+            let msg = e.to_string();
+            match e {
+                nidx::vietnam::NidError::Format(_) => NidFormatError::new_err(msg),
+                _ => NidError::new_err(msg),
+            }
+        })
+    }
+
+    m.add_function(wrap_pyfunction!(is_valid, &m)?)?;
+    m.add_function(wrap_pyfunction!(validate, &m)?)?;
+    Ok(m)
+}
+
 #[pymodule]
 fn _nidx(m: &Bound<'_, PyModule>) -> PyResult<()> {
     let py = m.py();
@@ -139,6 +1083,78 @@ fn _nidx(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_submodule(&albania)?;
     let kosovo = kosovo_module(py)?;
     m.add_submodule(&kosovo)?;
+    let serbia = serbia_module(py)?;
+    m.add_submodule(&serbia)?;
+    let north_macedonia = north_macedonia_module(py)?;
+    m.add_submodule(&north_macedonia)?;
+    let italy = italy_module(py)?;
+    m.add_submodule(&italy)?;
+    let spain = spain_module(py)?;
+    m.add_submodule(&spain)?;
+    let portugal = portugal_module(py)?;
+    m.add_submodule(&portugal)?;
+    let france = france_module(py)?;
+    m.add_submodule(&france)?;
+    let germany = germany_module(py)?;
+    m.add_submodule(&germany)?;
+    let switzerland = switzerland_module(py)?;
+    m.add_submodule(&switzerland)?;
+    let austria = austria_module(py)?;
+    m.add_submodule(&austria)?;
+    let poland = poland_module(py)?;
+    m.add_submodule(&poland)?;
+    let czechia = czechia_module(py)?;
+    m.add_submodule(&czechia)?;
+    let ukraine = ukraine_module(py)?;
+    m.add_submodule(&ukraine)?;
+    let turkey = turkey_module(py)?;
+    m.add_submodule(&turkey)?;
+    let finland = finland_module(py)?;
+    m.add_submodule(&finland)?;
+    let latvia = latvia_module(py)?;
+    m.add_submodule(&latvia)?;
+    let ireland = ireland_module(py)?;
+    m.add_submodule(&ireland)?;
+    let chile = chile_module(py)?;
+    m.add_submodule(&chile)?;
+    let south_korea = south_korea_module(py)?;
+    m.add_submodule(&south_korea)?;
+    let malaysia = malaysia_module(py)?;
+    m.add_submodule(&malaysia)?;
+    let egypt = egypt_module(py)?;
+    m.add_submodule(&egypt)?;
+    let australia = australia_module(py)?;
+    m.add_submodule(&australia)?;
+    let belarus = belarus_module(py)?;
+    m.add_submodule(&belarus)?;
+    let russia = russia_module(py)?;
+    m.add_submodule(&russia)?;
+    let georgia = georgia_module(py)?;
+    m.add_submodule(&georgia)?;
+    let cyprus = cyprus_module(py)?;
+    m.add_submodule(&cyprus)?;
+    let uae = uae_module(py)?;
+    m.add_submodule(&uae)?;
+    let bahrain = bahrain_module(py)?;
+    m.add_submodule(&bahrain)?;
+    let kenya = kenya_module(py)?;
+    m.add_submodule(&kenya)?;
+    let zimbabwe = zimbabwe_module(py)?;
+    m.add_submodule(&zimbabwe)?;
+    let botswana = botswana_module(py)?;
+    m.add_submodule(&botswana)?;
+    let peru = peru_module(py)?;
+    m.add_submodule(&peru)?;
+    let ecuador = ecuador_module(py)?;
+    m.add_submodule(&ecuador)?;
+    let venezuela = venezuela_module(py)?;
+    m.add_submodule(&venezuela)?;
+    let colombia = colombia_module(py)?;
+    m.add_submodule(&colombia)?;
+    let costa_rica = costa_rica_module(py)?;
+    m.add_submodule(&costa_rica)?;
+    let vietnam = vietnam_module(py)?;
+    m.add_submodule(&vietnam)?;
 
     m.add_class::<PyNidInfo>()?;
     m.add("NidError", py.get_type::<NidError>())?;
@@ -1,6 +1,6 @@
 import pytest
 
-from nidx import NidInfo, albania, kosovo
+from nidx import NidInfo, albania, australia, austria, bahrain, belarus, botswana, chile, colombia, costa_rica, cyprus, czechia, ecuador, egypt, finland, france, georgia, germany, ireland, italy, kenya, kosovo, latvia, malaysia, north_macedonia, peru, poland, portugal, russia, serbia, south_korea, spain, switzerland, turkey, uae, ukraine, venezuela, vietnam, zimbabwe
 
 VALID_NID = "J00101999W"
 
@@ -154,3 +154,903 @@ class TestKosovoValidate:
     def test_bad_checksum_raises_value_error(self):
         with pytest.raises(ValueError):
             kosovo.validate("1234567890")
+
+
+# ── Serbia ───────────────────────────────────────────────────────────────────
+
+VALID_SERBIA_NID = "0101990710008"
+
+
+class TestSerbiaIsValid:
+    def test_valid(self):
+        assert serbia.is_valid(VALID_SERBIA_NID) is True
+
+    def test_invalid(self):
+        assert serbia.is_valid("0101990710009") is False
+
+    def test_empty(self):
+        assert serbia.is_valid("") is False
+
+
+class TestSerbiaValidate:
+    def test_valid(self):
+        serbia.validate(VALID_SERBIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            serbia.validate("0101990710009")
+
+
+# ── North Macedonia ──────────────────────────────────────────────────────────
+
+VALID_NORTH_MACEDONIA_NID = "0101990450006"
+
+
+class TestNorthMacedoniaIsValid:
+    def test_valid(self):
+        assert north_macedonia.is_valid(VALID_NORTH_MACEDONIA_NID) is True
+
+    def test_invalid(self):
+        assert north_macedonia.is_valid("0101990450007") is False
+
+    def test_empty(self):
+        assert north_macedonia.is_valid("") is False
+
+
+class TestNorthMacedoniaValidate:
+    def test_valid(self):
+        north_macedonia.validate(VALID_NORTH_MACEDONIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            north_macedonia.validate("0101990450007")
+
+
+# ── Italy ────────────────────────────────────────────────────────────────────
+
+VALID_ITALY_NID = "RSSMRA85T10A562S"
+
+
+class TestItalyIsValid:
+    def test_valid(self):
+        assert italy.is_valid(VALID_ITALY_NID) is True
+
+    def test_invalid(self):
+        assert italy.is_valid("RSSMRA85T10A562A") is False
+
+    def test_empty(self):
+        assert italy.is_valid("") is False
+
+
+class TestItalyValidate:
+    def test_valid(self):
+        italy.validate(VALID_ITALY_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            italy.validate("RSSMRA85T10A562A")
+
+
+# ── Spain ────────────────────────────────────────────────────────────────────
+
+VALID_SPAIN_NID = "12345678Z"
+
+
+class TestSpainIsValid:
+    def test_valid(self):
+        assert spain.is_valid(VALID_SPAIN_NID) is True
+
+    def test_invalid(self):
+        assert spain.is_valid("12345678T") is False
+
+    def test_empty(self):
+        assert spain.is_valid("") is False
+
+
+class TestSpainValidate:
+    def test_valid(self):
+        spain.validate(VALID_SPAIN_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            spain.validate("12345678T")
+
+
+# ── Portugal ─────────────────────────────────────────────────────────────────
+
+VALID_PORTUGAL_NID = "123456789ZZ1"
+
+
+class TestPortugalIsValid:
+    def test_valid(self):
+        assert portugal.is_valid(VALID_PORTUGAL_NID) is True
+
+    def test_invalid(self):
+        assert portugal.is_valid("123456789ZZ2") is False
+
+    def test_empty(self):
+        assert portugal.is_valid("") is False
+
+
+class TestPortugalValidate:
+    def test_valid(self):
+        portugal.validate(VALID_PORTUGAL_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            portugal.validate("123456789ZZ2")
+
+
+# ── France ───────────────────────────────────────────────────────────────────
+
+VALID_FRANCE_NID = "185057800608491"
+
+
+class TestFranceIsValid:
+    def test_valid(self):
+        assert france.is_valid(VALID_FRANCE_NID) is True
+
+    def test_invalid(self):
+        assert france.is_valid("185057800608492") is False
+
+    def test_empty(self):
+        assert france.is_valid("") is False
+
+
+class TestFranceValidate:
+    def test_valid(self):
+        france.validate(VALID_FRANCE_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            france.validate("185057800608492")
+
+
+# ── Germany ──────────────────────────────────────────────────────────────────
+
+VALID_GERMANY_NID = "86095742719"
+
+
+class TestGermanyIsValid:
+    def test_valid(self):
+        assert germany.is_valid(VALID_GERMANY_NID) is True
+
+    def test_invalid(self):
+        assert germany.is_valid("86095742718") is False
+
+    def test_empty(self):
+        assert germany.is_valid("") is False
+
+
+class TestGermanyValidate:
+    def test_valid(self):
+        germany.validate(VALID_GERMANY_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            germany.validate("86095742718")
+
+
+# ── Switzerland ──────────────────────────────────────────────────────────────
+
+VALID_SWITZERLAND_NID = "756.9217.0769.85"
+
+
+class TestSwitzerlandIsValid:
+    def test_valid(self):
+        assert switzerland.is_valid(VALID_SWITZERLAND_NID) is True
+
+    def test_invalid(self):
+        assert switzerland.is_valid("756.9217.0769.86") is False
+
+    def test_empty(self):
+        assert switzerland.is_valid("") is False
+
+
+class TestSwitzerlandValidate:
+    def test_valid(self):
+        switzerland.validate(VALID_SWITZERLAND_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            switzerland.validate("756.9217.0769.86")
+
+
+# ── Austria ──────────────────────────────────────────────────────────────────
+
+VALID_AUSTRIA_NID = "1237010180"
+
+
+class TestAustriaIsValid:
+    def test_valid(self):
+        assert austria.is_valid(VALID_AUSTRIA_NID) is True
+
+    def test_invalid(self):
+        assert austria.is_valid("1238010180") is False
+
+    def test_empty(self):
+        assert austria.is_valid("") is False
+
+
+class TestAustriaValidate:
+    def test_valid(self):
+        austria.validate(VALID_AUSTRIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            austria.validate("1238010180")
+
+
+# ── Poland ───────────────────────────────────────────────────────────────────
+
+VALID_POLAND_NID = "44051401359"
+
+
+class TestPolandIsValid:
+    def test_valid(self):
+        assert poland.is_valid(VALID_POLAND_NID) is True
+
+    def test_invalid(self):
+        assert poland.is_valid("44051401358") is False
+
+    def test_empty(self):
+        assert poland.is_valid("") is False
+
+
+class TestPolandValidate:
+    def test_valid(self):
+        poland.validate(VALID_POLAND_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            poland.validate("44051401358")
+
+
+# ── Czechia ──────────────────────────────────────────────────────────────────
+
+VALID_CZECHIA_NID = "7801231230"
+
+
+class TestCzechiaIsValid:
+    def test_valid(self):
+        assert czechia.is_valid(VALID_CZECHIA_NID) is True
+
+    def test_invalid(self):
+        assert czechia.is_valid("7801231231") is False
+
+    def test_empty(self):
+        assert czechia.is_valid("") is False
+
+
+class TestCzechiaValidate:
+    def test_valid(self):
+        czechia.validate(VALID_CZECHIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            czechia.validate("7801231231")
+
+
+# ── Ukraine ──────────────────────────────────────────────────────────────────
+
+VALID_UKRAINE_NID = "3287301233"
+
+
+class TestUkraineIsValid:
+    def test_valid(self):
+        assert ukraine.is_valid(VALID_UKRAINE_NID) is True
+
+    def test_invalid(self):
+        assert ukraine.is_valid("3287301234") is False
+
+    def test_empty(self):
+        assert ukraine.is_valid("") is False
+
+
+class TestUkraineValidate:
+    def test_valid(self):
+        ukraine.validate(VALID_UKRAINE_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            ukraine.validate("3287301234")
+
+
+# ── Turkey ───────────────────────────────────────────────────────────────────
+
+VALID_TURKEY_NID = "10000000146"
+
+
+class TestTurkeyIsValid:
+    def test_valid(self):
+        assert turkey.is_valid(VALID_TURKEY_NID) is True
+
+    def test_invalid(self):
+        assert turkey.is_valid("10000000147") is False
+
+    def test_empty(self):
+        assert turkey.is_valid("") is False
+
+
+class TestTurkeyValidate:
+    def test_valid(self):
+        turkey.validate(VALID_TURKEY_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            turkey.validate("10000000147")
+
+
+# ── Finland ──────────────────────────────────────────────────────────────────
+
+VALID_FINLAND_NID = "131052-308T"
+
+
+class TestFinlandIsValid:
+    def test_valid(self):
+        assert finland.is_valid(VALID_FINLAND_NID) is True
+
+    def test_invalid(self):
+        assert finland.is_valid("131052-308U") is False
+
+    def test_empty(self):
+        assert finland.is_valid("") is False
+
+
+class TestFinlandValidate:
+    def test_valid(self):
+        finland.validate(VALID_FINLAND_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            finland.validate("131052-308U")
+
+
+# ── Latvia ───────────────────────────────────────────────────────────────────
+
+VALID_LATVIA_NID = "16117519997"
+
+
+class TestLatviaIsValid:
+    def test_valid(self):
+        assert latvia.is_valid(VALID_LATVIA_NID) is True
+
+    def test_invalid(self):
+        assert latvia.is_valid("16117519996") is False
+
+    def test_empty(self):
+        assert latvia.is_valid("") is False
+
+
+class TestLatviaValidate:
+    def test_valid(self):
+        latvia.validate(VALID_LATVIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            latvia.validate("16117519996")
+
+
+# ── Ireland ──────────────────────────────────────────────────────────────────
+
+VALID_IRELAND_NID = "6433435F"
+
+
+class TestIrelandIsValid:
+    def test_valid(self):
+        assert ireland.is_valid(VALID_IRELAND_NID) is True
+
+    def test_invalid(self):
+        assert ireland.is_valid("6433435E") is False
+
+    def test_empty(self):
+        assert ireland.is_valid("") is False
+
+
+class TestIrelandValidate:
+    def test_valid(self):
+        ireland.validate(VALID_IRELAND_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            ireland.validate("6433435E")
+
+
+# ── Chile ────────────────────────────────────────────────────────────────────
+
+VALID_CHILE_NID = "12345678-5"
+
+
+class TestChileIsValid:
+    def test_valid(self):
+        assert chile.is_valid(VALID_CHILE_NID) is True
+
+    def test_invalid(self):
+        assert chile.is_valid("12345678-4") is False
+
+    def test_empty(self):
+        assert chile.is_valid("") is False
+
+
+class TestChileValidate:
+    def test_valid(self):
+        chile.validate(VALID_CHILE_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            chile.validate("12345678-4")
+
+
+# ── South Korea ──────────────────────────────────────────────────────────────
+
+VALID_SOUTH_KOREA_NID = "9001011234568"
+
+
+class TestSouthKoreaIsValid:
+    def test_valid(self):
+        assert south_korea.is_valid(VALID_SOUTH_KOREA_NID) is True
+
+    def test_invalid(self):
+        assert south_korea.is_valid("9001011234567") is False
+
+    def test_empty(self):
+        assert south_korea.is_valid("") is False
+
+
+class TestSouthKoreaValidate:
+    def test_valid(self):
+        south_korea.validate(VALID_SOUTH_KOREA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            south_korea.validate("9001011234567")
+
+
+# ── Malaysia ─────────────────────────────────────────────────────────────────
+
+VALID_MALAYSIA_NID = "850615101235"
+
+
+class TestMalaysiaIsValid:
+    def test_valid(self):
+        assert malaysia.is_valid(VALID_MALAYSIA_NID) is True
+
+    def test_invalid(self):
+        assert malaysia.is_valid("900101695678") is False
+
+    def test_empty(self):
+        assert malaysia.is_valid("") is False
+
+
+class TestMalaysiaValidate:
+    def test_valid(self):
+        malaysia.validate(VALID_MALAYSIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            malaysia.validate("900101695678")
+
+
+# ── Egypt ────────────────────────────────────────────────────────────────────
+
+VALID_EGYPT_NID = "29001010100211"
+
+
+class TestEgyptIsValid:
+    def test_valid(self):
+        assert egypt.is_valid(VALID_EGYPT_NID) is True
+
+    def test_invalid(self):
+        assert egypt.is_valid("29001010100212") is False
+
+    def test_empty(self):
+        assert egypt.is_valid("") is False
+
+
+class TestEgyptValidate:
+    def test_valid(self):
+        egypt.validate(VALID_EGYPT_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            egypt.validate("29001010100212")
+
+
+# ── Australia ────────────────────────────────────────────────────────────────
+
+VALID_AUSTRALIA_NID = "123456782"
+
+
+class TestAustraliaIsValid:
+    def test_valid(self):
+        assert australia.is_valid(VALID_AUSTRALIA_NID) is True
+
+    def test_invalid(self):
+        assert australia.is_valid("123456789") is False
+
+    def test_empty(self):
+        assert australia.is_valid("") is False
+
+
+class TestAustraliaValidate:
+    def test_valid(self):
+        australia.validate(VALID_AUSTRALIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            australia.validate("123456789")
+
+
+# ── Belarus ──────────────────────────────────────────────────────────────────
+
+VALID_BELARUS_NID = "3140391A001PB8"
+
+
+class TestBelarusIsValid:
+    def test_valid(self):
+        assert belarus.is_valid(VALID_BELARUS_NID) is True
+
+    def test_invalid(self):
+        assert belarus.is_valid("3140391A001PB7") is False
+
+    def test_empty(self):
+        assert belarus.is_valid("") is False
+
+
+class TestBelarusValidate:
+    def test_valid(self):
+        belarus.validate(VALID_BELARUS_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            belarus.validate("3140391A001PB7")
+
+
+# ── Russia ───────────────────────────────────────────────────────────────────
+
+VALID_RUSSIA_NID = "500100732259"
+
+
+class TestRussiaIsValid:
+    def test_valid(self):
+        assert russia.is_valid(VALID_RUSSIA_NID) is True
+
+    def test_invalid(self):
+        assert russia.is_valid("500100732258") is False
+
+    def test_empty(self):
+        assert russia.is_valid("") is False
+
+
+class TestRussiaValidate:
+    def test_valid(self):
+        russia.validate(VALID_RUSSIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            russia.validate("500100732258")
+
+
+# ── Georgia ──────────────────────────────────────────────────────────────────
+
+VALID_GEORGIA_NID = "01001011234"
+
+
+class TestGeorgiaIsValid:
+    def test_valid(self):
+        assert georgia.is_valid(VALID_GEORGIA_NID) is True
+
+    def test_invalid(self):
+        assert georgia.is_valid("00001011234") is False
+
+    def test_empty(self):
+        assert georgia.is_valid("") is False
+
+
+class TestGeorgiaValidate:
+    def test_valid(self):
+        georgia.validate(VALID_GEORGIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            georgia.validate("00001011234")
+
+
+# ── Cyprus ───────────────────────────────────────────────────────────────────
+
+VALID_CYPRUS_NID = "1234567"
+
+
+class TestCyprusIsValid:
+    def test_valid(self):
+        assert cyprus.is_valid(VALID_CYPRUS_NID) is True
+
+    def test_invalid(self):
+        assert cyprus.is_valid("12345X7") is False
+
+    def test_empty(self):
+        assert cyprus.is_valid("") is False
+
+
+class TestCyprusValidate:
+    def test_valid(self):
+        cyprus.validate(VALID_CYPRUS_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            cyprus.validate("12345X7")
+
+
+# ── United Arab Emirates ─────────────────────────────────────────────────────
+
+VALID_UAE_NID = "784199012345676"
+
+
+class TestUaeIsValid:
+    def test_valid(self):
+        assert uae.is_valid(VALID_UAE_NID) is True
+
+    def test_invalid(self):
+        assert uae.is_valid("784199012345677") is False
+
+    def test_empty(self):
+        assert uae.is_valid("") is False
+
+
+class TestUaeValidate:
+    def test_valid(self):
+        uae.validate(VALID_UAE_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            uae.validate("784199012345677")
+
+
+# ── Bahrain ──────────────────────────────────────────────────────────────────
+
+VALID_BAHRAIN_NID = "850612349"
+
+
+class TestBahrainIsValid:
+    def test_valid(self):
+        assert bahrain.is_valid(VALID_BAHRAIN_NID) is True
+
+    def test_invalid(self):
+        assert bahrain.is_valid("850612348") is False
+
+    def test_empty(self):
+        assert bahrain.is_valid("") is False
+
+
+class TestBahrainValidate:
+    def test_valid(self):
+        bahrain.validate(VALID_BAHRAIN_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            bahrain.validate("850612348")
+
+
+# ── Kenya ────────────────────────────────────────────────────────────────────
+
+VALID_KENYA_NID = "12345678"
+
+
+class TestKenyaIsValid:
+    def test_valid(self):
+        assert kenya.is_valid(VALID_KENYA_NID) is True
+
+    def test_invalid(self):
+        assert kenya.is_valid("123456") is False
+
+    def test_empty(self):
+        assert kenya.is_valid("") is False
+
+
+class TestKenyaValidate:
+    def test_valid(self):
+        kenya.validate(VALID_KENYA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            kenya.validate("123456")
+
+
+# ── Zimbabwe ─────────────────────────────────────────────────────────────────
+
+VALID_ZIMBABWE_NID = "63123456B42"
+
+
+class TestZimbabweIsValid:
+    def test_valid(self):
+        assert zimbabwe.is_valid(VALID_ZIMBABWE_NID) is True
+
+    def test_invalid(self):
+        assert zimbabwe.is_valid("63123456C42") is False
+
+    def test_empty(self):
+        assert zimbabwe.is_valid("") is False
+
+
+class TestZimbabweValidate:
+    def test_valid(self):
+        zimbabwe.validate(VALID_ZIMBABWE_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            zimbabwe.validate("63123456C42")
+
+
+# ── Botswana ─────────────────────────────────────────────────────────────────
+
+VALID_BOTSWANA_NID = "123412345"
+
+
+class TestBotswanaIsValid:
+    def test_valid(self):
+        assert botswana.is_valid(VALID_BOTSWANA_NID) is True
+
+    def test_invalid(self):
+        assert botswana.is_valid("123432345") is False
+
+    def test_empty(self):
+        assert botswana.is_valid("") is False
+
+
+class TestBotswanaValidate:
+    def test_valid(self):
+        botswana.validate(VALID_BOTSWANA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            botswana.validate("123432345")
+
+
+# ── Peru ─────────────────────────────────────────────────────────────────────
+
+VALID_PERU_NID = "12345678-1"
+
+
+class TestPeruIsValid:
+    def test_valid(self):
+        assert peru.is_valid(VALID_PERU_NID) is True
+
+    def test_invalid(self):
+        assert peru.is_valid("12345678-2") is False
+
+    def test_empty(self):
+        assert peru.is_valid("") is False
+
+
+class TestPeruValidate:
+    def test_valid(self):
+        peru.validate(VALID_PERU_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            peru.validate("12345678-2")
+
+
+# ── Ecuador ──────────────────────────────────────────────────────────────────
+
+VALID_ECUADOR_NID = "1710034065"
+
+
+class TestEcuadorIsValid:
+    def test_valid(self):
+        assert ecuador.is_valid(VALID_ECUADOR_NID) is True
+
+    def test_invalid(self):
+        assert ecuador.is_valid("1710034066") is False
+
+    def test_empty(self):
+        assert ecuador.is_valid("") is False
+
+
+class TestEcuadorValidate:
+    def test_valid(self):
+        ecuador.validate(VALID_ECUADOR_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            ecuador.validate("1710034066")
+
+
+# ── Venezuela ────────────────────────────────────────────────────────────────
+
+VALID_VENEZUELA_NID = "V-12345678"
+
+
+class TestVenezuelaIsValid:
+    def test_valid(self):
+        assert venezuela.is_valid(VALID_VENEZUELA_NID) is True
+
+    def test_invalid(self):
+        assert venezuela.is_valid("12345678") is False
+
+    def test_empty(self):
+        assert venezuela.is_valid("") is False
+
+
+class TestVenezuelaValidate:
+    def test_valid(self):
+        venezuela.validate(VALID_VENEZUELA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            venezuela.validate("12345678")
+
+
+# ── Colombia ─────────────────────────────────────────────────────────────────
+
+VALID_COLOMBIA_NID = "79555123"
+
+
+class TestColombiaIsValid:
+    def test_valid(self):
+        assert colombia.is_valid(VALID_COLOMBIA_NID) is True
+
+    def test_invalid(self):
+        assert colombia.is_valid("79555123-8") is False
+
+    def test_empty(self):
+        assert colombia.is_valid("") is False
+
+
+class TestColombiaValidate:
+    def test_valid(self):
+        colombia.validate(VALID_COLOMBIA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            colombia.validate("79555123-8")
+
+
+# ── Costa Rica ───────────────────────────────────────────────────────────────
+
+VALID_COSTA_RICA_NID = "1-0234-0567"
+
+
+class TestCostaRicaIsValid:
+    def test_valid(self):
+        assert costa_rica.is_valid(VALID_COSTA_RICA_NID) is True
+
+    def test_invalid(self):
+        assert costa_rica.is_valid("1-0234-056") is False
+
+    def test_empty(self):
+        assert costa_rica.is_valid("") is False
+
+
+class TestCostaRicaValidate:
+    def test_valid(self):
+        costa_rica.validate(VALID_COSTA_RICA_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            costa_rica.validate("1-0234-056")
+
+
+# ── Vietnam ──────────────────────────────────────────────────────────────────
+
+VALID_VIETNAM_NID = "001090012345"
+
+
+class TestVietnamIsValid:
+    def test_valid(self):
+        assert vietnam.is_valid(VALID_VIETNAM_NID) is True
+
+    def test_invalid(self):
+        assert vietnam.is_valid("003090012345") is False
+
+    def test_empty(self):
+        assert vietnam.is_valid("") is False
+
+
+class TestVietnamValidate:
+    def test_valid(self):
+        vietnam.validate(VALID_VIETNAM_NID)
+
+    def test_invalid_raises_value_error(self):
+        with pytest.raises(ValueError):
+            vietnam.validate("003090012345")
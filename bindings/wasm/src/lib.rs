@@ -140,3 +140,1053 @@ impl Kosovo {
         nidx::kosovo::is_valid(nid)
     }
 }
+
+// ── Serbia ──────────────────────────────────────────────────────────────────
+
+fn serbia_to_js_error(e: nidx::serbia::NidError) -> JsError {
+    let code = match &e {
+        nidx::serbia::NidError::Format(_) => "FORMAT",
+        nidx::serbia::NidError::Checksum => "CHECKSUM",
+        nidx::serbia::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Serbia JMBG operations.
+#[wasm_bindgen]
+pub struct Serbia;
+
+#[wasm_bindgen]
+impl Serbia {
+    /// Validate a Serbian JMBG. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::serbia::validate(nid).map_err(serbia_to_js_error)
+    }
+
+    /// Check whether a Serbian JMBG string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::serbia::is_valid(nid)
+    }
+}
+
+// ── North Macedonia ─────────────────────────────────────────────────────────
+
+fn north_macedonia_to_js_error(e: nidx::north_macedonia::NidError) -> JsError {
+    let code = match &e {
+        nidx::north_macedonia::NidError::Format(_) => "FORMAT",
+        nidx::north_macedonia::NidError::Checksum => "CHECKSUM",
+        nidx::north_macedonia::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for North Macedonia EMBG operations.
+#[wasm_bindgen]
+pub struct NorthMacedonia;
+
+#[wasm_bindgen]
+impl NorthMacedonia {
+    /// Validate a North Macedonian EMBG. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::north_macedonia::validate(nid).map_err(north_macedonia_to_js_error)
+    }
+
+    /// Check whether a North Macedonian EMBG string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::north_macedonia::is_valid(nid)
+    }
+}
+
+// ── Italy ───────────────────────────────────────────────────────────────────
+
+fn italy_to_js_error(e: nidx::italy::NidError) -> JsError {
+    let code = match &e {
+        nidx::italy::NidError::Format(_) => "FORMAT",
+        nidx::italy::NidError::Checksum => "CHECKSUM",
+        nidx::italy::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Italy codice fiscale operations.
+#[wasm_bindgen]
+pub struct Italy;
+
+#[wasm_bindgen]
+impl Italy {
+    /// Validate a Italian codice fiscale. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::italy::validate(nid).map_err(italy_to_js_error)
+    }
+
+    /// Check whether a Italian codice fiscale string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::italy::is_valid(nid)
+    }
+}
+
+// ── Spain ───────────────────────────────────────────────────────────────────
+
+fn spain_to_js_error(e: nidx::spain::NidError) -> JsError {
+    let code = match &e {
+        nidx::spain::NidError::Format(_) => "FORMAT",
+        nidx::spain::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Spain DNI/NIE operations.
+#[wasm_bindgen]
+pub struct Spain;
+
+#[wasm_bindgen]
+impl Spain {
+    /// Validate a Spanish DNI or NIE. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::spain::validate(nid).map_err(spain_to_js_error)
+    }
+
+    /// Check whether a Spanish DNI or NIE string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::spain::is_valid(nid)
+    }
+}
+
+// ── Portugal ────────────────────────────────────────────────────────────────
+
+fn portugal_to_js_error(e: nidx::portugal::NidError) -> JsError {
+    let code = match &e {
+        nidx::portugal::NidError::Format(_) => "FORMAT",
+        nidx::portugal::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Portugal Cartão de Cidadão number operations.
+#[wasm_bindgen]
+pub struct Portugal;
+
+#[wasm_bindgen]
+impl Portugal {
+    /// Validate a Portuguese Cartão de Cidadão number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::portugal::validate(nid).map_err(portugal_to_js_error)
+    }
+
+    /// Check whether a Portuguese Cartão de Cidadão number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::portugal::is_valid(nid)
+    }
+}
+
+// ── France ──────────────────────────────────────────────────────────────────
+
+fn france_to_js_error(e: nidx::france::NidError) -> JsError {
+    let code = match &e {
+        nidx::france::NidError::Format(_) => "FORMAT",
+        nidx::france::NidError::Checksum => "CHECKSUM",
+        nidx::france::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for France NIR operations.
+#[wasm_bindgen]
+pub struct France;
+
+#[wasm_bindgen]
+impl France {
+    /// Validate a French NIR. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::france::validate(nid).map_err(france_to_js_error)
+    }
+
+    /// Check whether a French NIR string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::france::is_valid(nid)
+    }
+}
+
+// ── Germany ─────────────────────────────────────────────────────────────────
+
+fn germany_to_js_error(e: nidx::germany::NidError) -> JsError {
+    let code = match &e {
+        nidx::germany::NidError::Format(_) => "FORMAT",
+        nidx::germany::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Germany IdNr operations.
+#[wasm_bindgen]
+pub struct Germany;
+
+#[wasm_bindgen]
+impl Germany {
+    /// Validate a German tax identification number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::germany::validate(nid).map_err(germany_to_js_error)
+    }
+
+    /// Check whether a German tax identification number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::germany::is_valid(nid)
+    }
+}
+
+// ── Switzerland ─────────────────────────────────────────────────────────────
+
+fn switzerland_to_js_error(e: nidx::switzerland::NidError) -> JsError {
+    let code = match &e {
+        nidx::switzerland::NidError::Format(_) => "FORMAT",
+        nidx::switzerland::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Switzerland AHV number operations.
+#[wasm_bindgen]
+pub struct Switzerland;
+
+#[wasm_bindgen]
+impl Switzerland {
+    /// Validate a Swiss AHV number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::switzerland::validate(nid).map_err(switzerland_to_js_error)
+    }
+
+    /// Check whether a Swiss AHV number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::switzerland::is_valid(nid)
+    }
+}
+
+// ── Austria ─────────────────────────────────────────────────────────────────
+
+fn austria_to_js_error(e: nidx::austria::NidError) -> JsError {
+    let code = match &e {
+        nidx::austria::NidError::Format(_) => "FORMAT",
+        nidx::austria::NidError::Checksum => "CHECKSUM",
+        nidx::austria::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Austria social insurance number operations.
+#[wasm_bindgen]
+pub struct Austria;
+
+#[wasm_bindgen]
+impl Austria {
+    /// Validate a Austrian social insurance number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::austria::validate(nid).map_err(austria_to_js_error)
+    }
+
+    /// Check whether a Austrian social insurance number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::austria::is_valid(nid)
+    }
+}
+
+// ── Poland ──────────────────────────────────────────────────────────────────
+
+fn poland_to_js_error(e: nidx::poland::NidError) -> JsError {
+    let code = match &e {
+        nidx::poland::NidError::Format(_) => "FORMAT",
+        nidx::poland::NidError::Checksum => "CHECKSUM",
+        nidx::poland::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Poland PESEL operations.
+#[wasm_bindgen]
+pub struct Poland;
+
+#[wasm_bindgen]
+impl Poland {
+    /// Validate a Polish PESEL. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::poland::validate(nid).map_err(poland_to_js_error)
+    }
+
+    /// Check whether a Polish PESEL string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::poland::is_valid(nid)
+    }
+}
+
+// ── Czechia ─────────────────────────────────────────────────────────────────
+
+fn czechia_to_js_error(e: nidx::czechia::NidError) -> JsError {
+    let code = match &e {
+        nidx::czechia::NidError::Format(_) => "FORMAT",
+        nidx::czechia::NidError::Checksum => "CHECKSUM",
+        nidx::czechia::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Czechia birth number operations.
+#[wasm_bindgen]
+pub struct Czechia;
+
+#[wasm_bindgen]
+impl Czechia {
+    /// Validate a Czech birth number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::czechia::validate(nid).map_err(czechia_to_js_error)
+    }
+
+    /// Check whether a Czech birth number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::czechia::is_valid(nid)
+    }
+}
+
+// ── Ukraine ─────────────────────────────────────────────────────────────────
+
+fn ukraine_to_js_error(e: nidx::ukraine::NidError) -> JsError {
+    let code = match &e {
+        nidx::ukraine::NidError::Format(_) => "FORMAT",
+        nidx::ukraine::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Ukraine RNTRC operations.
+#[wasm_bindgen]
+pub struct Ukraine;
+
+#[wasm_bindgen]
+impl Ukraine {
+    /// Validate a Ukrainian RNTRC. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::ukraine::validate(nid).map_err(ukraine_to_js_error)
+    }
+
+    /// Check whether a Ukrainian RNTRC string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::ukraine::is_valid(nid)
+    }
+}
+
+// ── Turkey ──────────────────────────────────────────────────────────────────
+
+fn turkey_to_js_error(e: nidx::turkey::NidError) -> JsError {
+    let code = match &e {
+        nidx::turkey::NidError::Format(_) => "FORMAT",
+        nidx::turkey::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Turkey TCKN operations.
+#[wasm_bindgen]
+pub struct Turkey;
+
+#[wasm_bindgen]
+impl Turkey {
+    /// Validate a Turkish identity number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::turkey::validate(nid).map_err(turkey_to_js_error)
+    }
+
+    /// Check whether a Turkish identity number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::turkey::is_valid(nid)
+    }
+}
+
+// ── Finland ─────────────────────────────────────────────────────────────────
+
+fn finland_to_js_error(e: nidx::finland::NidError) -> JsError {
+    let code = match &e {
+        nidx::finland::NidError::Format(_) => "FORMAT",
+        nidx::finland::NidError::Checksum => "CHECKSUM",
+        nidx::finland::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Finland HETU operations.
+#[wasm_bindgen]
+pub struct Finland;
+
+#[wasm_bindgen]
+impl Finland {
+    /// Validate a Finnish HETU. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::finland::validate(nid).map_err(finland_to_js_error)
+    }
+
+    /// Check whether a Finnish HETU string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::finland::is_valid(nid)
+    }
+}
+
+// ── Latvia ──────────────────────────────────────────────────────────────────
+
+fn latvia_to_js_error(e: nidx::latvia::NidError) -> JsError {
+    let code = match &e {
+        nidx::latvia::NidError::Format(_) => "FORMAT",
+        nidx::latvia::NidError::Checksum => "CHECKSUM",
+        nidx::latvia::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Latvia personal code operations.
+#[wasm_bindgen]
+pub struct Latvia;
+
+#[wasm_bindgen]
+impl Latvia {
+    /// Validate a Latvian personal code. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::latvia::validate(nid).map_err(latvia_to_js_error)
+    }
+
+    /// Check whether a Latvian personal code string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::latvia::is_valid(nid)
+    }
+}
+
+// ── Ireland ─────────────────────────────────────────────────────────────────
+
+fn ireland_to_js_error(e: nidx::ireland::NidError) -> JsError {
+    let code = match &e {
+        nidx::ireland::NidError::Format(_) => "FORMAT",
+        nidx::ireland::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Ireland PPSN operations.
+#[wasm_bindgen]
+pub struct Ireland;
+
+#[wasm_bindgen]
+impl Ireland {
+    /// Validate a Irish PPSN. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::ireland::validate(nid).map_err(ireland_to_js_error)
+    }
+
+    /// Check whether a Irish PPSN string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::ireland::is_valid(nid)
+    }
+}
+
+// ── Chile ───────────────────────────────────────────────────────────────────
+
+fn chile_to_js_error(e: nidx::chile::NidError) -> JsError {
+    let code = match &e {
+        nidx::chile::NidError::Format(_) => "FORMAT",
+        nidx::chile::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Chile RUN operations.
+#[wasm_bindgen]
+pub struct Chile;
+
+#[wasm_bindgen]
+impl Chile {
+    /// Validate a Chilean RUN. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::chile::validate(nid).map_err(chile_to_js_error)
+    }
+
+    /// Check whether a Chilean RUN string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::chile::is_valid(nid)
+    }
+}
+
+// ── South Korea ─────────────────────────────────────────────────────────────
+
+fn south_korea_to_js_error(e: nidx::south_korea::NidError) -> JsError {
+    let code = match &e {
+        nidx::south_korea::NidError::Format(_) => "FORMAT",
+        nidx::south_korea::NidError::Checksum => "CHECKSUM",
+        nidx::south_korea::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for South Korea RRN operations.
+#[wasm_bindgen]
+pub struct SouthKorea;
+
+#[wasm_bindgen]
+impl SouthKorea {
+    /// Validate a South Korean RRN. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::south_korea::validate(nid).map_err(south_korea_to_js_error)
+    }
+
+    /// Check whether a South Korean RRN string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::south_korea::is_valid(nid)
+    }
+}
+
+// ── Malaysia ────────────────────────────────────────────────────────────────
+
+fn malaysia_to_js_error(e: nidx::malaysia::NidError) -> JsError {
+    let code = match &e {
+        nidx::malaysia::NidError::Format(_) => "FORMAT",
+        nidx::malaysia::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Malaysia NRIC operations.
+#[wasm_bindgen]
+pub struct Malaysia;
+
+#[wasm_bindgen]
+impl Malaysia {
+    /// Validate a Malaysian NRIC. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::malaysia::validate(nid).map_err(malaysia_to_js_error)
+    }
+
+    /// Check whether a Malaysian NRIC string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::malaysia::is_valid(nid)
+    }
+}
+
+// ── Egypt ───────────────────────────────────────────────────────────────────
+
+fn egypt_to_js_error(e: nidx::egypt::NidError) -> JsError {
+    let code = match &e {
+        nidx::egypt::NidError::Format(_) => "FORMAT",
+        nidx::egypt::NidError::Checksum => "CHECKSUM",
+        nidx::egypt::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Egypt national ID number operations.
+#[wasm_bindgen]
+pub struct Egypt;
+
+#[wasm_bindgen]
+impl Egypt {
+    /// Validate a Egyptian national ID number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::egypt::validate(nid).map_err(egypt_to_js_error)
+    }
+
+    /// Check whether a Egyptian national ID number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::egypt::is_valid(nid)
+    }
+}
+
+// ── Australia ───────────────────────────────────────────────────────────────
+
+fn australia_to_js_error(e: nidx::australia::NidError) -> JsError {
+    let code = match &e {
+        nidx::australia::NidError::Format(_) => "FORMAT",
+        nidx::australia::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Australia TFN operations.
+#[wasm_bindgen]
+pub struct Australia;
+
+#[wasm_bindgen]
+impl Australia {
+    /// Validate a Australian TFN. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::australia::validate(nid).map_err(australia_to_js_error)
+    }
+
+    /// Check whether a Australian TFN string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::australia::is_valid(nid)
+    }
+}
+
+// ── Belarus ─────────────────────────────────────────────────────────────────
+
+fn belarus_to_js_error(e: nidx::belarus::NidError) -> JsError {
+    let code = match &e {
+        nidx::belarus::NidError::Format(_) => "FORMAT",
+        nidx::belarus::NidError::Checksum => "CHECKSUM",
+        nidx::belarus::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Belarus identification number operations.
+#[wasm_bindgen]
+pub struct Belarus;
+
+#[wasm_bindgen]
+impl Belarus {
+    /// Validate a Belarusian identification number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::belarus::validate(nid).map_err(belarus_to_js_error)
+    }
+
+    /// Check whether a Belarusian identification number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::belarus::is_valid(nid)
+    }
+}
+
+// ── Russia ──────────────────────────────────────────────────────────────────
+
+fn russia_to_js_error(e: nidx::russia::inn::InnError) -> JsError {
+    let code = match &e {
+        nidx::russia::inn::InnError::Format(_) => "FORMAT",
+        nidx::russia::inn::InnError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Russia taxpayer number operations.
+#[wasm_bindgen]
+pub struct Russia;
+
+#[wasm_bindgen]
+impl Russia {
+    /// Validate a Russian individual taxpayer number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::russia::inn::validate(nid).map_err(russia_to_js_error)
+    }
+
+    /// Check whether a Russian individual taxpayer number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::russia::inn::is_valid(nid)
+    }
+}
+
+// ── Georgia ─────────────────────────────────────────────────────────────────
+
+fn georgia_to_js_error(e: nidx::georgia::NidError) -> JsError {
+    let code = match &e {
+        nidx::georgia::NidError::Format(_) => "FORMAT",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Georgia personal number operations.
+#[wasm_bindgen]
+pub struct Georgia;
+
+#[wasm_bindgen]
+impl Georgia {
+    /// Validate a Georgian personal number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::georgia::validate(nid).map_err(georgia_to_js_error)
+    }
+
+    /// Check whether a Georgian personal number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::georgia::is_valid(nid)
+    }
+}
+
+// ── Cyprus ──────────────────────────────────────────────────────────────────
+
+fn cyprus_to_js_error(e: nidx::cyprus::NidError) -> JsError {
+    let code = match &e {
+        nidx::cyprus::NidError::Format(_) => "FORMAT",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Cyprus identity number operations.
+#[wasm_bindgen]
+pub struct Cyprus;
+
+#[wasm_bindgen]
+impl Cyprus {
+    /// Validate a Cypriot identity number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::cyprus::validate(nid).map_err(cyprus_to_js_error)
+    }
+
+    /// Check whether a Cypriot identity number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::cyprus::is_valid(nid)
+    }
+}
+
+// ── United Arab Emirates ────────────────────────────────────────────────────
+
+fn uae_to_js_error(e: nidx::uae::NidError) -> JsError {
+    let code = match &e {
+        nidx::uae::NidError::Format(_) => "FORMAT",
+        nidx::uae::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for United Arab Emirates Emirates ID operations.
+#[wasm_bindgen]
+pub struct Uae;
+
+#[wasm_bindgen]
+impl Uae {
+    /// Validate a Emirates ID. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::uae::validate(nid).map_err(uae_to_js_error)
+    }
+
+    /// Check whether a Emirates ID string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::uae::is_valid(nid)
+    }
+}
+
+// ── Bahrain ─────────────────────────────────────────────────────────────────
+
+fn bahrain_to_js_error(e: nidx::bahrain::NidError) -> JsError {
+    let code = match &e {
+        nidx::bahrain::NidError::Format(_) => "FORMAT",
+        nidx::bahrain::NidError::Checksum => "CHECKSUM",
+        nidx::bahrain::NidError::InvalidDate(_) => "INVALID_DATE",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Bahrain CPR number operations.
+#[wasm_bindgen]
+pub struct Bahrain;
+
+#[wasm_bindgen]
+impl Bahrain {
+    /// Validate a Bahraini CPR number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::bahrain::validate(nid).map_err(bahrain_to_js_error)
+    }
+
+    /// Check whether a Bahraini CPR number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::bahrain::is_valid(nid)
+    }
+}
+
+// ── Kenya ───────────────────────────────────────────────────────────────────
+
+fn kenya_to_js_error(e: nidx::kenya::NidError) -> JsError {
+    let code = match &e {
+        nidx::kenya::NidError::Format(_) => "FORMAT",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Kenya national ID number operations.
+#[wasm_bindgen]
+pub struct Kenya;
+
+#[wasm_bindgen]
+impl Kenya {
+    /// Validate a Kenyan national ID number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::kenya::validate(nid).map_err(kenya_to_js_error)
+    }
+
+    /// Check whether a Kenyan national ID number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::kenya::is_valid(nid)
+    }
+}
+
+// ── Zimbabwe ────────────────────────────────────────────────────────────────
+
+fn zimbabwe_to_js_error(e: nidx::zimbabwe::NidError) -> JsError {
+    let code = match &e {
+        nidx::zimbabwe::NidError::Format(_) => "FORMAT",
+        nidx::zimbabwe::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Zimbabwe registration number operations.
+#[wasm_bindgen]
+pub struct Zimbabwe;
+
+#[wasm_bindgen]
+impl Zimbabwe {
+    /// Validate a Zimbabwean registration number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::zimbabwe::validate(nid).map_err(zimbabwe_to_js_error)
+    }
+
+    /// Check whether a Zimbabwean registration number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::zimbabwe::is_valid(nid)
+    }
+}
+
+// ── Botswana ────────────────────────────────────────────────────────────────
+
+fn botswana_to_js_error(e: nidx::botswana::NidError) -> JsError {
+    let code = match &e {
+        nidx::botswana::NidError::Format(_) => "FORMAT",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Botswana Omang number operations.
+#[wasm_bindgen]
+pub struct Botswana;
+
+#[wasm_bindgen]
+impl Botswana {
+    /// Validate a Botswana Omang number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::botswana::validate(nid).map_err(botswana_to_js_error)
+    }
+
+    /// Check whether a Botswana Omang number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::botswana::is_valid(nid)
+    }
+}
+
+// ── Peru ────────────────────────────────────────────────────────────────────
+
+fn peru_to_js_error(e: nidx::peru::NidError) -> JsError {
+    let code = match &e {
+        nidx::peru::NidError::Format(_) => "FORMAT",
+        nidx::peru::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Peru DNI operations.
+#[wasm_bindgen]
+pub struct Peru;
+
+#[wasm_bindgen]
+impl Peru {
+    /// Validate a Peruvian DNI. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::peru::validate(nid).map_err(peru_to_js_error)
+    }
+
+    /// Check whether a Peruvian DNI string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::peru::is_valid(nid)
+    }
+}
+
+// ── Ecuador ─────────────────────────────────────────────────────────────────
+
+fn ecuador_to_js_error(e: nidx::ecuador::NidError) -> JsError {
+    let code = match &e {
+        nidx::ecuador::NidError::Format(_) => "FORMAT",
+        nidx::ecuador::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Ecuador cédula operations.
+#[wasm_bindgen]
+pub struct Ecuador;
+
+#[wasm_bindgen]
+impl Ecuador {
+    /// Validate a Ecuadorian cédula. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::ecuador::validate(nid).map_err(ecuador_to_js_error)
+    }
+
+    /// Check whether a Ecuadorian cédula string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::ecuador::is_valid(nid)
+    }
+}
+
+// ── Venezuela ───────────────────────────────────────────────────────────────
+
+fn venezuela_to_js_error(e: nidx::venezuela::NidError) -> JsError {
+    let code = match &e {
+        nidx::venezuela::NidError::Format(_) => "FORMAT",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Venezuela cédula operations.
+#[wasm_bindgen]
+pub struct Venezuela;
+
+#[wasm_bindgen]
+impl Venezuela {
+    /// Validate a Venezuelan cédula. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::venezuela::validate(nid).map_err(venezuela_to_js_error)
+    }
+
+    /// Check whether a Venezuelan cédula string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::venezuela::is_valid(nid)
+    }
+}
+
+// ── Colombia ────────────────────────────────────────────────────────────────
+
+fn colombia_to_js_error(e: nidx::colombia::NidError) -> JsError {
+    let code = match &e {
+        nidx::colombia::NidError::Format(_) => "FORMAT",
+        nidx::colombia::NidError::Checksum => "CHECKSUM",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Colombia identity number operations.
+#[wasm_bindgen]
+pub struct Colombia;
+
+#[wasm_bindgen]
+impl Colombia {
+    /// Validate a Colombian identity number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::colombia::validate(nid).map_err(colombia_to_js_error)
+    }
+
+    /// Check whether a Colombian identity number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::colombia::is_valid(nid)
+    }
+}
+
+// ── Costa Rica ──────────────────────────────────────────────────────────────
+
+fn costa_rica_to_js_error(e: nidx::costa_rica::NidError) -> JsError {
+    let code = match &e {
+        nidx::costa_rica::NidError::Format(_) => "FORMAT",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Costa Rica identity number operations.
+#[wasm_bindgen]
+pub struct CostaRica;
+
+#[wasm_bindgen]
+impl CostaRica {
+    /// Validate a Costa Rican identity number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::costa_rica::validate(nid).map_err(costa_rica_to_js_error)
+    }
+
+    /// Check whether a Costa Rican identity number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::costa_rica::is_valid(nid)
+    }
+}
+
+// ── Vietnam ─────────────────────────────────────────────────────────────────
+
+fn vietnam_to_js_error(e: nidx::vietnam::NidError) -> JsError {
+    let code = match &e {
+        nidx::vietnam::NidError::Format(_) => "FORMAT",
+        _ => "UNKNOWN",
+    };
+    JsError::new(&format!("[{code}] {e}"))
+}
+
+/// Namespace for Vietnam citizen identity number operations.
+#[wasm_bindgen]
+pub struct Vietnam;
+
+#[wasm_bindgen]
+impl Vietnam {
+    /// Validate a Vietnamese citizen identity number. Throws on invalid input.
+    pub fn validate(nid: &str) -> Result<(), JsError> {
+        nidx::vietnam::validate(nid).map_err(vietnam_to_js_error)
+    }
+
+    /// Check whether a Vietnamese citizen identity number string is valid.
+    #[wasm_bindgen(js_name = "isValid")]
+    pub fn is_valid(nid: &str) -> bool {
+        nidx::vietnam::is_valid(nid)
+    }
+}
@@ -1,4 +1,9 @@
-use nidx_wasm::{Albania, Kosovo};
+use nidx_wasm::{
+    Albania, Australia, Austria, Bahrain, Belarus, Botswana, Chile, Colombia, CostaRica, Cyprus,
+    Czechia, Ecuador, Egypt, Finland, France, Georgia, Germany, Ireland, Italy, Kenya, Kosovo,
+    Latvia, Malaysia, NorthMacedonia, Peru, Poland, Portugal, Russia, Serbia, SouthKorea, Spain,
+    Switzerland, Turkey, Uae, Ukraine, Venezuela, Vietnam, Zimbabwe,
+};
 use wasm_bindgen_test::*;
 
 #[wasm_bindgen_test]
@@ -148,3 +153,867 @@ fn kosovo_is_valid_rejects_invalid() {
     assert!(!Kosovo::is_valid(""));
     assert!(!Kosovo::is_valid("1234567890"));
 }
+
+// ── Serbia ──────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn serbia_validate_valid() {
+    Serbia::validate("0101990710008").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn serbia_validate_invalid_returns_checksum_error() {
+    let err = Serbia::validate("0101990710009").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn serbia_is_valid() {
+    assert!(Serbia::is_valid("0101990710008"));
+    assert!(!Serbia::is_valid("0101990710009"));
+    assert!(!Serbia::is_valid(""));
+}
+
+// ── North Macedonia ─────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn north_macedonia_validate_valid() {
+    NorthMacedonia::validate("0101990450006").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn north_macedonia_validate_invalid_returns_checksum_error() {
+    let err = NorthMacedonia::validate("0101990450007").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn north_macedonia_is_valid() {
+    assert!(NorthMacedonia::is_valid("0101990450006"));
+    assert!(!NorthMacedonia::is_valid("0101990450007"));
+    assert!(!NorthMacedonia::is_valid(""));
+}
+
+// ── Italy ───────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn italy_validate_valid() {
+    Italy::validate("RSSMRA85T10A562S").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn italy_validate_invalid_returns_checksum_error() {
+    let err = Italy::validate("RSSMRA85T10A562A").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn italy_is_valid() {
+    assert!(Italy::is_valid("RSSMRA85T10A562S"));
+    assert!(!Italy::is_valid("RSSMRA85T10A562A"));
+    assert!(!Italy::is_valid(""));
+}
+
+// ── Spain ───────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn spain_validate_valid() {
+    Spain::validate("12345678Z").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn spain_validate_invalid_returns_checksum_error() {
+    let err = Spain::validate("12345678T").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn spain_is_valid() {
+    assert!(Spain::is_valid("12345678Z"));
+    assert!(!Spain::is_valid("12345678T"));
+    assert!(!Spain::is_valid(""));
+}
+
+// ── Portugal ────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn portugal_validate_valid() {
+    Portugal::validate("123456789ZZ1").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn portugal_validate_invalid_returns_checksum_error() {
+    let err = Portugal::validate("123456789ZZ2").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn portugal_is_valid() {
+    assert!(Portugal::is_valid("123456789ZZ1"));
+    assert!(!Portugal::is_valid("123456789ZZ2"));
+    assert!(!Portugal::is_valid(""));
+}
+
+// ── France ──────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn france_validate_valid() {
+    France::validate("185057800608491").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn france_validate_invalid_returns_checksum_error() {
+    let err = France::validate("185057800608492").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn france_is_valid() {
+    assert!(France::is_valid("185057800608491"));
+    assert!(!France::is_valid("185057800608492"));
+    assert!(!France::is_valid(""));
+}
+
+// ── Germany ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn germany_validate_valid() {
+    Germany::validate("86095742719").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn germany_validate_invalid_returns_checksum_error() {
+    let err = Germany::validate("86095742718").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn germany_is_valid() {
+    assert!(Germany::is_valid("86095742719"));
+    assert!(!Germany::is_valid("86095742718"));
+    assert!(!Germany::is_valid(""));
+}
+
+// ── Switzerland ─────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn switzerland_validate_valid() {
+    Switzerland::validate("756.9217.0769.85").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn switzerland_validate_invalid_returns_checksum_error() {
+    let err = Switzerland::validate("756.9217.0769.86").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn switzerland_is_valid() {
+    assert!(Switzerland::is_valid("756.9217.0769.85"));
+    assert!(!Switzerland::is_valid("756.9217.0769.86"));
+    assert!(!Switzerland::is_valid(""));
+}
+
+// ── Austria ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn austria_validate_valid() {
+    Austria::validate("1237010180").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn austria_validate_invalid_returns_checksum_error() {
+    let err = Austria::validate("1238010180").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn austria_is_valid() {
+    assert!(Austria::is_valid("1237010180"));
+    assert!(!Austria::is_valid("1238010180"));
+    assert!(!Austria::is_valid(""));
+}
+
+// ── Poland ──────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn poland_validate_valid() {
+    Poland::validate("44051401359").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn poland_validate_invalid_returns_checksum_error() {
+    let err = Poland::validate("44051401358").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn poland_is_valid() {
+    assert!(Poland::is_valid("44051401359"));
+    assert!(!Poland::is_valid("44051401358"));
+    assert!(!Poland::is_valid(""));
+}
+
+// ── Czechia ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn czechia_validate_valid() {
+    Czechia::validate("7801231230").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn czechia_validate_invalid_returns_checksum_error() {
+    let err = Czechia::validate("7801231231").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn czechia_is_valid() {
+    assert!(Czechia::is_valid("7801231230"));
+    assert!(!Czechia::is_valid("7801231231"));
+    assert!(!Czechia::is_valid(""));
+}
+
+// ── Ukraine ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn ukraine_validate_valid() {
+    Ukraine::validate("3287301233").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn ukraine_validate_invalid_returns_checksum_error() {
+    let err = Ukraine::validate("3287301234").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn ukraine_is_valid() {
+    assert!(Ukraine::is_valid("3287301233"));
+    assert!(!Ukraine::is_valid("3287301234"));
+    assert!(!Ukraine::is_valid(""));
+}
+
+// ── Turkey ──────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn turkey_validate_valid() {
+    Turkey::validate("10000000146").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn turkey_validate_invalid_returns_checksum_error() {
+    let err = Turkey::validate("10000000147").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn turkey_is_valid() {
+    assert!(Turkey::is_valid("10000000146"));
+    assert!(!Turkey::is_valid("10000000147"));
+    assert!(!Turkey::is_valid(""));
+}
+
+// ── Finland ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn finland_validate_valid() {
+    Finland::validate("131052-308T").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn finland_validate_invalid_returns_checksum_error() {
+    let err = Finland::validate("131052-308U").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn finland_is_valid() {
+    assert!(Finland::is_valid("131052-308T"));
+    assert!(!Finland::is_valid("131052-308U"));
+    assert!(!Finland::is_valid(""));
+}
+
+// ── Latvia ──────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn latvia_validate_valid() {
+    Latvia::validate("16117519997").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn latvia_validate_invalid_returns_checksum_error() {
+    let err = Latvia::validate("16117519996").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn latvia_is_valid() {
+    assert!(Latvia::is_valid("16117519997"));
+    assert!(!Latvia::is_valid("16117519996"));
+    assert!(!Latvia::is_valid(""));
+}
+
+// ── Ireland ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn ireland_validate_valid() {
+    Ireland::validate("6433435F").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn ireland_validate_invalid_returns_checksum_error() {
+    let err = Ireland::validate("6433435E").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn ireland_is_valid() {
+    assert!(Ireland::is_valid("6433435F"));
+    assert!(!Ireland::is_valid("6433435E"));
+    assert!(!Ireland::is_valid(""));
+}
+
+// ── Chile ───────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn chile_validate_valid() {
+    Chile::validate("12345678-5").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn chile_validate_invalid_returns_checksum_error() {
+    let err = Chile::validate("12345678-4").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn chile_is_valid() {
+    assert!(Chile::is_valid("12345678-5"));
+    assert!(!Chile::is_valid("12345678-4"));
+    assert!(!Chile::is_valid(""));
+}
+
+// ── South Korea ─────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn south_korea_validate_valid() {
+    SouthKorea::validate("9001011234568").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn south_korea_validate_invalid_returns_checksum_error() {
+    let err = SouthKorea::validate("9001011234567").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn south_korea_is_valid() {
+    assert!(SouthKorea::is_valid("9001011234568"));
+    assert!(!SouthKorea::is_valid("9001011234567"));
+    assert!(!SouthKorea::is_valid(""));
+}
+
+// ── Malaysia ────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn malaysia_validate_valid() {
+    Malaysia::validate("850615101235").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn malaysia_validate_invalid_returns_format_error() {
+    let err = Malaysia::validate("900101695678").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn malaysia_is_valid() {
+    assert!(Malaysia::is_valid("850615101235"));
+    assert!(!Malaysia::is_valid("900101695678"));
+    assert!(!Malaysia::is_valid(""));
+}
+
+// ── Egypt ───────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn egypt_validate_valid() {
+    Egypt::validate("29001010100211").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn egypt_validate_invalid_returns_checksum_error() {
+    let err = Egypt::validate("29001010100212").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn egypt_is_valid() {
+    assert!(Egypt::is_valid("29001010100211"));
+    assert!(!Egypt::is_valid("29001010100212"));
+    assert!(!Egypt::is_valid(""));
+}
+
+// ── Australia ───────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn australia_validate_valid() {
+    Australia::validate("123456782").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn australia_validate_invalid_returns_checksum_error() {
+    let err = Australia::validate("123456789").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn australia_is_valid() {
+    assert!(Australia::is_valid("123456782"));
+    assert!(!Australia::is_valid("123456789"));
+    assert!(!Australia::is_valid(""));
+}
+
+// ── Belarus ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn belarus_validate_valid() {
+    Belarus::validate("3140391A001PB8").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn belarus_validate_invalid_returns_checksum_error() {
+    let err = Belarus::validate("3140391A001PB7").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn belarus_is_valid() {
+    assert!(Belarus::is_valid("3140391A001PB8"));
+    assert!(!Belarus::is_valid("3140391A001PB7"));
+    assert!(!Belarus::is_valid(""));
+}
+
+// ── Russia ──────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn russia_validate_valid() {
+    Russia::validate("500100732259").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn russia_validate_invalid_returns_checksum_error() {
+    let err = Russia::validate("500100732258").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn russia_is_valid() {
+    assert!(Russia::is_valid("500100732259"));
+    assert!(!Russia::is_valid("500100732258"));
+    assert!(!Russia::is_valid(""));
+}
+
+// ── Georgia ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn georgia_validate_valid() {
+    Georgia::validate("01001011234").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn georgia_validate_invalid_returns_format_error() {
+    let err = Georgia::validate("00001011234").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn georgia_is_valid() {
+    assert!(Georgia::is_valid("01001011234"));
+    assert!(!Georgia::is_valid("00001011234"));
+    assert!(!Georgia::is_valid(""));
+}
+
+// ── Cyprus ──────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn cyprus_validate_valid() {
+    Cyprus::validate("1234567").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn cyprus_validate_invalid_returns_format_error() {
+    let err = Cyprus::validate("12345X7").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn cyprus_is_valid() {
+    assert!(Cyprus::is_valid("1234567"));
+    assert!(!Cyprus::is_valid("12345X7"));
+    assert!(!Cyprus::is_valid(""));
+}
+
+// ── United Arab Emirates ────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn uae_validate_valid() {
+    Uae::validate("784199012345676").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn uae_validate_invalid_returns_checksum_error() {
+    let err = Uae::validate("784199012345677").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn uae_is_valid() {
+    assert!(Uae::is_valid("784199012345676"));
+    assert!(!Uae::is_valid("784199012345677"));
+    assert!(!Uae::is_valid(""));
+}
+
+// ── Bahrain ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn bahrain_validate_valid() {
+    Bahrain::validate("850612349").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn bahrain_validate_invalid_returns_checksum_error() {
+    let err = Bahrain::validate("850612348").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn bahrain_is_valid() {
+    assert!(Bahrain::is_valid("850612349"));
+    assert!(!Bahrain::is_valid("850612348"));
+    assert!(!Bahrain::is_valid(""));
+}
+
+// ── Kenya ───────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn kenya_validate_valid() {
+    Kenya::validate("12345678").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn kenya_validate_invalid_returns_format_error() {
+    let err = Kenya::validate("123456").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn kenya_is_valid() {
+    assert!(Kenya::is_valid("12345678"));
+    assert!(!Kenya::is_valid("123456"));
+    assert!(!Kenya::is_valid(""));
+}
+
+// ── Zimbabwe ────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn zimbabwe_validate_valid() {
+    Zimbabwe::validate("63123456B42").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn zimbabwe_validate_invalid_returns_checksum_error() {
+    let err = Zimbabwe::validate("63123456C42").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn zimbabwe_is_valid() {
+    assert!(Zimbabwe::is_valid("63123456B42"));
+    assert!(!Zimbabwe::is_valid("63123456C42"));
+    assert!(!Zimbabwe::is_valid(""));
+}
+
+// ── Botswana ────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn botswana_validate_valid() {
+    Botswana::validate("123412345").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn botswana_validate_invalid_returns_format_error() {
+    let err = Botswana::validate("123432345").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn botswana_is_valid() {
+    assert!(Botswana::is_valid("123412345"));
+    assert!(!Botswana::is_valid("123432345"));
+    assert!(!Botswana::is_valid(""));
+}
+
+// ── Peru ────────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn peru_validate_valid() {
+    Peru::validate("12345678-1").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn peru_validate_invalid_returns_checksum_error() {
+    let err = Peru::validate("12345678-2").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn peru_is_valid() {
+    assert!(Peru::is_valid("12345678-1"));
+    assert!(!Peru::is_valid("12345678-2"));
+    assert!(!Peru::is_valid(""));
+}
+
+// ── Ecuador ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn ecuador_validate_valid() {
+    Ecuador::validate("1710034065").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn ecuador_validate_invalid_returns_checksum_error() {
+    let err = Ecuador::validate("1710034066").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn ecuador_is_valid() {
+    assert!(Ecuador::is_valid("1710034065"));
+    assert!(!Ecuador::is_valid("1710034066"));
+    assert!(!Ecuador::is_valid(""));
+}
+
+// ── Venezuela ───────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn venezuela_validate_valid() {
+    Venezuela::validate("V-12345678").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn venezuela_validate_invalid_returns_format_error() {
+    let err = Venezuela::validate("12345678").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn venezuela_is_valid() {
+    assert!(Venezuela::is_valid("V-12345678"));
+    assert!(!Venezuela::is_valid("12345678"));
+    assert!(!Venezuela::is_valid(""));
+}
+
+// ── Colombia ────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn colombia_validate_valid() {
+    Colombia::validate("79555123").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn colombia_validate_invalid_returns_checksum_error() {
+    let err = Colombia::validate("79555123-8").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[CHECKSUM]"),
+        "expected CHECKSUM error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn colombia_is_valid() {
+    assert!(Colombia::is_valid("79555123"));
+    assert!(!Colombia::is_valid("79555123-8"));
+    assert!(!Colombia::is_valid(""));
+}
+
+// ── Costa Rica ──────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn costa_rica_validate_valid() {
+    CostaRica::validate("1-0234-0567").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn costa_rica_validate_invalid_returns_format_error() {
+    let err = CostaRica::validate("1-0234-056").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn costa_rica_is_valid() {
+    assert!(CostaRica::is_valid("1-0234-0567"));
+    assert!(!CostaRica::is_valid("1-0234-056"));
+    assert!(!CostaRica::is_valid(""));
+}
+
+// ── Vietnam ─────────────────────────────────────────────────────────────────
+
+#[wasm_bindgen_test]
+fn vietnam_validate_valid() {
+    Vietnam::validate("001090012345").unwrap();
+}
+
+#[wasm_bindgen_test]
+fn vietnam_validate_invalid_returns_format_error() {
+    let err = Vietnam::validate("003090012345").unwrap_err();
+    let msg = format!("{err:?}");
+    assert!(
+        msg.contains("[FORMAT]"),
+        "expected FORMAT error, got: {msg}"
+    );
+}
+
+#[wasm_bindgen_test]
+fn vietnam_is_valid() {
+    assert!(Vietnam::is_valid("001090012345"));
+    assert!(!Vietnam::is_valid("003090012345"));
+    assert!(!Vietnam::is_valid(""));
+}
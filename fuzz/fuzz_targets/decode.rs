@@ -0,0 +1,19 @@
+//! Decode arbitrary strings as every country's ID.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use nidx::Country;
+
+fuzz_target!(|input: (Country, &str)| {
+    let (country, nid) = input;
+    let decoded = nidx::decode(country, nid);
+    assert_eq!(nidx::is_valid(country, nid), decoded.is_ok());
+    assert_eq!(nidx::validate(country, nid).is_ok(), decoded.is_ok());
+
+    if let Ok(id) = decoded {
+        let canonical = nidx::normalize(country, nid);
+        let again = nidx::decode(country, &canonical).expect("canonical form decodes");
+        assert_eq!(again.summary(), id.summary());
+    }
+});
@@ -0,0 +1,23 @@
+//! Decode raw bytes through the byte-slice entry points.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|bytes: &[u8]| {
+    let albania = nidx::albania::decode_bytes(bytes);
+    assert_eq!(
+        nidx::albania::validate_bytes(bytes).is_ok(),
+        albania.is_ok()
+    );
+    if let Ok(info) = albania {
+        let nid = nidx::albania::encode(info.birthday, info.sex, info.is_national, info.serial)
+            .expect("decoded fields re-encode");
+        assert_eq!(nid.as_bytes(), bytes.to_ascii_uppercase());
+    }
+
+    if nidx::kosovo::validate_bytes(bytes).is_ok() && bytes[0] != b'9' {
+        let digits: &[u8; 9] = bytes[..9].try_into().expect("10 digits");
+        assert_eq!(bytes[9], b'0' + nidx::kosovo::check_digit(digits));
+    }
+});
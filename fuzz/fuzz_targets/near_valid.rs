@@ -0,0 +1,61 @@
+//! Decode valid IDs after a few byte-level edits.
+//!
+//! Random strings rarely get past the length and character checks, so this
+//! target starts from a valid ID and mutates it, reaching the date, region
+//! and checksum logic far more often.
+
+#![no_main]
+
+use arbitrary::Arbitrary;
+use libfuzzer_sys::fuzz_target;
+use nidx::arbitrary::ValidNid;
+
+#[derive(Debug, Arbitrary)]
+enum Edit {
+    Replace { at: usize, byte: u8 },
+    Insert { at: usize, byte: u8 },
+    Remove { at: usize },
+    Swap { at: usize },
+    Increment { at: usize },
+}
+
+impl Edit {
+    fn apply(&self, nid: &mut Vec<u8>) {
+        if nid.is_empty() {
+            if let Edit::Insert { byte, .. } = *self {
+                nid.push(byte);
+            }
+            return;
+        }
+        let len = nid.len();
+        match *self {
+            Edit::Replace { at, byte } => nid[at % len] = byte,
+            Edit::Insert { at, byte } => nid.insert(at % (len + 1), byte),
+            Edit::Remove { at } => {
+                nid.remove(at % len);
+            }
+            Edit::Swap { at } => nid.swap(at % len, (at + 1) % len),
+            Edit::Increment { at } => nid[at % len] = nid[at % len].wrapping_add(1),
+        }
+    }
+}
+
+fuzz_target!(|input: (ValidNid, Vec<Edit>)| {
+    let (ValidNid { country, nid }, edits) = input;
+    assert!(nidx::is_valid(country, &nid), "{country}: {nid}");
+
+    let mut bytes = nid.into_bytes();
+    for edit in edits.iter().take(4) {
+        edit.apply(&mut bytes);
+    }
+    let Ok(mutated) = String::from_utf8(bytes) else {
+        return;
+    };
+
+    let decoded = nidx::decode(country, &mutated);
+    assert_eq!(nidx::is_valid(country, &mutated), decoded.is_ok());
+    if let Err(err) = decoded {
+        assert!(!err.error_code().is_empty());
+        assert!(!err.to_string().is_empty());
+    }
+});
@@ -0,0 +1,149 @@
+//! [arbitrary](https://docs.rs/arbitrary) support for fuzzing.
+//!
+//! With the `arbitrary` feature, [`Country`], [`Date`], [`Sex`] and
+//! [`albania::NidInfo`] implement [`Arbitrary`], so cargo-fuzz targets can
+//! derive structured inputs from the fuzzer's bytes. Generated values are
+//! well-formed: dates exist in the calendar, and an Albanian `NidInfo` can be
+//! encoded and has the month code that [`albania::decode`] would report.
+//! [`ValidNid`] produces an ID that passes validation for an arbitrary
+//! country.
+//!
+//! This module requires the `arbitrary` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use arbitrary::{Arbitrary, Unstructured};
+//! use nidx::arbitrary::ValidNid;
+//!
+//! let bytes = [7u8; 32];
+//! let mut u = Unstructured::new(&bytes);
+//! let ValidNid { country, nid } = ValidNid::arbitrary(&mut u).unwrap();
+//! assert!(nidx::is_valid(country, &nid));
+//! ```
+
+use ::arbitrary::{Arbitrary, Result, Unstructured};
+
+use crate::date::{civil_from_days, days_from_civil};
+use crate::{Country, Date, Sex, albania};
+
+/// Earliest date produced by the [`Date`] implementation.
+const EARLIEST: Date = Date {
+    year: 1800,
+    month: 1,
+    day: 1,
+};
+
+/// Latest date produced by the [`Date`] implementation.
+const LATEST: Date = Date {
+    year: 2099,
+    month: 12,
+    day: 31,
+};
+
+/// An ID that passes validation for its country.
+///
+/// The ID is produced by
+/// [`generate_seeded`](crate::generator::generate_seeded) from a seed read
+/// from the input, so the same bytes always give the same ID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidNid {
+    /// The issuing country.
+    pub country: Country,
+    /// The ID, in canonical form.
+    pub nid: String,
+}
+
+impl<'a> Arbitrary<'a> for ValidNid {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let country = Country::arbitrary(u)?;
+        let seed = u64::arbitrary(u)?;
+        Ok(ValidNid {
+            country,
+            nid: crate::generator::generate_seeded(country, seed, 0),
+        })
+    }
+}
+
+impl<'a> Arbitrary<'a> for Country {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(Country::ALL).copied()
+    }
+}
+
+/// Any valid calendar date from 1800-01-01 to 2099-12-31, the range the
+/// decoders produce.
+impl<'a> Arbitrary<'a> for Date {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let days = u.int_in_range(days_from_civil(EARLIEST)..=days_from_civil(LATEST))?;
+        Ok(civil_from_days(days))
+    }
+}
+
+impl<'a> Arbitrary<'a> for Sex {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        u.choose(&[Sex::Male, Sex::Female, Sex::Other, Sex::Unspecified])
+            .copied()
+    }
+}
+
+/// Fields that [`albania::encode`] accepts, with the matching month code.
+impl<'a> Arbitrary<'a> for albania::NidInfo {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let birthday = Date::arbitrary(u)?;
+        let sex = if bool::arbitrary(u)? {
+            Sex::Female
+        } else {
+            Sex::Male
+        };
+        let is_national = bool::arbitrary(u)?;
+        let serial = u.int_in_range(0..=999)?;
+        let nid = albania::encode(birthday, sex, is_national, serial)
+            .expect("the date range is encodable");
+        Ok(albania::decode(&nid).expect("encoded NIDs decode"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn each_input(mut check: impl FnMut(&mut Unstructured<'_>)) {
+        let mut state = 1u32;
+        for len in 0..64 {
+            let bytes: Vec<u8> = (0..len)
+                .map(|_| {
+                    state = state.wrapping_mul(1_103_515_245).wrapping_add(12_345);
+                    (state >> 16) as u8
+                })
+                .collect();
+            check(&mut Unstructured::new(&bytes));
+        }
+    }
+
+    #[test]
+    fn valid_nids_validate() {
+        each_input(|u| {
+            let ValidNid { country, nid } = ValidNid::arbitrary(u).unwrap();
+            assert!(crate::is_valid(country, &nid), "{country}: {nid}");
+        });
+    }
+
+    #[test]
+    fn dates_exist() {
+        each_input(|u| {
+            let date = Date::arbitrary(u).unwrap();
+            assert!((EARLIEST..=LATEST).contains(&date));
+            assert!(crate::date::validate_date(date.year, date.month, date.day).is_some());
+        });
+    }
+
+    #[test]
+    fn albania_info_roundtrips() {
+        each_input(|u| {
+            let info = albania::NidInfo::arbitrary(u).unwrap();
+            let nid = albania::encode(info.birthday, info.sex, info.is_national, info.serial);
+            assert_eq!(albania::decode(&nid.unwrap()), Ok(info));
+        });
+    }
+}
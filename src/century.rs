@@ -0,0 +1,124 @@
+use crate::Date;
+
+/// How a two-digit year is expanded to a full year.
+///
+/// Formats such as the Italian codice fiscale, the French NIR or the
+/// Malaysian NRIC record only the last two digits of the birth year, so the
+/// century has to be inferred. The decoders of those countries read the
+/// policy from [`ParseOptions::century`](crate::ParseOptions::century);
+/// their plain `decode` functions use [`CenturyPolicy::DEFAULT`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{CenturyPolicy, Date, ParseOptions};
+///
+/// assert_eq!(CenturyPolicy::DEFAULT.resolve(29), 2029);
+/// assert_eq!(CenturyPolicy::DEFAULT.resolve(30), 1930);
+///
+/// // Births cannot be in the future: read years as falling in the
+/// // 100 years up to today.
+/// let today = Date { year: 2026, month: 10, day: 16 };
+/// let policy = CenturyPolicy::sliding_window(today);
+/// assert_eq!(policy.resolve(26), 2026);
+/// assert_eq!(policy.resolve(27), 1927);
+///
+/// let options = ParseOptions::new().century(policy);
+/// let info = nidx::malaysia::decode_with("271215-14-5678", &options).unwrap();
+/// assert_eq!(info.birthday.year, 1927);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CenturyPolicy {
+    /// Two-digit years below the pivot are read as 20xx, the rest as 19xx.
+    Pivot(u8),
+    /// Two-digit years are read as the latest matching year not after
+    /// `reference_year`, i.e. within the 100 years ending then.
+    SlidingWindow {
+        /// The last year of the window, usually the current year.
+        reference_year: u16,
+    },
+}
+
+impl CenturyPolicy {
+    /// The policy used when none is given: years `00`–`29` are read as
+    /// 2000–2029 and `30`–`99` as 1930–1999.
+    pub const DEFAULT: Self = CenturyPolicy::Pivot(30);
+
+    /// A sliding window of the 100 years ending in the year of `reference`,
+    /// usually today.
+    #[inline]
+    #[must_use]
+    pub const fn sliding_window(reference: Date) -> Self {
+        CenturyPolicy::SlidingWindow {
+            reference_year: reference.year,
+        }
+    }
+
+    /// Expand the two-digit year `yy` (`0`–`99`) to a full year.
+    #[must_use]
+    pub const fn resolve(self, yy: u8) -> u16 {
+        let yy = yy as u16;
+        match self {
+            CenturyPolicy::Pivot(pivot) => {
+                if yy < pivot as u16 {
+                    2000 + yy
+                } else {
+                    1900 + yy
+                }
+            }
+            CenturyPolicy::SlidingWindow { reference_year } => {
+                let year = reference_year - reference_year % 100 + yy;
+                if year > reference_year {
+                    year.saturating_sub(100)
+                } else {
+                    year
+                }
+            }
+        }
+    }
+}
+
+impl Default for CenturyPolicy {
+    #[inline]
+    fn default() -> Self {
+        CenturyPolicy::DEFAULT
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn pivot_splits_centuries() {
+        let policy = CenturyPolicy::Pivot(50);
+        assert_eq!(policy.resolve(0), 2000);
+        assert_eq!(policy.resolve(49), 2049);
+        assert_eq!(policy.resolve(50), 1950);
+        assert_eq!(policy.resolve(99), 1999);
+        assert_eq!(CenturyPolicy::Pivot(0).resolve(0), 1900);
+    }
+
+    #[test]
+    fn sliding_window_ends_at_reference_year() {
+        let policy = CenturyPolicy::SlidingWindow {
+            reference_year: 2026,
+        };
+        assert_eq!(policy.resolve(0), 2000);
+        assert_eq!(policy.resolve(26), 2026);
+        assert_eq!(policy.resolve(27), 1927);
+        assert_eq!(policy.resolve(99), 1999);
+
+        let policy = CenturyPolicy::SlidingWindow {
+            reference_year: 2000,
+        };
+        assert_eq!(policy.resolve(0), 2000);
+        assert_eq!(policy.resolve(1), 1901);
+    }
+
+    #[test]
+    fn default_matches_historic_pivot() {
+        assert_eq!(CenturyPolicy::default(), CenturyPolicy::Pivot(30));
+    }
+}
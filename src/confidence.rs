@@ -0,0 +1,54 @@
+use std::fmt;
+
+/// How far an ID got through validation, as returned by
+/// [`score`](crate::score) for any country and by the `score` functions of
+/// the Albania and Kosovo modules.
+///
+/// Grades are ordered from weakest to strongest, so pipelines can compare
+/// them (`confidence >= Confidence::Checksum`) or map them to weights.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Confidence, Date, Plausibility};
+///
+/// let check = Plausibility::new(Date { year: 2026, month: 10, day: 16 });
+/// assert_eq!(nidx::albania::score("J00101999W", &check), Confidence::Plausible);
+/// assert_eq!(nidx::albania::score("J00101999A", &check), Confidence::Structure);
+/// assert_eq!(nidx::albania::score("invalid", &check), Confidence::Invalid);
+/// assert!(Confidence::Plausible > Confidence::Checksum);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+pub enum Confidence {
+    /// The input does not have the shape of an ID: wrong length, illegal
+    /// characters or fields that cannot be decoded.
+    Invalid,
+    /// The input has the shape of an ID, but its check character does not
+    /// match.
+    Structure,
+    /// The input is a valid ID, but the data it encodes is implausible, for
+    /// example a birthday in the future.
+    Checksum,
+    /// The input is a valid ID and nothing it encodes is implausible.
+    Plausible,
+    /// The input is a valid, plausible ID whose serial lies in a range known
+    /// to have been issued. Only `albania::serial_ranges::score` (with the
+    /// `serial-ranges` feature) returns it, since it needs a table of issued
+    /// serials.
+    Issued,
+}
+
+impl fmt::Display for Confidence {
+    /// Formats as a lowercase word, e.g. `"plausible"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Confidence::Invalid => "invalid",
+            Confidence::Structure => "structure",
+            Confidence::Checksum => "checksum",
+            Confidence::Plausible => "plausible",
+            Confidence::Issued => "issued",
+        })
+    }
+}
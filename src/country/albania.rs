@@ -28,9 +28,21 @@
 //! ```
 
 use std::fmt;
+use std::iter::FusedIterator;
 
 use crate::date::validate_date;
-use crate::{Date, Sex};
+use crate::placeholder::is_trivial_sequence;
+use crate::suggest::suggest;
+use crate::types::FoundByte;
+use crate::{
+    CharClass, Confidence, Country, Date, EncodeId, MatchReport, Metadata, NationalId,
+    ParseOptions, Plausibility, Run, Sex, Suggestion, Summary, ValidationReport,
+};
+
+pub mod nipt;
+pub mod passport;
+#[cfg(feature = "serial-ranges")]
+pub mod serial_ranges;
 
 /// Maps decade character position to base year: index * 10 + 1800.
 const DECADE_CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRST";
@@ -38,6 +50,23 @@ const DECADE_CHARS: &[u8] = b"0123456789ABCDEFGHIJKLMNOPQRST";
 /// Alphabet used for checksum computation and the 10th (check) character.
 const CHECKSUM_CHARS: &[u8] = b"WABCDEFGHIJKLMNOPQRSTUV";
 
+/// Static facts about the Albanian NID format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Albania,
+    name: "Albania",
+    alpha2: "AL",
+    alpha3: "ALB",
+    min_length: 10,
+    max_length: 10,
+    shapes: &[&[
+        Run::one(CharClass::Alphanumeric),
+        Run::exactly(CharClass::Digit, 8),
+        Run::one(CharClass::Letter),
+    ]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
 /// Decode a two-digit month code into (offset, sex, is_national).
 ///
 /// Month codes encode both the calendar month and sex/national status:
@@ -45,7 +74,7 @@ const CHECKSUM_CHARS: &[u8] = b"WABCDEFGHIJKLMNOPQRSTUV";
 /// - `31`–`42` = male foreigner (offset 30)
 /// - `51`–`62` = female Albanian (offset 50)
 /// - `81`–`92` = female foreigner (offset 80)
-fn decode_month_code(code: u8) -> Option<(u8, Sex, bool)> {
+const fn decode_month_code(code: u8) -> Option<(u8, Sex, bool)> {
     match code {
         1..=12 => Some((0, Sex::Male, true)),
         31..=42 => Some((30, Sex::Male, false)),
@@ -65,6 +94,41 @@ pub struct NidInfo {
     pub sex: Sex,
     /// `true` if the NID holder is an Albanian national, `false` for foreign residents.
     pub is_national: bool,
+    /// The three-digit serial (characters 7–9) distinguishing NIDs issued for
+    /// the same birthday, sex and national status.
+    pub serial: u16,
+    /// The raw two-digit month code (characters 3–4), which combines the
+    /// calendar month with sex and national status.
+    pub month_code: u8,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::albania::decode("J00101999W").unwrap().summary();
+    /// assert_eq!(summary.sex, Some(nidx::Sex::Male));
+    /// assert_eq!(summary.is_national, Some(true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: Some(self.is_national),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex> <national|foreign>"`, e.g. `"1990-01-01 M national"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
 }
 
 /// Specific reason a NID was rejected due to formatting.
@@ -74,11 +138,20 @@ pub enum FormatKind {
     /// Input is not exactly 10 characters.
     InvalidLength,
     /// The first (decade) character is not in the valid range `0`–`9`, `A`–`T`.
-    InvalidDecadeChar,
+    InvalidDecadeChar {
+        /// The offending byte, uppercased if it is an ASCII letter.
+        found: u8,
+    },
     /// Characters at positions 2–9 are not all ASCII digits.
-    NonDigitCharacter,
+    NonDigitCharacter {
+        /// The first offending byte, uppercased if it is an ASCII letter.
+        found: u8,
+    },
     /// The checksum (10th) character is not in the checksum alphabet.
-    InvalidChecksumChar,
+    InvalidChecksumChar {
+        /// The offending byte, uppercased if it is an ASCII letter.
+        found: u8,
+    },
     /// The two-digit month code does not map to any known range.
     InvalidMonthCode {
         /// The raw two-digit month code that was found.
@@ -91,9 +164,17 @@ impl fmt::Display for FormatKind {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         match self {
             FormatKind::InvalidLength => write!(f, "NID must be exactly 10 characters"),
-            FormatKind::InvalidDecadeChar => write!(f, "first character out of range"),
-            FormatKind::NonDigitCharacter => write!(f, "characters 2-9 must be ASCII digits"),
-            FormatKind::InvalidChecksumChar => write!(f, "invalid checksum character"),
+            FormatKind::InvalidDecadeChar { found } => {
+                write!(f, "first character out of range: {}", FoundByte(*found))
+            }
+            FormatKind::NonDigitCharacter { found } => write!(
+                f,
+                "characters 2-9 must be ASCII digits, found {}",
+                FoundByte(*found)
+            ),
+            FormatKind::InvalidChecksumChar { found } => {
+                write!(f, "invalid checksum character: {}", FoundByte(*found))
+            }
             FormatKind::InvalidMonthCode { code } => {
                 write!(f, "invalid month code: {code}")
             }
@@ -147,6 +228,63 @@ pub enum NidError {
     Checksum,
     /// The encoded date is not a valid calendar date.
     InvalidDate(DateKind),
+    /// The serial was never issued for the encoded birth year, according to
+    /// the table passed to `serial_ranges::decode` (requires the
+    /// `serial-ranges` feature).
+    UnissuedSerial {
+        /// The three-digit serial that was found.
+        serial: u16,
+    },
+    /// The NID is valid but is an obvious placeholder or a published sample.
+    /// Only returned when [`ParseOptions::reject_placeholders`] is set.
+    Placeholder,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// Codes are prefixed with `AL_` and never change meaning between
+    /// semver-compatible releases, so they can be exposed by HTTP APIs and
+    /// bindings without matching on [`Display`](fmt::Display) output:
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `AL_FORMAT_LENGTH` |
+    /// | [`FormatKind::InvalidDecadeChar`] | `AL_FORMAT_DECADE_CHAR` |
+    /// | [`FormatKind::NonDigitCharacter`] | `AL_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidChecksumChar`] | `AL_FORMAT_CHECKSUM_CHAR` |
+    /// | [`FormatKind::InvalidMonthCode`] | `AL_FORMAT_MONTH_CODE` |
+    /// | [`NidError::Checksum`] | `AL_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `AL_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `AL_DATE_DAY` |
+    /// | [`NidError::UnissuedSerial`] | `AL_UNISSUED_SERIAL` |
+    /// | [`NidError::Placeholder`] | `AL_PLACEHOLDER` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::albania::validate("short").unwrap_err();
+    /// assert_eq!(err.error_code(), "AL_FORMAT_LENGTH");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(kind) => match kind {
+                FormatKind::InvalidLength => "AL_FORMAT_LENGTH",
+                FormatKind::InvalidDecadeChar { .. } => "AL_FORMAT_DECADE_CHAR",
+                FormatKind::NonDigitCharacter { .. } => "AL_FORMAT_NON_DIGIT",
+                FormatKind::InvalidChecksumChar { .. } => "AL_FORMAT_CHECKSUM_CHAR",
+                FormatKind::InvalidMonthCode { .. } => "AL_FORMAT_MONTH_CODE",
+            },
+            NidError::Checksum => "AL_CHECKSUM",
+            NidError::InvalidDate(kind) => match kind {
+                DateKind::MonthOutOfRange { .. } => "AL_DATE_MONTH",
+                DateKind::DayOutOfRange { .. } => "AL_DATE_DAY",
+            },
+            NidError::UnissuedSerial { .. } => "AL_UNISSUED_SERIAL",
+            NidError::Placeholder => "AL_PLACEHOLDER",
+        }
+    }
 }
 
 impl fmt::Display for NidError {
@@ -156,6 +294,10 @@ impl fmt::Display for NidError {
             NidError::Format(kind) => write!(f, "format error: {kind}"),
             NidError::Checksum => write!(f, "checksum validation failed"),
             NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+            NidError::UnissuedSerial { serial } => {
+                write!(f, "serial {serial:03} was never issued for this birth year")
+            }
+            NidError::Placeholder => write!(f, "placeholder NID"),
         }
     }
 }
@@ -170,29 +312,160 @@ impl std::error::Error for NidError {
     }
 }
 
-fn verify_checksum(bytes: &[u8; 10]) -> Result<(), NidError> {
-    let check_char = bytes[9];
-    if !CHECKSUM_CHARS.contains(&check_char) {
-        return Err(NidError::Format(FormatKind::InvalidChecksumChar));
+/// Marks bytes without a value in a table built by [`value_table`].
+const NO_VALUE: u8 = u8::MAX;
+
+/// Map every byte to its index in `alphabet`, or [`NO_VALUE`].
+const fn value_table(alphabet: &[u8]) -> [u8; 256] {
+    let mut table = [NO_VALUE; 256];
+    let mut i = 0;
+    while i < alphabet.len() {
+        table[alphabet[i] as usize] = i as u8;
+        i += 1;
+    }
+    table
+}
+
+/// Value of each (uppercase) decade character.
+const DECADE_VALUES: [u8; 256] = value_table(DECADE_CHARS);
+
+/// Value of each (uppercase) check character.
+const CHECKSUM_VALUES: [u8; 256] = value_table(CHECKSUM_CHARS);
+
+/// Checksum value of each payload character, in either case: digits count
+/// as themselves, letters by their position in [`CHECKSUM_CHARS`].
+const PAYLOAD_VALUES: [u8; 256] = {
+    let mut table = CHECKSUM_VALUES;
+    let mut digit = 0;
+    while digit < 10 {
+        table[(b'0' + digit) as usize] = digit;
+        digit += 1;
+    }
+    let mut letter = b'a';
+    while letter <= b'z' {
+        table[letter as usize] = CHECKSUM_VALUES[letter.to_ascii_uppercase() as usize];
+        letter += 1;
+    }
+    table
+};
+
+/// Checksum value of each decade character, by its index in
+/// [`DECADE_CHARS`].
+const DECADE_PAYLOAD_VALUES: [u8; 30] = {
+    let mut table = [0; 30];
+    let mut i = 0;
+    while i < 30 {
+        table[i] = PAYLOAD_VALUES[DECADE_CHARS[i] as usize];
+        i += 1;
+    }
+    table
+};
+
+/// Look `c` up in a table built by [`value_table`], usable in `const`
+/// contexts.
+const fn lookup(table: &[u8; 256], c: u8) -> Option<usize> {
+    match table[c as usize] {
+        NO_VALUE => None,
+        value => Some(value as usize),
+    }
+}
+
+/// Errors that can occur when encoding an Albanian NID with [`encode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The year is outside the representable range 1800–2099.
+    YearOutOfRange {
+        /// The requested year.
+        year: u16,
+    },
+    /// The birthday is not a valid calendar date.
+    InvalidDate(DateKind),
+    /// The serial is greater than 999.
+    SerialOutOfRange {
+        /// The requested serial.
+        serial: u16,
+    },
+    /// The NID format only encodes [`Sex::Male`] and [`Sex::Female`].
+    UnsupportedSex {
+        /// The requested sex.
+        sex: Sex,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::YearOutOfRange { year } => {
+                write!(f, "year {year} is outside the range 1800-2099")
+            }
+            EncodeError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+            EncodeError::SerialOutOfRange { serial } => {
+                write!(f, "serial {serial} is greater than 999")
+            }
+            EncodeError::UnsupportedSex { sex } => {
+                write!(f, "sex {sex} cannot be encoded, only M and F")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            EncodeError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
     }
+}
 
+/// Compute the check character for the first 9 characters of a NID, in
+/// either case.
+///
+/// Returns `None` if a character has no checksum value.
+const fn check_char(payload: &[u8]) -> Option<u8> {
+    // Invalid characters are flagged rather than returned on, and the table
+    // folds case, so the loop has no branches and unrolls into straight-line
+    // table loads.
     let mut total: usize = 0;
-    for (i, &ch) in bytes[..9].iter().enumerate() {
+    let mut invalid = false;
+    let mut i = 0;
+    while i < 9 {
+        let value = PAYLOAD_VALUES[payload[i] as usize];
         // Position 0 uses weight 1 (not 0) per spec, so the decade char contributes to the checksum.
         let weight = if i == 0 { 1 } else { i };
-        let value = if ch.is_ascii_digit() {
-            (ch - b'0') as usize
-        } else {
-            CHECKSUM_CHARS
-                .iter()
-                .position(|&c| c == ch)
-                .ok_or(NidError::Format(FormatKind::InvalidChecksumChar))?
-        };
-        total += weight * value;
+        invalid |= value == NO_VALUE;
+        total += weight * value as usize;
+        i += 1;
+    }
+    if invalid {
+        return None;
+    }
+    Some(CHECKSUM_CHARS[total % 23])
+}
+
+/// Verify the check character of a 10-character NID, in either case, whose
+/// decade character has index `decade_index` and whose characters 2–9 are
+/// digits, as checked by [`check_structure`].
+///
+/// The digits count as themselves, so the sum needs a single table load for
+/// the decade character and no branches.
+const fn verify_check_char(bytes: &[u8], decade_index: usize) -> Result<(), NidError> {
+    let mut total = DECADE_PAYLOAD_VALUES[decade_index] as usize;
+    let mut i = 1;
+    while i < 9 {
+        total += i * (bytes[i] - b'0') as usize;
+        i += 1;
     }
 
-    if CHECKSUM_CHARS[total % 23] == check_char {
+    let check = bytes[9].to_ascii_uppercase();
+    if CHECKSUM_CHARS[total % 23] == check {
         Ok(())
+    } else if lookup(&CHECKSUM_VALUES, check).is_none() {
+        Err(NidError::Format(FormatKind::InvalidChecksumChar {
+            found: check,
+        }))
     } else {
         Err(NidError::Checksum)
     }
@@ -225,52 +498,369 @@ fn verify_checksum(bytes: &[u8; 10]) -> Result<(), NidError> {
 /// let err = nidx::albania::decode("invalid").unwrap_err();
 /// assert!(matches!(err, nidx::albania::NidError::Format(_)));
 /// ```
+#[inline]
 #[must_use = "this returns the decoded NID info; use `is_valid` if you only need a bool"]
-pub fn decode(nid: &str) -> Result<NidInfo, NidError> {
-    let src = nid.as_bytes();
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    decode_bytes(nid.as_bytes())
+}
+
+/// Decode an Albanian National ID given as raw bytes.
+///
+/// Behaves exactly like [`decode`] but skips UTF-8 validation, for callers
+/// reading NIDs out of fixed-width binary records or network buffers.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::albania::decode_bytes(b"J00101999W").unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// assert!(nidx::albania::decode_bytes(b"J001\xff1999W").is_err());
+/// ```
+#[must_use = "this returns the decoded NID info; use `validate_bytes` if you only need a check"]
+#[inline]
+pub const fn decode_bytes(src: &[u8]) -> Result<NidInfo, NidError> {
+    decode_impl(src, true)
+}
+
+/// Decode an Albanian National ID without verifying its check character.
+///
+/// Length, character classes, month code and date are still validated, but
+/// the 10th character is ignored entirely. Use this to re-decode NIDs that
+/// were already validated at ingest, or legacy records whose payload is
+/// known to be correct despite a bad check character. Never use it to
+/// decide whether an untrusted NID is genuine.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], except [`NidError::Checksum`] and
+/// [`FormatKind::InvalidChecksumChar`].
+///
+/// # Examples
+///
+/// ```
+/// // Wrong check character: rejected by `decode`, accepted here.
+/// assert!(nidx::albania::decode("J00101999A").is_err());
+/// let info = nidx::albania::decode_unchecked("J00101999A").unwrap();
+/// assert_eq!(info, nidx::albania::decode("J00101999W").unwrap());
+/// ```
+#[inline]
+#[must_use = "this returns the decoded NID info"]
+pub const fn decode_unchecked(nid: &str) -> Result<NidInfo, NidError> {
+    decode_impl(nid.as_bytes(), false)
+}
+
+/// Decode many Albanian National IDs, yielding one result per NID in input
+/// order.
+///
+/// Each result equals what [`decode`] returns for that NID. The iterator is
+/// lazy and keeps the length, double-ended and fused properties of `nids`,
+/// so batch callers can `zip` results with their records, `collect` them or
+/// stop at the first error. Batches should go through this function rather
+/// than a `map` over [`decode`]: faster backends, such as checking several
+/// NIDs per step, can then be added underneath without changing callers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::albania::{NidError, decode_many};
+///
+/// let rows = ["J00101999W", "J00101999A", "j00101999w"];
+/// let results: Vec<_> = decode_many(rows).collect();
+/// assert_eq!(results[0].unwrap().birthday.to_string(), "1990-01-01");
+/// assert_eq!(results[1], Err(NidError::Checksum));
+/// assert_eq!(results[2], results[0]);
+///
+/// // Stop at the first invalid NID.
+/// let all: Result<Vec<_>, _> = decode_many(rows).collect();
+/// assert_eq!(all, Err(NidError::Checksum));
+/// ```
+#[inline]
+pub fn decode_many<'a, I>(nids: I) -> DecodeMany<I::IntoIter>
+where
+    I: IntoIterator<Item = &'a str>,
+{
+    DecodeMany {
+        nids: nids.into_iter(),
+    }
+}
+
+/// Iterator over decoded NIDs, returned by [`decode_many`].
+#[derive(Debug, Clone)]
+#[must_use = "iterators are lazy and do nothing unless consumed"]
+pub struct DecodeMany<I> {
+    nids: I,
+}
+
+impl<'a, I: Iterator<Item = &'a str>> Iterator for DecodeMany<I> {
+    type Item = Result<NidInfo, NidError>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.nids.next().map(decode)
+    }
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.nids.size_hint()
+    }
+}
+
+impl<'a, I: DoubleEndedIterator<Item = &'a str>> DoubleEndedIterator for DecodeMany<I> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.nids.next_back().map(decode)
+    }
+}
+
+impl<'a, I: ExactSizeIterator<Item = &'a str>> ExactSizeIterator for DecodeMany<I> {}
+
+impl<'a, I: FusedIterator<Item = &'a str>> FusedIterator for DecodeMany<I> {}
+
+/// Check the length and the character classes of the first 9 characters,
+/// returning the index of the decade character.
+///
+/// Only the letters are case-folded, as they are read, so the input is never
+/// copied; reported characters are uppercased like the rest of the module.
+const fn check_structure(src: &[u8]) -> Result<usize, NidError> {
     if src.len() != 10 {
         return Err(NidError::Format(FormatKind::InvalidLength));
     }
-    let mut bytes = [0u8; 10];
-    bytes.copy_from_slice(src);
-    bytes.make_ascii_uppercase();
 
-    let decade_index = DECADE_CHARS
-        .iter()
-        .position(|&c| c == bytes[0])
-        .ok_or(NidError::Format(FormatKind::InvalidDecadeChar))?;
+    let decade = src[0].to_ascii_uppercase();
+    let Some(decade_index) = lookup(&DECADE_VALUES, decade) else {
+        return Err(NidError::Format(FormatKind::InvalidDecadeChar {
+            found: decade,
+        }));
+    };
+
+    let mut i = 1;
+    while i < 9 {
+        if !src[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter {
+                found: src[i].to_ascii_uppercase(),
+            }));
+        }
+        i += 1;
+    }
+    Ok(decade_index)
+}
 
-    if !bytes[1..9].iter().all(|b| b.is_ascii_digit()) {
-        return Err(NidError::Format(FormatKind::NonDigitCharacter));
+/// Check an Albanian National ID's structure and check character only.
+///
+/// The length, decade character, digits and check character are verified
+/// exactly as in [`decode`], but the month code and date are not decoded.
+/// This is the cheapest check that catches typing errors, for pre-filtering
+/// large volumes of NIDs whose decoded fields are not needed; a NID that
+/// passes may still be rejected by [`validate`].
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or illegal
+/// characters, and [`NidError::Checksum`] if the check character does not
+/// match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::verify_checksum("J00101999W").is_ok());
+/// assert_eq!(
+///     nidx::albania::verify_checksum("J00101999A"),
+///     Err(nidx::albania::NidError::Checksum)
+/// );
+///
+/// // Month code 13 does not exist, but the check character matches.
+/// let nid = nidx::albania::complete("J01301999").unwrap();
+/// assert!(nidx::albania::verify_checksum(&nid).is_ok());
+/// assert!(nidx::albania::validate(&nid).is_err());
+/// ```
+#[inline]
+pub const fn verify_checksum(nid: &str) -> Result<(), NidError> {
+    let bytes = nid.as_bytes();
+    match check_structure(bytes) {
+        Ok(decade_index) => verify_check_char(bytes, decade_index),
+        Err(e) => Err(e),
     }
+}
+
+const fn decode_impl(bytes: &[u8], verify: bool) -> Result<NidInfo, NidError> {
+    let decade_index = match check_structure(bytes) {
+        Ok(index) => index,
+        Err(e) => return Err(e),
+    };
 
-    verify_checksum(&bytes)?;
+    if verify {
+        if let Err(e) = verify_check_char(bytes, decade_index) {
+            return Err(e);
+        }
+    }
 
     let year = 1800 + (decade_index as u16 * 10) + (bytes[1] - b'0') as u16;
 
     let month_code = (bytes[2] - b'0') * 10 + (bytes[3] - b'0');
 
-    let (offset, sex, is_national) =
-        decode_month_code(month_code).ok_or(NidError::Format(FormatKind::InvalidMonthCode {
+    let Some((offset, sex, is_national)) = decode_month_code(month_code) else {
+        return Err(NidError::Format(FormatKind::InvalidMonthCode {
             code: month_code,
-        }))?;
+        }));
+    };
 
     let month = month_code - offset;
 
     let day = (bytes[4] - b'0') * 10 + (bytes[5] - b'0');
 
-    let birthday = validate_date(year, month, day).ok_or(NidError::InvalidDate(
-        if !(1..=12).contains(&month) {
+    let serial =
+        (bytes[6] - b'0') as u16 * 100 + (bytes[7] - b'0') as u16 * 10 + (bytes[8] - b'0') as u16;
+
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(if month < 1 || month > 12 {
             DateKind::MonthOutOfRange { month }
         } else {
             DateKind::DayOutOfRange { year, month, day }
-        },
-    ))?;
+        }));
+    };
 
     Ok(NidInfo {
         birthday,
         sex,
         is_national,
+        serial,
+        month_code,
+    })
+}
+
+/// Decode an Albanian National ID string after pre-processing it according
+/// to `options`.
+///
+/// With [`ParseOptions::lenient`], input such as `"J0 0101 999W\n"` is accepted.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input, and
+/// [`NidError::Placeholder`] if [`ParseOptions::reject_placeholders`] is set
+/// and the NID is a placeholder: characters 2–9 are one digit repeated or a
+/// run such as `12345678`, or the NID is a published sample number.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::albania::decode_with("J0-0101-999W", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+///
+/// let strict = ParseOptions::new().reject_placeholders(true);
+/// let nid = nidx::albania::complete("J11111111").unwrap();
+/// assert!(nidx::albania::decode(&nid).is_ok());
+/// assert_eq!(
+///     nidx::albania::decode_with(&nid, &strict),
+///     Err(nidx::albania::NidError::Placeholder)
+/// );
+/// ```
+#[must_use = "this returns the decoded NID info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    let nid = options.prepare(nid);
+    let info = decode(&nid)?;
+    if options.reject_placeholders && is_placeholder(&nid) {
+        return Err(NidError::Placeholder);
+    }
+    Ok(info)
+}
+
+/// Sample NIDs published in documentation, including this crate's.
+const PUBLISHED_SAMPLES: &[&str] = &["J00101999W"];
+
+/// Whether a valid NID is an obvious placeholder.
+fn is_placeholder(nid: &str) -> bool {
+    is_trivial_sequence(&nid.as_bytes()[1..9])
+        || PUBLISHED_SAMPLES
+            .iter()
+            .any(|sample| sample.eq_ignore_ascii_case(nid))
+}
+
+/// The result of [`decode_repaired`]: the decoded NID together with the
+/// repaired string it was decoded from.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Repaired {
+    /// The NID after substitutions, in canonical (uppercase) form.
+    pub nid: String,
+    /// Information decoded from [`nid`](Self::nid).
+    pub info: NidInfo,
+    /// Whether any character was substituted.
+    pub repaired: bool,
+}
+
+/// Undo the OCR confusions `O`→`0` and `I`/`l`→`1` at the positions where
+/// they cannot be what was printed.
+///
+/// Characters 2 to 9 of a NID are always digits, so `O`/`o` there becomes
+/// `0` and `I`/`i`/`l` becomes `1`. The check character is always a letter,
+/// so `0` there becomes `O` and `1` becomes `I`. The decade character is
+/// left alone, since both `0` and `O` are valid there.
+///
+/// Returns the repaired NID in canonical (uppercase) form and whether any
+/// character was substituted, or `None` if the input is not 10 bytes long.
+fn repair_ocr(nid: &str) -> Option<(String, bool)> {
+    let bytes: [u8; 10] = nid.as_bytes().try_into().ok()?;
+    let mut repaired = false;
+    let bytes = core::array::from_fn::<u8, 10, _>(|i| {
+        let b = bytes[i];
+        let fixed = match (i, b) {
+            (1..=8, b'O' | b'o') => b'0',
+            (1..=8, b'I' | b'i' | b'l') => b'1',
+            (9, b'0') => b'O',
+            (9, b'1') => b'I',
+            _ => return b.to_ascii_uppercase(),
+        };
+        repaired = true;
+        fixed
+    });
+    // Only ASCII bytes were substituted, so the result is still valid UTF-8.
+    let nid = String::from_utf8(bytes.to_vec()).ok()?;
+    Some((nid, repaired))
+}
+
+/// Decode an Albanian National ID read by OCR, undoing the common
+/// confusions of `O` with `0` and `I`/`l` with `1` first.
+///
+/// A confused character is only replaced where the NID format leaves no
+/// doubt: letters among the eight digits after the decade character, and
+/// `0`/`1` in the check-character position. The decade character is never
+/// changed. The repaired NID must still pass every check [`decode`] makes,
+/// including the checksum, so a repair can never turn a wrong NID into a
+/// valid one. [`Repaired::repaired`] reports whether anything was
+/// substituted, so callers can flag the record for review.
+///
+/// This is opt-in; [`decode`] and [`decode_with`] never substitute
+/// characters.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the repaired input.
+///
+/// # Examples
+///
+/// ```
+/// let fixed = nidx::albania::decode_repaired("J0OIOl999W").unwrap();
+/// assert_eq!(fixed.nid, "J00101999W");
+/// assert!(fixed.repaired);
+/// assert_eq!(fixed.info, nidx::albania::decode("J00101999W").unwrap());
+///
+/// assert!(!nidx::albania::decode_repaired("J00101999W").unwrap().repaired);
+/// ```
+#[must_use = "this returns the decoded NID info"]
+pub fn decode_repaired(nid: &str) -> Result<Repaired, NidError> {
+    let Some((nid, repaired)) = repair_ocr(nid) else {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    };
+    let info = decode(&nid)?;
+    Ok(Repaired {
+        nid,
+        info,
+        repaired,
     })
 }
 
@@ -292,74 +882,1156 @@ pub fn decode(nid: &str) -> Result<NidInfo, NidError> {
 /// assert!(nidx::albania::validate("invalid").is_err());
 /// ```
 #[inline]
-pub fn validate(nid: &str) -> Result<(), NidError> {
-    decode(nid).map(|_| ())
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
 }
 
-/// Check whether an Albanian National ID string is valid.
+/// Validate an Albanian National ID string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
 ///
-/// This is a convenience wrapper around [`validate`] that returns a simple boolean.
+/// Returns the same errors as [`validate`], for the pre-processed input.
 ///
 /// # Examples
 ///
 /// ```
-/// assert!(nidx::albania::is_valid("J00101999W"));
-/// assert!(!nidx::albania::is_valid("invalid"));
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::albania::validate_with(" J00101999W\n", &ParseOptions::lenient()).is_ok());
 /// ```
 #[inline]
-#[must_use]
-pub fn is_valid(nid: &str) -> bool {
-    validate(nid).is_ok()
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    const VALID_NID: &str = "J00101999W";
+/// Validate an Albanian National ID string, collecting every detectable issue
+/// instead of stopping at the first one.
+///
+/// Issues are listed in the order [`validate`] checks them, so the first
+/// issue is the error [`validate`] would return. A wrong length is reported
+/// on its own, since character positions are meaningless in that case. The
+/// checksum and date are only checked when the characters they depend on
+/// are well-formed.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::albania::{FormatKind, NidError};
+///
+/// assert!(nidx::albania::validate_all("J00101999W").is_valid());
+///
+/// let report = nidx::albania::validate_all("Z01301999W");
+/// assert_eq!(
+///     report.issues(),
+///     [
+///         NidError::Format(FormatKind::InvalidDecadeChar { found: b'Z' }),
+///         NidError::Format(FormatKind::InvalidMonthCode { code: 13 }),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn validate_all(nid: &str) -> ValidationReport<NidError> {
+    let mut report = ValidationReport::new();
+    let src = nid.as_bytes();
+    if src.len() != 10 {
+        report.push(NidError::Format(FormatKind::InvalidLength));
+        return report;
+    }
+    let mut bytes = [0u8; 10];
+    bytes.copy_from_slice(src);
+    bytes.make_ascii_uppercase();
 
-    /// Builds a valid NID from 9 content bytes by appending the computed checksum.
-    fn make_nid(partial: &[u8; 9]) -> String {
-        let total: usize = partial
-            .iter()
-            .enumerate()
-            .map(|(i, &ch)| {
-                let weight = if i == 0 { 1 } else { i };
-                let value = if ch.is_ascii_digit() {
-                    (ch - b'0') as usize
-                } else {
-                    CHECKSUM_CHARS.iter().position(|&c| c == ch).unwrap()
-                };
-                weight * value
-            })
-            .sum();
-        let check = CHECKSUM_CHARS[total % 23] as char;
-        format!("{}{check}", std::str::from_utf8(partial).unwrap())
+    let decade_index = lookup(&DECADE_VALUES, bytes[0]);
+    if decade_index.is_none() {
+        report.push(NidError::Format(FormatKind::InvalidDecadeChar {
+            found: bytes[0],
+        }));
     }
 
-    #[test]
-    fn decode_valid() {
-        let info = decode(VALID_NID).unwrap();
-        assert_eq!(
-            info.birthday,
-            Date {
-                year: 1990,
-                month: 1,
-                day: 1
-            }
-        );
-        assert_eq!(info.sex, Sex::Male);
-        assert!(info.is_national);
+    let non_digit = bytes[1..9].iter().find(|b| !b.is_ascii_digit());
+    if let Some(&found) = non_digit {
+        report.push(NidError::Format(FormatKind::NonDigitCharacter { found }));
     }
+    let digits_ok = non_digit.is_none();
 
-    #[test]
-    fn decode_lowercase_input() {
-        let info = decode("j00101999w").unwrap();
-        assert_eq!(info.sex, Sex::Male);
-        assert!(info.is_national);
+    if let (Some(decade_index), true) = (decade_index, digits_ok) {
+        if let Err(e) = verify_check_char(&bytes, decade_index) {
+            report.push(e);
+        }
+    } else if !CHECKSUM_CHARS.contains(&bytes[9]) {
+        report.push(NidError::Format(FormatKind::InvalidChecksumChar {
+            found: bytes[9],
+        }));
     }
 
-    #[test]
+    if !(bytes[2].is_ascii_digit() && bytes[3].is_ascii_digit()) {
+        return report;
+    }
+    let month_code = (bytes[2] - b'0') * 10 + (bytes[3] - b'0');
+    let Some((offset, _, _)) = decode_month_code(month_code) else {
+        report.push(NidError::Format(FormatKind::InvalidMonthCode {
+            code: month_code,
+        }));
+        return report;
+    };
+
+    if let Some(decade_index) = decade_index {
+        if bytes[1].is_ascii_digit() && bytes[4].is_ascii_digit() && bytes[5].is_ascii_digit() {
+            let year = 1800 + (decade_index as u16 * 10) + (bytes[1] - b'0') as u16;
+            let month = month_code - offset;
+            let day = (bytes[4] - b'0') * 10 + (bytes[5] - b'0');
+            if validate_date(year, month, day).is_none() {
+                report.push(NidError::InvalidDate(DateKind::DayOutOfRange {
+                    year,
+                    month,
+                    day,
+                }));
+            }
+        }
+    }
+
+    report
+}
+
+/// Validate an Albanian National ID given as raw bytes.
+///
+/// Behaves exactly like [`validate`] but skips UTF-8 validation.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::validate_bytes(b"J00101999W").is_ok());
+/// ```
+#[inline]
+pub const fn validate_bytes(nid: &[u8]) -> Result<(), NidError> {
+    match decode_bytes(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check whether an Albanian National ID string is valid.
+///
+/// Returns `true` exactly when [`validate`] succeeds, but runs a separate
+/// validation-only path for hot-path filtering: the digits and check
+/// character are checked without branching on each character, the checksum
+/// is compared before the date is looked at, and no [`NidInfo`] or error is
+/// built. Like [`decode`] and [`validate`], it is a `const fn`, so fixed NIDs
+/// (test fixtures, configuration constants) can be checked at compile time.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::is_valid("J00101999W"));
+/// assert!(!nidx::albania::is_valid("invalid"));
+///
+/// const _: () = assert!(nidx::albania::is_valid("J00101999W"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    let src = nid.as_bytes();
+    if src.len() != 10 {
+        return false;
+    }
+    let Some(decade_index) = lookup(&DECADE_VALUES, src[0].to_ascii_uppercase()) else {
+        return false;
+    };
+    let mut digits = true;
+    let mut total = DECADE_PAYLOAD_VALUES[decade_index] as usize;
+    let mut i = 1;
+    while i < 9 {
+        let digit = src[i].wrapping_sub(b'0');
+        digits &= digit < 10;
+        total += i * digit as usize;
+        i += 1;
+    }
+    if !digits || CHECKSUM_CHARS[total % 23] != src[9].to_ascii_uppercase() {
+        return false;
+    }
+    let month_code = (src[2] - b'0') * 10 + (src[3] - b'0');
+    let Some((offset, _, _)) = decode_month_code(month_code) else {
+        return false;
+    };
+    let year = 1800 + (decade_index as u16 * 10) + (src[1] - b'0') as u16;
+    let day = (src[4] - b'0') * 10 + (src[5] - b'0');
+    validate_date(year, month_code - offset, day).is_some()
+}
+
+/// Decode an Albanian NID and check it against declared personal data.
+///
+/// Albanian NIDs encode both the birthday and the sex, so the report never
+/// has [unverified](MatchReport::unverified) fields.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Date, Field, Sex};
+///
+/// let birthday = Date { year: 1990, month: 1, day: 1 };
+/// assert!(nidx::albania::verify_matches("J00101999W", birthday, Sex::Male).unwrap().is_full_match());
+///
+/// let report = nidx::albania::verify_matches("J00101999W", birthday, Sex::Female).unwrap();
+/// assert_eq!(report.mismatches()[0].field(), Field::Sex);
+/// ```
+pub fn verify_matches(
+    nid: &str,
+    expected_birthday: Date,
+    expected_sex: Sex,
+) -> Result<MatchReport, NidError> {
+    let info = decode(nid)?;
+    Ok(MatchReport::compare(
+        &info.summary(),
+        expected_birthday,
+        expected_sex,
+    ))
+}
+
+/// Grade how far an Albanian NID gets through validation, for scoring
+/// pipelines that want more than a yes/no answer.
+///
+/// - [`Confidence::Invalid`]: wrong length or characters, an unknown month
+///   code, a date that does not exist, or a character outside the checksum
+///   alphabet in the check position.
+/// - [`Confidence::Structure`]: well-formed, but the check character does
+///   not match.
+/// - [`Confidence::Checksum`]: valid, but `plausibility` rejects the birthday.
+/// - [`Confidence::Plausible`]: valid with a plausible birthday.
+///
+/// The NID is decoded once; the check character is compared after the
+/// structure and date are known to be sound.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Confidence, Date, Plausibility};
+///
+/// let check = Plausibility::new(Date { year: 2026, month: 10, day: 16 });
+/// assert_eq!(nidx::albania::score("J00101999W", &check), Confidence::Plausible);
+/// // Valid, but born in 1800.
+/// let old = nidx::albania::complete("000101999").unwrap();
+/// assert_eq!(nidx::albania::score(&old, &check), Confidence::Checksum);
+/// ```
+#[must_use]
+pub const fn score(nid: &str, plausibility: &Plausibility) -> Confidence {
+    match grade(nid, plausibility) {
+        Ok(_) => Confidence::Plausible,
+        Err(confidence) => confidence,
+    }
+}
+
+/// Grade a NID as [`score`] does, returning its decoded information instead
+/// when it is valid and plausible.
+const fn grade(nid: &str, plausibility: &Plausibility) -> Result<NidInfo, Confidence> {
+    let bytes = nid.as_bytes();
+    // `decode` checks the checksum before the month code and date, so
+    // establish the structure first.
+    let info = match decode_impl(bytes, false) {
+        Ok(info) => info,
+        Err(_) => return Err(Confidence::Invalid),
+    };
+    let decade_index = ((info.birthday.year - 1800) / 10) as usize;
+    match verify_check_char(bytes, decade_index) {
+        Ok(()) => {}
+        Err(NidError::Checksum) => return Err(Confidence::Structure),
+        Err(_) => return Err(Confidence::Invalid),
+    }
+    match plausibility.check(info.birthday) {
+        Ok(()) => Ok(info),
+        Err(_) => Err(Confidence::Checksum),
+    }
+}
+
+/// The character standing for a redacted position in a masked NID.
+pub const MASK_CHAR: char = '*';
+
+/// `true` if every visible character of `masked` equals the corresponding
+/// character of `value`. Both must already be uppercase.
+fn fits(masked: &[u8], value: &[u8]) -> bool {
+    masked
+        .iter()
+        .zip(value)
+        .all(|(&m, &v)| m == MASK_CHAR as u8 || m == v)
+}
+
+/// Check whether a partially redacted Albanian NID, such as `J00101***W`,
+/// could be a valid NID.
+///
+/// Masked positions are written as [`MASK_CHAR`] (`*`). The visible
+/// characters must fit the format: a decade character, digits in positions
+/// 2–9 and a letter from the checksum alphabet in position 10. There must
+/// also be a valid month code and calendar date consistent with the visible
+/// date characters. The checksum can only be verified when nothing is
+/// masked, in which case this is the same as [`is_valid`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::is_possibly_valid("J00101***W"));
+/// assert!(nidx::albania::is_possibly_valid("**********"));
+/// // Month code 13 is not assigned, whatever the hidden characters are.
+/// assert!(!nidx::albania::is_possibly_valid("J013*****W"));
+/// // No month has a 32nd day.
+/// assert!(!nidx::albania::is_possibly_valid("J0**32***W"));
+/// ```
+#[must_use]
+pub fn is_possibly_valid(masked: &str) -> bool {
+    let Ok(bytes) = <[u8; 10]>::try_from(masked.as_bytes()) else {
+        return false;
+    };
+    let mask = MASK_CHAR as u8;
+    if !bytes.contains(&mask) {
+        return is_valid(masked);
+    }
+    let bytes = bytes.map(|b| b.to_ascii_uppercase());
+    if (bytes[0] != mask && lookup(&DECADE_VALUES, bytes[0]).is_none())
+        || bytes[1..9]
+            .iter()
+            .any(|&b| b != mask && !b.is_ascii_digit())
+        || (bytes[9] != mask && lookup(&CHECKSUM_VALUES, bytes[9]).is_none())
+    {
+        return false;
+    }
+
+    // Look for a birthday consistent with the visible date characters.
+    for month_code in 1..=92u8 {
+        let Some((offset, _, _)) = decode_month_code(month_code) else {
+            continue;
+        };
+        if !fits(
+            &bytes[2..4],
+            &[b'0' + month_code / 10, b'0' + month_code % 10],
+        ) {
+            continue;
+        }
+        for day in 1..=31u8 {
+            if !fits(&bytes[4..6], &[b'0' + day / 10, b'0' + day % 10]) {
+                continue;
+            }
+            for (decade_index, &decade) in DECADE_CHARS.iter().enumerate() {
+                for year_digit in 0..10u8 {
+                    if fits(&bytes[..2], &[decade, b'0' + year_digit])
+                        && validate_date(
+                            1800 + decade_index as u16 * 10 + year_digit as u16,
+                            month_code - offset,
+                            day,
+                        )
+                        .is_some()
+                    {
+                        return true;
+                    }
+                }
+            }
+        }
+    }
+    false
+}
+
+/// Check whether a partially redacted Albanian NID is consistent with a
+/// known full NID.
+///
+/// Returns `true` if `full` is a valid NID, `masked` has the same length and
+/// every visible character of `masked` equals the character of `full` in the
+/// same position. Masked positions are written as [`MASK_CHAR`] (`*`); the
+/// comparison is case-insensitive.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::matches_pattern("J00101***W", "J00101999W"));
+/// assert!(nidx::albania::matches_pattern("j0****999w", "J00101999W"));
+/// assert!(!nidx::albania::matches_pattern("J00102***W", "J00101999W"));
+/// // The full NID must itself be valid.
+/// assert!(!nidx::albania::matches_pattern("J00101***A", "J00101999A"));
+/// ```
+#[must_use]
+pub fn matches_pattern(masked: &str, full: &str) -> bool {
+    masked.len() == full.len()
+        && is_valid(full)
+        && fits(
+            &masked.to_ascii_uppercase().into_bytes(),
+            &full.to_ascii_uppercase().into_bytes(),
+        )
+}
+
+/// Build a correctly checksummed Albanian NID from its components.
+///
+/// This is the inverse of [`decode`]: decoding the result yields the same
+/// birthday, sex, national status and serial.
+///
+/// # Errors
+///
+/// Returns [`EncodeError::YearOutOfRange`] if the year cannot be represented,
+/// [`EncodeError::InvalidDate`] if `birthday` is not a valid calendar date,
+/// [`EncodeError::SerialOutOfRange`] if `serial` exceeds 999, and
+/// [`EncodeError::UnsupportedSex`] if `sex` is neither male nor female.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Date, Sex};
+///
+/// let birthday = Date { year: 1990, month: 1, day: 1 };
+/// let nid = nidx::albania::encode(birthday, Sex::Male, true, 999).unwrap();
+/// assert_eq!(nid, "J00101999W");
+///
+/// let info = nidx::albania::decode(&nid).unwrap();
+/// assert_eq!(info.birthday, birthday);
+/// assert_eq!(info.serial, 999);
+/// ```
+pub fn encode(
+    birthday: Date,
+    sex: Sex,
+    is_national: bool,
+    serial: u16,
+) -> Result<String, EncodeError> {
+    let prefix = encode_prefix(birthday, sex, is_national)?;
+    if serial > 999 {
+        return Err(EncodeError::SerialOutOfRange { serial });
+    }
+    Ok(encode_serial(&prefix, serial))
+}
+
+/// Iterate over every valid Albanian NID for a birthday, sex and national
+/// status, in serial order `000`–`999`.
+///
+/// # Errors
+///
+/// Returns [`EncodeError::YearOutOfRange`] or [`EncodeError::InvalidDate`] if
+/// `birthday` cannot be encoded, and [`EncodeError::UnsupportedSex`] if `sex`
+/// cannot; see [`encode`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Date, Sex};
+///
+/// let birthday = Date { year: 1990, month: 1, day: 1 };
+/// let nids: Vec<String> = nidx::albania::enumerate(birthday, Sex::Male, true)
+///     .unwrap()
+///     .collect();
+/// assert_eq!(nids.len(), 1000);
+/// assert_eq!(nids[999], "J00101999W");
+/// assert!(nids.iter().all(|nid| nidx::albania::is_valid(nid)));
+/// ```
+pub fn enumerate(
+    birthday: Date,
+    sex: Sex,
+    is_national: bool,
+) -> Result<impl ExactSizeIterator<Item = String> + DoubleEndedIterator, EncodeError> {
+    let prefix = encode_prefix(birthday, sex, is_national)?;
+    Ok((0..1000).map(move |serial| encode_serial(&prefix, serial)))
+}
+
+/// Earliest birthday produced by [`generate`].
+#[cfg(feature = "rand")]
+const GENERATE_EARLIEST: Date = Date {
+    year: 1940,
+    month: 1,
+    day: 1,
+};
+
+/// Latest birthday produced by [`generate`].
+#[cfg(feature = "rand")]
+const GENERATE_LATEST: Date = Date {
+    year: 2009,
+    month: 12,
+    day: 31,
+};
+
+/// Generate a random valid Albanian NID.
+///
+/// The birthday is drawn uniformly from 1940-01-01 to 2009-12-31; sex,
+/// national status and serial are drawn uniformly. The holder is not a real
+/// person, which makes the output suitable for load tests and demo data. Use
+/// [`generate_born_between`] to choose the birthday range.
+///
+/// # Examples
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let nid = nidx::albania::generate(&mut rng);
+/// assert!(nidx::albania::is_valid(&nid));
+/// ```
+#[cfg(feature = "rand")]
+#[must_use]
+pub fn generate<R: rand::Rng + ?Sized>(rng: &mut R) -> String {
+    generate_born_between(rng, GENERATE_EARLIEST, GENERATE_LATEST)
+        .expect("the default birthday range is encodable")
+}
+
+/// Generate a random valid Albanian NID whose holder was born between
+/// `earliest` and `latest`, inclusive.
+///
+/// # Errors
+///
+/// Returns [`EncodeError::YearOutOfRange`] or [`EncodeError::InvalidDate`] if
+/// either bound cannot be encoded; see [`encode`].
+///
+/// # Panics
+///
+/// Panics if `earliest` is after `latest`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Date;
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let earliest = Date { year: 1960, month: 1, day: 1 };
+/// let latest = Date { year: 1969, month: 12, day: 31 };
+/// let nid = nidx::albania::generate_born_between(&mut rng, earliest, latest).unwrap();
+/// let birthday = nidx::albania::decode(&nid).unwrap().birthday;
+/// assert!((earliest..=latest).contains(&birthday));
+/// ```
+#[cfg(feature = "rand")]
+pub fn generate_born_between<R: rand::Rng + ?Sized>(
+    rng: &mut R,
+    earliest: Date,
+    latest: Date,
+) -> Result<String, EncodeError> {
+    use crate::date::{civil_from_days, days_from_civil};
+
+    encode_prefix(earliest, Sex::Male, true)?;
+    encode_prefix(latest, Sex::Male, true)?;
+    assert!(earliest <= latest, "`earliest` must not be after `latest`");
+
+    let days = rng.random_range(days_from_civil(earliest)..=days_from_civil(latest));
+    let sex = if rng.random_bool(0.5) {
+        Sex::Male
+    } else {
+        Sex::Female
+    };
+    let prefix = encode_prefix(civil_from_days(days), sex, rng.random_bool(0.5))?;
+    Ok(encode_serial(&prefix, rng.random_range(0..=999)))
+}
+
+/// Validate the date-dependent inputs of [`encode`] and build the first six
+/// characters (decade, year digit, month code, day).
+fn encode_prefix(birthday: Date, sex: Sex, is_national: bool) -> Result<[u8; 6], EncodeError> {
+    let Date { year, month, day } = birthday;
+    if !(1800..=2099).contains(&year) {
+        return Err(EncodeError::YearOutOfRange { year });
+    }
+    if validate_date(year, month, day).is_none() {
+        return Err(EncodeError::InvalidDate(if !(1..=12).contains(&month) {
+            DateKind::MonthOutOfRange { month }
+        } else {
+            DateKind::DayOutOfRange { year, month, day }
+        }));
+    }
+
+    let offset = match (sex, is_national) {
+        (Sex::Male, true) => 0,
+        (Sex::Male, false) => 30,
+        (Sex::Female, true) => 50,
+        (Sex::Female, false) => 80,
+        (sex, _) => return Err(EncodeError::UnsupportedSex { sex }),
+    };
+    let month_code = month + offset;
+
+    Ok([
+        DECADE_CHARS[((year - 1800) / 10) as usize],
+        b'0' + (year % 10) as u8,
+        b'0' + month_code / 10,
+        b'0' + month_code % 10,
+        b'0' + day / 10,
+        b'0' + day % 10,
+    ])
+}
+
+/// Append a three-digit serial (at most 999) and the check character to a prefix.
+fn encode_serial(prefix: &[u8; 6], serial: u16) -> String {
+    let mut payload = [0u8; 9];
+    payload[..6].copy_from_slice(prefix);
+    payload[6] = b'0' + (serial / 100) as u8;
+    payload[7] = b'0' + (serial / 10 % 10) as u8;
+    payload[8] = b'0' + (serial % 10) as u8;
+
+    let check = check_char(&payload).expect("decade chars and digits always have a checksum value");
+
+    let mut nid = String::with_capacity(10);
+    nid.extend(payload.iter().map(|&b| char::from(b)));
+    nid.push(char::from(check));
+    nid
+}
+
+/// Compute the check character for the first 9 characters of an Albanian NID.
+///
+/// Only the checksum arithmetic is applied: the decade character, month code
+/// and date are not validated. Input is case-insensitive. Returns `None` if
+/// `partial` is not exactly 9 characters or contains a character that has no
+/// checksum value.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::albania::compute_checksum("J00101999"), Some('W'));
+/// assert_eq!(nidx::albania::compute_checksum("j00101999"), Some('W'));
+/// assert_eq!(nidx::albania::compute_checksum("J0010199"), None);
+/// ```
+#[must_use]
+pub fn compute_checksum(partial: &str) -> Option<char> {
+    let bytes: [u8; 9] = partial.as_bytes().try_into().ok()?;
+    check_char(&bytes).map(char::from)
+}
+
+/// Append the correct check character to the first 9 characters of an
+/// Albanian NID, returning the complete NID in canonical (uppercase) form.
+///
+/// Like [`compute_checksum`], this does not validate the rest of the format.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::albania::complete("j00101999").as_deref(), Some("J00101999W"));
+/// assert_eq!(nidx::albania::complete("J00101999W"), None);
+/// ```
+#[must_use]
+pub fn complete(partial: &str) -> Option<String> {
+    let check = compute_checksum(partial)?;
+    let mut nid = partial.to_ascii_uppercase();
+    nid.push(check);
+    Some(nid)
+}
+
+/// Find valid NIDs one typing mistake away from a rejected one, for
+/// "did you mean" prompts in data-entry forms.
+///
+/// Tries replacing each character with every digit and uppercase letter and
+/// swapping each pair of adjacent characters, after [`normalize`]. Returns
+/// an empty list if `nid` is already valid or no single edit makes it valid.
+/// Several suggestions may be returned; present them as candidates, never
+/// apply one automatically.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Correction;
+///
+/// let suggestions = nidx::albania::suggest_corrections("J00101998W");
+/// assert!(suggestions.iter().any(|s| s.nid == "J00101999W"
+///     && s.correction == Correction::Substitution { position: 8, found: '8', replacement: '9' }));
+/// assert!(nidx::albania::suggest_corrections("J00101999W").is_empty());
+/// ```
+#[must_use]
+pub fn suggest_corrections(nid: &str) -> Vec<Suggestion> {
+    suggest(
+        &normalize(nid),
+        b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ",
+        is_valid,
+    )
+}
+
+/// Return the canonical form of an Albanian NID: surrounding whitespace
+/// removed and letters uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::albania::normalize(" j00101999w\n"), "J00101999W");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.trim().to_ascii_uppercase()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Albanian NIDs.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::albania::Albania;
+///
+/// let info = Albania::decode("J00101999W").unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Albania;
+
+impl NationalId for Albania {
+    const COUNTRY: Country = Country::Albania;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Albania {
+    type Components = NidInfo;
+
+    /// Build the NID with [`encode`], failing with
+    /// [`Inconsistent`](crate::EncodeError::Inconsistent) if `month_code` does
+    /// not match the month, sex and national status.
+    fn encode(info: NidInfo) -> Result<String, crate::EncodeError> {
+        let nid = encode(info.birthday, info.sex, info.is_national, info.serial)?;
+        if nid[2..4] != format!("{:02}", info.month_code) {
+            return Err(crate::EncodeError::Inconsistent {
+                field: "month_code",
+            });
+        }
+        Ok(nid)
+    }
+}
+
+impl From<EncodeError> for crate::EncodeError {
+    fn from(error: EncodeError) -> Self {
+        match error {
+            EncodeError::YearOutOfRange { .. } => {
+                crate::EncodeError::OutOfRange { field: "birthday" }
+            }
+            EncodeError::InvalidDate(_) => crate::EncodeError::InvalidDate,
+            EncodeError::SerialOutOfRange { .. } => {
+                crate::EncodeError::OutOfRange { field: "serial" }
+            }
+            EncodeError::UnsupportedSex { sex } => crate::EncodeError::UnsupportedSex { sex },
+        }
+    }
+}
+
+/// An Albanian NID that is known to be valid.
+///
+/// The wrapped string is stored in canonical (uppercase) form and its decoded
+/// information is kept alongside, so [`info`](AlbaniaNid::info) is free.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::albania::AlbaniaNid;
+///
+/// let nid: AlbaniaNid = "j00101999w".parse().unwrap();
+/// assert_eq!(nid.as_str(), "J00101999W");
+/// assert_eq!(nid.info().birthday.to_string(), "1990-01-01");
+///
+/// assert!("invalid".parse::<AlbaniaNid>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct AlbaniaNid {
+    nid: String,
+    info: NidInfo,
+}
+
+impl AlbaniaNid {
+    /// Validate `nid` and wrap it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`decode`].
+    pub fn new(nid: &str) -> Result<Self, NidError> {
+        let info = decode(nid)?;
+        Ok(AlbaniaNid {
+            nid: nid.to_ascii_uppercase(),
+            info,
+        })
+    }
+
+    /// The NID in canonical (uppercase) form.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.nid
+    }
+
+    /// The information decoded from the NID.
+    #[inline]
+    #[must_use]
+    pub const fn info(&self) -> NidInfo {
+        self.info
+    }
+
+    /// Unwrap the NID into its canonical string.
+    #[inline]
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.nid
+    }
+}
+
+impl fmt::Display for AlbaniaNid {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.nid)
+    }
+}
+
+impl std::str::FromStr for AlbaniaNid {
+    type Err = NidError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        AlbaniaNid::new(s)
+    }
+}
+
+impl TryFrom<&str> for AlbaniaNid {
+    type Error = NidError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        AlbaniaNid::new(s)
+    }
+}
+
+impl TryFrom<String> for AlbaniaNid {
+    type Error = NidError;
+
+    #[inline]
+    fn try_from(mut s: String) -> Result<Self, Self::Error> {
+        let info = decode(&s)?;
+        s.make_ascii_uppercase();
+        Ok(AlbaniaNid { nid: s, info })
+    }
+}
+
+impl From<AlbaniaNid> for String {
+    #[inline]
+    fn from(nid: AlbaniaNid) -> Self {
+        nid.nid
+    }
+}
+
+impl AsRef<str> for AlbaniaNid {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.nid
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_NID: &str = "J00101999W";
+
+    /// Builds a valid NID from 9 content bytes by appending the computed checksum.
+    fn make_nid(partial: &[u8; 9]) -> String {
+        let total: usize = partial
+            .iter()
+            .enumerate()
+            .map(|(i, &ch)| {
+                let weight = if i == 0 { 1 } else { i };
+                let value = if ch.is_ascii_digit() {
+                    (ch - b'0') as usize
+                } else {
+                    CHECKSUM_CHARS.iter().position(|&c| c == ch).unwrap()
+                };
+                weight * value
+            })
+            .sum();
+        let check = CHECKSUM_CHARS[total % 23] as char;
+        format!("{}{check}", std::str::from_utf8(partial).unwrap())
+    }
+
+    #[test]
+    fn decode_valid() {
+        let info = decode(VALID_NID).unwrap();
+        assert_eq!(
+            info.birthday,
+            Date {
+                year: 1990,
+                month: 1,
+                day: 1
+            }
+        );
+        assert_eq!(info.sex, Sex::Male);
+        assert!(info.is_national);
+    }
+
+    #[test]
+    fn value_tables_match_alphabets() {
+        for byte in 0..=u8::MAX {
+            let index = |alphabet: &[u8]| alphabet.iter().position(|&c| c == byte);
+            assert_eq!(lookup(&DECADE_VALUES, byte), index(DECADE_CHARS));
+            assert_eq!(lookup(&CHECKSUM_VALUES, byte), index(CHECKSUM_CHARS));
+            let payload = if byte.is_ascii_digit() {
+                Some(usize::from(byte - b'0'))
+            } else {
+                lookup(&CHECKSUM_VALUES, byte.to_ascii_uppercase())
+            };
+            assert_eq!(lookup(&PAYLOAD_VALUES, byte), payload);
+        }
+        for (index, &decade) in DECADE_CHARS.iter().enumerate() {
+            assert_eq!(
+                Some(usize::from(DECADE_PAYLOAD_VALUES[index])),
+                lookup(&PAYLOAD_VALUES, decade)
+            );
+        }
+    }
+
+    #[test]
+    fn decode_exposes_serial_and_month_code() {
+        let info = decode(VALID_NID).unwrap();
+        assert_eq!(info.serial, 999);
+        assert_eq!(info.month_code, 1);
+
+        let info = decode(&make_nid(b"J08101042")).unwrap();
+        assert_eq!(info.serial, 42);
+        assert_eq!(info.month_code, 81);
+        assert_eq!(info.birthday.month, 1);
+    }
+
+    #[test]
+    fn encode_roundtrips_through_decode() {
+        let birthday = Date {
+            year: 2004,
+            month: 2,
+            day: 29,
+        };
+        for (sex, is_national) in [
+            (Sex::Male, true),
+            (Sex::Male, false),
+            (Sex::Female, true),
+            (Sex::Female, false),
+        ] {
+            for serial in [0, 7, 42, 999] {
+                let nid = encode(birthday, sex, is_national, serial).unwrap();
+                let info = decode(&nid).unwrap();
+                assert_eq!(info.birthday, birthday);
+                assert_eq!(info.sex, sex);
+                assert_eq!(info.is_national, is_national);
+                assert_eq!(info.serial, serial);
+            }
+        }
+    }
+
+    #[test]
+    fn encode_matches_known_nids() {
+        let birthday = Date {
+            year: 1990,
+            month: 1,
+            day: 1,
+        };
+        assert_eq!(encode(birthday, Sex::Male, true, 999).unwrap(), VALID_NID);
+        assert_eq!(
+            encode(birthday, Sex::Female, false, 1).unwrap(),
+            make_nid(b"J08101001")
+        );
+    }
+
+    #[test]
+    fn encode_range_limits() {
+        let date = |year, month, day| Date { year, month, day };
+        assert!(encode(date(1800, 1, 1), Sex::Male, true, 0).is_ok());
+        assert!(encode(date(2099, 12, 31), Sex::Female, true, 999).is_ok());
+        assert_eq!(
+            encode(date(1799, 12, 31), Sex::Male, true, 0),
+            Err(EncodeError::YearOutOfRange { year: 1799 })
+        );
+        assert_eq!(
+            encode(date(2100, 1, 1), Sex::Male, true, 0),
+            Err(EncodeError::YearOutOfRange { year: 2100 })
+        );
+        assert_eq!(
+            encode(date(1990, 1, 1), Sex::Male, true, 1000),
+            Err(EncodeError::SerialOutOfRange { serial: 1000 })
+        );
+        assert_eq!(
+            encode(date(1990, 1, 1), Sex::Other, true, 0),
+            Err(EncodeError::UnsupportedSex { sex: Sex::Other })
+        );
+        assert_eq!(
+            EncodeError::UnsupportedSex {
+                sex: Sex::Unspecified
+            }
+            .to_string(),
+            "sex U cannot be encoded, only M and F"
+        );
+        assert_eq!(
+            encode(date(1990, 13, 1), Sex::Male, true, 0),
+            Err(EncodeError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            encode(date(1900, 2, 29), Sex::Male, true, 0),
+            Err(EncodeError::InvalidDate(DateKind::DayOutOfRange {
+                year: 1900,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn enumerate_yields_every_serial() {
+        let birthday = Date {
+            year: 2000,
+            month: 2,
+            day: 29,
+        };
+        let nids: Vec<_> = enumerate(birthday, Sex::Female, false).unwrap().collect();
+        assert_eq!(nids.len(), 1000);
+        for (serial, nid) in nids.iter().enumerate() {
+            let info = decode(nid).unwrap();
+            assert_eq!(info.birthday, birthday);
+            assert_eq!(info.sex, Sex::Female);
+            assert!(!info.is_national);
+            assert_eq!(usize::from(info.serial), serial);
+        }
+    }
+
+    #[test]
+    fn enumerate_rejects_invalid_birthday() {
+        let birthday = Date {
+            year: 1990,
+            month: 2,
+            day: 30,
+        };
+        assert!(matches!(
+            enumerate(birthday, Sex::Male, true),
+            Err(EncodeError::InvalidDate(_))
+        ));
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_produces_valid_nids_in_range() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let info = decode(&generate(&mut rng)).unwrap();
+            assert!((GENERATE_EARLIEST..=GENERATE_LATEST).contains(&info.birthday));
+        }
+
+        let day = Date {
+            year: 2000,
+            month: 2,
+            day: 29,
+        };
+        let nid = generate_born_between(&mut rng, day, day).unwrap();
+        assert_eq!(decode(&nid).unwrap().birthday, day);
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_born_between_rejects_unencodable_bounds() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        let earliest = Date {
+            year: 1700,
+            month: 1,
+            day: 1,
+        };
+        assert_eq!(
+            generate_born_between(&mut rng, earliest, GENERATE_LATEST),
+            Err(EncodeError::YearOutOfRange { year: 1700 })
+        );
+    }
+
+    #[test]
+    fn compute_checksum_matches_make_nid() {
+        for partial in [b"J00101999", b"J05115001", b"K00229001", b"A12345678"] {
+            let nid = make_nid(partial);
+            let partial = std::str::from_utf8(partial).unwrap();
+            assert_eq!(compute_checksum(partial), nid.chars().last());
+            assert_eq!(complete(partial), Some(nid));
+        }
+    }
+
+    #[test]
+    fn compute_checksum_rejects_bad_input() {
+        assert_eq!(compute_checksum(""), None);
+        assert_eq!(compute_checksum("J0010199"), None);
+        assert_eq!(compute_checksum("J00101999W"), None);
+        assert_eq!(compute_checksum("J0010199Z"), None);
+        assert_eq!(compute_checksum("J001019é9"), None);
+        assert_eq!(complete("J0010199-"), None);
+    }
+
+    #[test]
+    fn decode_lowercase_input() {
+        let info = decode("j00101999w").unwrap();
+        assert_eq!(info.sex, Sex::Male);
+        assert!(info.is_national);
+    }
+
+    #[test]
+    fn lowercase_input_decodes_like_uppercase() {
+        for nid in [
+            "j00101999w",
+            "j00101999a",
+            "z00101999w",
+            "j0010a999w",
+            "j001019991",
+            "j01301999w",
+        ] {
+            assert_eq!(decode(nid), decode(&nid.to_ascii_uppercase()), "{nid}");
+            assert_eq!(
+                verify_checksum(nid),
+                verify_checksum(&nid.to_ascii_uppercase()),
+                "{nid}"
+            );
+        }
+    }
+
+    #[test]
+    fn is_valid_agrees_with_validate() {
+        for decade in ["0", "J", "t"] {
+            for month_code in 0..100 {
+                for day in 0..40 {
+                    let nid = complete(&format!("{decade}8{month_code:02}{day:02}123")).unwrap();
+                    for nid in [nid.clone(), nid.replace(char::is_alphabetic, "Z")] {
+                        assert_eq!(is_valid(&nid), validate(&nid).is_ok(), "{nid}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decode_many_matches_decode() {
+        let nids = [VALID_NID, "J00101999A", "short", "j00101999w", "J01301999W"];
+        let results: Vec<_> = decode_many(nids).collect();
+        let expected: Vec<_> = nids.iter().map(|nid| decode(nid)).collect();
+        assert_eq!(results, expected);
+
+        let mut many = decode_many(nids.iter().copied());
+        assert_eq!(many.len(), 5);
+        assert_eq!(many.next_back(), Some(decode("J01301999W")));
+        assert_eq!(many.len(), 4);
+        assert_eq!(decode_many(Vec::new()).next(), None);
+    }
+
+    #[test]
     fn validate_valid() {
         assert!(validate(VALID_NID).is_ok());
     }
@@ -407,13 +2079,23 @@ mod tests {
     #[test]
     fn error_invalid_decade_char() {
         let err = decode("Z001011230").unwrap_err();
-        assert_eq!(err, NidError::Format(FormatKind::InvalidDecadeChar));
+        assert_eq!(
+            err,
+            NidError::Format(FormatKind::InvalidDecadeChar { found: b'Z' })
+        );
     }
 
     #[test]
     fn error_non_digit_middle() {
-        let err = decode("J0A101123R").unwrap_err();
-        assert_eq!(err, NidError::Format(FormatKind::NonDigitCharacter));
+        let err = decode("J0a101123R").unwrap_err();
+        assert_eq!(
+            err,
+            NidError::Format(FormatKind::NonDigitCharacter { found: b'A' })
+        );
+        assert_eq!(
+            err.to_string(),
+            "format error: characters 2-9 must be ASCII digits, found 'A'"
+        );
     }
 
     #[test]
@@ -471,12 +2153,298 @@ mod tests {
         assert!(matches!(result, Err(NidError::InvalidDate(_))));
     }
 
+    #[test]
+    fn normalize_uppercases_and_trims() {
+        assert_eq!(normalize("j00101999w"), VALID_NID);
+        assert_eq!(normalize("\t J00101999W \r\n"), VALID_NID);
+        assert_eq!(normalize(VALID_NID), VALID_NID);
+        assert!(is_valid(&normalize(" j00101999w ")));
+    }
+
+    #[test]
+    fn decode_with_lenient_strips_separators() {
+        let opts = ParseOptions::lenient();
+        let expected = decode(VALID_NID).unwrap();
+        assert_eq!(decode_with("J0 0101 999W", &opts), Ok(expected));
+        assert_eq!(decode_with("j0-0101-999w\n", &opts), Ok(expected));
+        assert!(validate_with(" J00101999W ", &opts).is_ok());
+    }
+
+    #[test]
+    fn decode_with_strict_is_default_behavior() {
+        let opts = ParseOptions::new();
+        assert_eq!(decode_with(VALID_NID, &opts), decode(VALID_NID));
+        assert_eq!(
+            decode_with("J0 0101 999W", &opts),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn decode_repaired_substitutes_digit_positions() {
+        let fixed = decode_repaired("jO0l0I999w").unwrap();
+        assert_eq!(fixed.nid, VALID_NID);
+        assert_eq!(fixed.info, decode(VALID_NID).unwrap());
+        assert!(fixed.repaired);
+
+        let clean = decode_repaired("j00101999w").unwrap();
+        assert_eq!(clean.nid, VALID_NID);
+        assert!(!clean.repaired);
+    }
+
+    #[test]
+    fn decode_repaired_substitutes_check_character() {
+        let nid = (0..1000)
+            .map(|serial| make_nid(format!("J00101{serial:03}").as_bytes().try_into().unwrap()))
+            .find(|nid| nid.ends_with('O'))
+            .unwrap();
+        let misread = format!("{}0", &nid[..9]);
+        assert_eq!(
+            decode(&misread),
+            Err(NidError::Format(FormatKind::InvalidChecksumChar {
+                found: b'0'
+            }))
+        );
+        let fixed = decode_repaired(&misread).unwrap();
+        assert_eq!(fixed.nid, nid);
+        assert!(fixed.repaired);
+    }
+
+    #[test]
+    fn decode_repaired_leaves_decade_character_and_checksum() {
+        // `0` and `O` are both valid decade characters, so neither is changed.
+        assert_eq!(decode_repaired("000101999W"), Err(NidError::Checksum));
+        // A repair never bypasses the checksum.
+        assert_eq!(decode_repaired("J00I02999W"), Err(NidError::Checksum));
+        assert_eq!(
+            decode_repaired("J0010199W"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn suggest_corrections_finds_transposition() {
+        let suggestions = suggest_corrections("j00110999w");
+        assert!(suggestions.contains(&Suggestion {
+            nid: VALID_NID.to_owned(),
+            correction: crate::Correction::Transposition { position: 4 },
+        }));
+        assert!(suggestions.iter().all(|s| is_valid(&s.nid)));
+        assert!(suggest_corrections(VALID_NID).is_empty());
+        assert!(suggest_corrections("J0010").is_empty());
+    }
+
+    #[test]
+    fn is_possibly_valid_checks_visible_characters() {
+        assert!(is_possibly_valid("J00101***W"));
+        assert!(is_possibly_valid("j0**01***w"));
+        assert!(is_possibly_valid("J00101999*"));
+        assert!(is_possibly_valid(VALID_NID));
+        assert!(!is_possibly_valid("J00101999A"));
+        assert!(!is_possibly_valid("Z00101***W"));
+        assert!(!is_possibly_valid("J0A101***W"));
+        assert!(!is_possibly_valid("J00101***X"));
+        assert!(!is_possibly_valid("J02******W"));
+        assert!(!is_possibly_valid("J00101**W"));
+    }
+
+    #[test]
+    fn is_possibly_valid_checks_date_against_visible_year() {
+        // 29 February exists in 1992 but not in 1990.
+        assert!(is_possibly_valid("J20229***W"));
+        assert!(!is_possibly_valid("J00229***W"));
+        assert!(is_possibly_valid("J*0229***W"));
+        // Month code 52 is February for a female national.
+        assert!(!is_possibly_valid("J05230***W"));
+    }
+
+    #[test]
+    fn matches_pattern_compares_visible_characters() {
+        assert!(matches_pattern("J00101***W", VALID_NID));
+        assert!(matches_pattern("**********", VALID_NID));
+        assert!(matches_pattern(VALID_NID, "j00101999w"));
+        assert!(!matches_pattern("J00101**8W", VALID_NID));
+        assert!(!matches_pattern("J00101***", VALID_NID));
+        assert!(!matches_pattern("J00101***W", "J00101999"));
+    }
+
+    #[test]
+    fn score_grades_validation_steps() {
+        let check = Plausibility::new(Date {
+            year: 2026,
+            month: 10,
+            day: 16,
+        });
+        assert_eq!(score(VALID_NID, &check), Confidence::Plausible);
+        assert_eq!(score(&make_nid(b"T00101999"), &check), Confidence::Checksum);
+        assert_eq!(score("J00101999A", &check), Confidence::Structure);
+        assert_eq!(score("J00101999X", &check), Confidence::Invalid);
+        assert_eq!(score("J01301999W", &check), Confidence::Invalid);
+        assert_eq!(score("J0010", &check), Confidence::Invalid);
+    }
+
+    #[test]
+    fn verify_checksum_skips_date_semantics() {
+        assert_eq!(verify_checksum(VALID_NID), Ok(()));
+        assert_eq!(verify_checksum("j00101999w"), Ok(()));
+        assert_eq!(verify_checksum("J00101999A"), Err(NidError::Checksum));
+        assert_eq!(
+            verify_checksum("J00101999X"),
+            Err(NidError::Format(FormatKind::InvalidChecksumChar {
+                found: b'X'
+            }))
+        );
+        assert_eq!(
+            verify_checksum("Z00101999W"),
+            Err(NidError::Format(FormatKind::InvalidDecadeChar {
+                found: b'Z'
+            }))
+        );
+        // Unknown month code and 31 February: only the checksum is verified.
+        assert_eq!(verify_checksum(&make_nid(b"J01301999")), Ok(()));
+        assert_eq!(verify_checksum(&make_nid(b"J00231999")), Ok(()));
+    }
+
+    #[test]
+    fn decode_with_rejects_placeholders_when_asked() {
+        let options = ParseOptions::lenient().reject_placeholders(true);
+        assert_eq!(decode_with(VALID_NID, &options), Err(NidError::Placeholder));
+        assert_eq!(
+            decode_with("j0 0101 999w", &options),
+            Err(NidError::Placeholder)
+        );
+        let repeated = make_nid(b"J11111111");
+        assert_eq!(decode_with(&repeated, &options), Err(NidError::Placeholder));
+        assert_eq!(
+            Albania::decode_with(&repeated, &options),
+            Err(NidError::Placeholder)
+        );
+        assert!(decode_with(&make_nid(b"J00101998"), &options).is_ok());
+        assert_eq!(decode_with("J00101999A", &options), Err(NidError::Checksum));
+        assert_eq!(NidError::Placeholder.error_code(), "AL_PLACEHOLDER");
+    }
+
+    #[test]
+    fn validate_all_valid() {
+        let report = validate_all(VALID_NID);
+        assert!(report.is_valid());
+        assert!(report.issues().is_empty());
+    }
+
+    #[test]
+    fn validate_all_wrong_length_only() {
+        let report = validate_all("Z01");
+        assert_eq!(
+            report.issues(),
+            [NidError::Format(FormatKind::InvalidLength)]
+        );
+    }
+
+    #[test]
+    fn validate_all_collects_independent_issues() {
+        let report = validate_all("Z0A301999W");
+        assert_eq!(
+            report.issues(),
+            [
+                NidError::Format(FormatKind::InvalidDecadeChar { found: b'Z' }),
+                NidError::Format(FormatKind::NonDigitCharacter { found: b'A' }),
+            ]
+        );
+
+        let report = validate_all("J0130199A1");
+        assert_eq!(
+            report.issues(),
+            [
+                NidError::Format(FormatKind::NonDigitCharacter { found: b'A' }),
+                NidError::Format(FormatKind::InvalidChecksumChar { found: b'1' }),
+                NidError::Format(FormatKind::InvalidMonthCode { code: 13 }),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_all_checksum_and_date() {
+        let report = validate_all("J90229001A");
+        assert_eq!(
+            report.issues(),
+            [
+                NidError::Checksum,
+                NidError::InvalidDate(DateKind::DayOutOfRange {
+                    year: 1999,
+                    month: 2,
+                    day: 29
+                }),
+            ]
+        );
+    }
+
+    #[test]
+    fn validate_all_first_issue_matches_validate() {
+        for nid in ["", "J00101999A", "Z0A301999W", "J0130199A1", "J00230123C"] {
+            assert_eq!(
+                validate_all(nid).first().copied(),
+                validate(nid).err(),
+                "{nid}"
+            );
+        }
+    }
+
+    #[test]
+    fn albania_nid_parses_valid() {
+        let nid: AlbaniaNid = VALID_NID.parse().unwrap();
+        assert_eq!(nid.as_str(), VALID_NID);
+        assert_eq!(nid.to_string(), VALID_NID);
+        assert_eq!(nid.info(), decode(VALID_NID).unwrap());
+        assert_eq!(String::from(nid), VALID_NID);
+    }
+
+    #[test]
+    fn albania_nid_stores_canonical_form() {
+        let nid = AlbaniaNid::try_from("j00101999w").unwrap();
+        assert_eq!(nid.as_str(), VALID_NID);
+        let nid = AlbaniaNid::try_from(String::from("j00101999W")).unwrap();
+        assert_eq!(nid.into_string(), VALID_NID);
+    }
+
+    #[test]
+    fn albania_nid_rejects_invalid() {
+        assert_eq!(
+            "invalid".parse::<AlbaniaNid>(),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(AlbaniaNid::new("J00101999A"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn national_id_impl_matches_module_functions() {
+        assert_eq!(
+            <Albania as NationalId>::decode(VALID_NID),
+            decode(VALID_NID)
+        );
+        assert!(<Albania as NationalId>::is_valid(VALID_NID));
+        assert_eq!(
+            <Albania as NationalId>::validate("invalid"),
+            validate("invalid")
+        );
+        assert_eq!(Albania::COUNTRY, Country::Albania);
+    }
+
     #[test]
     fn sex_display() {
         assert_eq!(Sex::Male.to_string(), "M");
         assert_eq!(Sex::Female.to_string(), "F");
     }
 
+    #[test]
+    fn nid_info_display() {
+        assert_eq!(
+            decode(VALID_NID).unwrap().to_string(),
+            "1990-01-01 M national"
+        );
+        let info = decode(&make_nid(b"J08101001")).unwrap();
+        assert_eq!(info.to_string(), "1990-01-01 F foreign");
+    }
+
     #[test]
     fn date_display() {
         let d = Date {
@@ -487,6 +2455,101 @@ mod tests {
         assert_eq!(d.to_string(), "1990-01-01");
     }
 
+    #[test]
+    fn decode_bytes_matches_decode() {
+        for nid in [VALID_NID, "j00101999w", "J00101999A", "short", "Z001011230"] {
+            assert_eq!(decode_bytes(nid.as_bytes()), decode(nid), "{nid}");
+            assert_eq!(validate_bytes(nid.as_bytes()), validate(nid), "{nid}");
+        }
+        assert_eq!(
+            decode_bytes(b"J0\xff101999W"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter {
+                found: 0xFF
+            }))
+        );
+    }
+
+    #[test]
+    fn decode_unchecked_ignores_check_character() {
+        let expected = decode(VALID_NID).unwrap();
+        for nid in [VALID_NID, "J00101999A", "J00101999z", "J00101999-"] {
+            assert_eq!(decode_unchecked(nid), Ok(expected), "{nid}");
+        }
+    }
+
+    #[test]
+    fn decode_unchecked_still_validates_payload() {
+        assert_eq!(
+            decode_unchecked("J0010199"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode_unchecked("Z00101999W"),
+            Err(NidError::Format(FormatKind::InvalidDecadeChar {
+                found: b'Z'
+            }))
+        );
+        assert_eq!(
+            decode_unchecked("J01301999W"),
+            Err(NidError::Format(FormatKind::InvalidMonthCode { code: 13 }))
+        );
+        assert!(matches!(
+            decode_unchecked("J00230123C"),
+            Err(NidError::InvalidDate(_))
+        ));
+    }
+
+    #[test]
+    fn decode_in_const_context() {
+        const INFO: Result<NidInfo, NidError> = decode(VALID_NID);
+        const _: () = assert!(is_valid("j00101999w"));
+        const INVALID: Result<(), NidError> = validate("J00101999A");
+        assert_eq!(INFO, decode(VALID_NID));
+        assert_eq!(INVALID, Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_codes_are_stable() {
+        let cases = [
+            (
+                NidError::Format(FormatKind::InvalidLength),
+                "AL_FORMAT_LENGTH",
+            ),
+            (
+                NidError::Format(FormatKind::InvalidDecadeChar { found: b'Z' }),
+                "AL_FORMAT_DECADE_CHAR",
+            ),
+            (
+                NidError::Format(FormatKind::NonDigitCharacter { found: b'O' }),
+                "AL_FORMAT_NON_DIGIT",
+            ),
+            (
+                NidError::Format(FormatKind::InvalidChecksumChar { found: b'1' }),
+                "AL_FORMAT_CHECKSUM_CHAR",
+            ),
+            (
+                NidError::Format(FormatKind::InvalidMonthCode { code: 13 }),
+                "AL_FORMAT_MONTH_CODE",
+            ),
+            (NidError::Checksum, "AL_CHECKSUM"),
+            (
+                NidError::InvalidDate(DateKind::MonthOutOfRange { month: 13 }),
+                "AL_DATE_MONTH",
+            ),
+            (
+                NidError::InvalidDate(DateKind::DayOutOfRange {
+                    year: 1990,
+                    month: 2,
+                    day: 30,
+                }),
+                "AL_DATE_DAY",
+            ),
+        ];
+        for (err, code) in cases {
+            assert_eq!(err.error_code(), code);
+        }
+    }
+
     #[test]
     fn error_display() {
         assert_eq!(
@@ -0,0 +1,329 @@
+//! Validate Albanian NIPT (Numri i Identifikimit për Personin e Tatueshëm)
+//! business and tax identification numbers.
+//!
+//! A NIPT is a 10-character string: a letter, 8 digits and a check letter.
+//! It shares its layout and check letter with the personal [NID](super): the
+//! first letter and following digit give the registration year, and the check
+//! letter uses the same weighted mod-23 checksum. For organisations the
+//! remaining digits are a registry-assigned serial rather than a birth date,
+//! so only the registration year is decoded.
+//!
+//! # Format
+//!
+//! `[decade][year_digit][digits (7)][check]`
+//!
+//! - **Decade letter**: `J`–`T` maps to 1990–2090 (NIPTs were introduced in the 1990s).
+//! - **Check letter**: weighted sum of the first 9 characters mod 23.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::albania::nipt;
+//!
+//! let info = nipt::decode("K22218003V").unwrap();
+//! assert_eq!(info.registration_year, 2002);
+//!
+//! assert!(nipt::is_valid("J91402501L"));
+//! assert!(!nipt::is_valid("J91402501A"));
+//! ```
+
+use std::fmt;
+
+use super::{CHECKSUM_VALUES, DECADE_VALUES, check_char, lookup};
+
+/// Index of the first decade letter accepted for a NIPT (`J`, the 1990s).
+const FIRST_DECADE: usize = 19;
+
+/// Decoded information from a valid NIPT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NiptInfo {
+    /// The year the NIPT was registered (for individuals, the birth year).
+    pub registration_year: u16,
+}
+
+/// Specific reason a NIPT was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 10 characters.
+    InvalidLength,
+    /// The first character is not a letter in the range `J`–`T`.
+    InvalidDecadeChar,
+    /// Characters at positions 2–9 are not all ASCII digits.
+    NonDigitCharacter,
+    /// The 10th character is not in the check letter alphabet.
+    InvalidCheckChar,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "NIPT must be exactly 10 characters"),
+            FormatKind::InvalidDecadeChar => write!(f, "first character must be a letter J-T"),
+            FormatKind::NonDigitCharacter => write!(f, "characters 2-9 must be ASCII digits"),
+            FormatKind::InvalidCheckChar => write!(f, "invalid check character"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a NIPT.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NiptError {
+    /// The input string has an invalid format (wrong length, illegal characters).
+    Format(FormatKind),
+    /// The check letter does not match the computed value.
+    Checksum,
+}
+
+impl NiptError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `AL_NIPT_FORMAT_LENGTH` |
+    /// | [`FormatKind::InvalidDecadeChar`] | `AL_NIPT_FORMAT_DECADE_CHAR` |
+    /// | [`FormatKind::NonDigitCharacter`] | `AL_NIPT_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCheckChar`] | `AL_NIPT_FORMAT_CHECK_CHAR` |
+    /// | [`NiptError::Checksum`] | `AL_NIPT_CHECKSUM` |
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NiptError::Format(FormatKind::InvalidLength) => "AL_NIPT_FORMAT_LENGTH",
+            NiptError::Format(FormatKind::InvalidDecadeChar) => "AL_NIPT_FORMAT_DECADE_CHAR",
+            NiptError::Format(FormatKind::NonDigitCharacter) => "AL_NIPT_FORMAT_NON_DIGIT",
+            NiptError::Format(FormatKind::InvalidCheckChar) => "AL_NIPT_FORMAT_CHECK_CHAR",
+            NiptError::Checksum => "AL_NIPT_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NiptError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NiptError::Format(kind) => write!(f, "format error: {kind}"),
+            NiptError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NiptError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NiptError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Decode an Albanian NIPT string.
+///
+/// The input is treated case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NiptError::Format`] if the input has wrong length or illegal
+/// characters, and [`NiptError::Checksum`] if the check letter does not match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::albania::nipt::decode("j91402501l").unwrap();
+/// assert_eq!(info.registration_year, 1999);
+/// ```
+#[must_use = "this returns the decoded NIPT info; use `is_valid` if you only need a bool"]
+pub const fn decode(nipt: &str) -> Result<NiptInfo, NiptError> {
+    let src = nipt.as_bytes();
+    if src.len() != 10 {
+        return Err(NiptError::Format(FormatKind::InvalidLength));
+    }
+    let mut bytes = [0u8; 9];
+    let mut i = 0;
+    while i < 9 {
+        bytes[i] = src[i].to_ascii_uppercase();
+        i += 1;
+    }
+    let check = src[9].to_ascii_uppercase();
+
+    let decade_index = match lookup(&DECADE_VALUES, bytes[0]) {
+        Some(index) if index >= FIRST_DECADE => index,
+        _ => return Err(NiptError::Format(FormatKind::InvalidDecadeChar)),
+    };
+
+    let mut i = 1;
+    while i < 9 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NiptError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+
+    if lookup(&CHECKSUM_VALUES, check).is_none() {
+        return Err(NiptError::Format(FormatKind::InvalidCheckChar));
+    }
+    match check_char(&bytes) {
+        Some(expected) if expected == check => {}
+        _ => return Err(NiptError::Checksum),
+    }
+
+    Ok(NiptInfo {
+        registration_year: 1800 + decade_index as u16 * 10 + (bytes[1] - b'0') as u16,
+    })
+}
+
+/// Validate an Albanian NIPT string, returning a [`NiptError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::nipt::validate("K22218003V").is_ok());
+/// assert!(nidx::albania::nipt::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nipt: &str) -> Result<(), NiptError> {
+    match decode(nipt) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check whether an Albanian NIPT string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::nipt::is_valid("K22218003V"));
+/// assert!(!nidx::albania::nipt::is_valid("K22218003A"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nipt: &str) -> bool {
+    validate(nipt).is_ok()
+}
+
+/// Return the canonical form of a NIPT: whitespace removed, an optional
+/// `AL` country prefix (as used in VAT numbers) dropped and letters
+/// uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::albania::nipt::normalize(" al k22218003v"), "K22218003V");
+/// ```
+#[must_use]
+pub fn normalize(nipt: &str) -> String {
+    let compact: String = nipt
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    match compact.strip_prefix("AL") {
+        Some(rest) if rest.len() == 10 => rest.to_owned(),
+        _ => compact,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("J91402501L"),
+            Ok(NiptInfo {
+                registration_year: 1999
+            })
+        );
+        assert_eq!(
+            decode("K22218003V"),
+            Ok(NiptInfo {
+                registration_year: 2002
+            })
+        );
+    }
+
+    #[test]
+    fn decode_lowercase_input() {
+        assert_eq!(decode("k22218003v"), decode("K22218003V"));
+    }
+
+    #[test]
+    fn personal_nid_is_valid_nipt() {
+        assert_eq!(
+            decode("J00101999W"),
+            Ok(NiptInfo {
+                registration_year: 1990
+            })
+        );
+    }
+
+    #[test]
+    fn normalize_strips_prefix_and_whitespace() {
+        assert_eq!(normalize("AL J91402501L"), "J91402501L");
+        assert_eq!(normalize("j9140 2501l\n"), "J91402501L");
+        assert_eq!(normalize("AL"), "AL");
+        assert!(is_valid(&normalize("al k22218003v")));
+    }
+
+    #[test]
+    fn error_wrong_length() {
+        assert_eq!(
+            decode("K2221800"),
+            Err(NiptError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn error_decade_before_nipt_era() {
+        assert_eq!(
+            decode("A22218003V"),
+            Err(NiptError::Format(FormatKind::InvalidDecadeChar))
+        );
+        assert_eq!(
+            decode("122218003V"),
+            Err(NiptError::Format(FormatKind::InvalidDecadeChar))
+        );
+    }
+
+    #[test]
+    fn error_non_digit() {
+        assert_eq!(
+            decode("K2221800AV"),
+            Err(NiptError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_check_char() {
+        assert_eq!(
+            decode("K222180031"),
+            Err(NiptError::Format(FormatKind::InvalidCheckChar))
+        );
+        assert_eq!(decode("K22218003A"), Err(NiptError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NiptError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: NIPT must be exactly 10 characters"
+        );
+        assert_eq!(
+            NiptError::Checksum.to_string(),
+            "checksum validation failed"
+        );
+        assert_eq!(NiptError::Checksum.error_code(), "AL_NIPT_CHECKSUM");
+    }
+}
@@ -0,0 +1,281 @@
+//! Validate Albanian passport numbers.
+//!
+//! Biometric Albanian passports carry a 9-character document number: two
+//! letters followed by 7 digits (e.g. `BA1234567`). The printed number has no
+//! check character of its own; the only check rule is the ICAO 9303 check
+//! digit that follows the number in the passport's machine-readable zone
+//! (MRZ), which [`validate_mrz`] verifies.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::albania::passport;
+//!
+//! assert!(passport::is_valid("BA1234567"));
+//! assert!(!passport::is_valid("1234567BA"));
+//!
+//! // Document number and check digit as read from the MRZ.
+//! assert!(passport::validate_mrz("BA1234567", '5').is_ok());
+//! ```
+
+use std::fmt;
+
+/// Specific reason a passport number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 9 characters.
+    InvalidLength,
+    /// The first two characters are not ASCII letters.
+    NonLetterPrefix,
+    /// Characters at positions 3–9 are not all ASCII digits.
+    NonDigitCharacter,
+    /// The MRZ check character is not an ASCII digit.
+    InvalidCheckDigit,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "passport number must be exactly 9 characters"),
+            FormatKind::NonLetterPrefix => write!(f, "characters 1-2 must be ASCII letters"),
+            FormatKind::NonDigitCharacter => write!(f, "characters 3-9 must be ASCII digits"),
+            FormatKind::InvalidCheckDigit => write!(f, "MRZ check digit must be an ASCII digit"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a passport number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum PassportError {
+    /// The input string has an invalid format (wrong length, illegal characters).
+    Format(FormatKind),
+    /// The MRZ check digit does not match the computed value.
+    Checksum,
+}
+
+impl PassportError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `AL_PASSPORT_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonLetterPrefix`] | `AL_PASSPORT_FORMAT_PREFIX` |
+    /// | [`FormatKind::NonDigitCharacter`] | `AL_PASSPORT_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCheckDigit`] | `AL_PASSPORT_FORMAT_CHECK_DIGIT` |
+    /// | [`PassportError::Checksum`] | `AL_PASSPORT_CHECKSUM` |
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            PassportError::Format(FormatKind::InvalidLength) => "AL_PASSPORT_FORMAT_LENGTH",
+            PassportError::Format(FormatKind::NonLetterPrefix) => "AL_PASSPORT_FORMAT_PREFIX",
+            PassportError::Format(FormatKind::NonDigitCharacter) => "AL_PASSPORT_FORMAT_NON_DIGIT",
+            PassportError::Format(FormatKind::InvalidCheckDigit) => {
+                "AL_PASSPORT_FORMAT_CHECK_DIGIT"
+            }
+            PassportError::Checksum => "AL_PASSPORT_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for PassportError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PassportError::Format(kind) => write!(f, "format error: {kind}"),
+            PassportError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for PassportError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            PassportError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Validate the format of an Albanian passport number.
+///
+/// The input is treated case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`PassportError::Format`] if the input has wrong length or illegal
+/// characters.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::passport::validate("ba1234567").is_ok());
+/// assert!(nidx::albania::passport::validate("BA123456").is_err());
+/// ```
+pub const fn validate(number: &str) -> Result<(), PassportError> {
+    let bytes = number.as_bytes();
+    if bytes.len() != 9 {
+        return Err(PassportError::Format(FormatKind::InvalidLength));
+    }
+    if !bytes[0].is_ascii_alphabetic() || !bytes[1].is_ascii_alphabetic() {
+        return Err(PassportError::Format(FormatKind::NonLetterPrefix));
+    }
+    let mut i = 2;
+    while i < 9 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(PassportError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Check whether an Albanian passport number has a valid format.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::albania::passport::is_valid("BA1234567"));
+/// assert!(!nidx::albania::passport::is_valid("BA12345678"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(number: &str) -> bool {
+    validate(number).is_ok()
+}
+
+/// Compute the ICAO 9303 MRZ check digit for a passport number.
+///
+/// Returns `None` if `number` is not a validly formatted passport number.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::albania::passport::mrz_check_digit("BA1234567"), Some('5'));
+/// ```
+#[must_use]
+pub const fn mrz_check_digit(number: &str) -> Option<char> {
+    if validate(number).is_err() {
+        return None;
+    }
+    const WEIGHTS: [u32; 3] = [7, 3, 1];
+    let bytes = number.as_bytes();
+    let mut sum = 0u32;
+    let mut i = 0;
+    while i < 9 {
+        let c = bytes[i].to_ascii_uppercase();
+        let value = if c.is_ascii_digit() {
+            (c - b'0') as u32
+        } else {
+            (c - b'A') as u32 + 10
+        };
+        sum += value * WEIGHTS[i % 3];
+        i += 1;
+    }
+    Some((b'0' + (sum % 10) as u8) as char)
+}
+
+/// Validate a passport number together with its MRZ check digit.
+///
+/// # Errors
+///
+/// Returns [`PassportError::Format`] if the number is malformed or `check`
+/// is not a digit, and [`PassportError::Checksum`] if `check` does not match.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::albania::passport::{self, PassportError};
+///
+/// assert!(passport::validate_mrz("BA1234567", '5').is_ok());
+/// assert_eq!(passport::validate_mrz("BA1234567", '3'), Err(PassportError::Checksum));
+/// ```
+pub const fn validate_mrz(number: &str, check: char) -> Result<(), PassportError> {
+    if let Err(e) = validate(number) {
+        return Err(e);
+    }
+    if !check.is_ascii_digit() {
+        return Err(PassportError::Format(FormatKind::InvalidCheckDigit));
+    }
+    match mrz_check_digit(number) {
+        Some(expected) if expected == check => Ok(()),
+        _ => Err(PassportError::Checksum),
+    }
+}
+
+/// Return the canonical form of a passport number: whitespace removed and
+/// letters uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::albania::passport::normalize(" ba 1234567\n"), "BA1234567");
+/// ```
+#[must_use]
+pub fn normalize(number: &str) -> String {
+    number
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_format() {
+        assert_eq!(validate("BA1234567"), Ok(()));
+        assert_eq!(validate("bb0000001"), Ok(()));
+        assert_eq!(
+            validate("BA123456"),
+            Err(PassportError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("B01234567"),
+            Err(PassportError::Format(FormatKind::NonLetterPrefix))
+        );
+        assert_eq!(
+            validate("BA123456X"),
+            Err(PassportError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn mrz_check_digit_follows_icao_weights() {
+        // B=11, A=10: 11*7 + 10*3 + 1*1 + 2*7 + 3*3 + 4*1 + 5*7 + 6*3 + 7*1 = 195.
+        assert_eq!(mrz_check_digit("BA1234567"), Some('5'));
+        assert_eq!(mrz_check_digit("ba1234567"), Some('5'));
+        assert_eq!(mrz_check_digit("BA12345"), None);
+    }
+
+    #[test]
+    fn validate_mrz_checks_digit() {
+        assert_eq!(validate_mrz("BA1234567", '5'), Ok(()));
+        assert_eq!(validate_mrz("BA1234567", '4'), Err(PassportError::Checksum));
+        assert_eq!(
+            validate_mrz("BA1234567", '<'),
+            Err(PassportError::Format(FormatKind::InvalidCheckDigit))
+        );
+        assert_eq!(
+            validate_mrz("BA12345", '5'),
+            Err(PassportError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            PassportError::Format(FormatKind::NonLetterPrefix).to_string(),
+            "format error: characters 1-2 must be ASCII letters"
+        );
+        assert_eq!(PassportError::Checksum.error_code(), "AL_PASSPORT_CHECKSUM");
+    }
+}
@@ -0,0 +1,333 @@
+//! Check Albanian NIDs against tables of serials known to have been issued.
+//!
+//! A NID can pass every structural check and still never have been issued:
+//! for older birth decades only the low end of the 3-digit serial range
+//! (characters 7–9) was ever allocated. A [`SerialRanges`] table records,
+//! for ranges of birth years, which serials were issued, and [`decode`]
+//! applies it with a chosen [`Strictness`].
+//!
+//! The civil registry does not publish these allocations, so the crate ships
+//! no built-in table. Tables are built in code with [`SerialRanges::issued`]
+//! or parsed from text, so they can be updated without a new release of the
+//! crate. Each line of the text form reads `<years>: <serials>`, where both
+//! sides are a single number or an inclusive `start-end` range; blank lines
+//! and `#` comments are ignored.
+//!
+//! This module requires the `serial-ranges` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::albania::serial_ranges::{self, SerialRanges, Strictness};
+//! use nidx::albania::NidError;
+//!
+//! let ranges: SerialRanges = "
+//!     1900-1939: 0-499   # only serials 000-499 were issued
+//! "
+//! .parse()
+//! .unwrap();
+//!
+//! let nid = nidx::albania::complete("H00101999").unwrap(); // born 1970
+//! assert_eq!(serial_ranges::decode(&nid, &ranges, Strictness::Reject).unwrap().issued, Some(true));
+//!
+//! let nid = nidx::albania::complete("D90101999").unwrap(); // born 1939
+//! let checked = serial_ranges::decode(&nid, &ranges, Strictness::Flag).unwrap();
+//! assert_eq!(checked.issued, Some(false));
+//! assert_eq!(
+//!     serial_ranges::decode(&nid, &ranges, Strictness::Reject),
+//!     Err(NidError::UnissuedSerial { serial: 999 })
+//! );
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+use std::str::FromStr;
+
+use super::{NidError, NidInfo};
+use crate::{Confidence, Plausibility};
+
+/// A table of the serials issued for ranges of birth years.
+///
+/// Birth years not covered by any entry are unrestricted. For a covered
+/// year, a serial counts as issued if any entry covering that year
+/// includes it.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct SerialRanges {
+    entries: Vec<(RangeInclusive<u16>, RangeInclusive<u16>)>,
+}
+
+impl SerialRanges {
+    /// An empty table, under which every serial counts as issued.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        SerialRanges {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Record that `serials` were issued to people born in `years`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::albania::serial_ranges::SerialRanges;
+    ///
+    /// let ranges = SerialRanges::new().issued(1900..=1939, 0..=499);
+    /// assert!(ranges.is_issued(1920, 499));
+    /// assert!(!ranges.is_issued(1920, 500));
+    /// assert!(ranges.is_issued(1990, 999));
+    /// ```
+    #[must_use]
+    pub fn issued(mut self, years: RangeInclusive<u16>, serials: RangeInclusive<u16>) -> Self {
+        self.entries.push((years, serials));
+        self
+    }
+
+    /// Whether `serial` was issued to people born in `birth_year`.
+    #[must_use]
+    pub fn is_issued(&self, birth_year: u16, serial: u16) -> bool {
+        self.lookup(birth_year, serial).unwrap_or(true)
+    }
+
+    /// Whether `serial` was issued to people born in `birth_year`, or `None`
+    /// if no entry covers `birth_year`.
+    fn lookup(&self, birth_year: u16, serial: u16) -> Option<bool> {
+        let mut covered = false;
+        for (years, serials) in &self.entries {
+            if years.contains(&birth_year) {
+                if serials.contains(&serial) {
+                    return Some(true);
+                }
+                covered = true;
+            }
+        }
+        if covered { Some(false) } else { None }
+    }
+
+    /// `true` if the table has no entries.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Error returned when parsing a [`SerialRanges`] table from text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseSerialRangesError {
+    /// The 1-based number of the offending line.
+    pub line: usize,
+}
+
+impl fmt::Display for ParseSerialRangesError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "line {}: expected `<years>: <serials>` with numbers or `start-end` ranges",
+            self.line
+        )
+    }
+}
+
+impl std::error::Error for ParseSerialRangesError {}
+
+/// Parse `n` or `start-end` into an inclusive range.
+fn parse_range(s: &str) -> Option<RangeInclusive<u16>> {
+    let (start, end) = s.split_once('-').unwrap_or((s, s));
+    let start = start.trim().parse().ok()?;
+    let end = end.trim().parse().ok()?;
+    (start <= end).then_some(start..=end)
+}
+
+impl FromStr for SerialRanges {
+    type Err = ParseSerialRangesError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut ranges = SerialRanges::new();
+        for (i, line) in s.lines().enumerate() {
+            let line = line.split_once('#').map_or(line, |(data, _)| data).trim();
+            if line.is_empty() {
+                continue;
+            }
+            let error = ParseSerialRangesError { line: i + 1 };
+            let (years, serials) = line.split_once(':').ok_or(error)?;
+            let years = parse_range(years).ok_or(error)?;
+            let serials = parse_range(serials).ok_or(error)?;
+            ranges = ranges.issued(years, serials);
+        }
+        Ok(ranges)
+    }
+}
+
+/// What [`decode`] does with a NID whose serial was never issued.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Strictness {
+    /// Do not consult the table; [`SerialCheck::issued`] is `None`.
+    Ignore,
+    /// Accept the NID and report the result in [`SerialCheck::issued`].
+    #[default]
+    Flag,
+    /// Reject the NID with [`NidError::UnissuedSerial`].
+    Reject,
+}
+
+/// The result of [`decode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct SerialCheck {
+    /// The decoded NID.
+    pub info: NidInfo,
+    /// Whether the serial was issued for the birth year, or `None` with
+    /// [`Strictness::Ignore`].
+    pub issued: Option<bool>,
+}
+
+/// Decode an Albanian NID and check its serial against `ranges`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`albania::decode`](super::decode), and with
+/// [`Strictness::Reject`] also [`NidError::UnissuedSerial`] if the serial
+/// was never issued for the birth year.
+pub fn decode(
+    nid: &str,
+    ranges: &SerialRanges,
+    strictness: Strictness,
+) -> Result<SerialCheck, NidError> {
+    let info = super::decode(nid)?;
+    let issued = match strictness {
+        Strictness::Ignore => None,
+        Strictness::Flag | Strictness::Reject => {
+            Some(ranges.is_issued(info.birthday.year, info.serial))
+        }
+    };
+    if strictness == Strictness::Reject && issued == Some(false) {
+        return Err(NidError::UnissuedSerial {
+            serial: info.serial,
+        });
+    }
+    Ok(SerialCheck { info, issued })
+}
+
+/// Grade an Albanian NID as [`albania::score`](super::score) does, then
+/// grade a valid, plausible NID by its serial.
+///
+/// - [`Confidence::Issued`]: the serial lies in a range `ranges` records as
+///   issued for the birth year.
+/// - [`Confidence::Plausible`]: `ranges` has no entry for the birth year.
+/// - [`Confidence::Checksum`]: the serial was never issued for the birth
+///   year, which is as implausible as a birthday in the future.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::albania::serial_ranges::{self, SerialRanges};
+/// use nidx::{Confidence, Date, Plausibility};
+///
+/// let ranges = SerialRanges::new().issued(1960..=1999, 0..=499);
+/// let check = Plausibility::new(Date { year: 2026, month: 10, day: 16 });
+///
+/// let issued = nidx::albania::complete("J00101123").unwrap(); // born 1990
+/// assert_eq!(serial_ranges::score(&issued, &check, &ranges), Confidence::Issued);
+/// assert_eq!(serial_ranges::score("J00101999W", &check, &ranges), Confidence::Checksum);
+/// assert_eq!(serial_ranges::score("J00101999A", &check, &ranges), Confidence::Structure);
+/// ```
+#[must_use]
+pub fn score(nid: &str, plausibility: &Plausibility, ranges: &SerialRanges) -> Confidence {
+    match super::grade(nid, plausibility) {
+        Ok(info) => match ranges.lookup(info.birthday.year, info.serial) {
+            Some(true) => Confidence::Issued,
+            Some(false) => Confidence::Checksum,
+            None => Confidence::Plausible,
+        },
+        Err(confidence) => confidence,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ranges() -> SerialRanges {
+        SerialRanges::new()
+            .issued(1900..=1939, 0..=299)
+            .issued(1930..=1939, 500..=599)
+    }
+
+    #[test]
+    fn overlapping_entries_combine() {
+        let ranges = ranges();
+        assert!(ranges.is_issued(1935, 299));
+        assert!(!ranges.is_issued(1935, 300));
+        assert!(ranges.is_issued(1935, 550));
+        assert!(!ranges.is_issued(1925, 550));
+        assert!(ranges.is_issued(1940, 999));
+        assert!(SerialRanges::new().is_issued(1900, 999));
+    }
+
+    #[test]
+    fn parse_text_table() {
+        let parsed: SerialRanges =
+            "# comment\n1900-1939: 0-299\n\n1930 - 1939 : 500-599 # trailing\n"
+                .parse()
+                .unwrap();
+        assert_eq!(parsed, ranges());
+        assert_eq!(
+            "1950: 7".parse::<SerialRanges>(),
+            Ok(SerialRanges::new().issued(1950..=1950, 7..=7))
+        );
+        assert_eq!(
+            "1900-1939: 0-299\n1939-1900: 0-1".parse::<SerialRanges>(),
+            Err(ParseSerialRangesError { line: 2 })
+        );
+        assert_eq!(
+            "1900 0-299".parse::<SerialRanges>(),
+            Err(ParseSerialRangesError { line: 1 })
+        );
+    }
+
+    #[test]
+    fn score_grades_serials() {
+        let ranges = ranges();
+        let check = Plausibility::new(crate::Date {
+            year: 2026,
+            month: 10,
+            day: 16,
+        });
+        let issued = crate::albania::complete("D00101100").unwrap();
+        let unissued = crate::albania::complete("D00101999").unwrap();
+        assert_eq!(score(&issued, &check, &ranges), Confidence::Issued);
+        assert_eq!(score(&unissued, &check, &ranges), Confidence::Checksum);
+        assert_eq!(score("J00101999W", &check, &ranges), Confidence::Plausible);
+        assert_eq!(score("J00101999A", &check, &ranges), Confidence::Structure);
+        assert_eq!(score("J0010", &check, &ranges), Confidence::Invalid);
+    }
+
+    #[test]
+    fn decode_applies_strictness() {
+        let ranges = ranges();
+        let nid = crate::albania::complete("D00101999").unwrap();
+        assert_eq!(
+            decode(&nid, &ranges, Strictness::Ignore).unwrap().issued,
+            None
+        );
+        assert_eq!(
+            decode(&nid, &ranges, Strictness::Flag).unwrap().issued,
+            Some(false)
+        );
+        assert_eq!(
+            decode(&nid, &ranges, Strictness::Reject),
+            Err(NidError::UnissuedSerial { serial: 999 })
+        );
+        let nid = crate::albania::complete("D00101100").unwrap();
+        assert_eq!(
+            decode(&nid, &ranges, Strictness::Reject).unwrap().issued,
+            Some(true)
+        );
+        assert_eq!(
+            decode("J00101999A", &ranges, Strictness::Flag),
+            Err(NidError::Checksum)
+        );
+    }
+}
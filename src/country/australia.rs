@@ -0,0 +1,297 @@
+//! Validate Australian tax file numbers (TFN).
+//!
+//! The TFN is issued by the Australian Taxation Office to individuals and
+//! organisations. Current numbers have 9 digits; some older ones have 8. The
+//! number carries no personal data.
+//!
+//! # Format
+//!
+//! `[digits (8-9)]`
+//!
+//! - **Check**: the digits weighted by `1 4 3 7 5 8 6 9 10` (9 digits) or
+//!   `10 7 8 4 6 3 5 1` (8 digits) must sum to a multiple of 11.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::australia::is_valid("123456782"));
+//! assert!(nidx::australia::is_valid("12345677"));
+//! assert!(!nidx::australia::is_valid("123456789"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run};
+
+/// Static facts about the Australian TFN format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Australia,
+    name: "Australia",
+    alpha2: "AU",
+    alpha3: "AUS",
+    min_length: 8,
+    max_length: 9,
+    shapes: &[&[Run::between(CharClass::Digit, 8, 9)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+const WEIGHTS_9: [u16; 9] = [1, 4, 3, 7, 5, 8, 6, 9, 10];
+const WEIGHTS_8: [u16; 8] = [10, 7, 8, 4, 6, 3, 5, 1];
+
+/// Specific reason a TFN was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 8 or 9 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "TFN must be 8 or 9 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a TFN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The weighted digit sum is not a multiple of 11.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `AU_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `AU_FORMAT_NON_DIGIT` |
+    /// | [`NidError::Checksum`] | `AU_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::australia::validate("123456789").unwrap_err();
+    /// assert_eq!(err.error_code(), "AU_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "AU_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "AU_FORMAT_NON_DIGIT",
+            NidError::Checksum => "AU_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Validate an Australian TFN.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters, and [`NidError::Checksum`] if the weighted sum is not a
+/// multiple of 11.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::australia::validate("876543210").is_ok());
+/// assert!(nidx::australia::validate("invalid").is_err());
+/// ```
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    let bytes = nid.as_bytes();
+    let weights: &[u16] = match bytes.len() {
+        9 => &WEIGHTS_9,
+        8 => &WEIGHTS_8,
+        _ => return Err(NidError::Format(FormatKind::InvalidLength)),
+    };
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        sum += (bytes[i] - b'0') as u16 * weights[i];
+        i += 1;
+    }
+    if sum % 11 != 0 {
+        return Err(NidError::Checksum);
+    }
+    Ok(())
+}
+
+/// Validate an Australian TFN string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::australia::validate_with("123 456 782", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    validate(&options.prepare(nid))
+}
+
+/// Check whether an Australian TFN string is valid.
+///
+/// Returns `true` exactly when [`validate`] succeeds, without building an
+/// error for the first failed check.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::australia::is_valid("123456782"));
+/// assert!(!nidx::australia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    let bytes = nid.as_bytes();
+    let weights: &[u16] = match bytes.len() {
+        9 => &WEIGHTS_9,
+        8 => &WEIGHTS_8,
+        _ => return false,
+    };
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return false;
+        }
+        sum += (bytes[i] - b'0') as u16 * weights[i];
+        i += 1;
+    }
+    sum % 11 == 0
+}
+
+/// Return the canonical form of a TFN: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::australia::normalize("123 456 782"), "123456782");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Australian tax file numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::australia::Australia;
+///
+/// assert_eq!(Australia::decode("123456782"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Australia;
+
+impl NationalId for Australia {
+    const COUNTRY: Country = Country::Australia;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_nine_digits() {
+        assert_eq!(validate("123456782"), Ok(()));
+        assert_eq!(validate("876543210"), Ok(()));
+        assert_eq!(validate("565051603"), Ok(()));
+    }
+
+    #[test]
+    fn validate_eight_digits() {
+        assert_eq!(validate("12345677"), Ok(()));
+        assert_eq!(validate("11111111"), Ok(()));
+        assert_eq!(validate("12345678"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            validate("1234567"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("1234567890"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("12345678X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(validate("123456789"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: TFN must be 8 or 9 digits"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "AU_CHECKSUM");
+    }
+}
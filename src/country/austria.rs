@@ -0,0 +1,528 @@
+//! Validate and decode Austrian social insurance numbers
+//! (Versicherungsnummer).
+//!
+//! The Versicherungsnummer is a 10-digit number made of a 3-digit serial, a
+//! check digit and the holder's date of birth as `DDMMYY`.
+//!
+//! # Format
+//!
+//! `[serial (3)][check][day (2)][month (2)][year (2)]`
+//!
+//! - **Serial**: `100`–`999`; the first digit is never `0`.
+//! - **Check digit**: the serial and date digits weighted by
+//!   `3 7 9 _ 5 8 4 2 1 6`, summed mod 11. Serials whose sum gives `10` are
+//!   never issued.
+//! - **Date**: usually the real date of birth. When it is unknown, or every
+//!   serial for a date has been used up, a fictitious date with month `13`–`15`
+//!   is assigned; such numbers decode with [`NidInfo::birthday`] set to `None`.
+//! - **Year**: the last two digits of the birth year; `00`–`29` maps to
+//!   2000–2029 and `30`–`99` to 1930–1999.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::austria::decode("1237010180").unwrap();
+//! assert_eq!(info.birthday.unwrap().to_string(), "1980-01-01");
+//! assert_eq!(info.serial, 123);
+//!
+//! assert!(nidx::austria::is_valid("1237010180"));
+//! assert!(!nidx::austria::is_valid("1238010180"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::{
+    CenturyPolicy, CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Summary,
+};
+
+/// Static facts about the Austrian social insurance number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Austria,
+    name: "Austria",
+    alpha2: "AT",
+    alpha3: "AUT",
+    min_length: 10,
+    max_length: 10,
+    shapes: &[&[Run::exactly(CharClass::Digit, 10)]],
+    encodes_birthday: true,
+    encodes_sex: false,
+};
+
+const WEIGHTS: [u16; 10] = [3, 7, 9, 0, 5, 8, 4, 2, 1, 6];
+
+/// Decoded information from a valid social insurance number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth, or `None` if the number carries a fictitious date
+    /// (month `13`–`15`).
+    pub birthday: Option<Date>,
+    /// Year of birth, present even when the date is fictitious.
+    pub birth_year: u16,
+    /// The three-digit serial (digits 1–3).
+    pub serial: u16,
+}
+
+impl NidInfo {
+    /// Whether the number carries a fictitious date of birth.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = nidx::austria::decode("7894131385").unwrap();
+    /// assert!(info.has_fictitious_date());
+    /// assert_eq!(info.birth_year, 1985);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn has_fictitious_date(&self) -> bool {
+        self.birthday.is_none()
+    }
+
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The Versicherungsnummer does not encode sex or citizenship, so `sex`
+    /// and `is_national` are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::austria::decode("1237010180").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1980-01-01");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: self.birthday,
+            sex: None,
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday>"`, e.g. `"1980-01-01"`, or nothing for a
+    /// fictitious date.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a social insurance number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 10 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The serial starts with `0`.
+    LeadingZero,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "social insurance number must be exactly 10 digits")
+            }
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::LeadingZero => write!(f, "first digit must not be 0"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a social insurance number was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a social insurance number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is neither a valid calendar date nor a fictitious date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `AT_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `AT_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::LeadingZero`] | `AT_FORMAT_LEADING_ZERO` |
+    /// | [`NidError::Checksum`] | `AT_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `AT_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `AT_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::austria::validate("1238010180").unwrap_err();
+    /// assert_eq!(err.error_code(), "AT_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "AT_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "AT_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::LeadingZero) => "AT_FORMAT_LEADING_ZERO",
+            NidError::Checksum => "AT_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "AT_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "AT_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the two ASCII digits at `bytes[i..i + 2]`.
+const fn two_digits(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i] - b'0') * 10 + (bytes[i + 1] - b'0')
+}
+
+/// Decode an Austrian social insurance number.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or a leading zero, [`NidError::Checksum`] if the check digit
+/// does not match (or the weighted sum gives `10`), and
+/// [`NidError::InvalidDate`] if the encoded date is not valid.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::austria::decode("4560290200").unwrap();
+/// assert_eq!(info.birthday.unwrap().to_string(), "2000-02-29");
+/// ```
+#[must_use = "this returns the decoded social insurance number info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    decode_impl(nid, CenturyPolicy::DEFAULT)
+}
+
+/// Decode with `century` expanding the two-digit birth year.
+const fn decode_impl(nid: &str, century: CenturyPolicy) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 10 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 10 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        sum += (bytes[i] - b'0') as u16 * WEIGHTS[i];
+        i += 1;
+    }
+    if bytes[0] == b'0' {
+        return Err(NidError::Format(FormatKind::LeadingZero));
+    }
+    if sum % 11 != (bytes[3] - b'0') as u16 {
+        return Err(NidError::Checksum);
+    }
+
+    let day = two_digits(bytes, 4);
+    let month = two_digits(bytes, 6);
+    let yy = two_digits(bytes, 8);
+    let year = century.resolve(yy);
+    let birthday = match month {
+        1..=12 => match validate_date(year, month, day) {
+            Some(date) => Some(date),
+            None => {
+                return Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                    year,
+                    month,
+                    day,
+                }));
+            }
+        },
+        13..=15 if day >= 1 && day <= 31 => None,
+        13..=15 => {
+            return Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year,
+                month,
+                day,
+            }));
+        }
+        _ => return Err(NidError::InvalidDate(DateKind::MonthOutOfRange { month })),
+    };
+
+    Ok(NidInfo {
+        birthday,
+        birth_year: year,
+        serial: (bytes[0] - b'0') as u16 * 100 + two_digits(bytes, 1) as u16,
+    })
+}
+
+/// Decode an Austrian social insurance number string after pre-processing it according to
+/// `options`.
+///
+/// The two-digit birth year is expanded with [`ParseOptions::century`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::austria::decode_with("1237 010180", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.serial, 123);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded social insurance number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode_impl(&options.prepare(nid), options.century)
+}
+
+/// Validate an Austrian social insurance number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::austria::validate("1237010180").is_ok());
+/// assert!(nidx::austria::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate an Austrian social insurance number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether an Austrian social insurance number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::austria::is_valid("1237010180"));
+/// assert!(!nidx::austria::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a social insurance number: whitespace
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::austria::normalize("1237 010180"), "1237010180");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Austrian social insurance numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::austria::Austria;
+///
+/// let info = Austria::decode("1237010180").unwrap();
+/// assert_eq!(info.birth_year, 1980);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Austria;
+
+impl NationalId for Austria {
+    const COUNTRY: Country = Country::Austria;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("1237010180"),
+            Ok(NidInfo {
+                birthday: Some(Date {
+                    year: 1980,
+                    month: 1,
+                    day: 1
+                }),
+                birth_year: 1980,
+                serial: 123,
+            })
+        );
+        assert_eq!(decode("2005010100").unwrap().birth_year, 2000);
+    }
+
+    #[test]
+    fn decode_leap_day() {
+        assert_eq!(
+            decode("4560290200").unwrap().birthday.unwrap().to_string(),
+            "2000-02-29"
+        );
+    }
+
+    #[test]
+    fn decode_fictitious_dates() {
+        let info = decode("7894131385").unwrap();
+        assert_eq!(info.birthday, None);
+        assert_eq!(info.birth_year, 1985);
+        assert!(info.has_fictitious_date());
+        assert!(decode("1238011580").unwrap().has_fictitious_date());
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("123701018"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("12370101A0"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("0230010180"),
+            Err(NidError::Format(FormatKind::LeadingZero))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("1238010180"), Err(NidError::Checksum));
+        // The weighted sum is 10 mod 11: never issued.
+        assert_eq!(decode("1000150685"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("4566290201"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+        assert_eq!(
+            decode("1232310299"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 1999,
+                month: 2,
+                day: 31
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::LeadingZero).to_string(),
+            "format error: first digit must not be 0"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "AT_CHECKSUM");
+    }
+}
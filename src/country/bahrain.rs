@@ -0,0 +1,473 @@
+//! Validate and decode Bahraini personal numbers (CPR, Central Population
+//! Register).
+//!
+//! Every citizen and resident of Bahrain is assigned a 9-digit CPR number. It
+//! encodes the year and month of birth, followed by a serial and a check digit.
+//!
+//! # Format
+//!
+//! `[year (2)][month (2)][serial (4)][check]`
+//!
+//! - **Year**: `00`–`29` map to 2000–2029, `30`–`99` to 1930–1999.
+//! - **Check digit**: the first 8 digits weighted by `9 8 7 6 5 4 3 2`; the
+//!   check digit is `11 - sum mod 11`, or `0` when the remainder is 0 or 1.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::bahrain::decode("850612349").unwrap();
+//! assert_eq!(info.birth_year, 1985);
+//! assert_eq!(info.birth_month, 6);
+//!
+//! assert!(nidx::bahrain::is_valid("051204568"));
+//! assert!(!nidx::bahrain::is_valid("850612348"));
+//! ```
+
+use std::fmt;
+
+use crate::encode::check_range;
+use crate::{
+    CenturyPolicy, CharClass, Country, EncodeError, EncodeId, Metadata, NationalId, ParseOptions,
+    Run, Summary,
+};
+
+/// Static facts about the Bahraini CPR number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Bahrain,
+    name: "Bahrain",
+    alpha2: "BH",
+    alpha3: "BHR",
+    min_length: 9,
+    max_length: 9,
+    shapes: &[&[Run::exactly(CharClass::Digit, 9)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Decoded information from a valid CPR number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Year of birth.
+    pub birth_year: u16,
+    /// Month of birth (1–12).
+    pub birth_month: u8,
+    /// Serial number (digits 5–8).
+    pub serial: u16,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The day of birth is not encoded and CPR numbers are issued to
+    /// residents as well as citizens, so every field is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::bahrain::decode("850612349").unwrap().summary();
+    /// assert_eq!(summary.birthday, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the year and month of birth, e.g. `"1985-06"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}-{:02}", self.birth_year, self.birth_month)
+    }
+}
+
+/// Specific reason a CPR number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 9 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "CPR number must be exactly 9 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a CPR number was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a CPR number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded month is not a valid calendar month.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `BH_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `BH_FORMAT_NON_DIGIT` |
+    /// | [`NidError::Checksum`] | `BH_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `BH_DATE_MONTH` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::bahrain::validate("850612348").unwrap_err();
+    /// assert_eq!(err.error_code(), "BH_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "BH_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "BH_FORMAT_NON_DIGIT",
+            NidError::Checksum => "BH_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "BH_DATE_MONTH",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Compute the check digit from the first 8 digits.
+const fn check_digit(bytes: &[u8]) -> u8 {
+    let mut sum = 0u16;
+    let mut i = 0;
+    while i < 8 {
+        sum += (bytes[i] - b'0') as u16 * (9 - i as u16);
+        i += 1;
+    }
+    match sum % 11 {
+        0 | 1 => 0,
+        r => (11 - r) as u8,
+    }
+}
+
+/// Decode a Bahraini CPR number.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters, [`NidError::Checksum`] if the check digit does not match, and
+/// [`NidError::InvalidDate`] if the encoded month is out of range.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::bahrain::decode("051204568").unwrap();
+/// assert_eq!(info.birth_year, 2005);
+/// assert_eq!(info.birth_month, 12);
+/// ```
+#[must_use = "this returns the decoded CPR info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    decode_impl(nid, CenturyPolicy::DEFAULT)
+}
+
+/// Decode with `century` expanding the two-digit birth year.
+const fn decode_impl(nid: &str, century: CenturyPolicy) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 9 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 9 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    if check_digit(bytes) != bytes[8] - b'0' {
+        return Err(NidError::Checksum);
+    }
+
+    let yy = (bytes[0] - b'0') * 10 + (bytes[1] - b'0');
+    let birth_year = century.resolve(yy);
+    let birth_month = (bytes[2] - b'0') * 10 + (bytes[3] - b'0');
+    if birth_month < 1 || birth_month > 12 {
+        return Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+            month: birth_month,
+        }));
+    }
+    let mut serial = 0u16;
+    let mut i = 4;
+    while i < 8 {
+        serial = serial * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+    Ok(NidInfo {
+        birth_year,
+        birth_month,
+        serial,
+    })
+}
+
+/// Decode a Bahraini CPR number string after pre-processing it according to
+/// `options`.
+///
+/// The two-digit birth year is expanded with [`ParseOptions::century`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::bahrain::decode_with(" 8506 1234 9\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birth_year, 1985);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded CPR number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode_impl(&options.prepare(nid), options.century)
+}
+
+/// Validate a Bahraini CPR number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::bahrain::validate("850612349").is_ok());
+/// assert!(nidx::bahrain::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Bahraini CPR number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Bahraini CPR number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::bahrain::is_valid("850612349"));
+/// assert!(!nidx::bahrain::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a CPR number: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::bahrain::normalize(" 8506 1234 9\n"), "850612349");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Bahraini CPR numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::bahrain::Bahrain;
+///
+/// let info = Bahrain::decode("850612349").unwrap();
+/// assert_eq!(info.birth_month, 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Bahrain;
+
+impl NationalId for Bahrain {
+    const COUNTRY: Country = Country::Bahrain;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Bahrain {
+    type Components = NidInfo;
+
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            birth_year,
+            birth_month,
+            serial,
+        } = info;
+        let yy = (birth_year % 100) as u8;
+        if CenturyPolicy::DEFAULT.resolve(yy) != birth_year {
+            return Err(EncodeError::OutOfRange {
+                field: "birth_year",
+            });
+        }
+        check_range("birth_month", birth_month, 1..=12)?;
+        check_range("serial", serial, 0..=9999)?;
+
+        let payload = format!("{yy:02}{birth_month:02}{serial:04}");
+        Ok(format!("{payload}{}", check_digit(payload.as_bytes())))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("850612349"),
+            Ok(NidInfo {
+                birth_year: 1985,
+                birth_month: 6,
+                serial: 1234,
+            })
+        );
+        assert_eq!(decode("850612349").unwrap().to_string(), "1985-06");
+    }
+
+    #[test]
+    fn decode_century_pivot() {
+        assert_eq!(decode("051204568").unwrap().birth_year, 2005);
+        assert_eq!(decode("310100003").unwrap().birth_year, 1931);
+        assert_eq!(decode("300100000").unwrap().birth_year, 1930);
+    }
+
+    #[test]
+    fn check_digit_remainder_one_is_zero() {
+        // The weighted sum is 89, remainder 1 mod 11.
+        assert!(decode("900100010").is_ok());
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("85061234"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("85061234X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("850612348"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("000000000"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 0
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::InvalidDate(DateKind::MonthOutOfRange { month: 13 }).to_string(),
+            "invalid date: month 13 out of range"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "BH_CHECKSUM");
+    }
+}
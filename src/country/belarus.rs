@@ -0,0 +1,731 @@
+//! Validate and decode Belarusian personal identification numbers
+//! (идентификационный номер).
+//!
+//! The identification number is a 14-character code encoding sex, century,
+//! date of birth, the region of issue and citizenship, followed by a check
+//! digit.
+//!
+//! # Format
+//!
+//! `[sex/century][day (2)][month (2)][year (2)][region][serial (3)][citizenship (2)][check]`
+//!
+//! - **Sex/century digit**: `1`/`2` for men/women born 1800–1899, `3`/`4` for
+//!   1900–1999 and `5`/`6` for 2000–2099.
+//! - **Region letter**: see [`Region`].
+//! - **Citizenship**: `PB` for citizens, `BA` for foreign nationals and `BI`
+//!   for stateless persons (see [`Citizenship`]).
+//! - **Check digit**: the first 13 characters, with letters valued `A` = 10
+//!   to `Z` = 35, weighted by `7 3 1` repeating, summed mod 10.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::belarus::Region;
+//!
+//! let info = nidx::belarus::decode("3140391A001PB8").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1991-03-14");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert_eq!(info.region, Region::Brest);
+//!
+//! assert!(nidx::belarus::is_valid("4150585A001PB9"));
+//! assert!(!nidx::belarus::is_valid("3140391A001PB7"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::encode::{check_birthday, check_range, is_female};
+use crate::{
+    CharClass, Country, Date, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Sex,
+    Summary,
+};
+
+/// Static facts about the Belarusian identification number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Belarus,
+    name: "Belarus",
+    alpha2: "BY",
+    alpha3: "BLR",
+    min_length: 14,
+    max_length: 14,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 7),
+        Run::one(CharClass::Letter),
+        Run::exactly(CharClass::Digit, 3),
+        Run::exactly(CharClass::Letter, 2),
+        Run::one(CharClass::Digit),
+    ]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+const WEIGHTS: [u16; 3] = [7, 3, 1];
+
+/// The region that issued the number, encoded by the 8th character.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Region {
+    /// Letter `A`: Brest Region.
+    Brest,
+    /// Letter `B`: Vitebsk Region.
+    Vitebsk,
+    /// Letter `C`: Gomel Region.
+    Gomel,
+    /// Letter `K`: Grodno Region.
+    Grodno,
+    /// Letter `E`: Minsk Region.
+    MinskRegion,
+    /// Letter `M`: Mogilev Region.
+    Mogilev,
+    /// Letter `H`: the city of Minsk.
+    MinskCity,
+}
+
+impl Region {
+    /// Look up a region by its letter, in either case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::belarus::Region;
+    ///
+    /// assert_eq!(Region::from_letter('H'), Some(Region::MinskCity));
+    /// assert_eq!(Region::from_letter('D'), None);
+    /// ```
+    #[must_use]
+    pub const fn from_letter(letter: char) -> Option<Region> {
+        match letter.to_ascii_uppercase() {
+            'A' => Some(Region::Brest),
+            'B' => Some(Region::Vitebsk),
+            'C' => Some(Region::Gomel),
+            'K' => Some(Region::Grodno),
+            'E' => Some(Region::MinskRegion),
+            'M' => Some(Region::Mogilev),
+            'H' => Some(Region::MinskCity),
+            _ => None,
+        }
+    }
+
+    /// The letter encoding the region.
+    #[must_use]
+    pub const fn letter(self) -> char {
+        match self {
+            Region::Brest => 'A',
+            Region::Vitebsk => 'B',
+            Region::Gomel => 'C',
+            Region::Grodno => 'K',
+            Region::MinskRegion => 'E',
+            Region::Mogilev => 'M',
+            Region::MinskCity => 'H',
+        }
+    }
+
+    /// The English name of the region.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Region::Brest => "Brest Region",
+            Region::Vitebsk => "Vitebsk Region",
+            Region::Gomel => "Gomel Region",
+            Region::Grodno => "Grodno Region",
+            Region::MinskRegion => "Minsk Region",
+            Region::Mogilev => "Mogilev Region",
+            Region::MinskCity => "Minsk",
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The holder's citizenship status, encoded by characters 12–13.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Citizenship {
+    /// `PB`: a citizen of Belarus.
+    Belarus,
+    /// `BA`: a foreign national.
+    Foreign,
+    /// `BI`: a stateless person.
+    Stateless,
+}
+
+/// Decoded information from a valid identification number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// The issuing region.
+    pub region: Region,
+    /// The three-digit serial (characters 9–11).
+    pub serial: u16,
+    /// The holder's citizenship status.
+    pub citizenship: Citizenship,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// `is_national` is `true` only for [`Citizenship::Belarus`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::belarus::decode("3140391A001PB8").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1991-03-14 M national");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: Some(matches!(self.citizenship, Citizenship::Belarus)),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex> <national|foreign>"`, e.g.
+    /// `"1991-03-14 M national"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason an identification number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 14 characters.
+    InvalidLength,
+    /// Characters at positions 1–7, 9–11 or 14 are not all ASCII digits.
+    NonDigitCharacter,
+    /// Characters at positions 8, 12 or 13 are not all ASCII letters.
+    NonLetterCharacter,
+    /// The first digit is not `1`–`6`.
+    InvalidSexDigit,
+    /// The region letter is not one of `A`, `B`, `C`, `E`, `H`, `K` or `M`.
+    InvalidRegion,
+    /// The citizenship code is not `PB`, `BA` or `BI`.
+    InvalidCitizenship,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "identification number must be exactly 14 characters")
+            }
+            FormatKind::NonDigitCharacter => {
+                write!(f, "characters 1-7, 9-11 and 14 must be ASCII digits")
+            }
+            FormatKind::NonLetterCharacter => {
+                write!(f, "characters 8, 12 and 13 must be ASCII letters")
+            }
+            FormatKind::InvalidSexDigit => write!(f, "first digit must be 1-6"),
+            FormatKind::InvalidRegion => write!(f, "region letter must be A, B, C, E, H, K or M"),
+            FormatKind::InvalidCitizenship => write!(f, "citizenship code must be PB, BA or BI"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason an identification number was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding an identification number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters, unknown codes).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `BY_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `BY_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::NonLetterCharacter`] | `BY_FORMAT_NON_LETTER` |
+    /// | [`FormatKind::InvalidSexDigit`] | `BY_FORMAT_SEX_DIGIT` |
+    /// | [`FormatKind::InvalidRegion`] | `BY_FORMAT_REGION` |
+    /// | [`FormatKind::InvalidCitizenship`] | `BY_FORMAT_CITIZENSHIP` |
+    /// | [`NidError::Checksum`] | `BY_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `BY_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `BY_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::belarus::validate("3140391A001PB7").unwrap_err();
+    /// assert_eq!(err.error_code(), "BY_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "BY_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "BY_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::NonLetterCharacter) => "BY_FORMAT_NON_LETTER",
+            NidError::Format(FormatKind::InvalidSexDigit) => "BY_FORMAT_SEX_DIGIT",
+            NidError::Format(FormatKind::InvalidRegion) => "BY_FORMAT_REGION",
+            NidError::Format(FormatKind::InvalidCitizenship) => "BY_FORMAT_CITIZENSHIP",
+            NidError::Checksum => "BY_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "BY_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "BY_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the two ASCII digits at `bytes[i..i + 2]`.
+const fn two_digits(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i] - b'0') * 10 + (bytes[i + 1] - b'0')
+}
+
+/// Decode a Belarusian identification number.
+///
+/// Letters are treated case-insensitively. This is a `const fn`, so fixed
+/// numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, illegal
+/// characters or an unknown sex digit, region or citizenship code,
+/// [`NidError::Checksum`] if the check digit does not match, and
+/// [`NidError::InvalidDate`] if the encoded date is not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::belarus::Citizenship;
+///
+/// let info = nidx::belarus::decode("3010180E014BA5").unwrap();
+/// assert_eq!(info.birthday.to_string(), "1980-01-01");
+/// assert_eq!(info.citizenship, Citizenship::Foreign);
+/// ```
+#[must_use = "this returns the decoded identification number info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 14 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 14 {
+        let c = bytes[i].to_ascii_uppercase();
+        let value = if i == 7 || i == 11 || i == 12 {
+            if !c.is_ascii_uppercase() {
+                return Err(NidError::Format(FormatKind::NonLetterCharacter));
+            }
+            c - b'A' + 10
+        } else {
+            if !c.is_ascii_digit() {
+                return Err(NidError::Format(FormatKind::NonDigitCharacter));
+            }
+            c - b'0'
+        };
+        if i < 13 {
+            sum += value as u16 * WEIGHTS[i % 3];
+        }
+        i += 1;
+    }
+    if sum % 10 != (bytes[13] - b'0') as u16 {
+        return Err(NidError::Checksum);
+    }
+
+    let (century, sex) = match bytes[0] {
+        b'1' => (1800, Sex::Male),
+        b'2' => (1800, Sex::Female),
+        b'3' => (1900, Sex::Male),
+        b'4' => (1900, Sex::Female),
+        b'5' => (2000, Sex::Male),
+        b'6' => (2000, Sex::Female),
+        _ => return Err(NidError::Format(FormatKind::InvalidSexDigit)),
+    };
+    let Some(region) = Region::from_letter(bytes[7] as char) else {
+        return Err(NidError::Format(FormatKind::InvalidRegion));
+    };
+    let citizenship = match [
+        bytes[11].to_ascii_uppercase(),
+        bytes[12].to_ascii_uppercase(),
+    ] {
+        [b'P', b'B'] => Citizenship::Belarus,
+        [b'B', b'A'] => Citizenship::Foreign,
+        [b'B', b'I'] => Citizenship::Stateless,
+        _ => return Err(NidError::Format(FormatKind::InvalidCitizenship)),
+    };
+
+    let day = two_digits(bytes, 1);
+    let month = two_digits(bytes, 3);
+    let year = century + two_digits(bytes, 5) as u16;
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(if month < 1 || month > 12 {
+            DateKind::MonthOutOfRange { month }
+        } else {
+            DateKind::DayOutOfRange { year, month, day }
+        }));
+    };
+
+    Ok(NidInfo {
+        birthday,
+        sex,
+        region,
+        serial: (bytes[8] - b'0') as u16 * 100 + two_digits(bytes, 9) as u16,
+        citizenship,
+    })
+}
+
+/// Decode a Belarusian identification number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::belarus::decode_with(" 3140391A001PB8\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1991-03-14");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded identification number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Belarusian identification number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::belarus::validate("3140391A001PB8").is_ok());
+/// assert!(nidx::belarus::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Belarusian identification number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Belarusian identification number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::belarus::is_valid("3140391A001PB8"));
+/// assert!(!nidx::belarus::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an identification number: whitespace
+/// removed and letters uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::belarus::normalize(" 3140391a001pb8\n"), "3140391A001PB8");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Belarusian identification numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::belarus::Belarus;
+///
+/// let info = Belarus::decode("3140391A001PB8").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Belarus;
+
+impl NationalId for Belarus {
+    const COUNTRY: Country = Country::Belarus;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Belarus {
+    type Components = NidInfo;
+
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            birthday,
+            sex,
+            region,
+            serial,
+            citizenship,
+        } = info;
+        check_birthday(birthday, 1800..=2099)?;
+        check_range("serial", serial, 0..=999)?;
+
+        let Date { year, month, day } = birthday;
+        let first = 2 * (year / 100 - 17) - 1 + u16::from(is_female(sex)?);
+        let citizenship = match citizenship {
+            Citizenship::Belarus => "PB",
+            Citizenship::Foreign => "BA",
+            Citizenship::Stateless => "BI",
+        };
+        let payload = format!(
+            "{first}{day:02}{month:02}{:02}{}{serial:03}{citizenship}",
+            year % 100,
+            region.letter()
+        );
+        let sum: u16 = payload
+            .bytes()
+            .zip(WEIGHTS.iter().cycle())
+            .map(|(b, weight)| {
+                let value = if b.is_ascii_digit() {
+                    b - b'0'
+                } else {
+                    b - b'A' + 10
+                };
+                u16::from(value) * weight
+            })
+            .sum();
+        Ok(format!("{payload}{}", sum % 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("3140391A001PB8"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1991,
+                    month: 3,
+                    day: 14
+                },
+                sex: Sex::Male,
+                region: Region::Brest,
+                serial: 1,
+                citizenship: Citizenship::Belarus,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_sex_century_digit() {
+        let info = decode("1010100K001PB6").unwrap();
+        assert_eq!(info.birthday.to_string(), "1800-01-01");
+        assert_eq!(info.sex, Sex::Male);
+        assert_eq!(info.region, Region::Grodno);
+        let info = decode("2311299M999PB6").unwrap();
+        assert_eq!(info.birthday.to_string(), "1899-12-31");
+        assert_eq!(info.sex, Sex::Female);
+        assert_eq!(info.serial, 999);
+        let info = decode("6290200H001PB9").unwrap();
+        assert_eq!(info.birthday.to_string(), "2000-02-29");
+        assert_eq!(info.sex, Sex::Female);
+        assert_eq!(info.region, Region::MinskCity);
+    }
+
+    #[test]
+    fn decode_citizenship() {
+        assert_eq!(
+            decode("3010180E014BA5").unwrap().citizenship,
+            Citizenship::Foreign
+        );
+        assert_eq!(
+            decode("3050385H005BI1").unwrap().citizenship,
+            Citizenship::Stateless
+        );
+        assert_eq!(
+            decode("3050385H005BI1").unwrap().summary().is_national,
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn decode_lowercase_input() {
+        assert_eq!(decode("3140391a001pb8"), decode("3140391A001PB8"));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("3140391A001PB"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("31403910001PB8"),
+            Err(NidError::Format(FormatKind::NonLetterCharacter))
+        );
+        assert_eq!(
+            decode("3140391AA01PB8"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("7140391A001PB6"),
+            Err(NidError::Format(FormatKind::InvalidSexDigit))
+        );
+        assert_eq!(
+            decode("3140391D001PB7"),
+            Err(NidError::Format(FormatKind::InvalidRegion))
+        );
+        assert_eq!(
+            decode("3140391A001XX0"),
+            Err(NidError::Format(FormatKind::InvalidCitizenship))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("3140391A001PB7"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("3141391A001PB5"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        // 1900 is not a leap year.
+        assert_eq!(
+            decode("4290200H001PB5"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 1900,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn region_letters_round_trip() {
+        for letter in ['A', 'B', 'C', 'E', 'H', 'K', 'M'] {
+            assert_eq!(Region::from_letter(letter).unwrap().letter(), letter);
+        }
+        assert_eq!(Region::from_letter('h'), Some(Region::MinskCity));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidCitizenship).to_string(),
+            "format error: citizenship code must be PB, BA or BI"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "BY_CHECKSUM");
+    }
+}
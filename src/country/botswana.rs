@@ -0,0 +1,339 @@
+//! Validate and decode Botswana national identity numbers (Omang).
+//!
+//! The Omang is the national identity card issued to citizens of Botswana. Its
+//! 9-digit number encodes the holder's sex in the fifth digit; the remaining
+//! digits are a serial with no published check digit.
+//!
+//! # Format
+//!
+//! `[serial (4)][sex][serial (4)]`
+//!
+//! - **Sex digit**: `1` for males, `2` for females.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::botswana::decode("123412345").unwrap();
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//!
+//! assert!(nidx::botswana::is_valid("987621234"));
+//! assert!(!nidx::botswana::is_valid("123432345"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Sex, Summary};
+
+/// Static facts about the Botswana Omang number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Botswana,
+    name: "Botswana",
+    alpha2: "BW",
+    alpha3: "BWA",
+    min_length: 9,
+    max_length: 9,
+    shapes: &[&[Run::exactly(CharClass::Digit, 9)]],
+    encodes_birthday: false,
+    encodes_sex: true,
+};
+
+/// Decoded information from a valid Omang number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Biological sex.
+    pub sex: Sex,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The Omang is only issued to citizens, so `is_national` is always
+    /// `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::botswana::decode("987621234").unwrap().summary();
+    /// assert_eq!(summary.sex, Some(nidx::Sex::Female));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: Some(self.sex),
+            is_national: Some(true),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the sex, e.g. `"M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.sex, f)
+    }
+}
+
+/// Specific reason an Omang number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 9 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The fifth digit is not `1` or `2`.
+    InvalidSexDigit,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "Omang number must be exactly 9 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::InvalidSexDigit => write!(f, "fifth digit must be 1 or 2"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding an Omang number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, sex digit).
+    Format(FormatKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `BW_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `BW_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidSexDigit`] | `BW_FORMAT_SEX_DIGIT` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::botswana::validate("123432345").unwrap_err();
+    /// assert_eq!(err.error_code(), "BW_FORMAT_SEX_DIGIT");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "BW_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "BW_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidSexDigit) => "BW_FORMAT_SEX_DIGIT",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Decode a Botswana Omang number.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or a fifth digit other than `1` or `2`.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::botswana::decode("987621234").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// ```
+#[must_use = "this returns the decoded Omang info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 9 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 9 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    let sex = match bytes[4] {
+        b'1' => Sex::Male,
+        b'2' => Sex::Female,
+        _ => return Err(NidError::Format(FormatKind::InvalidSexDigit)),
+    };
+    Ok(NidInfo { sex })
+}
+
+/// Decode a Botswana Omang number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::botswana::decode_with(" 1234 1 2345\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded Omang number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Botswana Omang number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::botswana::validate("123412345").is_ok());
+/// assert!(nidx::botswana::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Botswana Omang number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Botswana Omang number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::botswana::is_valid("123412345"));
+/// assert!(!nidx::botswana::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an Omang number: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::botswana::normalize(" 1234 1 2345\n"), "123412345");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Botswana Omang numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::botswana::Botswana;
+///
+/// let info = Botswana::decode("123412345").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Botswana;
+
+impl NationalId for Botswana {
+    const COUNTRY: Country = Country::Botswana;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_sex() {
+        assert_eq!(decode("123412345"), Ok(NidInfo { sex: Sex::Male }));
+        assert_eq!(decode("987621234"), Ok(NidInfo { sex: Sex::Female }));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("12341234"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("12341234X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("123402345"),
+            Err(NidError::Format(FormatKind::InvalidSexDigit))
+        );
+        assert_eq!(
+            decode("123432345"),
+            Err(NidError::Format(FormatKind::InvalidSexDigit))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidSexDigit).to_string(),
+            "format error: fifth digit must be 1 or 2"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidSexDigit).error_code(),
+            "BW_FORMAT_SEX_DIGIT"
+        );
+    }
+}
@@ -0,0 +1,429 @@
+//! Validate Chilean national identification numbers (RUN/RUT).
+//!
+//! The RUN (Rol Único Nacional) is a 7- or 8-digit serial followed by a check
+//! character, usually written with thousands dots and a hyphen, e.g.
+//! `12.345.678-5`. The hyphenated (`12345678-5`) and plain (`123456785`) forms
+//! are accepted too; [`format`] produces the dotted form. The same number
+//! serves as the tax number (RUT). It carries no personal data.
+//!
+//! # Format
+//!
+//! `[serial (7-8)][check]`
+//!
+//! - **Check character**: the serial digits, from the right, weighted by
+//!   `2 3 4 5 6 7` repeating; `11 - sum mod 11`, with `11` written as `0` and
+//!   `10` as `K`.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::chile::is_valid("12.345.678-5"));
+//! assert!(nidx::chile::is_valid("10000013-K"));
+//! assert!(!nidx::chile::is_valid("12.345.678-4"));
+//!
+//! assert_eq!(nidx::chile::format("123456785").unwrap(), "12.345.678-5");
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run};
+
+/// Static facts about the Chilean RUN format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Chile,
+    name: "Chile",
+    alpha2: "CL",
+    alpha3: "CHL",
+    min_length: 8,
+    max_length: 12,
+    shapes: &[
+        &[
+            Run::between(CharClass::Digit, 7, 8),
+            Run::optional(CharClass::Exact(b'-')),
+            Run::one(CharClass::Alphanumeric),
+        ],
+        &[
+            Run::between(CharClass::Digit, 1, 2),
+            Run::one(CharClass::Exact(b'.')),
+            Run::exactly(CharClass::Digit, 3),
+            Run::one(CharClass::Exact(b'.')),
+            Run::exactly(CharClass::Digit, 3),
+            Run::one(CharClass::Exact(b'-')),
+            Run::one(CharClass::Alphanumeric),
+        ],
+    ],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Specific reason a RUN was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// The serial is not 7 or 8 digits long.
+    InvalidLength,
+    /// A serial character other than a separator is not an ASCII digit.
+    NonDigitCharacter,
+    /// A dot or hyphen is misplaced, or dots are used without the hyphen.
+    InvalidSeparator,
+    /// The check character is neither an ASCII digit nor `K`.
+    InvalidCheckChar,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(
+                f,
+                "RUN must have a 7 or 8 digit serial and a check character"
+            ),
+            FormatKind::NonDigitCharacter => write!(f, "serial characters must be ASCII digits"),
+            FormatKind::InvalidSeparator => write!(
+                f,
+                "dots must separate thousands and the hyphen must precede the check character"
+            ),
+            FormatKind::InvalidCheckChar => write!(f, "check character must be a digit or K"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a RUN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters, misplaced separators).
+    Format(FormatKind),
+    /// The check character does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `CL_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `CL_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidSeparator`] | `CL_FORMAT_SEPARATOR` |
+    /// | [`FormatKind::InvalidCheckChar`] | `CL_FORMAT_CHECK_CHAR` |
+    /// | [`NidError::Checksum`] | `CL_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::chile::validate("12345678-4").unwrap_err();
+    /// assert_eq!(err.error_code(), "CL_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "CL_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "CL_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidSeparator) => "CL_FORMAT_SEPARATOR",
+            NidError::Format(FormatKind::InvalidCheckChar) => "CL_FORMAT_CHECK_CHAR",
+            NidError::Checksum => "CL_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Parse a RUN in any accepted form into its serial and uppercased check
+/// character.
+const fn parse(bytes: &[u8]) -> Result<(u32, u8), NidError> {
+    let len = bytes.len();
+    if len < 8 || len > 12 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let check = bytes[len - 1].to_ascii_uppercase();
+    if !check.is_ascii_digit() && check != b'K' {
+        return Err(NidError::Format(FormatKind::InvalidCheckChar));
+    }
+    let hyphen = bytes[len - 2] == b'-';
+    let end = if hyphen { len - 2 } else { len - 1 };
+    let dotted = end > 4 && bytes[end - 4] == b'.';
+    if dotted && !hyphen {
+        return Err(NidError::Format(FormatKind::InvalidSeparator));
+    }
+
+    let mut serial = 0u32;
+    let mut digits = 0;
+    let mut i = 0;
+    while i < end {
+        let b = bytes[i];
+        let from_right = end - 1 - i;
+        if dotted && (from_right == 3 || from_right == 7) {
+            if b != b'.' {
+                return Err(NidError::Format(FormatKind::InvalidSeparator));
+            }
+        } else if b.is_ascii_digit() {
+            if digits == 8 {
+                return Err(NidError::Format(FormatKind::InvalidLength));
+            }
+            serial = serial * 10 + (b - b'0') as u32;
+            digits += 1;
+        } else if b == b'.' || b == b'-' {
+            return Err(NidError::Format(FormatKind::InvalidSeparator));
+        } else {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    if digits != 7 && digits != 8 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    if check_char(serial) != check {
+        return Err(NidError::Checksum);
+    }
+    Ok((serial, check))
+}
+
+/// Compute the check character for a serial.
+const fn check_char(mut serial: u32) -> u8 {
+    let mut sum = 0;
+    let mut weight = 2;
+    while serial > 0 {
+        sum += serial % 10 * weight;
+        serial /= 10;
+        weight = if weight == 7 { 2 } else { weight + 1 };
+    }
+    match 11 - sum % 11 {
+        11 => b'0',
+        10 => b'K',
+        d => b'0' + d as u8,
+    }
+}
+
+/// Validate a Chilean RUN, in dotted, hyphenated or plain form.
+///
+/// The check character `K` is accepted in either case.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, illegal
+/// characters or misplaced separators, and [`NidError::Checksum`] if the
+/// check character does not match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::chile::validate("7.654.321-6").is_ok());
+/// assert!(nidx::chile::validate("invalid").is_err());
+/// ```
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match parse(nid.as_bytes()) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Chilean RUN string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::chile::validate_with(" 12.345.678-5\n", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    validate(&options.prepare(nid))
+}
+
+/// Check whether a Chilean RUN string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::chile::is_valid("12345678-5"));
+/// assert!(!nidx::chile::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Validate a RUN and return it in the canonical dotted form, e.g.
+/// `12.345.678-5`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`].
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::chile::format("7654321-6").unwrap(), "7.654.321-6");
+/// assert_eq!(nidx::chile::format("10000013k").unwrap(), "10.000.013-K");
+/// assert!(nidx::chile::format("12345678-4").is_err());
+/// ```
+pub fn format(nid: &str) -> Result<String, NidError> {
+    let (serial, check) = parse(nid.as_bytes())?;
+    Ok(format!(
+        "{}.{:03}.{:03}-{}",
+        serial / 1_000_000,
+        serial / 1000 % 1000,
+        serial % 1000,
+        check as char
+    ))
+}
+
+/// Return the canonical form of a RUN: whitespace, dots and the hyphen
+/// removed and `K` uppercased.
+///
+/// Use [`format`] for the dotted display form.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::chile::normalize(" 12.345.678-5\n"), "123456785");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.' && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Chilean RUN numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::chile::Chile;
+///
+/// assert_eq!(Chile::decode("12.345.678-5"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Chile;
+
+impl NationalId for Chile {
+    const COUNTRY: Country = Country::Chile;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_forms() {
+        assert_eq!(validate("12.345.678-5"), Ok(()));
+        assert_eq!(validate("12345678-5"), Ok(()));
+        assert_eq!(validate("123456785"), Ok(()));
+        assert_eq!(validate("1.234.567-4"), Ok(()));
+        assert_eq!(validate("1234567-4"), Ok(()));
+        assert_eq!(validate("12345674"), Ok(()));
+    }
+
+    #[test]
+    fn validate_special_check_chars() {
+        assert_eq!(validate("10.000.013-K"), Ok(()));
+        assert_eq!(validate("10.000.013-k"), Ok(()));
+        assert_eq!(validate("10.000.004-0"), Ok(()));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            validate("123456-0"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("123.456.789-0"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("86095742719"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("1234567X-5"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            validate("12345678-X"),
+            Err(NidError::Format(FormatKind::InvalidCheckChar))
+        );
+        assert_eq!(
+            validate("12.345.6785"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            validate("12.3456.78-5"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            validate("1234-5678-5"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(validate("12.345.678-4"), Err(NidError::Checksum));
+        assert_eq!(validate("10000013-0"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn format_dotted() {
+        assert_eq!(format("123456785").as_deref(), Ok("12.345.678-5"));
+        assert_eq!(format("12.345.678-5").as_deref(), Ok("12.345.678-5"));
+        assert_eq!(format("1234567-4").as_deref(), Ok("1.234.567-4"));
+        assert_eq!(format("10000004-0").as_deref(), Ok("10.000.004-0"));
+        assert_eq!(format("12345678-4"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidCheckChar).to_string(),
+            "format error: check character must be a digit or K"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "CL_CHECKSUM");
+    }
+}
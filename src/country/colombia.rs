@@ -0,0 +1,541 @@
+//! Validate Colombian personal identity numbers: cédula de ciudadanía and
+//! NUIP (Número Único de Identificación Personal).
+//!
+//! The Registraduría Nacional issued sequential cédula numbers of up to 8
+//! digits to adult citizens until the early 2000s. Citizens registered since
+//! then are assigned a 10-digit NUIP at birth, which becomes their cédula
+//! number at 18. Neither number has a check digit of its own. When the same
+//! number is used as a tax identification number (NIT) it is written with a
+//! hyphen and the DIAN check digit, e.g. `79555123-7`; that form is accepted
+//! too and its check digit verified.
+//!
+//! # Format
+//!
+//! `[number (3–8 or 10)][-][NIT check digit]`
+//!
+//! - **Cédula**: 3 to 8 digits, not starting with `0`.
+//! - **NUIP**: 10 digits. NUIPs are allocated upwards from `1000000000`
+//!   and have not yet passed `1199999999`, so the first two digits must be
+//!   `10` or `11`.
+//! - **NIT check digit**: the digits, from the right, weighted by `3 7 13 17
+//!   19 23 29 37 41 43`; with `r = sum mod 11` the check digit is `r` if
+//!   `r < 2`, otherwise `11 - r`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::colombia::DocumentClass;
+//!
+//! assert_eq!(nidx::colombia::decode("79555123").unwrap().class, DocumentClass::Cedula);
+//! assert_eq!(nidx::colombia::decode("1020304050").unwrap().class, DocumentClass::Nuip);
+//!
+//! assert!(nidx::colombia::is_valid("79555123-7"));
+//! assert!(!nidx::colombia::is_valid("79555123-8"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Colombian identity number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Colombia,
+    name: "Colombia",
+    alpha2: "CO",
+    alpha3: "COL",
+    min_length: 3,
+    max_length: 12,
+    shapes: &[
+        &[Run::between(CharClass::Digit, 3, 10)],
+        &[
+            Run::between(CharClass::Digit, 3, 10),
+            Run::one(CharClass::Exact(b'-')),
+            Run::one(CharClass::Digit),
+        ],
+    ],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// DIAN weights, applied from the rightmost digit.
+const NIT_WEIGHTS: [u16; 10] = [3, 7, 13, 17, 19, 23, 29, 37, 41, 43];
+
+/// Which kind of personal number matched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentClass {
+    /// A legacy cédula de ciudadanía number of 3 to 8 digits.
+    Cedula,
+    /// A 10-digit NUIP, starting with `10` or `11`.
+    Nuip,
+}
+
+impl fmt::Display for DocumentClass {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentClass::Cedula => write!(f, "CC"),
+            DocumentClass::Nuip => write!(f, "NUIP"),
+        }
+    }
+}
+
+/// Decoded information from a valid identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Which kind of personal number matched.
+    pub class: DocumentClass,
+    /// The NIT check digit, if the number was given in NIT form.
+    pub nit_check_digit: Option<u8>,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Cédulas de ciudadanía and NUIPs are only issued to citizens, so
+    /// `is_national` is always `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::colombia::decode("79555123").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(true),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the document class, e.g. `"NUIP"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.class, f)
+    }
+}
+
+/// Specific reason an identity number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// The number is not 3 to 8 or 10 digits, or the NIT check digit is not a single digit.
+    InvalidLength,
+    /// A character other than the hyphen before the check digit is not an ASCII digit.
+    NonDigitCharacter,
+    /// A cédula starts with `0`, or a NUIP does not start with `10` or `11`.
+    InvalidLeadingDigit,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "number must be 3 to 8 or 10 digits"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the hyphen must be ASCII digits")
+            }
+            FormatKind::InvalidLeadingDigit => write!(
+                f,
+                "cédula must not start with 0 and NUIP must start with 10 or 11"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding an identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, leading digit).
+    Format(FormatKind),
+    /// The NIT check digit does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `CO_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `CO_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidLeadingDigit`] | `CO_FORMAT_LEADING_DIGIT` |
+    /// | [`NidError::Checksum`] | `CO_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::colombia::validate("79555123-8").unwrap_err();
+    /// assert_eq!(err.error_code(), "CO_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "CO_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "CO_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidLeadingDigit) => "CO_FORMAT_LEADING_DIGIT",
+            NidError::Checksum => "CO_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Compute the DIAN check digit over `digits`, which must all be ASCII
+/// digits and at most 10 long.
+const fn compute_check_digit(digits: &[u8]) -> u8 {
+    let mut sum = 0u16;
+    let mut i = 0;
+    while i < digits.len() {
+        sum += (digits[digits.len() - 1 - i] - b'0') as u16 * NIT_WEIGHTS[i];
+        i += 1;
+    }
+    match sum % 11 {
+        r @ (0 | 1) => r as u8,
+        r => (11 - r) as u8,
+    }
+}
+
+/// Compute the DIAN check digit used when `number` is written as a NIT.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if `number` is not a valid cédula or NUIP
+/// without a check digit.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::colombia::nit_check_digit("79555123"), Ok(7));
+/// ```
+pub const fn nit_check_digit(number: &str) -> Result<u8, NidError> {
+    let bytes = number.as_bytes();
+    if let Err(e) = classify(bytes) {
+        return Err(e);
+    }
+    Ok(compute_check_digit(bytes))
+}
+
+/// Determine the class of a bare number.
+const fn classify(digits: &[u8]) -> Result<DocumentClass, NidError> {
+    let class = match digits.len() {
+        3..=8 => DocumentClass::Cedula,
+        10 => DocumentClass::Nuip,
+        _ => return Err(NidError::Format(FormatKind::InvalidLength)),
+    };
+    let mut i = 0;
+    while i < digits.len() {
+        if !digits[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    let leading_ok = match class {
+        DocumentClass::Cedula => digits[0] != b'0',
+        DocumentClass::Nuip => digits[0] == b'1' && (digits[1] == b'0' || digits[1] == b'1'),
+    };
+    if !leading_ok {
+        return Err(NidError::Format(FormatKind::InvalidLeadingDigit));
+    }
+    Ok(class)
+}
+
+/// Decode a Colombian cédula or NUIP, optionally in NIT form.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or an invalid leading digit, and [`NidError::Checksum`] if a
+/// NIT check digit is present and does not match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::colombia::decode("1020304050-8").unwrap();
+/// assert_eq!(info.nit_check_digit, Some(8));
+/// ```
+#[must_use = "this returns the decoded identity number info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let (digits, check) = match src.len() {
+        len if len > 2 && src[len - 2] == b'-' => {
+            let (digits, rest) = src.split_at(len - 2);
+            (digits, Some(rest[1]))
+        }
+        _ => (src, None),
+    };
+    let class = match classify(digits) {
+        Ok(class) => class,
+        Err(e) => return Err(e),
+    };
+    let nit_check_digit = match check {
+        None => None,
+        Some(c) if c.is_ascii_digit() => {
+            if compute_check_digit(digits) != c - b'0' {
+                return Err(NidError::Checksum);
+            }
+            Some(c - b'0')
+        }
+        Some(_) => return Err(NidError::Format(FormatKind::NonDigitCharacter)),
+    };
+    Ok(NidInfo {
+        class,
+        nit_check_digit,
+    })
+}
+
+/// Decode a Colombian identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// The hyphen before a NIT check digit is kept when separators are
+/// stripped.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::colombia::decode_with(" 79.555.123-7\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.nit_check_digit, Some(7));
+/// ```
+#[inline]
+#[must_use = "this returns the decoded identity number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare_keep_hyphen(nid))
+}
+
+/// Validate a Colombian identity number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::colombia::validate("79555123").is_ok());
+/// assert!(nidx::colombia::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Colombian identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Colombian identity number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::colombia::is_valid("79555123"));
+/// assert!(!nidx::colombia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an identity number: whitespace and dots
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::colombia::normalize(" 79.555.123-7"), "79555123-7");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Colombian identity numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::colombia::{Colombia, DocumentClass};
+///
+/// let info = Colombia::decode("1020304050").unwrap();
+/// assert_eq!(info.class, DocumentClass::Nuip);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Colombia;
+
+impl NationalId for Colombia {
+    const COUNTRY: Country = Country::Colombia;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_classes() {
+        assert_eq!(
+            decode("79555123"),
+            Ok(NidInfo {
+                class: DocumentClass::Cedula,
+                nit_check_digit: None,
+            })
+        );
+        assert_eq!(
+            decode("1020304050"),
+            Ok(NidInfo {
+                class: DocumentClass::Nuip,
+                nit_check_digit: None,
+            })
+        );
+        assert_eq!(decode("123").unwrap().class, DocumentClass::Cedula);
+    }
+
+    #[test]
+    fn decode_nit_form() {
+        assert_eq!(
+            decode("79555123-7"),
+            Ok(NidInfo {
+                class: DocumentClass::Cedula,
+                nit_check_digit: Some(7),
+            })
+        );
+        assert_eq!(decode("52123456-2").unwrap().nit_check_digit, Some(2));
+        // Remainder 1 gives check digit 1.
+        assert_eq!(decode("1000000000-1").unwrap().nit_check_digit, Some(1));
+    }
+
+    #[test]
+    fn lenient_keeps_nit_hyphen() {
+        let opts = ParseOptions::lenient();
+        assert_eq!(decode_with("79.555.123-7", &opts), decode("79555123-7"));
+        assert_eq!(
+            Colombia::decode_with(" 1020304050-8 ", &opts),
+            decode("1020304050-8")
+        );
+    }
+
+    #[test]
+    fn nit_check_digit_helper() {
+        assert_eq!(nit_check_digit("1020304050"), Ok(8));
+        assert_eq!(
+            nit_check_digit("79555123-7"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("12"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("123456789"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("79555123-77"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("7955512X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("79555123-X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("09555123"),
+            Err(NidError::Format(FormatKind::InvalidLeadingDigit))
+        );
+        assert_eq!(
+            decode("2020304050"),
+            Err(NidError::Format(FormatKind::InvalidLeadingDigit))
+        );
+        assert_eq!(
+            decode("1234567890"),
+            Err(NidError::Format(FormatKind::InvalidLeadingDigit))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("79555123-8"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: number must be 3 to 8 or 10 digits"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "CO_CHECKSUM");
+    }
+}
@@ -0,0 +1,443 @@
+//! Validate Costa Rican identity numbers: citizens' cédulas de identidad
+//! and DIMEX numbers for foreign residents.
+//!
+//! The Registro Civil issues each citizen a 9-digit cédula number, printed as
+//! `1-0234-0567`: the province of registration, then the tome (tomo) and entry
+//! (asiento) of the civil register. Foreign residents receive an 11- or
+//! 12-digit DIMEX (Documento de Identidad Migratorio para Extranjeros) number
+//! from the Dirección General de Migración. Neither has a check digit.
+//!
+//! # Format
+//!
+//! - **Cédula**: `[province][-][tome (4)][-][entry (4)]`. The province digit
+//!   is `1`–`7` for the seven provinces, `8` for naturalised citizens and `9`
+//!   for special registrations; `0` is never used. Both hyphens are optional
+//!   but must be used together.
+//! - **DIMEX**: 11 or 12 digits, not starting with `0`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::costa_rica::DocumentKind;
+//!
+//! let info = nidx::costa_rica::decode("1-0234-0567").unwrap();
+//! assert_eq!(info.kind, DocumentKind::Cedula);
+//! assert_eq!(info.province, Some(1));
+//!
+//! let info = nidx::costa_rica::decode("155812345678").unwrap();
+//! assert_eq!(info.kind, DocumentKind::Dimex);
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Costa Rican identity number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::CostaRica,
+    name: "Costa Rica",
+    alpha2: "CR",
+    alpha3: "CRI",
+    min_length: 9,
+    max_length: 12,
+    shapes: &[
+        &[Run::exactly(CharClass::Digit, 9)],
+        &[
+            Run::one(CharClass::Digit),
+            Run::one(CharClass::Exact(b'-')),
+            Run::exactly(CharClass::Digit, 4),
+            Run::one(CharClass::Exact(b'-')),
+            Run::exactly(CharClass::Digit, 4),
+        ],
+        &[Run::between(CharClass::Digit, 11, 12)],
+    ],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Length of the hyphenated cédula form, `P-TTTT-AAAA`.
+const HYPHENATED_LEN: usize = 11;
+
+/// Which document an identity number belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentKind {
+    /// Cédula de identidad, issued to Costa Rican citizens.
+    Cedula,
+    /// Documento de Identidad Migratorio para Extranjeros, issued to foreign
+    /// residents.
+    Dimex,
+}
+
+impl fmt::Display for DocumentKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentKind::Cedula => write!(f, "cédula"),
+            DocumentKind::Dimex => write!(f, "DIMEX"),
+        }
+    }
+}
+
+/// Decoded information from a valid identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Which document the number belongs to.
+    pub kind: DocumentKind,
+    /// The province digit of a cédula (`1`–`9`), or `None` for a DIMEX.
+    pub province: Option<u8>,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Cédulas are only issued to citizens and DIMEX numbers only to
+    /// foreigners, so `is_national` follows from the document kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::costa_rica::decode("155812345678").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(matches!(self.kind, DocumentKind::Cedula)),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the document kind, e.g. `"DIMEX"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+/// Specific reason an identity number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 9 digits (cédula), 11 characters with hyphens (cédula) or 11 or 12 digits (DIMEX).
+    InvalidLength,
+    /// A hyphenated cédula does not have hyphens after the province digit and the tome.
+    InvalidSeparator,
+    /// A character other than the hyphens is not an ASCII digit.
+    NonDigitCharacter,
+    /// The first digit is `0`.
+    LeadingZero,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(
+                f,
+                "identity number must be a 9-digit cédula or an 11- or 12-digit DIMEX"
+            ),
+            FormatKind::InvalidSeparator => {
+                write!(f, "hyphens must follow the province digit and the tome")
+            }
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the hyphens must be ASCII digits")
+            }
+            FormatKind::LeadingZero => write!(f, "first digit must not be 0"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding an identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, misplaced hyphens, non-digit characters, leading zero).
+    Format(FormatKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `CR_FORMAT_LENGTH` |
+    /// | [`FormatKind::InvalidSeparator`] | `CR_FORMAT_SEPARATOR` |
+    /// | [`FormatKind::NonDigitCharacter`] | `CR_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::LeadingZero`] | `CR_FORMAT_LEADING_ZERO` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::costa_rica::validate("1-0234-056").unwrap_err();
+    /// assert_eq!(err.error_code(), "CR_FORMAT_LENGTH");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "CR_FORMAT_LENGTH",
+            NidError::Format(FormatKind::InvalidSeparator) => "CR_FORMAT_SEPARATOR",
+            NidError::Format(FormatKind::NonDigitCharacter) => "CR_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::LeadingZero) => "CR_FORMAT_LEADING_ZERO",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Decode a Costa Rican cédula or DIMEX number.
+///
+/// A 9-digit input, or 11 characters with hyphens after the first and sixth,
+/// is a cédula; 11 or 12 digits without hyphens are a DIMEX.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, misplaced
+/// hyphens, non-digit characters or a leading zero.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::costa_rica::decode("802340567").unwrap();
+/// assert_eq!(info.province, Some(8));
+/// ```
+#[must_use = "this returns the decoded identity number info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let hyphenated = src.len() == HYPHENATED_LEN && (src[1] == b'-' || src[6] == b'-');
+    let kind = match src.len() {
+        9 => DocumentKind::Cedula,
+        HYPHENATED_LEN if hyphenated => {
+            if src[1] != b'-' || src[6] != b'-' {
+                return Err(NidError::Format(FormatKind::InvalidSeparator));
+            }
+            DocumentKind::Cedula
+        }
+        11 | 12 => DocumentKind::Dimex,
+        _ => return Err(NidError::Format(FormatKind::InvalidLength)),
+    };
+    let mut i = 0;
+    while i < src.len() {
+        if !(hyphenated && (i == 1 || i == 6)) && !src[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    if src[0] == b'0' {
+        return Err(NidError::Format(FormatKind::LeadingZero));
+    }
+    let province = match kind {
+        DocumentKind::Cedula => Some(src[0] - b'0'),
+        DocumentKind::Dimex => None,
+    };
+    Ok(NidInfo { kind, province })
+}
+
+/// Decode a Costa Rican identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::costa_rica::decode_with(" 1 0234 0567\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.province, Some(1));
+/// ```
+#[inline]
+#[must_use = "this returns the decoded identity number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Costa Rican identity number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::costa_rica::validate("1-0234-0567").is_ok());
+/// assert!(nidx::costa_rica::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Costa Rican identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Costa Rican identity number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::costa_rica::is_valid("1-0234-0567"));
+/// assert!(!nidx::costa_rica::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an identity number: whitespace and hyphens
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::costa_rica::normalize("1-0234-0567"), "102340567");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Costa Rican identity numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::costa_rica::{CostaRica, DocumentKind};
+///
+/// let info = CostaRica::decode("1-0234-0567").unwrap();
+/// assert_eq!(info.kind, DocumentKind::Cedula);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct CostaRica;
+
+impl NationalId for CostaRica {
+    const COUNTRY: Country = Country::CostaRica;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_cedula() {
+        assert_eq!(
+            decode("102340567"),
+            Ok(NidInfo {
+                kind: DocumentKind::Cedula,
+                province: Some(1),
+            })
+        );
+        assert_eq!(decode("1-0234-0567"), decode("102340567"));
+        assert_eq!(decode("912345678").unwrap().province, Some(9));
+    }
+
+    #[test]
+    fn decode_dimex() {
+        assert_eq!(
+            decode("155812345678"),
+            Ok(NidInfo {
+                kind: DocumentKind::Dimex,
+                province: None,
+            })
+        );
+        assert_eq!(decode("15581234567").unwrap().kind, DocumentKind::Dimex);
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("10234056"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("1558123456789"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("1-02340-567"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            decode("1-0234-056X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("10-23-40567"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("002340567"),
+            Err(NidError::Format(FormatKind::LeadingZero))
+        );
+        assert_eq!(
+            decode("05581234567"),
+            Err(NidError::Format(FormatKind::LeadingZero))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::LeadingZero).to_string(),
+            "format error: first digit must not be 0"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidSeparator).error_code(),
+            "CR_FORMAT_SEPARATOR"
+        );
+    }
+}
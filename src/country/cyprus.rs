@@ -0,0 +1,444 @@
+//! Validate Cypriot identity numbers: identity card numbers and Alien
+//! Registration Certificate (ARC) numbers.
+//!
+//! Cypriot citizens are issued an identity card (Δελτίο Ταυτότητας) carrying a
+//! sequential identity number. Foreign residents are instead registered by the
+//! Civil Registry and Migration Department and receive an ARC number. Neither
+//! series has a check digit or encodes personal data.
+//!
+//! # Format
+//!
+//! - **Identity card**: 1 to 8 digits, e.g. `1234567`.
+//! - **ARC**: the letters `ARC` followed by 1 to 8 digits, e.g. `ARC123456`.
+//!   The prefix is what tells the two series apart, so an ARC number written
+//!   without it is read as an identity card number.
+//! - **Number**: never all zeros.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::cyprus::DocumentKind;
+//!
+//! assert_eq!(nidx::cyprus::decode("1234567").unwrap().kind, DocumentKind::IdentityCard);
+//! assert_eq!(nidx::cyprus::decode("ARC123456").unwrap().kind, DocumentKind::Arc);
+//!
+//! assert!(!nidx::cyprus::is_valid("123456789"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Cypriot identity number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Cyprus,
+    name: "Cyprus",
+    alpha2: "CY",
+    alpha3: "CYP",
+    min_length: 1,
+    max_length: 11,
+    shapes: &[
+        &[Run::between(CharClass::Digit, 1, 8)],
+        &[
+            Run::one(CharClass::Exact(b'A')),
+            Run::one(CharClass::Exact(b'R')),
+            Run::one(CharClass::Exact(b'C')),
+            Run::between(CharClass::Digit, 1, 8),
+        ],
+    ],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// The prefix written before ARC numbers.
+const ARC_PREFIX: &[u8] = b"ARC";
+
+/// Maximum number of digits in either series.
+const MAX_DIGITS: usize = 8;
+
+/// Which series an identity number belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentKind {
+    /// Identity card number, issued to Cypriot citizens.
+    IdentityCard,
+    /// Alien Registration Certificate number, issued to foreign residents.
+    Arc,
+}
+
+impl fmt::Display for DocumentKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentKind::IdentityCard => write!(f, "ID"),
+            DocumentKind::Arc => write!(f, "ARC"),
+        }
+    }
+}
+
+/// Decoded information from a valid identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Which series the number belongs to.
+    pub kind: DocumentKind,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Identity cards are only issued to citizens and ARCs only to
+    /// foreigners, so `is_national` follows from the document kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::cyprus::decode("ARC123456").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(matches!(self.kind, DocumentKind::IdentityCard)),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the document kind, e.g. `"ARC"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+/// Specific reason an identity number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// The number, after any `ARC` prefix, is empty or longer than 8 digits.
+    InvalidLength,
+    /// A character after any `ARC` prefix is not an ASCII digit.
+    NonDigitCharacter,
+    /// Every digit is `0`.
+    AllZeros,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "number must be 1 to 8 digits"),
+            FormatKind::NonDigitCharacter => write!(
+                f,
+                "all characters after the ARC prefix must be ASCII digits"
+            ),
+            FormatKind::AllZeros => write!(f, "number must not be all zeros"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding an identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, all zeros).
+    Format(FormatKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `CY_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `CY_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::AllZeros`] | `CY_FORMAT_ALL_ZEROS` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::cyprus::validate("12345X7").unwrap_err();
+    /// assert_eq!(err.error_code(), "CY_FORMAT_NON_DIGIT");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "CY_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "CY_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::AllZeros) => "CY_FORMAT_ALL_ZEROS",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Whether `src` starts with the `ARC` prefix, in any case.
+const fn has_arc_prefix(src: &[u8]) -> bool {
+    if src.len() < ARC_PREFIX.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < ARC_PREFIX.len() {
+        if src[i].to_ascii_uppercase() != ARC_PREFIX[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Decode a Cypriot identity card or ARC number.
+///
+/// The `ARC` prefix is matched case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the number after any prefix is empty,
+/// longer than 8 digits, contains a non-digit character or is all zeros.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::cyprus::DocumentKind;
+///
+/// let info = nidx::cyprus::decode("arc123456").unwrap();
+/// assert_eq!(info.kind, DocumentKind::Arc);
+/// ```
+#[must_use = "this returns the decoded identity number info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let (kind, start) = if has_arc_prefix(src) {
+        (DocumentKind::Arc, ARC_PREFIX.len())
+    } else {
+        (DocumentKind::IdentityCard, 0)
+    };
+    let len = src.len() - start;
+    if len == 0 || len > MAX_DIGITS {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut all_zeros = true;
+    let mut i = start;
+    while i < src.len() {
+        if !src[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        if src[i] != b'0' {
+            all_zeros = false;
+        }
+        i += 1;
+    }
+    if all_zeros {
+        return Err(NidError::Format(FormatKind::AllZeros));
+    }
+    Ok(NidInfo { kind })
+}
+
+/// Decode a Cypriot identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::cyprus::decode_with(" ARC 123-456\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.kind, nidx::cyprus::DocumentKind::Arc);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded identity number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Cypriot identity number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::cyprus::validate("1234567").is_ok());
+/// assert!(nidx::cyprus::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Cypriot identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Cypriot identity number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::cyprus::is_valid("1234567"));
+/// assert!(!nidx::cyprus::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an identity number: whitespace and hyphens
+/// removed and the `ARC` prefix uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::cyprus::normalize(" arc 123-456"), "ARC123456");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Cypriot identity numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::cyprus::{Cyprus, DocumentKind};
+///
+/// let info = Cyprus::decode("1234567").unwrap();
+/// assert_eq!(info.kind, DocumentKind::IdentityCard);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Cyprus;
+
+impl NationalId for Cyprus {
+    const COUNTRY: Country = Country::Cyprus;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_identity_card() {
+        assert_eq!(
+            decode("1234567"),
+            Ok(NidInfo {
+                kind: DocumentKind::IdentityCard
+            })
+        );
+        assert!(decode("1").is_ok());
+        assert!(decode("00000001").is_ok());
+    }
+
+    #[test]
+    fn decode_arc() {
+        assert_eq!(
+            decode("ARC123456"),
+            Ok(NidInfo {
+                kind: DocumentKind::Arc
+            })
+        );
+        assert_eq!(decode("arc123456"), decode("ARC123456"));
+        assert_eq!(decode("Arc12345678").unwrap().kind, DocumentKind::Arc);
+    }
+
+    #[test]
+    fn summary_follows_kind() {
+        assert_eq!(decode("1234567").unwrap().summary().is_national, Some(true));
+        assert_eq!(
+            decode("ARC1234567").unwrap().summary().is_national,
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(decode(""), Err(NidError::Format(FormatKind::InvalidLength)));
+        assert_eq!(
+            decode("ARC"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("123456789"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("ARC 123456"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("AR123456"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("ARC0000"),
+            Err(NidError::Format(FormatKind::AllZeros))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::AllZeros).to_string(),
+            "format error: number must not be all zeros"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).error_code(),
+            "CY_FORMAT_LENGTH"
+        );
+    }
+}
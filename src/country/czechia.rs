@@ -0,0 +1,521 @@
+//! Validate and decode Czech birth numbers (rodné číslo).
+//!
+//! The rodné číslo encodes date of birth and sex. Numbers issued before 1954
+//! have 9 digits and no check digit; later numbers have 10 digits. The number
+//! is usually written with a slash after the date, e.g. `780123/1230`; both
+//! forms are accepted.
+//!
+//! # Format
+//!
+//! `[year (2)][month (2)][day (2)]/[serial (3)][check]`
+//!
+//! - **Year**: 9-digit numbers map `00`–`53` to 1900–1953. 10-digit numbers map
+//!   `54`–`99` to 1954–1999 and `00`–`53` to 2000–2053.
+//! - **Month**: `+50` for women. From 2004, when the serials for a day run
+//!   out, `+20` is added as well (`21`–`32` for men, `71`–`82` for women).
+//! - **Check digit**: the 10-digit number is divisible by 11. Historically,
+//!   when the first 9 digits give remainder 10 mod 11, the check digit is `0`.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::czechia::decode("785123/1234").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1978-01-23");
+//! assert_eq!(info.sex, nidx::Sex::Female);
+//!
+//! assert!(nidx::czechia::is_valid("7801231230"));
+//! assert!(!nidx::czechia::is_valid("7801231231"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::{CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Sex, Summary};
+
+/// Static facts about the Czech birth number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Czechia,
+    name: "Czechia",
+    alpha2: "CZ",
+    alpha3: "CZE",
+    min_length: 9,
+    max_length: 11,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 6),
+        Run::optional(CharClass::Exact(b'/')),
+        Run::between(CharClass::Digit, 3, 4),
+    ]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+/// Decoded information from a valid birth number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Birth numbers are also issued to foreign residents, so `is_national`
+    /// is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::czechia::decode("7801231230").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1978-01-23 M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1978-01-23 M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a birth number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 9 or 10 digits, with an optional slash after the sixth.
+    InvalidLength,
+    /// A character other than the slash is not an ASCII digit.
+    NonDigitCharacter,
+    /// A 9-digit number encodes a year after 1953.
+    ShortFormAfter1953,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "birth number must be 9 or 10 digits"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the slash must be ASCII digits")
+            }
+            FormatKind::ShortFormAfter1953 => {
+                write!(f, "9-digit birth numbers were only issued before 1954")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a birth number was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month (including offsets) is out of range, or uses the
+    /// `+20` offset before 2004.
+    MonthOutOfRange {
+        /// The encoded month value, including offsets.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The decoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a birth number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `CZ_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `CZ_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::ShortFormAfter1953`] | `CZ_FORMAT_SHORT_FORM` |
+    /// | [`NidError::Checksum`] | `CZ_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `CZ_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `CZ_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::czechia::validate("7801231231").unwrap_err();
+    /// assert_eq!(err.error_code(), "CZ_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "CZ_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "CZ_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::ShortFormAfter1953) => "CZ_FORMAT_SHORT_FORM",
+            NidError::Checksum => "CZ_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "CZ_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "CZ_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Decode a Czech birth number, with or without the slash.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters, or is a 9-digit number after 1953, [`NidError::Checksum`] if
+/// the check digit does not match, and [`NidError::InvalidDate`] if the
+/// encoded date is not valid.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::czechia::decode("0421231239").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2004-01-23");
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[must_use = "this returns the decoded birth number info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let slash = src.len() > 6 && src[6] == b'/';
+    let len = if slash { src.len() - 1 } else { src.len() };
+    if len != 9 && len != 10 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut digits = [0u8; 10];
+    let mut n = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if !(slash && i == 6) {
+            if !src[i].is_ascii_digit() {
+                return Err(NidError::Format(FormatKind::NonDigitCharacter));
+            }
+            digits[n] = src[i] - b'0';
+            n += 1;
+        }
+        i += 1;
+    }
+
+    let yy = (digits[0] * 10 + digits[1]) as u16;
+    let year = if len == 9 {
+        if yy >= 54 {
+            return Err(NidError::Format(FormatKind::ShortFormAfter1953));
+        }
+        1900 + yy
+    } else {
+        let mut rest = 0u64;
+        let mut i = 0;
+        while i < 9 {
+            rest = rest * 10 + digits[i] as u64;
+            i += 1;
+        }
+        if (rest % 11 % 10) as u8 != digits[9] {
+            return Err(NidError::Checksum);
+        }
+        if yy >= 54 { 1900 + yy } else { 2000 + yy }
+    };
+
+    let encoded_month = digits[2] * 10 + digits[3];
+    let (month, sex) = match encoded_month {
+        1..=12 => (encoded_month, Sex::Male),
+        51..=62 => (encoded_month - 50, Sex::Female),
+        21..=32 if year >= 2004 => (encoded_month - 20, Sex::Male),
+        71..=82 if year >= 2004 => (encoded_month - 70, Sex::Female),
+        _ => {
+            return Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: encoded_month,
+            }));
+        }
+    };
+    let day = digits[4] * 10 + digits[5];
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+            year,
+            month,
+            day,
+        }));
+    };
+
+    Ok(NidInfo { birthday, sex })
+}
+
+/// Decode a Czech birth number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::czechia::decode_with(" 780123/1230\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1978-01-23");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded birth number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Czech birth number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::czechia::validate("7801231230").is_ok());
+/// assert!(nidx::czechia::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Czech birth number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Czech birth number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::czechia::is_valid("7801231230"));
+/// assert!(!nidx::czechia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a birth number: whitespace and the slash
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::czechia::normalize(" 780123/1230\n"), "7801231230");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '/')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Czech birth numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::czechia::Czechia;
+///
+/// let info = Czechia::decode("780123/1230").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Czechia;
+
+impl NationalId for Czechia {
+    const COUNTRY: Country = Country::Czechia;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("7801231230"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1978,
+                    month: 1,
+                    day: 23
+                },
+                sex: Sex::Male,
+            })
+        );
+        assert_eq!(decode("780123/1230"), decode("7801231230"));
+    }
+
+    #[test]
+    fn decode_month_offsets() {
+        let female = decode("7851231234").unwrap();
+        assert_eq!(female.birthday.to_string(), "1978-01-23");
+        assert_eq!(female.sex, Sex::Female);
+        assert_eq!(decode("0421231239").unwrap().sex, Sex::Male);
+        assert_eq!(decode("0451231231").unwrap().sex, Sex::Female);
+        let overflow = decode("0471231233").unwrap();
+        assert_eq!(overflow.birthday.to_string(), "2004-01-23");
+        assert_eq!(overflow.sex, Sex::Female);
+    }
+
+    #[test]
+    fn decode_nine_digit_form() {
+        let info = decode("530101123").unwrap();
+        assert_eq!(info.birthday.to_string(), "1953-01-01");
+        assert_eq!(decode("530101/123"), Ok(info));
+        assert_eq!(
+            decode("540101123"),
+            Err(NidError::Format(FormatKind::ShortFormAfter1953))
+        );
+    }
+
+    #[test]
+    fn decode_remainder_ten_exception() {
+        // The first 9 digits give remainder 10 mod 11.
+        assert!(decode("8001010040").is_ok());
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("78012312"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("780123-1230"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("78012312X0"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("7801231231"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("7813231239"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        // The +20 offset was introduced in 2004.
+        assert_eq!(
+            decode("0221231230"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 21
+            }))
+        );
+        assert!(decode("0002290002").is_ok());
+        assert_eq!(
+            decode("0102290001"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::ShortFormAfter1953).to_string(),
+            "format error: 9-digit birth numbers were only issued before 1954"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "CZ_CHECKSUM");
+    }
+}
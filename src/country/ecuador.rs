@@ -0,0 +1,423 @@
+//! Validate and decode Ecuadorian identity numbers (cédula de identidad).
+//!
+//! The Registro Civil issues a 10-digit cédula to every citizen and resident.
+//! It starts with the code of the province where it was issued; the same
+//! number, followed by `001`, is the holder's RUC tax number.
+//!
+//! # Format
+//!
+//! `[province (2)][type][serial (6)][check]`
+//!
+//! - **Province**: `01`–`24` for the provinces of Ecuador, `30` for numbers
+//!   issued to foreigners and to Ecuadorians registered abroad.
+//! - **Type digit**: `0`–`5` for natural persons; higher values are used by
+//!   company RUCs and are rejected.
+//! - **Check digit**: the first 9 digits are weighted alternately by `2` and
+//!   `1`, subtracting 9 from products above 9. The check digit is
+//!   `(10 - sum mod 10) mod 10`.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::ecuador::decode("1710034065").unwrap();
+//! assert_eq!(info.province, 17);
+//! assert!(info.is_national);
+//!
+//! assert!(nidx::ecuador::is_valid("0926687856"));
+//! assert!(!nidx::ecuador::is_valid("0926687857"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Ecuadorian cédula format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Ecuador,
+    name: "Ecuador",
+    alpha2: "EC",
+    alpha3: "ECU",
+    min_length: 10,
+    max_length: 10,
+    shapes: &[&[Run::exactly(CharClass::Digit, 10)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Number of provinces, with codes `01` to `24`.
+const PROVINCES: u8 = 24;
+
+/// Province code for numbers issued to foreigners and Ecuadorians abroad.
+const FOREIGN_PROVINCE: u8 = 30;
+
+/// Decoded information from a valid cédula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Code of the issuing province, `1`–`24`, or `30`.
+    pub province: u8,
+    /// Whether the number was issued in an Ecuadorian province rather than
+    /// under code `30`.
+    pub is_national: bool,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::ecuador::decode("3012345678").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(self.is_national),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the national status, e.g. `"national"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a cédula was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 10 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The province code is not `01`–`24` or `30`.
+    InvalidProvince {
+        /// The encoded province code.
+        code: u8,
+    },
+    /// The third digit is above `5`.
+    InvalidTypeDigit,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "cédula must be exactly 10 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::InvalidProvince { code } => write!(f, "invalid province code {code:02}"),
+            FormatKind::InvalidTypeDigit => write!(f, "third digit must be 0-5"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding a cédula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, province or type digit out of range).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `EC_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `EC_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidProvince`] | `EC_FORMAT_PROVINCE` |
+    /// | [`FormatKind::InvalidTypeDigit`] | `EC_FORMAT_TYPE_DIGIT` |
+    /// | [`NidError::Checksum`] | `EC_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::ecuador::validate("1710034066").unwrap_err();
+    /// assert_eq!(err.error_code(), "EC_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "EC_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "EC_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidProvince { .. }) => "EC_FORMAT_PROVINCE",
+            NidError::Format(FormatKind::InvalidTypeDigit) => "EC_FORMAT_TYPE_DIGIT",
+            NidError::Checksum => "EC_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Compute the check digit from the first 9 digits.
+const fn check_digit(bytes: &[u8]) -> u8 {
+    let mut sum = 0;
+    let mut i = 0;
+    while i < 9 {
+        let mut d = bytes[i] - b'0';
+        if i % 2 == 0 {
+            d *= 2;
+            if d > 9 {
+                d -= 9;
+            }
+        }
+        sum += d;
+        i += 1;
+    }
+    (10 - sum % 10) % 10
+}
+
+/// Decode an Ecuadorian cédula.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters, an unknown province code or a type digit above `5`, and
+/// [`NidError::Checksum`] if the check digit does not match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::ecuador::decode("0926687856").unwrap();
+/// assert_eq!(info.province, 9);
+/// ```
+#[must_use = "this returns the decoded cédula info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 10 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 10 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    let province = (bytes[0] - b'0') * 10 + (bytes[1] - b'0');
+    if (province == 0 || province > PROVINCES) && province != FOREIGN_PROVINCE {
+        return Err(NidError::Format(FormatKind::InvalidProvince {
+            code: province,
+        }));
+    }
+    if bytes[2] > b'5' {
+        return Err(NidError::Format(FormatKind::InvalidTypeDigit));
+    }
+    if check_digit(bytes) != bytes[9] - b'0' {
+        return Err(NidError::Checksum);
+    }
+    Ok(NidInfo {
+        province,
+        is_national: province != FOREIGN_PROVINCE,
+    })
+}
+
+/// Decode an Ecuadorian cédula string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::ecuador::decode_with(" 171003406-5\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.province, 17);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded cédula info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate an Ecuadorian cédula string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::ecuador::validate("1710034065").is_ok());
+/// assert!(nidx::ecuador::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate an Ecuadorian cédula string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether an Ecuadorian cédula string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::ecuador::is_valid("1710034065"));
+/// assert!(!nidx::ecuador::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a cédula: whitespace and hyphens removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::ecuador::normalize("171003406-5"), "1710034065");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Ecuadorian cédula numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::ecuador::Ecuador;
+///
+/// let info = Ecuador::decode("1710034065").unwrap();
+/// assert_eq!(info.province, 17);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ecuador;
+
+impl NationalId for Ecuador {
+    const COUNTRY: Country = Country::Ecuador;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("1710034065"),
+            Ok(NidInfo {
+                province: 17,
+                is_national: true,
+            })
+        );
+        assert_eq!(decode("0926687856").unwrap().province, 9);
+        assert_eq!(decode("2400000002").unwrap().province, 24);
+    }
+
+    #[test]
+    fn decode_foreign_province() {
+        assert_eq!(
+            decode("3012345678"),
+            Ok(NidInfo {
+                province: 30,
+                is_national: false,
+            })
+        );
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("171003406"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("171003406X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("0010034065"),
+            Err(NidError::Format(FormatKind::InvalidProvince { code: 0 }))
+        );
+        assert_eq!(
+            decode("2510034065"),
+            Err(NidError::Format(FormatKind::InvalidProvince { code: 25 }))
+        );
+        assert_eq!(
+            decode("1760000008"),
+            Err(NidError::Format(FormatKind::InvalidTypeDigit))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("1710034066"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidProvince { code: 25 }).to_string(),
+            "format error: invalid province code 25"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "EC_CHECKSUM");
+    }
+}
@@ -0,0 +1,775 @@
+//! Validate and decode Egyptian national ID numbers (الرقم القومي).
+//!
+//! The national ID number is a 14-digit number encoding date of birth, the
+//! governorate of birth registration and sex, followed by a check digit.
+//!
+//! # Format
+//!
+//! `[century][year (2)][month (2)][day (2)][governorate (2)][serial (4)][check]`
+//!
+//! - **Century digit**: `2` for 1900–1999, `3` for 2000–2099.
+//! - **Governorate**: the governorate where the birth was registered, or `88`
+//!   for births abroad (see [`Governorate`]).
+//! - **Serial**: the last digit is odd for males and even for females.
+//! - **Check digit**: the first 13 digits weighted by
+//!   `2 7 6 5 4 3 2 7 6 5 4 3 2`; `(11 - sum mod 11) mod 10`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::egypt::Governorate;
+//!
+//! let info = nidx::egypt::decode("29001010100211").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1990-01-01");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert_eq!(info.governorate, Governorate::Cairo);
+//!
+//! assert!(nidx::egypt::is_valid("28506151200318"));
+//! assert!(!nidx::egypt::is_valid("28506151200317"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::encode::{check_birthday, check_range, is_female};
+use crate::{
+    CharClass, Country, Date, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Sex,
+    Summary,
+};
+
+/// Static facts about the Egyptian national ID number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Egypt,
+    name: "Egypt",
+    alpha2: "EG",
+    alpha3: "EGY",
+    min_length: 14,
+    max_length: 14,
+    shapes: &[&[Run::exactly(CharClass::Digit, 14)]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+const WEIGHTS: [u16; 13] = [2, 7, 6, 5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+
+/// An Egyptian governorate of birth registration, encoded in digits 8–9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Governorate {
+    /// Code `01`.
+    Cairo,
+    /// Code `02`.
+    Alexandria,
+    /// Code `03`.
+    PortSaid,
+    /// Code `04`.
+    Suez,
+    /// Code `11`.
+    Damietta,
+    /// Code `12`.
+    Dakahlia,
+    /// Code `13`.
+    Sharqia,
+    /// Code `14`.
+    Qalyubia,
+    /// Code `15`.
+    KafrElSheikh,
+    /// Code `16`.
+    Gharbia,
+    /// Code `17`.
+    Monufia,
+    /// Code `18`.
+    Beheira,
+    /// Code `19`.
+    Ismailia,
+    /// Code `21`.
+    Giza,
+    /// Code `22`.
+    BeniSuef,
+    /// Code `23`.
+    Faiyum,
+    /// Code `24`.
+    Minya,
+    /// Code `25`.
+    Asyut,
+    /// Code `26`.
+    Sohag,
+    /// Code `27`.
+    Qena,
+    /// Code `28`.
+    Aswan,
+    /// Code `29`.
+    Luxor,
+    /// Code `31`.
+    RedSea,
+    /// Code `32`.
+    NewValley,
+    /// Code `33`.
+    Matrouh,
+    /// Code `34`.
+    NorthSinai,
+    /// Code `35`.
+    SouthSinai,
+    /// Code `88`; births registered outside Egypt.
+    Abroad,
+}
+
+impl Governorate {
+    /// Every governorate, in code order.
+    pub const ALL: &'static [Governorate] = &[
+        Governorate::Cairo,
+        Governorate::Alexandria,
+        Governorate::PortSaid,
+        Governorate::Suez,
+        Governorate::Damietta,
+        Governorate::Dakahlia,
+        Governorate::Sharqia,
+        Governorate::Qalyubia,
+        Governorate::KafrElSheikh,
+        Governorate::Gharbia,
+        Governorate::Monufia,
+        Governorate::Beheira,
+        Governorate::Ismailia,
+        Governorate::Giza,
+        Governorate::BeniSuef,
+        Governorate::Faiyum,
+        Governorate::Minya,
+        Governorate::Asyut,
+        Governorate::Sohag,
+        Governorate::Qena,
+        Governorate::Aswan,
+        Governorate::Luxor,
+        Governorate::RedSea,
+        Governorate::NewValley,
+        Governorate::Matrouh,
+        Governorate::NorthSinai,
+        Governorate::SouthSinai,
+        Governorate::Abroad,
+    ];
+
+    /// Look up a governorate by its two-digit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::egypt::Governorate;
+    ///
+    /// assert_eq!(Governorate::from_code(1), Some(Governorate::Cairo));
+    /// assert_eq!(Governorate::from_code(88), Some(Governorate::Abroad));
+    /// assert_eq!(Governorate::from_code(20), None);
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: u8) -> Option<Governorate> {
+        Some(match code {
+            1 => Governorate::Cairo,
+            2 => Governorate::Alexandria,
+            3 => Governorate::PortSaid,
+            4 => Governorate::Suez,
+            11 => Governorate::Damietta,
+            12 => Governorate::Dakahlia,
+            13 => Governorate::Sharqia,
+            14 => Governorate::Qalyubia,
+            15 => Governorate::KafrElSheikh,
+            16 => Governorate::Gharbia,
+            17 => Governorate::Monufia,
+            18 => Governorate::Beheira,
+            19 => Governorate::Ismailia,
+            21 => Governorate::Giza,
+            22 => Governorate::BeniSuef,
+            23 => Governorate::Faiyum,
+            24 => Governorate::Minya,
+            25 => Governorate::Asyut,
+            26 => Governorate::Sohag,
+            27 => Governorate::Qena,
+            28 => Governorate::Aswan,
+            29 => Governorate::Luxor,
+            31 => Governorate::RedSea,
+            32 => Governorate::NewValley,
+            33 => Governorate::Matrouh,
+            34 => Governorate::NorthSinai,
+            35 => Governorate::SouthSinai,
+            88 => Governorate::Abroad,
+            _ => return None,
+        })
+    }
+
+    /// The two-digit code of the governorate.
+    #[must_use]
+    pub const fn code(self) -> u8 {
+        match self {
+            Governorate::Cairo => 1,
+            Governorate::Alexandria => 2,
+            Governorate::PortSaid => 3,
+            Governorate::Suez => 4,
+            Governorate::Damietta => 11,
+            Governorate::Dakahlia => 12,
+            Governorate::Sharqia => 13,
+            Governorate::Qalyubia => 14,
+            Governorate::KafrElSheikh => 15,
+            Governorate::Gharbia => 16,
+            Governorate::Monufia => 17,
+            Governorate::Beheira => 18,
+            Governorate::Ismailia => 19,
+            Governorate::Giza => 21,
+            Governorate::BeniSuef => 22,
+            Governorate::Faiyum => 23,
+            Governorate::Minya => 24,
+            Governorate::Asyut => 25,
+            Governorate::Sohag => 26,
+            Governorate::Qena => 27,
+            Governorate::Aswan => 28,
+            Governorate::Luxor => 29,
+            Governorate::RedSea => 31,
+            Governorate::NewValley => 32,
+            Governorate::Matrouh => 33,
+            Governorate::NorthSinai => 34,
+            Governorate::SouthSinai => 35,
+            Governorate::Abroad => 88,
+        }
+    }
+
+    /// The English name of the governorate.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Governorate::Cairo => "Cairo",
+            Governorate::Alexandria => "Alexandria",
+            Governorate::PortSaid => "Port Said",
+            Governorate::Suez => "Suez",
+            Governorate::Damietta => "Damietta",
+            Governorate::Dakahlia => "Dakahlia",
+            Governorate::Sharqia => "Sharqia",
+            Governorate::Qalyubia => "Qalyubia",
+            Governorate::KafrElSheikh => "Kafr El Sheikh",
+            Governorate::Gharbia => "Gharbia",
+            Governorate::Monufia => "Monufia",
+            Governorate::Beheira => "Beheira",
+            Governorate::Ismailia => "Ismailia",
+            Governorate::Giza => "Giza",
+            Governorate::BeniSuef => "Beni Suef",
+            Governorate::Faiyum => "Faiyum",
+            Governorate::Minya => "Minya",
+            Governorate::Asyut => "Asyut",
+            Governorate::Sohag => "Sohag",
+            Governorate::Qena => "Qena",
+            Governorate::Aswan => "Aswan",
+            Governorate::Luxor => "Luxor",
+            Governorate::RedSea => "Red Sea",
+            Governorate::NewValley => "New Valley",
+            Governorate::Matrouh => "Matrouh",
+            Governorate::NorthSinai => "North Sinai",
+            Governorate::SouthSinai => "South Sinai",
+            Governorate::Abroad => "Born abroad",
+        }
+    }
+}
+
+impl fmt::Display for Governorate {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Decoded information from a valid national ID number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// Governorate of birth registration.
+    pub governorate: Governorate,
+    /// The four-digit serial (digits 10–13).
+    pub serial: u16,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// National ID numbers are issued to citizens only, so `is_national` is
+    /// `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::egypt::decode("29001010100211").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1990-01-01 M national");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: Some(true),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex> national"`, e.g.
+    /// `"1990-01-01 M national"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a national ID number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 14 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The first digit is not `2` or `3`.
+    InvalidCenturyDigit,
+    /// The governorate code is not assigned.
+    InvalidGovernorate {
+        /// The two-digit governorate code that was found.
+        code: u8,
+    },
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "national ID number must be exactly 14 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::InvalidCenturyDigit => write!(f, "century digit must be 2 or 3"),
+            FormatKind::InvalidGovernorate { code } => {
+                write!(f, "governorate code {code:02} is not assigned")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a national ID number was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a national ID number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, century digit, governorate).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `EG_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `EG_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCenturyDigit`] | `EG_FORMAT_CENTURY_DIGIT` |
+    /// | [`FormatKind::InvalidGovernorate`] | `EG_FORMAT_GOVERNORATE` |
+    /// | [`NidError::Checksum`] | `EG_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `EG_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `EG_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::egypt::validate("29001010100212").unwrap_err();
+    /// assert_eq!(err.error_code(), "EG_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "EG_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "EG_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidCenturyDigit) => "EG_FORMAT_CENTURY_DIGIT",
+            NidError::Format(FormatKind::InvalidGovernorate { .. }) => "EG_FORMAT_GOVERNORATE",
+            NidError::Checksum => "EG_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "EG_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "EG_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the two ASCII digits at `bytes[i..i + 2]`.
+const fn two_digits(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i] - b'0') * 10 + (bytes[i + 1] - b'0')
+}
+
+/// Decode an Egyptian national ID number.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters, an invalid century digit or an unassigned governorate code,
+/// [`NidError::Checksum`] if the check digit does not match, and
+/// [`NidError::InvalidDate`] if the encoded date is not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::egypt::Governorate;
+///
+/// let info = nidx::egypt::decode("30002298800133").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2000-02-29");
+/// assert_eq!(info.governorate, Governorate::Abroad);
+/// ```
+#[must_use = "this returns the decoded national ID info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 14 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 14 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        if i < 13 {
+            sum += (bytes[i] - b'0') as u16 * WEIGHTS[i];
+        }
+        i += 1;
+    }
+    let century = match bytes[0] {
+        b'2' => 1900,
+        b'3' => 2000,
+        _ => return Err(NidError::Format(FormatKind::InvalidCenturyDigit)),
+    };
+    if (11 - sum % 11) % 10 != (bytes[13] - b'0') as u16 {
+        return Err(NidError::Checksum);
+    }
+
+    let code = two_digits(bytes, 7);
+    let Some(governorate) = Governorate::from_code(code) else {
+        return Err(NidError::Format(FormatKind::InvalidGovernorate { code }));
+    };
+
+    let year = century + two_digits(bytes, 1) as u16;
+    let month = two_digits(bytes, 3);
+    let day = two_digits(bytes, 5);
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(if month < 1 || month > 12 {
+            DateKind::MonthOutOfRange { month }
+        } else {
+            DateKind::DayOutOfRange { year, month, day }
+        }));
+    };
+
+    Ok(NidInfo {
+        birthday,
+        sex: if (bytes[12] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        },
+        governorate,
+        serial: two_digits(bytes, 9) as u16 * 100 + two_digits(bytes, 11) as u16,
+    })
+}
+
+/// Decode an Egyptian national ID number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::egypt::decode_with(" 2 900101 01 0021 1\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded national ID number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate an Egyptian national ID number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::egypt::validate("29001010100211").is_ok());
+/// assert!(nidx::egypt::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate an Egyptian national ID number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether an Egyptian national ID number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::egypt::is_valid("29001010100211"));
+/// assert!(!nidx::egypt::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a national ID number: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::egypt::normalize(" 2 900101 01 0021 1\n"), "29001010100211");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Egyptian national ID numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::egypt::Egypt;
+///
+/// let info = Egypt::decode("29001010100211").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Egypt;
+
+impl NationalId for Egypt {
+    const COUNTRY: Country = Country::Egypt;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Egypt {
+    type Components = NidInfo;
+
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            birthday,
+            sex,
+            governorate,
+            serial,
+        } = info;
+        check_birthday(birthday, 1900..=2099)?;
+        check_range("serial", serial, 0..=9999)?;
+        if is_female(sex)? != (serial % 2 == 0) {
+            return Err(EncodeError::Inconsistent { field: "sex" });
+        }
+
+        let Date { year, month, day } = birthday;
+        let payload = format!(
+            "{}{:02}{month:02}{day:02}{:02}{serial:04}",
+            year / 100 - 17,
+            year % 100,
+            governorate.code()
+        );
+        let sum: u16 = payload
+            .bytes()
+            .zip(WEIGHTS)
+            .map(|(b, weight)| u16::from(b - b'0') * weight)
+            .sum();
+        Ok(format!("{payload}{}", (11 - sum % 11) % 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("28506151200318"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1985,
+                    month: 6,
+                    day: 15
+                },
+                sex: Sex::Male,
+                governorate: Governorate::Dakahlia,
+                serial: 31,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_century_and_sex() {
+        let info = decode("29001010100122").unwrap();
+        assert_eq!(info.birthday.to_string(), "1990-01-01");
+        assert_eq!(info.sex, Sex::Female);
+        let info = decode("32312313500423").unwrap();
+        assert_eq!(info.birthday.to_string(), "2023-12-31");
+        assert_eq!(info.governorate, Governorate::SouthSinai);
+        assert_eq!(info.sex, Sex::Female);
+    }
+
+    #[test]
+    fn governorate_table() {
+        for &governorate in Governorate::ALL {
+            assert_eq!(
+                Governorate::from_code(governorate.code()),
+                Some(governorate)
+            );
+        }
+        assert_eq!(Governorate::ALL.len(), 28);
+        assert_eq!(Governorate::KafrElSheikh.to_string(), "Kafr El Sheikh");
+        for code in [0, 5, 10, 20, 30, 36, 87, 99] {
+            assert_eq!(Governorate::from_code(code), None, "{code}");
+        }
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("2900101010021"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("2900101010021X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("49001010100137"),
+            Err(NidError::Format(FormatKind::InvalidCenturyDigit))
+        );
+        assert_eq!(
+            decode("29001012000133"),
+            Err(NidError::Format(FormatKind::InvalidGovernorate {
+                code: 20
+            }))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("29001010100212"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("29013010100139"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            decode("30102290100137"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidGovernorate { code: 20 }).to_string(),
+            "format error: governorate code 20 is not assigned"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "EG_CHECKSUM");
+    }
+}
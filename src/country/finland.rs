@@ -0,0 +1,657 @@
+//! Validate and decode Finnish personal identity codes (henkilötunnus, HETU).
+//!
+//! The HETU is an 11-character code encoding date of birth, a century sign and
+//! sex, followed by a control character.
+//!
+//! # Format
+//!
+//! `[day (2)][month (2)][year (2)][century sign][individual number (3)][control]`
+//!
+//! - **Century sign**: `+` for 1800–1899; `-`, `Y`, `X`, `W`, `V` or `U` for
+//!   1900–1999; `A`, `B`, `C`, `D`, `E` or `F` for 2000–2099. The letter signs
+//!   were added in 2023 and are used alongside the original `-` and `A`.
+//! - **Individual number**: `002`–`899`, or `900`–`999` for temporary codes;
+//!   odd for males, even for females.
+//! - **Control character**: the 9-digit number formed by the date and the
+//!   individual number, mod 31, looked up in
+//!   `0123456789ABCDEFHJKLMNPRSTUVWXY`.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::finland::decode("131052-308T").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1952-10-13");
+//! assert_eq!(info.sex, nidx::Sex::Female);
+//!
+//! assert!(nidx::finland::is_valid("010594Y9021"));
+//! assert!(!nidx::finland::is_valid("131052-308U"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::encode::{check_birthday, check_range, is_female};
+use crate::{
+    CharClass, Country, Date, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Sex,
+    Summary,
+};
+
+/// Static facts about the Finnish HETU format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Finland,
+    name: "Finland",
+    alpha2: "FI",
+    alpha3: "FIN",
+    min_length: 11,
+    max_length: 11,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 6),
+        Run::one(CharClass::Graphic),
+        Run::exactly(CharClass::Digit, 3),
+        Run::one(CharClass::Alphanumeric),
+    ]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+/// Control characters indexed by the remainder mod 31.
+const CONTROL_CHARS: &[u8; 31] = b"0123456789ABCDEFHJKLMNPRSTUVWXY";
+
+/// Decoded information from a valid HETU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// The individual number (`2`–`999`).
+    pub individual_number: u16,
+}
+
+impl NidInfo {
+    /// Whether this is a temporary code (individual number `900`–`999`),
+    /// issued by organisations outside the population register.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// assert!(nidx::finland::decode("010594Y9021").unwrap().is_temporary());
+    /// assert!(!nidx::finland::decode("131052-308T").unwrap().is_temporary());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_temporary(&self) -> bool {
+        self.individual_number >= 900
+    }
+
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// HETUs are also issued to foreign residents, so `is_national` is
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::finland::decode("131052-308T").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1952-10-13 F");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1952-10-13 F"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a HETU was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 11 characters.
+    InvalidLength,
+    /// Characters at positions 1–6 or 8–10 are not all ASCII digits.
+    NonDigitCharacter,
+    /// The 7th character is not a recognised century sign.
+    InvalidCenturySign,
+    /// The individual number is `000` or `001`.
+    InvalidIndividualNumber,
+    /// The last character is not one of the 31 control characters.
+    InvalidControlChar,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "HETU must be exactly 11 characters"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "characters 1-6 and 8-10 must be ASCII digits")
+            }
+            FormatKind::InvalidCenturySign => {
+                write!(f, "character 7 must be a century sign (+, -, U-Y or A-F)")
+            }
+            FormatKind::InvalidIndividualNumber => {
+                write!(f, "individual number must be at least 002")
+            }
+            FormatKind::InvalidControlChar => write!(
+                f,
+                "character 11 must be a digit or one of ABCDEFHJKLMNPRSTUVWXY"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a HETU was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a HETU.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters, individual number).
+    Format(FormatKind),
+    /// The control character does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `FI_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `FI_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCenturySign`] | `FI_FORMAT_CENTURY_SIGN` |
+    /// | [`FormatKind::InvalidIndividualNumber`] | `FI_FORMAT_INDIVIDUAL_NUMBER` |
+    /// | [`FormatKind::InvalidControlChar`] | `FI_FORMAT_CONTROL_CHAR` |
+    /// | [`NidError::Checksum`] | `FI_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `FI_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `FI_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::finland::validate("131052-308U").unwrap_err();
+    /// assert_eq!(err.error_code(), "FI_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "FI_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "FI_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidCenturySign) => "FI_FORMAT_CENTURY_SIGN",
+            NidError::Format(FormatKind::InvalidIndividualNumber) => "FI_FORMAT_INDIVIDUAL_NUMBER",
+            NidError::Format(FormatKind::InvalidControlChar) => "FI_FORMAT_CONTROL_CHAR",
+            NidError::Checksum => "FI_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "FI_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "FI_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the two ASCII digits at `bytes[i..i + 2]`.
+const fn two_digits(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i] - b'0') * 10 + (bytes[i + 1] - b'0')
+}
+
+/// The century a century sign stands for, or `None` if `sign` is not one.
+const fn century(sign: u8) -> Option<u16> {
+    match sign.to_ascii_uppercase() {
+        b'+' => Some(1800),
+        b'-' | b'U'..=b'Y' => Some(1900),
+        b'A'..=b'F' => Some(2000),
+        _ => None,
+    }
+}
+
+/// Decode a Finnish HETU string.
+///
+/// The input is treated case-insensitively. This is a `const fn`, so fixed
+/// codes can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, illegal
+/// characters or individual number, [`NidError::Checksum`] if the control
+/// character does not match, and [`NidError::InvalidDate`] if the encoded
+/// date is not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::finland::decode("290200A002C").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2000-02-29");
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// ```
+#[must_use = "this returns the decoded HETU info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut number: u32 = 0;
+    let mut i = 0;
+    while i < 10 {
+        if i != 6 {
+            if !bytes[i].is_ascii_digit() {
+                return Err(NidError::Format(FormatKind::NonDigitCharacter));
+            }
+            number = number * 10 + (bytes[i] - b'0') as u32;
+        }
+        i += 1;
+    }
+    let Some(century) = century(bytes[6]) else {
+        return Err(NidError::Format(FormatKind::InvalidCenturySign));
+    };
+    let individual_number = (number % 1000) as u16;
+    if individual_number < 2 {
+        return Err(NidError::Format(FormatKind::InvalidIndividualNumber));
+    }
+    let control = bytes[10].to_ascii_uppercase();
+    if !control.is_ascii_alphanumeric() || matches!(control, b'G' | b'I' | b'O' | b'Q' | b'Z') {
+        return Err(NidError::Format(FormatKind::InvalidControlChar));
+    }
+    if CONTROL_CHARS[(number % 31) as usize] != control {
+        return Err(NidError::Checksum);
+    }
+
+    let day = two_digits(bytes, 0);
+    let month = two_digits(bytes, 2);
+    let year = century + two_digits(bytes, 4) as u16;
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(if month < 1 || month > 12 {
+            DateKind::MonthOutOfRange { month }
+        } else {
+            DateKind::DayOutOfRange { year, month, day }
+        }));
+    };
+
+    Ok(NidInfo {
+        birthday,
+        sex: if individual_number % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        },
+        individual_number,
+    })
+}
+
+/// Decode a Finnish HETU string after pre-processing it according to
+/// `options`.
+///
+/// Lenient options only strip whitespace here: `-` is a century sign, not a
+/// separator.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::finland::decode_with(" 131052-308T\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1952-10-13");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded HETU info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare_whitespace(nid))
+}
+
+/// Validate a Finnish HETU string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::finland::validate("131052-308T").is_ok());
+/// assert!(nidx::finland::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Finnish HETU string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Finnish HETU string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::finland::is_valid("131052-308T"));
+/// assert!(!nidx::finland::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a HETU: whitespace removed and letters
+/// uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::finland::normalize(" 131052-308t\n"), "131052-308T");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Finnish HETU numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::finland::Finland;
+///
+/// let info = Finland::decode("131052-308T").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Finland;
+
+impl NationalId for Finland {
+    const COUNTRY: Country = Country::Finland;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Finland {
+    type Components = NidInfo;
+
+    /// Build the HETU with the century sign `+`, `-` or `A`.
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            birthday,
+            sex,
+            individual_number,
+        } = info;
+        check_birthday(birthday, 1800..=2099)?;
+        check_range("individual_number", individual_number, 2..=999)?;
+        if is_female(sex)? != (individual_number % 2 == 0) {
+            return Err(EncodeError::Inconsistent { field: "sex" });
+        }
+
+        let Date { year, month, day } = birthday;
+        let sign = match year / 100 {
+            18 => '+',
+            19 => '-',
+            _ => 'A',
+        };
+        let number = u32::from(day) * 10_000_000
+            + u32::from(month) * 100_000
+            + u32::from(year % 100) * 1000
+            + u32::from(individual_number);
+        let control = char::from(CONTROL_CHARS[(number % 31) as usize]);
+        Ok(format!(
+            "{day:02}{month:02}{:02}{sign}{individual_number:03}{control}",
+            year % 100
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("131052-308T"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1952,
+                    month: 10,
+                    day: 13
+                },
+                sex: Sex::Female,
+                individual_number: 308,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_century_signs() {
+        assert_eq!(
+            decode("010100+002H").unwrap().birthday.to_string(),
+            "1800-01-01"
+        );
+        assert_eq!(
+            decode("311299U1236").unwrap().birthday.to_string(),
+            "1999-12-31"
+        );
+        assert_eq!(
+            decode("311299V1236").unwrap().birthday.to_string(),
+            "1999-12-31"
+        );
+        assert_eq!(
+            decode("311299W1236").unwrap().birthday.to_string(),
+            "1999-12-31"
+        );
+        assert_eq!(
+            decode("311299X1236").unwrap().birthday.to_string(),
+            "1999-12-31"
+        );
+        assert_eq!(
+            decode("010594Y9021").unwrap().birthday.to_string(),
+            "1994-05-01"
+        );
+        assert_eq!(
+            decode("131052A308T").unwrap().birthday.to_string(),
+            "2052-10-13"
+        );
+        assert_eq!(
+            decode("150280B987S").unwrap().birthday.to_string(),
+            "2080-02-15"
+        );
+        assert_eq!(
+            decode("150280C987S").unwrap().birthday.to_string(),
+            "2080-02-15"
+        );
+        assert_eq!(
+            decode("150280D987S").unwrap().birthday.to_string(),
+            "2080-02-15"
+        );
+        assert_eq!(
+            decode("020502E902X").unwrap().birthday.to_string(),
+            "2002-05-02"
+        );
+        assert_eq!(
+            decode("010101F002S").unwrap().birthday.to_string(),
+            "2001-01-01"
+        );
+    }
+
+    #[test]
+    fn decode_sex_and_temporary() {
+        let info = decode("311299U1236").unwrap();
+        assert_eq!(info.sex, Sex::Male);
+        assert!(!info.is_temporary());
+        let info = decode("150280B987S").unwrap();
+        assert_eq!(info.individual_number, 987);
+        assert!(info.is_temporary());
+    }
+
+    #[test]
+    fn decode_lowercase_input() {
+        assert_eq!(decode("010594y9021"), decode("010594Y9021"));
+        assert_eq!(decode("131052-308t"), decode("131052-308T"));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("131052-308"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("13105X-308T"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("131052G308T"),
+            Err(NidError::Format(FormatKind::InvalidCenturySign))
+        );
+        assert_eq!(
+            decode("010150-001C"),
+            Err(NidError::Format(FormatKind::InvalidIndividualNumber))
+        );
+        assert_eq!(
+            decode("131052-308G"),
+            Err(NidError::Format(FormatKind::InvalidControlChar))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("131052-308U"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("011390-002D"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            decode("290201A002M"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn lenient_keeps_century_sign() {
+        let opts = ParseOptions::lenient();
+        assert!(decode_with(" 131052-308T\n", &opts).is_ok());
+        assert_eq!(
+            Finland::decode_with("131052 -308T", &opts),
+            decode("131052-308T")
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidCenturySign).to_string(),
+            "format error: character 7 must be a century sign (+, -, U-Y or A-F)"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "FI_CHECKSUM");
+    }
+}
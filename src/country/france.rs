@@ -0,0 +1,661 @@
+//! Validate and decode French social security numbers (NIR, or INSEE number).
+//!
+//! The NIR (numéro d'inscription au répertoire) is a 15-character number: 13
+//! characters identifying the person and a two-digit key.
+//!
+//! # Format
+//!
+//! `[sex][year (2)][month (2)][department (2)][commune (3)][serial (3)][key (2)]`
+//!
+//! - **Sex**: `1` (or `7` for temporary numbers) for males, `2` (or `8`) for
+//!   females.
+//! - **Year**: the last two digits of the birth year; `00`–`29` maps to
+//!   2000–2029 and `30`–`99` to 1930–1999.
+//! - **Month**: `01`–`12`, or `20`, `30`–`42` or `50`–`99` when the month of
+//!   birth is unknown.
+//! - **Department**: `01`–`95`, `2A`/`2B` for Corsica (`20` before 1976), or
+//!   `99` for people born abroad. Overseas departments use three digits
+//!   (`971`–`978`), leaving two digits for the commune.
+//! - **Key**: `97 - (number mod 97)`, where the Corsican `2A` and `2B` count as
+//!   `19` and `18`.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::france::decode("185057800608491").unwrap();
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert_eq!(info.birth_year, 1985);
+//! assert_eq!(info.birth_month, Some(5));
+//! assert_eq!(info.department.as_str(), "78");
+//!
+//! assert!(nidx::france::is_valid("285052B00408472"));
+//! assert!(!nidx::france::is_valid("185057800608492"));
+//! ```
+
+use std::fmt;
+
+use crate::encode::{check_range, is_female};
+use crate::{
+    CenturyPolicy, CharClass, Country, EncodeError, EncodeId, Metadata, NationalId, ParseOptions,
+    Run, Sex, Summary,
+};
+
+/// Static facts about the French NIR format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::France,
+    name: "France",
+    alpha2: "FR",
+    alpha3: "FRA",
+    min_length: 15,
+    max_length: 15,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 6),
+        Run::one(CharClass::Alphanumeric),
+        Run::exactly(CharClass::Digit, 8),
+    ]],
+    encodes_birthday: false,
+    encodes_sex: true,
+};
+
+/// The département of birth encoded in a NIR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Department {
+    code: [u8; 3],
+    len: u8,
+}
+
+impl Department {
+    /// The department code, e.g. `"78"`, `"2A"` or `"971"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match std::str::from_utf8(self.code.split_at(self.len as usize).0) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Whether the department is in Corsica (`2A`, `2B`, or `20` before
+    /// the 1976 split).
+    #[inline]
+    #[must_use]
+    pub const fn is_corsica(&self) -> bool {
+        self.len == 2 && self.code[0] == b'2' && matches!(self.code[1], b'0' | b'A' | b'B')
+    }
+
+    /// Whether the department is overseas (a three-digit `97x` code).
+    #[inline]
+    #[must_use]
+    pub const fn is_overseas(&self) -> bool {
+        self.len == 3
+    }
+
+    /// Whether the holder was born abroad (department `99`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = nidx::france::decode("290069912345619").unwrap();
+    /// assert!(info.department.is_abroad());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_abroad(&self) -> bool {
+        self.len == 2 && self.code[0] == b'9' && self.code[1] == b'9'
+    }
+}
+
+impl fmt::Display for Department {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Decoded information from a valid NIR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Biological sex.
+    pub sex: Sex,
+    /// Year of birth.
+    pub birth_year: u16,
+    /// Month of birth (1–12), or `None` if it was unknown at registration.
+    pub birth_month: Option<u8>,
+    /// Département of birth.
+    pub department: Department,
+    /// The INSEE code of the commune of birth within the department, or the
+    /// country code for people born abroad.
+    pub commune: u16,
+    /// The serial distinguishing people born in the same place and month.
+    pub serial: u16,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The NIR does not encode the day of birth or citizenship, so
+    /// `birthday` and `is_national` are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::france::decode("185057800608491").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<sex>"`, e.g. `"M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a NIR was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 15 characters.
+    InvalidLength,
+    /// A character other than a Corsican `A`/`B` is not an ASCII digit.
+    NonDigitCharacter,
+    /// The first digit is not `1`, `2`, `7` or `8`.
+    InvalidSexDigit,
+    /// The department code is `00`.
+    InvalidDepartment,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "NIR must be exactly 15 characters"),
+            FormatKind::NonDigitCharacter => write!(
+                f,
+                "all characters except a Corsican department must be ASCII digits"
+            ),
+            FormatKind::InvalidSexDigit => write!(f, "first digit must be 1, 2, 7 or 8"),
+            FormatKind::InvalidDepartment => write!(f, "invalid department code"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a NIR was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is neither 1–12 nor one of the unknown-month codes.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a NIR.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters).
+    Format(FormatKind),
+    /// The two-digit key does not match the computed value.
+    Checksum,
+    /// The encoded month is not valid.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `FR_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `FR_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidSexDigit`] | `FR_FORMAT_SEX_DIGIT` |
+    /// | [`FormatKind::InvalidDepartment`] | `FR_FORMAT_DEPARTMENT` |
+    /// | [`NidError::Checksum`] | `FR_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `FR_DATE_MONTH` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::france::validate("185057800608492").unwrap_err();
+    /// assert_eq!(err.error_code(), "FR_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "FR_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "FR_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidSexDigit) => "FR_FORMAT_SEX_DIGIT",
+            NidError::Format(FormatKind::InvalidDepartment) => "FR_FORMAT_DEPARTMENT",
+            NidError::Checksum => "FR_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "FR_DATE_MONTH",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the ASCII digits at `bytes[start..end]`.
+const fn digits(bytes: &[u8], start: usize, end: usize) -> u64 {
+    let mut value = 0u64;
+    let mut i = start;
+    while i < end {
+        value = value * 10 + (bytes[i] - b'0') as u64;
+        i += 1;
+    }
+    value
+}
+
+/// Decode a French NIR string.
+///
+/// The Corsican department letters are treated case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, illegal
+/// characters or an invalid sex digit or department, [`NidError::Checksum`]
+/// if the key does not match, and [`NidError::InvalidDate`] if the encoded
+/// month is not valid.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::france::decode("285052b00408472").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// assert!(info.department.is_corsica());
+/// assert_eq!(info.department.to_string(), "2B");
+/// ```
+#[must_use = "this returns the decoded NIR info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    decode_impl(nid, CenturyPolicy::DEFAULT)
+}
+
+/// Decode with `century` expanding the two-digit birth year.
+const fn decode_impl(nid: &str, century: CenturyPolicy) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    if src.len() != 15 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut bytes = [0u8; 15];
+    let mut i = 0;
+    while i < 15 {
+        bytes[i] = src[i].to_ascii_uppercase();
+        i += 1;
+    }
+
+    // Corsican departments: replace the letter by its checksum digits.
+    let corsica_offset = match (bytes[5], bytes[6]) {
+        (b'2', b'A') => 1_000_000,
+        (b'2', b'B') => 2_000_000,
+        _ => 0,
+    };
+    if corsica_offset != 0 {
+        bytes[6] = b'0';
+    }
+    let mut i = 0;
+    while i < 15 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+
+    let sex = match bytes[0] {
+        b'1' | b'7' => Sex::Male,
+        b'2' | b'8' => Sex::Female,
+        _ => return Err(NidError::Format(FormatKind::InvalidSexDigit)),
+    };
+    if bytes[5] == b'0' && bytes[6] == b'0' {
+        return Err(NidError::Format(FormatKind::InvalidDepartment));
+    }
+
+    let number = digits(&bytes, 0, 13) - corsica_offset;
+    if 97 - number % 97 != digits(&bytes, 13, 15) {
+        return Err(NidError::Checksum);
+    }
+
+    let yy = digits(&bytes, 1, 3) as u8;
+    let birth_year = century.resolve(yy);
+    let month = digits(&bytes, 3, 5) as u8;
+    let birth_month = match month {
+        1..=12 => Some(month),
+        20 | 30..=42 | 50..=99 => None,
+        _ => return Err(NidError::InvalidDate(DateKind::MonthOutOfRange { month })),
+    };
+
+    let overseas = bytes[5] == b'9' && bytes[6] == b'7';
+    let department = Department {
+        code: [
+            src[5].to_ascii_uppercase(),
+            src[6].to_ascii_uppercase(),
+            if overseas { bytes[7] } else { 0 },
+        ],
+        len: if overseas { 3 } else { 2 },
+    };
+    let commune = if overseas {
+        digits(&bytes, 8, 10)
+    } else {
+        digits(&bytes, 7, 10)
+    };
+
+    Ok(NidInfo {
+        sex,
+        birth_year,
+        birth_month,
+        department,
+        commune: commune as u16,
+        serial: digits(&bytes, 10, 13) as u16,
+    })
+}
+
+/// Decode a French NIR string after pre-processing it according to
+/// `options`.
+///
+/// The two-digit birth year is expanded with [`ParseOptions::century`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::france::decode_with("1 85 05 78 006 084 91", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birth_year, 1985);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded NIR info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode_impl(&options.prepare(nid), options.century)
+}
+
+/// Validate a French NIR string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::france::validate("185057800608491").is_ok());
+/// assert!(nidx::france::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a French NIR string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a French NIR string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::france::is_valid("185057800608491"));
+/// assert!(!nidx::france::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a NIR: whitespace removed and letters
+/// uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::france::normalize("2 85 05 2b 004 084 72"), "285052B00408472");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for French NIRs.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::france::France;
+///
+/// let info = France::decode("185057800608491").unwrap();
+/// assert_eq!(info.commune, 6);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct France;
+
+impl NationalId for France {
+    const COUNTRY: Country = Country::France;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for France {
+    type Components = NidInfo;
+
+    /// Build the NIR with the permanent sex digit `1` or `2`, and month `20`
+    /// when the month of birth is unknown.
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            sex,
+            birth_year,
+            birth_month,
+            department,
+            commune,
+            serial,
+        } = info;
+        let sex_digit = if is_female(sex)? { 2 } else { 1 };
+        let yy = (birth_year % 100) as u8;
+        if CenturyPolicy::DEFAULT.resolve(yy) != birth_year {
+            return Err(EncodeError::OutOfRange {
+                field: "birth_year",
+            });
+        }
+        let month = birth_month.unwrap_or(20);
+        if birth_month.is_some() {
+            check_range("birth_month", month, 1..=12)?;
+        }
+        let commune = if department.is_overseas() {
+            check_range("commune", commune, 0..=99)?;
+            format!("{commune:02}")
+        } else {
+            check_range("commune", commune, 0..=999)?;
+            format!("{commune:03}")
+        };
+        check_range("serial", serial, 0..=999)?;
+
+        let number = format!("{sex_digit}{yy:02}{month:02}{department}{commune}{serial:03}");
+        let corsica_offset = match department.as_str() {
+            "2A" => 1_000_000,
+            "2B" => 2_000_000,
+            _ => 0,
+        };
+        let value: u64 = number.replace(['A', 'B'], "0").parse().expect("13 digits");
+        Ok(format!("{number}{:02}", 97 - (value - corsica_offset) % 97))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("185057800608491"),
+            Ok(NidInfo {
+                sex: Sex::Male,
+                birth_year: 1985,
+                birth_month: Some(5),
+                department: Department {
+                    code: *b"78\0",
+                    len: 2
+                },
+                commune: 6,
+                serial: 84,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_corsica() {
+        let a = decode("185052A00408495").unwrap();
+        assert_eq!(a.department.as_str(), "2A");
+        assert!(a.department.is_corsica());
+        let b = decode("285052b00408472").unwrap();
+        assert_eq!(b.department.as_str(), "2B");
+        let before_split = decode("185052000408468").unwrap();
+        assert_eq!(before_split.department.as_str(), "20");
+        assert!(before_split.department.is_corsica());
+    }
+
+    #[test]
+    fn decode_overseas_and_abroad() {
+        let info = decode("200099711234525").unwrap();
+        assert_eq!(info.birth_year, 2000);
+        assert_eq!(info.department.as_str(), "971");
+        assert!(info.department.is_overseas());
+        assert_eq!(info.commune, 12);
+        assert_eq!(info.serial, 345);
+        assert!(decode("290069912345619").unwrap().department.is_abroad());
+    }
+
+    #[test]
+    fn decode_unknown_month() {
+        assert_eq!(decode("185307800608406").unwrap().birth_month, None);
+        assert_eq!(decode("299209912345693").unwrap().birth_month, None);
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("18505780060849"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("1850578006084X1"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("18505A800608491"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("385057800608491"),
+            Err(NidError::Format(FormatKind::InvalidSexDigit))
+        );
+        assert_eq!(
+            decode("185050000608463"),
+            Err(NidError::Format(FormatKind::InvalidDepartment))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("185057800608492"), Err(NidError::Checksum));
+        // The Corsican letter takes part in the key.
+        assert_eq!(decode("185052B00408495"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_month() {
+        assert_eq!(
+            decode("185137800608425"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidSexDigit).to_string(),
+            "format error: first digit must be 1, 2, 7 or 8"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "FR_CHECKSUM");
+    }
+}
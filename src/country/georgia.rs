@@ -0,0 +1,284 @@
+//! Validate Georgian personal numbers (პირადი ნომერი, piradi nomeri).
+//!
+//! Every Georgian citizen and resident is assigned an 11-digit personal number
+//! by the Public Service Development Agency. It is printed on the ID card and
+//! passport and carries no decodable personal data.
+//!
+//! # Format
+//!
+//! `[registration area (2)][serial (9)]`
+//!
+//! - **Registration area**: the first two digits identify where the number
+//!   was first registered, from `01` (Tbilisi) upwards; `00` is never issued.
+//! - **Check digit**: there is none, and the allocation rules are not
+//!   published, so only the format and the registration area range are
+//!   checked. A number that passes may still be unassigned.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::georgia::is_valid("01001011234"));
+//! assert!(!nidx::georgia::is_valid("00001011234"));
+//! assert!(!nidx::georgia::is_valid("0100101123"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run};
+
+/// Static facts about the Georgian personal number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Georgia,
+    name: "Georgia",
+    alpha2: "GE",
+    alpha3: "GEO",
+    min_length: 11,
+    max_length: 11,
+    shapes: &[&[Run::exactly(CharClass::Digit, 11)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Specific reason a personal number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 11 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The registration area (first two digits) is `00`.
+    InvalidRegion,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "personal number must be exactly 11 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::InvalidRegion => write!(f, "registration area must not be 00"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a personal number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, registration area out of range).
+    Format(FormatKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `GE_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `GE_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidRegion`] | `GE_FORMAT_REGION` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::georgia::validate("00001011234").unwrap_err();
+    /// assert_eq!(err.error_code(), "GE_FORMAT_REGION");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "GE_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "GE_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidRegion) => "GE_FORMAT_REGION",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Validate a Georgian personal number.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or a registration area of `00`.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::georgia::validate("61001045678").is_ok());
+/// assert!(nidx::georgia::validate("invalid").is_err());
+/// ```
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 11 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    if bytes[0] == b'0' && bytes[1] == b'0' {
+        return Err(NidError::Format(FormatKind::InvalidRegion));
+    }
+    Ok(())
+}
+
+/// Validate a Georgian personal number string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::georgia::validate_with("010 0101 1234", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    validate(&options.prepare(nid))
+}
+
+/// Check whether a Georgian personal number string is valid.
+///
+/// Returns `true` exactly when [`validate`] succeeds, without building an
+/// error for the first failed check.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::georgia::is_valid("01001011234"));
+/// assert!(!nidx::georgia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 || (bytes[0] == b'0' && bytes[1] == b'0') {
+        return false;
+    }
+    let mut i = 0;
+    while i < 11 {
+        if !bytes[i].is_ascii_digit() {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+/// Return the canonical form of a personal number: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::georgia::normalize("010 0101 1234"), "01001011234");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Georgian personal numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::georgia::Georgia;
+///
+/// assert_eq!(Georgia::decode("01001011234"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Georgia;
+
+impl NationalId for Georgia {
+    const COUNTRY: Country = Country::Georgia;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_valid() {
+        assert_eq!(validate("01001011234"), Ok(()));
+        assert_eq!(validate("61001045678"), Ok(()));
+        assert_eq!(validate("99999999999"), Ok(()));
+        // Only the first two digits form the registration area.
+        assert_eq!(validate("10000000000"), Ok(()));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            validate("0100101123"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("010010112345"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("0100101123X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            validate("00001011234"),
+            Err(NidError::Format(FormatKind::InvalidRegion))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidRegion).to_string(),
+            "format error: registration area must not be 00"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).error_code(),
+            "GE_FORMAT_LENGTH"
+        );
+    }
+}
@@ -0,0 +1,386 @@
+//! Validate German tax identification numbers (Steuerliche
+//! Identifikationsnummer, IdNr).
+//!
+//! Every resident of Germany is assigned an 11-digit IdNr by the Federal
+//! Central Tax Office. The number is random and carries no personal data.
+//!
+//! # Format
+//!
+//! `[digits (10)][check]`
+//!
+//! - **First digit**: never `0`.
+//! - **Digit distribution**: among the first 10 digits, exactly one digit
+//!   appears twice or three times and every other digit at most once. A digit
+//!   that appears three times may not fill three adjacent positions.
+//! - **Check digit**: ISO 7064 MOD 11,10 over the first 10 digits.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::germany::is_valid("86095742719"));
+//! assert!(!nidx::germany::is_valid("86095742718"));
+//!
+//! // Every digit distinct: no digit repeats.
+//! assert!(!nidx::germany::is_valid("12345678903"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run};
+
+/// Static facts about the German tax identification number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Germany,
+    name: "Germany",
+    alpha2: "DE",
+    alpha3: "DEU",
+    min_length: 11,
+    max_length: 11,
+    shapes: &[&[Run::exactly(CharClass::Digit, 11)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Specific reason a tax identification number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 11 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The first digit is `0`.
+    LeadingZero,
+    /// The first 10 digits do not contain exactly one repeated digit, or a digit repeats three times in a row.
+    InvalidDigitDistribution,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "IdNr must be exactly 11 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::LeadingZero => write!(f, "first digit must not be 0"),
+            FormatKind::InvalidDigitDistribution => write!(
+                f,
+                "exactly one digit must repeat two or three times, not three times in a row"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a tax identification number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, digit distribution).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `DE_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `DE_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::LeadingZero`] | `DE_FORMAT_LEADING_ZERO` |
+    /// | [`FormatKind::InvalidDigitDistribution`] | `DE_FORMAT_DIGIT_DISTRIBUTION` |
+    /// | [`NidError::Checksum`] | `DE_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::germany::validate("86095742718").unwrap_err();
+    /// assert_eq!(err.error_code(), "DE_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "DE_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "DE_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::LeadingZero) => "DE_FORMAT_LEADING_ZERO",
+            NidError::Format(FormatKind::InvalidDigitDistribution) => {
+                "DE_FORMAT_DIGIT_DISTRIBUTION"
+            }
+            NidError::Checksum => "DE_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the first 10 digits satisfy the multiplicity rule.
+const fn valid_distribution(bytes: &[u8]) -> bool {
+    let mut counts = [0u8; 10];
+    let mut i = 0;
+    while i < 10 {
+        counts[(bytes[i] - b'0') as usize] += 1;
+        i += 1;
+    }
+    let mut repeated = None;
+    let mut d = 0;
+    while d < 10 {
+        match counts[d] {
+            0 | 1 => {}
+            2 | 3 if repeated.is_none() => repeated = Some(d),
+            _ => return false,
+        }
+        d += 1;
+    }
+    let Some(digit) = repeated else {
+        return false;
+    };
+    if counts[digit] == 3 {
+        let digit = b'0' + digit as u8;
+        let mut i = 0;
+        while i + 2 < 10 {
+            if bytes[i] == digit && bytes[i + 1] == digit && bytes[i + 2] == digit {
+                return false;
+            }
+            i += 1;
+        }
+    }
+    true
+}
+
+/// Compute the ISO 7064 MOD 11,10 check digit over the first 10 digits.
+const fn check_digit(bytes: &[u8]) -> u8 {
+    let mut product = 10;
+    let mut i = 0;
+    while i < 10 {
+        let mut sum = (bytes[i] - b'0' + product) % 10;
+        if sum == 0 {
+            sum = 10;
+        }
+        product = sum * 2 % 11;
+        i += 1;
+    }
+    (11 - product) % 10
+}
+
+/// Validate a German tax identification number.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters, a leading zero or an invalid digit distribution, and
+/// [`NidError::Checksum`] if the check digit does not match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::germany::validate("65929970489").is_ok());
+/// assert!(nidx::germany::validate("invalid").is_err());
+/// ```
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 11 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    if bytes[0] == b'0' {
+        return Err(NidError::Format(FormatKind::LeadingZero));
+    }
+    if !valid_distribution(bytes) {
+        return Err(NidError::Format(FormatKind::InvalidDigitDistribution));
+    }
+    if check_digit(bytes) != bytes[10] - b'0' {
+        return Err(NidError::Checksum);
+    }
+    Ok(())
+}
+
+/// Validate a German tax identification number string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::germany::validate_with("86 095 742 719", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    validate(&options.prepare(nid))
+}
+
+/// Check whether a German tax identification number string is valid.
+///
+/// Returns `true` exactly when [`validate`] succeeds, without building an
+/// error for the first failed check.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::germany::is_valid("86095742719"));
+/// assert!(!nidx::germany::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 || bytes[0] == b'0' {
+        return false;
+    }
+    let mut i = 0;
+    while i < 11 {
+        if !bytes[i].is_ascii_digit() {
+            return false;
+        }
+        i += 1;
+    }
+    valid_distribution(bytes) && check_digit(bytes) == bytes[10] - b'0'
+}
+
+/// Return the canonical form of a tax identification number: whitespace
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::germany::normalize("86 095 742 719"), "86095742719");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for German tax identification numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::germany::Germany;
+///
+/// assert_eq!(Germany::decode("86095742719"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Germany;
+
+impl NationalId for Germany {
+    const COUNTRY: Country = Country::Germany;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_valid() {
+        assert_eq!(validate("86095742719"), Ok(()));
+        assert_eq!(validate("65929970489"), Ok(()));
+        assert_eq!(validate("36574261809"), Ok(()));
+        assert_eq!(validate("11234567890"), Ok(()));
+    }
+
+    #[test]
+    fn digit_appearing_three_times() {
+        // Three times, not adjacent.
+        assert_eq!(validate("11213456783"), Ok(()));
+        // Three times in a row.
+        assert_eq!(
+            validate("11123456786"),
+            Err(NidError::Format(FormatKind::InvalidDigitDistribution))
+        );
+    }
+
+    #[test]
+    fn error_digit_distribution() {
+        // No repeated digit.
+        assert_eq!(
+            validate("12345678903"),
+            Err(NidError::Format(FormatKind::InvalidDigitDistribution))
+        );
+        // Two repeated digits.
+        assert_eq!(
+            validate("11223456785"),
+            Err(NidError::Format(FormatKind::InvalidDigitDistribution))
+        );
+        // One digit four times.
+        assert_eq!(
+            validate("11112345678"),
+            Err(NidError::Format(FormatKind::InvalidDigitDistribution))
+        );
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            validate("8609574271"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("8609574271X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            validate("01234567896"),
+            Err(NidError::Format(FormatKind::LeadingZero))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(validate("86095742718"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::LeadingZero).to_string(),
+            "format error: first digit must not be 0"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "DE_CHECKSUM");
+    }
+}
@@ -0,0 +1,355 @@
+//! Validate Irish Personal Public Service Numbers (PPSN).
+//!
+//! The PPSN is 7 digits followed by a check letter and, on many numbers, a
+//! second letter. Numbers issued since 2013 are 9 characters long and end in
+//! `A` (or `H`); older numbers are 8 characters, or 9 with a legacy `W`, `T`
+//! or `X` suffix. The number carries no personal data.
+//!
+//! # Format
+//!
+//! `[digits (7)][check letter][second letter (optional)]`
+//!
+//! - **Check letter**: the digits weighted by `8 7 6 5 4 3 2`, plus 9 times
+//!   the value of a second letter `A` (1) or `H` (8), mod 23, mapped through
+//!   `WABCDEFGHIJKLMNOPQRSTUV`. Legacy second letters do not count.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::ireland::is_valid("6433435F"));
+//! assert!(nidx::ireland::is_valid("6433435FT"));
+//! assert!(nidx::ireland::is_valid("6433435OA"));
+//! assert!(!nidx::ireland::is_valid("6433435FA"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run};
+
+/// Static facts about the Irish PPSN format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Ireland,
+    name: "Ireland",
+    alpha2: "IE",
+    alpha3: "IRL",
+    min_length: 8,
+    max_length: 9,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 7),
+        Run::between(CharClass::Letter, 1, 2),
+    ]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Check letters indexed by the weighted sum mod 23.
+const CHECK_LETTERS: &[u8; 23] = b"WABCDEFGHIJKLMNOPQRSTUV";
+
+/// Specific reason a PPSN was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 8 or 9 characters.
+    InvalidLength,
+    /// Characters at positions 1–7 are not all ASCII digits.
+    NonDigitCharacter,
+    /// The 8th character is not a letter `A`–`W`.
+    InvalidCheckLetter,
+    /// The 9th character is not one of `A`, `H`, `W`, `T` or `X`.
+    InvalidSecondLetter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "PPSN must be 8 or 9 characters"),
+            FormatKind::NonDigitCharacter => write!(f, "characters 1-7 must be ASCII digits"),
+            FormatKind::InvalidCheckLetter => write!(f, "character 8 must be a letter A-W"),
+            FormatKind::InvalidSecondLetter => write!(f, "character 9 must be A, H, W, T or X"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a PPSN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters).
+    Format(FormatKind),
+    /// The check letter does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `IE_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `IE_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCheckLetter`] | `IE_FORMAT_CHECK_LETTER` |
+    /// | [`FormatKind::InvalidSecondLetter`] | `IE_FORMAT_SECOND_LETTER` |
+    /// | [`NidError::Checksum`] | `IE_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::ireland::validate("6433435E").unwrap_err();
+    /// assert_eq!(err.error_code(), "IE_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "IE_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "IE_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidCheckLetter) => "IE_FORMAT_CHECK_LETTER",
+            NidError::Format(FormatKind::InvalidSecondLetter) => "IE_FORMAT_SECOND_LETTER",
+            NidError::Checksum => "IE_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Validate an Irish PPSN.
+///
+/// The input is treated case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or illegal
+/// characters, and [`NidError::Checksum`] if the check letter does not match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::ireland::validate("1234567FA").is_ok());
+/// assert!(nidx::ireland::validate("invalid").is_err());
+/// ```
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 8 && bytes.len() != 9 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 7 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        sum += (bytes[i] - b'0') as u16 * (8 - i as u16);
+        i += 1;
+    }
+    let check = bytes[7].to_ascii_uppercase();
+    if !matches!(check, b'A'..=b'W') {
+        return Err(NidError::Format(FormatKind::InvalidCheckLetter));
+    }
+    if bytes.len() == 9 {
+        match bytes[8].to_ascii_uppercase() {
+            b'A' => sum += 9,
+            b'H' => sum += 9 * 8,
+            b'W' | b'T' | b'X' => {}
+            _ => return Err(NidError::Format(FormatKind::InvalidSecondLetter)),
+        }
+    }
+    if CHECK_LETTERS[(sum % 23) as usize] != check {
+        return Err(NidError::Checksum);
+    }
+    Ok(())
+}
+
+/// Validate an Irish PPSN string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::ireland::validate_with("643 3435 F", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    validate(&options.prepare(nid))
+}
+
+/// Check whether an Irish PPSN string is valid.
+///
+/// Returns `true` exactly when [`validate`] succeeds, without building an
+/// error for the first failed check.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::ireland::is_valid("6433435F"));
+/// assert!(!nidx::ireland::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 8 && bytes.len() != 9 {
+        return false;
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 7 {
+        if !bytes[i].is_ascii_digit() {
+            return false;
+        }
+        sum += (bytes[i] - b'0') as u16 * (8 - i as u16);
+        i += 1;
+    }
+    if bytes.len() == 9 {
+        match bytes[8].to_ascii_uppercase() {
+            b'A' => sum += 9,
+            b'H' => sum += 9 * 8,
+            b'W' | b'T' | b'X' => {}
+            _ => return false,
+        }
+    }
+    let check = bytes[7].to_ascii_uppercase();
+    matches!(check, b'A'..=b'W') && CHECK_LETTERS[(sum % 23) as usize] == check
+}
+
+/// Return the canonical form of a PPSN: whitespace removed and letters
+/// uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::ireland::normalize(" 6433435oa\n"), "6433435OA");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Irish PPS numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::ireland::Ireland;
+///
+/// assert_eq!(Ireland::decode("6433435F"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ireland;
+
+impl NationalId for Ireland {
+    const COUNTRY: Country = Country::Ireland;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_legacy() {
+        assert_eq!(validate("6433435F"), Ok(()));
+        assert_eq!(validate("1234567T"), Ok(()));
+        // Remainder 0 maps to W.
+        assert_eq!(validate("0000000W"), Ok(()));
+        // Legacy second letters do not take part in the check.
+        assert_eq!(validate("6433435FT"), Ok(()));
+        assert_eq!(validate("1234567TW"), Ok(()));
+        assert_eq!(validate("6433435FX"), Ok(()));
+    }
+
+    #[test]
+    fn validate_second_letter_in_check() {
+        assert_eq!(validate("6433435OA"), Ok(()));
+        assert_eq!(validate("1234567FA"), Ok(()));
+        assert_eq!(validate("1234567WH"), Ok(()));
+        // The legacy check letter is wrong once `A` takes part.
+        assert_eq!(validate("6433435FA"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn validate_lowercase_input() {
+        assert_eq!(validate("6433435oa"), Ok(()));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            validate("6433435"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("6433435FAA"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("643343XF"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            validate("6433435Z"),
+            Err(NidError::Format(FormatKind::InvalidCheckLetter))
+        );
+        assert_eq!(
+            validate("6433435FB"),
+            Err(NidError::Format(FormatKind::InvalidSecondLetter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(validate("6433435E"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidSecondLetter).to_string(),
+            "format error: character 9 must be A, H, W, T or X"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "IE_CHECKSUM");
+    }
+}
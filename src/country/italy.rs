@@ -0,0 +1,695 @@
+//! Validate and decode Italian tax codes (codice fiscale).
+//!
+//! The codice fiscale is a 16-character code derived from a person's surname,
+//! given name, date and place of birth and sex, followed by a check letter.
+//!
+//! # Format
+//!
+//! `[surname (3)][name (3)][year (2)][month][day (2)][place (4)][check]`
+//!
+//! - **Surname / name**: three letters each, taken from the consonants (then
+//!   vowels, then `X` padding) of the surname and given name. Only the letters
+//!   are checked, since the names themselves are not part of the input.
+//! - **Year**: the last two digits of the birth year; `00`–`29` maps to
+//!   2000–2029 and `30`–`99` to 1930–1999.
+//! - **Month**: a letter from `ABCDEHLMPRST` (January–December).
+//! - **Day**: `01`–`31` for males, `41`–`71` (day + 40) for females.
+//! - **Place**: the Belfiore code of the comune of birth, or `Z` followed by
+//!   three digits for people born abroad (see [`PlaceCode`]).
+//! - **Check letter**: characters in odd positions are mapped through a fixed
+//!   table, characters in even positions by their value; the sum mod 26 gives
+//!   the letter.
+//!
+//! # Omocodia
+//!
+//! When two people would receive the same code, the revenue agency replaces
+//! digits from the right with the letters `LMNPQRSTUV` (standing for `0`–`9`).
+//! Such codes are decoded as if the original digits were present, and
+//! [`NidInfo::is_omocode`] reports the substitution.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::italy::decode("RSSMRA85T10A562S").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1985-12-10");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert_eq!(info.place_of_birth.as_str(), "A562");
+//!
+//! assert!(nidx::italy::is_valid("RSSMRA85T10A562S"));
+//! assert!(!nidx::italy::is_valid("RSSMRA85T10A562A"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::{
+    CenturyPolicy, CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Sex, Summary,
+};
+
+/// Static facts about the Italian codice fiscale format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Italy,
+    name: "Italy",
+    alpha2: "IT",
+    alpha3: "ITA",
+    min_length: 16,
+    max_length: 16,
+    shapes: &[&[
+        Run::exactly(CharClass::Letter, 6),
+        Run::exactly(CharClass::Alphanumeric, 2),
+        Run::one(CharClass::Letter),
+        Run::exactly(CharClass::Alphanumeric, 2),
+        Run::one(CharClass::Letter),
+        Run::exactly(CharClass::Alphanumeric, 3),
+        Run::one(CharClass::Letter),
+    ]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+/// Month letters, January to December.
+const MONTH_CHARS: &[u8; 12] = b"ABCDEHLMPRST";
+
+/// Omocodia substitution letters, standing for the digits `0`–`9`.
+const OMOCODIA_CHARS: &[u8; 10] = b"LMNPQRSTUV";
+
+/// Positions that hold digits, or their omocodia substitutes.
+const DIGIT_POSITIONS: [usize; 7] = [6, 7, 9, 10, 12, 13, 14];
+
+/// Values of characters in odd (1st, 3rd, …) positions, indexed by the
+/// character's position in `0`–`9` or `A`–`Z`.
+const ODD_VALUES: [u8; 26] = [
+    1, 0, 5, 7, 9, 13, 15, 17, 19, 21, 2, 4, 18, 20, 11, 3, 6, 8, 12, 14, 16, 10, 22, 25, 24, 23,
+];
+
+/// The four-character Belfiore code of a place of birth.
+///
+/// Italian comuni have codes of a letter `A`–`M` followed by three digits;
+/// people born abroad have `Z` followed by a three-digit country code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlaceCode([u8; 4]);
+
+impl PlaceCode {
+    /// The code as a string, e.g. `"A562"`.
+    #[must_use]
+    pub const fn as_str(&self) -> &str {
+        match std::str::from_utf8(&self.0) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// Whether the code names a foreign country (`Z` codes) rather than an
+    /// Italian comune.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let info = nidx::italy::decode("BNCLRA90A50Z112S").unwrap();
+    /// assert!(info.place_of_birth.is_foreign());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn is_foreign(&self) -> bool {
+        self.0[0] == b'Z'
+    }
+}
+
+impl fmt::Display for PlaceCode {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Decoded information from a valid codice fiscale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// Where the holder was born.
+    pub place_of_birth: PlaceCode,
+    /// The three letters derived from the surname (characters 1–3).
+    pub surname_code: [u8; 3],
+    /// The three letters derived from the given name (characters 4–6).
+    pub name_code: [u8; 3],
+    /// Whether any digit was replaced by an omocodia letter.
+    pub is_omocode: bool,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The codice fiscale does not encode citizenship, so `is_national` is
+    /// `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::italy::decode("RSSMRA85T10A562S").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1985-12-10 M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1985-12-10 M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a codice fiscale was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 16 characters.
+    InvalidLength,
+    /// Characters 1–6 are not all ASCII letters.
+    InvalidNameChar,
+    /// A date or place digit is neither an ASCII digit nor an omocodia letter.
+    NonDigitCharacter,
+    /// Character 9 is not a month letter (`ABCDEHLMPRST`).
+    InvalidMonthChar,
+    /// Character 12 is not an ASCII letter.
+    InvalidPlaceChar,
+    /// Character 16 is not an ASCII letter.
+    InvalidCheckChar,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "codice fiscale must be exactly 16 characters"),
+            FormatKind::InvalidNameChar => write!(f, "characters 1-6 must be ASCII letters"),
+            FormatKind::NonDigitCharacter => write!(
+                f,
+                "date and place digits must be digits or omocodia letters"
+            ),
+            FormatKind::InvalidMonthChar => write!(f, "character 9 must be a month letter"),
+            FormatKind::InvalidPlaceChar => write!(f, "character 12 must be an ASCII letter"),
+            FormatKind::InvalidCheckChar => write!(f, "invalid check character"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a codice fiscale was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded day (after removing the female offset of 40) is outside
+    /// the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The decoded month.
+        month: u8,
+        /// The encoded day, including any female offset.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a codice fiscale.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters).
+    Format(FormatKind),
+    /// The check letter does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `IT_FORMAT_LENGTH` |
+    /// | [`FormatKind::InvalidNameChar`] | `IT_FORMAT_NAME_CHAR` |
+    /// | [`FormatKind::NonDigitCharacter`] | `IT_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidMonthChar`] | `IT_FORMAT_MONTH_CHAR` |
+    /// | [`FormatKind::InvalidPlaceChar`] | `IT_FORMAT_PLACE_CHAR` |
+    /// | [`FormatKind::InvalidCheckChar`] | `IT_FORMAT_CHECK_CHAR` |
+    /// | [`NidError::Checksum`] | `IT_CHECKSUM` |
+    /// | [`DateKind::DayOutOfRange`] | `IT_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::italy::validate("RSSMRA85T10A562A").unwrap_err();
+    /// assert_eq!(err.error_code(), "IT_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "IT_FORMAT_LENGTH",
+            NidError::Format(FormatKind::InvalidNameChar) => "IT_FORMAT_NAME_CHAR",
+            NidError::Format(FormatKind::NonDigitCharacter) => "IT_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidMonthChar) => "IT_FORMAT_MONTH_CHAR",
+            NidError::Format(FormatKind::InvalidPlaceChar) => "IT_FORMAT_PLACE_CHAR",
+            NidError::Format(FormatKind::InvalidCheckChar) => "IT_FORMAT_CHECK_CHAR",
+            NidError::Checksum => "IT_CHECKSUM",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "IT_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// The digit an omocodia-capable position stands for, or `None` if the
+/// byte is neither a digit nor a substitution letter.
+const fn digit_value(byte: u8) -> Option<u8> {
+    if byte.is_ascii_digit() {
+        return Some(byte - b'0');
+    }
+    let mut i = 0;
+    while i < OMOCODIA_CHARS.len() {
+        if OMOCODIA_CHARS[i] == byte {
+            return Some(i as u8);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// The month (1–12) encoded by a month letter.
+const fn month_value(byte: u8) -> Option<u8> {
+    let mut i = 0;
+    while i < MONTH_CHARS.len() {
+        if MONTH_CHARS[i] == byte {
+            return Some(i as u8 + 1);
+        }
+        i += 1;
+    }
+    None
+}
+
+/// Compute the check letter over the first 15 (uppercased) characters.
+const fn check_char(bytes: &[u8; 16]) -> u8 {
+    let mut sum: u32 = 0;
+    let mut i = 0;
+    while i < 15 {
+        let b = bytes[i];
+        let value = if b.is_ascii_digit() {
+            b - b'0'
+        } else {
+            b - b'A'
+        };
+        sum += if i % 2 == 0 {
+            ODD_VALUES[value as usize]
+        } else {
+            value
+        } as u32;
+        i += 1;
+    }
+    b'A' + (sum % 26) as u8
+}
+
+/// Decode an Italian codice fiscale string.
+///
+/// The input is treated case-insensitively. This is a `const fn`, so fixed
+/// codes can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or illegal
+/// characters, [`NidError::Checksum`] if the check letter does not match, and
+/// [`NidError::InvalidDate`] if the encoded day is not valid for the month.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::italy::decode("bnclra90a50z112s").unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-10");
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// assert_eq!(info.place_of_birth.as_str(), "Z112");
+/// ```
+#[must_use = "this returns the decoded codice fiscale info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    decode_impl(nid, CenturyPolicy::DEFAULT)
+}
+
+/// Decode with `century` expanding the two-digit birth year.
+const fn decode_impl(nid: &str, century: CenturyPolicy) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    if src.len() != 16 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut bytes = [0u8; 16];
+    let mut i = 0;
+    while i < 16 {
+        bytes[i] = src[i].to_ascii_uppercase();
+        i += 1;
+    }
+
+    let mut i = 0;
+    while i < 6 {
+        if !bytes[i].is_ascii_uppercase() {
+            return Err(NidError::Format(FormatKind::InvalidNameChar));
+        }
+        i += 1;
+    }
+
+    let mut digits = [0u8; 7];
+    let mut is_omocode = false;
+    let mut i = 0;
+    while i < DIGIT_POSITIONS.len() {
+        let b = bytes[DIGIT_POSITIONS[i]];
+        match digit_value(b) {
+            Some(d) => digits[i] = d,
+            None => return Err(NidError::Format(FormatKind::NonDigitCharacter)),
+        }
+        if !b.is_ascii_digit() {
+            is_omocode = true;
+        }
+        i += 1;
+    }
+
+    let Some(month) = month_value(bytes[8]) else {
+        return Err(NidError::Format(FormatKind::InvalidMonthChar));
+    };
+    if !bytes[11].is_ascii_uppercase() {
+        return Err(NidError::Format(FormatKind::InvalidPlaceChar));
+    }
+    if !bytes[15].is_ascii_uppercase() {
+        return Err(NidError::Format(FormatKind::InvalidCheckChar));
+    }
+    if check_char(&bytes) != bytes[15] {
+        return Err(NidError::Checksum);
+    }
+
+    let yy = digits[0] * 10 + digits[1];
+    let year = century.resolve(yy);
+    let encoded_day = digits[2] * 10 + digits[3];
+    let (day, sex) = if encoded_day > 40 {
+        (encoded_day - 40, Sex::Female)
+    } else {
+        (encoded_day, Sex::Male)
+    };
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+            year,
+            month,
+            day: encoded_day,
+        }));
+    };
+
+    Ok(NidInfo {
+        birthday,
+        sex,
+        place_of_birth: PlaceCode([
+            bytes[11],
+            b'0' + digits[4],
+            b'0' + digits[5],
+            b'0' + digits[6],
+        ]),
+        surname_code: [bytes[0], bytes[1], bytes[2]],
+        name_code: [bytes[3], bytes[4], bytes[5]],
+        is_omocode,
+    })
+}
+
+/// Decode an Italian codice fiscale string after pre-processing it according to
+/// `options`.
+///
+/// The two-digit birth year is expanded with [`ParseOptions::century`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::italy::decode_with("RSS MRA 85T10 A562S", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1985-12-10");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded codice fiscale info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode_impl(&options.prepare(nid), options.century)
+}
+
+/// Validate an Italian codice fiscale string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::italy::validate("RSSMRA85T10A562S").is_ok());
+/// assert!(nidx::italy::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate an Italian codice fiscale string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether an Italian codice fiscale string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::italy::is_valid("RSSMRA85T10A562S"));
+/// assert!(!nidx::italy::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a codice fiscale: whitespace removed and
+/// letters uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::italy::normalize(" rssmra85t10 a562s\n"), "RSSMRA85T10A562S");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Italian codici fiscali.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::italy::Italy;
+///
+/// let info = Italy::decode("RSSMRA85T10A562S").unwrap();
+/// assert_eq!(info.place_of_birth.as_str(), "A562");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Italy;
+
+impl NationalId for Italy {
+    const COUNTRY: Country = Country::Italy;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_NID: &str = "RSSMRA85T10A562S";
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode(VALID_NID),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1985,
+                    month: 12,
+                    day: 10
+                },
+                sex: Sex::Male,
+                place_of_birth: PlaceCode(*b"A562"),
+                surname_code: *b"RSS",
+                name_code: *b"MRA",
+                is_omocode: false,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_female_born_abroad() {
+        let info = decode("BNCLRA90A50Z112S").unwrap();
+        assert_eq!(info.birthday.to_string(), "1990-01-10");
+        assert_eq!(info.sex, Sex::Female);
+        assert!(info.place_of_birth.is_foreign());
+        assert_eq!(info.place_of_birth.to_string(), "Z112");
+    }
+
+    #[test]
+    fn decode_lowercase_input() {
+        assert_eq!(decode("rssmra85t10a562s"), decode(VALID_NID));
+    }
+
+    #[test]
+    fn decode_omocode() {
+        // Last place digit 2 replaced by N.
+        let info = decode("RSSMRA85T10A56NH").unwrap();
+        assert!(info.is_omocode);
+        assert_eq!(info.place_of_birth.as_str(), "A562");
+        assert_eq!(info.birthday, decode(VALID_NID).unwrap().birthday);
+    }
+
+    #[test]
+    fn decode_century_pivot() {
+        assert_eq!(decode("RSSMRA00B29H501Y").unwrap().birthday.year, 2000);
+        assert_eq!(decode("MRTMTT25D09F205Z").unwrap().birthday.year, 2025);
+    }
+
+    #[test]
+    fn error_wrong_length() {
+        assert_eq!(
+            decode("RSSMRA85T10A562"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn error_format_positions() {
+        assert_eq!(
+            decode("RS1MRA85T10A562S"),
+            Err(NidError::Format(FormatKind::InvalidNameChar))
+        );
+        assert_eq!(
+            decode("RSSMRA8AT10A562S"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("RSSMRA85F10A562S"),
+            Err(NidError::Format(FormatKind::InvalidMonthChar))
+        );
+        assert_eq!(
+            decode("RSSMRA85T101562S"),
+            Err(NidError::Format(FormatKind::InvalidPlaceChar))
+        );
+        assert_eq!(
+            decode("RSSMRA85T10A5621"),
+            Err(NidError::Format(FormatKind::InvalidCheckChar))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("RSSMRA85T10A562A"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_day() {
+        // 2001-02-29 does not exist.
+        assert_eq!(
+            decode("RSSMRA01B29H501Z"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidMonthChar).to_string(),
+            "format error: character 9 must be a month letter"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "IT_CHECKSUM");
+    }
+}
@@ -0,0 +1,319 @@
+//! The unique master citizen number (JMBG) shared by the former Yugoslav
+//! republics.
+//!
+//! Every JMBG variant has the same 13-digit layout and check digit; the
+//! country modules ([`serbia`](crate::serbia),
+//! [`north_macedonia`](crate::north_macedonia)) parse it with a common core
+//! and differ only in which region codes they accept. This module exposes the
+//! political region table those codes come from as [`Area`].
+//!
+//! # Format
+//!
+//! `[day (2)][month (2)][year (3)][region (2)][serial (3)][check]`
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::jmbg::Area;
+//!
+//! let info = nidx::serbia::decode("0101990710008").unwrap();
+//! assert_eq!(Area::from_code(info.region), Some(Area::CentralSerbia));
+//! assert_eq!(Area::CentralSerbia.codes(), 70..=79);
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::date::validate_date;
+use crate::encode::{check_birthday, check_range, is_female};
+use crate::{Date, EncodeError, Sex};
+
+const WEIGHTS: [u16; 12] = [7, 6, 5, 4, 3, 2, 7, 6, 5, 4, 3, 2];
+
+/// A political area of birth registration, encoded by the tens digit of the
+/// JMBG region code (digits 8–9).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Area {
+    /// Codes `00`–`09`: foreigners registered in any former Yugoslav republic.
+    Foreign,
+    /// Codes `10`–`19`.
+    BosniaAndHerzegovina,
+    /// Codes `20`–`29`.
+    Montenegro,
+    /// Codes `30`–`39`.
+    Croatia,
+    /// Codes `40`–`49`.
+    NorthMacedonia,
+    /// Codes `50`–`59`.
+    Slovenia,
+    /// Codes `60`–`69`: temporary residents.
+    TemporaryResidence,
+    /// Codes `70`–`79`.
+    CentralSerbia,
+    /// Codes `80`–`89`.
+    Vojvodina,
+    /// Codes `90`–`99`.
+    Kosovo,
+}
+
+impl Area {
+    /// Every area, in code order.
+    pub const ALL: &'static [Area] = &[
+        Area::Foreign,
+        Area::BosniaAndHerzegovina,
+        Area::Montenegro,
+        Area::Croatia,
+        Area::NorthMacedonia,
+        Area::Slovenia,
+        Area::TemporaryResidence,
+        Area::CentralSerbia,
+        Area::Vojvodina,
+        Area::Kosovo,
+    ];
+
+    /// Look up the area a two-digit region code belongs to.
+    ///
+    /// Returns `None` if `code` is greater than `99`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::jmbg::Area;
+    ///
+    /// assert_eq!(Area::from_code(45), Some(Area::NorthMacedonia));
+    /// assert_eq!(Area::from_code(100), None);
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: u8) -> Option<Area> {
+        if code > 99 {
+            return None;
+        }
+        Some(Area::ALL[(code / 10) as usize])
+    }
+
+    /// The region codes belonging to the area.
+    #[must_use]
+    pub const fn codes(self) -> RangeInclusive<u8> {
+        let first = self as u8 * 10;
+        RangeInclusive::new(first, first + 9)
+    }
+
+    /// The English name of the area.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Area::Foreign => "Foreign",
+            Area::BosniaAndHerzegovina => "Bosnia and Herzegovina",
+            Area::Montenegro => "Montenegro",
+            Area::Croatia => "Croatia",
+            Area::NorthMacedonia => "North Macedonia",
+            Area::Slovenia => "Slovenia",
+            Area::TemporaryResidence => "Temporary residence",
+            Area::CentralSerbia => "Central Serbia",
+            Area::Vojvodina => "Vojvodina",
+            Area::Kosovo => "Kosovo",
+        }
+    }
+}
+
+impl fmt::Display for Area {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// The fields of a structurally valid JMBG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) struct Parts {
+    pub(crate) birthday: Date,
+    pub(crate) sex: Sex,
+    pub(crate) region: u8,
+    pub(crate) serial: u16,
+}
+
+/// Reasons the shared core rejects a JMBG, mapped onto each country module's
+/// public error type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum JmbgError {
+    InvalidLength,
+    NonDigitCharacter,
+    Checksum,
+    InvalidRegion { code: u8 },
+    MonthOutOfRange { month: u8 },
+    DayOutOfRange { year: u16, month: u8, day: u8 },
+}
+
+/// Parse the two ASCII digits at `bytes[i..i + 2]`.
+const fn two_digits(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i] - b'0') * 10 + (bytes[i + 1] - b'0')
+}
+
+/// Whether `code` falls within any of `regions`.
+const fn in_regions(code: u8, regions: &[RangeInclusive<u8>]) -> bool {
+    let mut i = 0;
+    while i < regions.len() {
+        if code >= *regions[i].start() && code <= *regions[i].end() {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Parse a JMBG, accepting only region codes within one of `regions`.
+///
+/// Checks run in order: length, digits, check digit, region, date.
+pub(crate) const fn parse(
+    bytes: &[u8],
+    regions: &[RangeInclusive<u8>],
+) -> Result<Parts, JmbgError> {
+    if bytes.len() != 13 {
+        return Err(JmbgError::InvalidLength);
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 13 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(JmbgError::NonDigitCharacter);
+        }
+        if i < 12 {
+            sum += (bytes[i] - b'0') as u16 * WEIGHTS[i];
+        }
+        i += 1;
+    }
+
+    let check = 11 - sum % 11;
+    let check = if check > 9 { 0 } else { check as u8 };
+    if check != bytes[12] - b'0' {
+        return Err(JmbgError::Checksum);
+    }
+
+    let region = two_digits(bytes, 7);
+    if !in_regions(region, regions) {
+        return Err(JmbgError::InvalidRegion { code: region });
+    }
+
+    let day = two_digits(bytes, 0);
+    let month = two_digits(bytes, 2);
+    let year_digits = two_digits(bytes, 4) as u16 * 10 + (bytes[6] - b'0') as u16;
+    let year = if year_digits >= 800 {
+        1000 + year_digits
+    } else {
+        2000 + year_digits
+    };
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(if month < 1 || month > 12 {
+            JmbgError::MonthOutOfRange { month }
+        } else {
+            JmbgError::DayOutOfRange { year, month, day }
+        });
+    };
+
+    let serial = two_digits(bytes, 9) as u16 * 10 + (bytes[11] - b'0') as u16;
+    Ok(Parts {
+        birthday,
+        sex: if serial < 500 { Sex::Male } else { Sex::Female },
+        region,
+        serial,
+    })
+}
+
+/// Build a JMBG from its fields, the inverse of [`parse`] with the same
+/// `regions`.
+pub(crate) fn encode(parts: Parts, regions: &[RangeInclusive<u8>]) -> Result<String, EncodeError> {
+    let Parts {
+        birthday,
+        sex,
+        region,
+        serial,
+    } = parts;
+    check_birthday(birthday, 1800..=2799)?;
+    if !in_regions(region, regions) {
+        return Err(EncodeError::OutOfRange { field: "region" });
+    }
+    check_range("serial", serial, 0..=999)?;
+    if is_female(sex)? != (serial >= 500) {
+        return Err(EncodeError::Inconsistent { field: "sex" });
+    }
+
+    let Date { year, month, day } = birthday;
+    let payload = format!("{day:02}{month:02}{:03}{region:02}{serial:03}", year % 1000);
+    let sum: u16 = payload
+        .bytes()
+        .zip(WEIGHTS)
+        .map(|(b, weight)| u16::from(b - b'0') * weight)
+        .sum();
+    let check = 11 - sum % 11;
+    Ok(format!("{payload}{}", if check > 9 { 0 } else { check }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn area_table_covers_every_code() {
+        for code in 0..=99 {
+            let area = Area::from_code(code).unwrap();
+            assert!(area.codes().contains(&code));
+        }
+        assert_eq!(Area::from_code(100), None);
+        assert_eq!(Area::Kosovo.codes(), 90..=99);
+        assert_eq!(Area::NorthMacedonia.to_string(), "North Macedonia");
+    }
+
+    #[test]
+    fn parse_fields() {
+        assert_eq!(
+            parse(b"2902000805506", &[0..=99]),
+            Ok(Parts {
+                birthday: Date {
+                    year: 2000,
+                    month: 2,
+                    day: 29
+                },
+                sex: Sex::Female,
+                region: 80,
+                serial: 550,
+            })
+        );
+    }
+
+    #[test]
+    fn parse_restricts_regions() {
+        assert!(parse(b"0101990710008", &[70..=89]).is_ok());
+        assert_eq!(
+            parse(b"0101990710008", &[41..=49]),
+            Err(JmbgError::InvalidRegion { code: 71 })
+        );
+        assert!(parse(b"0101990710008", &[0..=9, 70..=99]).is_ok());
+        assert_eq!(
+            parse(b"0101990710008", &[0..=9, 80..=99]),
+            Err(JmbgError::InvalidRegion { code: 71 })
+        );
+    }
+
+    #[test]
+    fn parse_check_order() {
+        assert_eq!(
+            parse(b"010199071000", &[0..=99]),
+            Err(JmbgError::InvalidLength)
+        );
+        assert_eq!(
+            parse(b"010199071000X", &[0..=99]),
+            Err(JmbgError::NonDigitCharacter)
+        );
+        // Bad check digit is reported before the out-of-range region.
+        assert_eq!(
+            parse(b"0101990710009", &[41..=49]),
+            Err(JmbgError::Checksum)
+        );
+        assert_eq!(
+            parse(b"0113990450004", &[0..=99]),
+            Err(JmbgError::MonthOutOfRange { month: 13 })
+        );
+    }
+}
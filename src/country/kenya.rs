@@ -0,0 +1,369 @@
+//! Validate Kenyan national identification numbers.
+//!
+//! Two generations of numbers are in use. The legacy national identity card
+//! carries a sequential 7- or 8-digit number. Since 2023 citizens are instead
+//! assigned a 9-digit Maisha Namba, a lifelong unique personal identifier that
+//! also appears on the Maisha card. Neither generation has a check digit or
+//! encodes personal data, so only the format is checked; the length tells the
+//! generations apart.
+//!
+//! # Format
+//!
+//! - **Legacy**: 7 or 8 digits, e.g. `12345678`.
+//! - **Maisha Namba**: 9 digits, e.g. `123456789`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::kenya::Generation;
+//!
+//! assert_eq!(nidx::kenya::decode("12345678").unwrap().generation, Generation::Legacy);
+//! assert_eq!(nidx::kenya::decode("123456789").unwrap().generation, Generation::MaishaNamba);
+//!
+//! assert!(!nidx::kenya::is_valid("123456"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Kenyan national ID number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Kenya,
+    name: "Kenya",
+    alpha2: "KE",
+    alpha3: "KEN",
+    min_length: 7,
+    max_length: 9,
+    shapes: &[&[Run::between(CharClass::Digit, 7, 9)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Which generation of the numbering scheme a national ID number belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Generation {
+    /// A 7- or 8-digit number from the legacy national identity card.
+    Legacy,
+    /// A 9-digit Maisha Namba (unique personal identifier), issued since 2023.
+    MaishaNamba,
+}
+
+impl fmt::Display for Generation {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Generation::Legacy => write!(f, "legacy"),
+            Generation::MaishaNamba => write!(f, "Maisha Namba"),
+        }
+    }
+}
+
+/// Decoded information from a valid national ID number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Which generation of the numbering scheme matched.
+    pub generation: Generation,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Both generations are only issued to Kenyan citizens (foreign
+    /// residents hold alien cards from a separate series), so `is_national`
+    /// is always `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::kenya::decode("12345678").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(true),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the generation, e.g. `"Maisha Namba"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.generation, f)
+    }
+}
+
+/// Specific reason a national ID number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 7 or 8 digits (legacy) or 9 digits (Maisha Namba).
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "national ID number must be 7 to 9 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding a national ID number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `KE_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `KE_FORMAT_NON_DIGIT` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::kenya::validate("123456").unwrap_err();
+    /// assert_eq!(err.error_code(), "KE_FORMAT_LENGTH");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "KE_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "KE_FORMAT_NON_DIGIT",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Decode a Kenyan national ID number of either generation.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::kenya::Generation;
+///
+/// let info = nidx::kenya::decode("1234567").unwrap();
+/// assert_eq!(info.generation, Generation::Legacy);
+/// ```
+#[must_use = "this returns the decoded national ID info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    let generation = match bytes.len() {
+        7 | 8 => Generation::Legacy,
+        9 => Generation::MaishaNamba,
+        _ => return Err(NidError::Format(FormatKind::InvalidLength)),
+    };
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    Ok(NidInfo { generation })
+}
+
+/// Decode a Kenyan national ID number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::kenya::decode_with(" 1234 5678\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.generation, nidx::kenya::Generation::Legacy);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded national ID number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Kenyan national ID number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kenya::validate("12345678").is_ok());
+/// assert!(nidx::kenya::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Kenyan national ID number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Kenyan national ID number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kenya::is_valid("12345678"));
+/// assert!(!nidx::kenya::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a national ID number: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::kenya::normalize(" 1234 5678\n"), "12345678");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Kenyan national ID numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::kenya::{Generation, Kenya};
+///
+/// let info = Kenya::decode("123456789").unwrap();
+/// assert_eq!(info.generation, Generation::MaishaNamba);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Kenya;
+
+impl NationalId for Kenya {
+    const COUNTRY: Country = Country::Kenya;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_generations() {
+        assert_eq!(
+            decode("1234567"),
+            Ok(NidInfo {
+                generation: Generation::Legacy
+            })
+        );
+        assert_eq!(
+            decode("12345678"),
+            Ok(NidInfo {
+                generation: Generation::Legacy
+            })
+        );
+        assert_eq!(
+            decode("123456789"),
+            Ok(NidInfo {
+                generation: Generation::MaishaNamba
+            })
+        );
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("123456"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("1234567890"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("1234567X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: national ID number must be 7 to 9 digits"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::NonDigitCharacter).error_code(),
+            "KE_FORMAT_NON_DIGIT"
+        );
+    }
+}
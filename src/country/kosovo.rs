@@ -14,7 +14,24 @@
 //! if check == 11 → use 0
 //! ```
 //!
-//! Numbers starting with `'9'` bypass check digit validation.
+//! Numbers starting with `'9'` bypass check digit validation. Use
+//! [`validate_strict`] to enforce the check digit for every number.
+//!
+//! # Legacy formats
+//!
+//! Only the current Civil Registration Agency numbering is supported.
+//! Identity numbers issued under UNMIK administration follow conventions for
+//! which no public specification exists, so this module cannot tell a legacy
+//! number from a mistyped current one and does not report an issuing era.
+//! Records carrying them need to be checked against the civil registry
+//! instead.
+//!
+//! For the same reason no issuing municipality or region is decoded: the
+//! current 9-digit payload is opaque, and the municipality codes said to
+//! appear in older numbers are not publicly documented.
+//!
+//! Business registration numbers are handled by the [`business`] submodule,
+//! and tax administration fiscal numbers by [`fiscal`].
 //!
 //! # Examples
 //!
@@ -25,6 +42,32 @@
 
 use std::fmt;
 
+use crate::placeholder::is_trivial_sequence;
+use crate::suggest::suggest;
+use crate::types::FoundByte;
+use crate::{
+    CharClass, Confidence, Country, Metadata, NationalId, ParseOptions, Plausibility, Run,
+    Suggestion, ValidationReport,
+};
+
+pub mod business;
+pub mod fiscal;
+#[cfg(feature = "simd")]
+mod simd;
+
+/// Static facts about the Kosovo personal number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Kosovo,
+    name: "Kosovo",
+    alpha2: "XK",
+    alpha3: "XKX",
+    min_length: 10,
+    max_length: 10,
+    shapes: &[&[Run::exactly(CharClass::Digit, 10)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
 const WEIGHTS: [u8; 9] = [4, 3, 2, 7, 6, 5, 4, 3, 2];
 
 /// Errors that can occur when validating a Kosovo personal number.
@@ -35,6 +78,40 @@ pub enum NidError {
     Format(FormatKind),
     /// The check digit does not match the computed value.
     Checksum,
+    /// The number is valid but is an obvious placeholder (repeated or
+    /// sequential digits). Only returned when
+    /// [`ParseOptions::reject_placeholders`] is set.
+    Placeholder,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// Codes are prefixed with `XK_` and never change meaning between
+    /// semver-compatible releases:
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `XK_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `XK_FORMAT_NON_DIGIT` |
+    /// | [`NidError::Checksum`] | `XK_CHECKSUM` |
+    /// | [`NidError::Placeholder`] | `XK_PLACEHOLDER` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::kosovo::validate("1234567890").unwrap_err();
+    /// assert_eq!(err.error_code(), "XK_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "XK_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter { .. }) => "XK_FORMAT_NON_DIGIT",
+            NidError::Checksum => "XK_CHECKSUM",
+            NidError::Placeholder => "XK_PLACEHOLDER",
+        }
+    }
 }
 
 impl fmt::Display for NidError {
@@ -43,6 +120,7 @@ impl fmt::Display for NidError {
         match self {
             NidError::Format(kind) => write!(f, "format error: {kind}"),
             NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::Placeholder => write!(f, "placeholder number"),
         }
     }
 }
@@ -63,7 +141,10 @@ pub enum FormatKind {
     /// Input is not exactly 10 characters.
     InvalidLength,
     /// Not all characters are ASCII digits.
-    NonDigitCharacter,
+    NonDigitCharacter {
+        /// The first offending byte.
+        found: u8,
+    },
 }
 
 impl fmt::Display for FormatKind {
@@ -73,7 +154,11 @@ impl fmt::Display for FormatKind {
             FormatKind::InvalidLength => {
                 write!(f, "personal number must be exactly 10 digits")
             }
-            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::NonDigitCharacter { found } => write!(
+                f,
+                "all characters must be ASCII digits, found {}",
+                FoundByte(*found)
+            ),
         }
     }
 }
@@ -82,6 +167,8 @@ impl std::error::Error for FormatKind {}
 
 /// Validate a Kosovo personal number string, returning a [`NidError`] on failure.
 ///
+/// This is a `const fn`, so fixed personal numbers can be checked at compile time.
+///
 /// # Errors
 ///
 /// Returns [`NidError::Format`] if the input has wrong length or non-digit characters.
@@ -92,43 +179,348 @@ impl std::error::Error for FormatKind {}
 /// ```
 /// assert!(nidx::kosovo::validate("1234567892").is_ok());
 /// assert!(nidx::kosovo::validate("invalid").is_err());
+///
+/// const _: () = assert!(nidx::kosovo::validate("1234567892").is_ok());
 /// ```
-pub fn validate(nid: &str) -> Result<(), NidError> {
-    let bytes = nid.as_bytes();
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    validate_bytes(nid.as_bytes())
+}
+
+/// Validate a Kosovo personal number given as raw bytes.
+///
+/// Behaves exactly like [`validate`] but skips UTF-8 validation, for callers
+/// reading numbers out of fixed-width binary records or network buffers.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::validate_bytes(b"1234567892").is_ok());
+/// assert!(nidx::kosovo::validate_bytes(b"12345678\xff2").is_err());
+/// ```
+pub const fn validate_bytes(bytes: &[u8]) -> Result<(), NidError> {
+    validate_impl(bytes, false)
+}
+
+/// Validate many Kosovo personal numbers, returning one result per number
+/// in input order.
+///
+/// Each result equals what [`validate`] returns for that number. With the
+/// `simd` feature, numbers are checked 16 at a time with their digits laid
+/// out across vector lanes, which speeds up bulk jobs over millions of
+/// numbers; without it they are validated one by one.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::kosovo::{NidError, validate_batch};
+///
+/// let results = validate_batch(&["1234567892", "1234567890", "9000000001"]);
+/// assert_eq!(results, [Ok(()), Err(NidError::Checksum), Ok(())]);
+/// ```
+#[must_use]
+pub fn validate_batch(nids: &[&str]) -> Vec<Result<(), NidError>> {
+    let mut results = Vec::with_capacity(nids.len());
+    #[cfg(feature = "simd")]
+    simd::validate_into(nids, &mut results);
+    #[cfg(not(feature = "simd"))]
+    results.extend(nids.iter().map(|nid| validate(nid)));
+    results
+}
+
+/// Validate many Kosovo personal numbers on the rayon thread pool, returning
+/// one result per number in input order.
+///
+/// The batch is split into chunks of a few thousand numbers, each checked
+/// with [`validate_batch`], so the `simd` feature speeds up every thread.
+/// Results equal those of [`validate_batch`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::kosovo::{NidError, par_validate_batch};
+///
+/// let results = par_validate_batch(&["1234567892", "1234567890", "9000000001"]);
+/// assert_eq!(results, [Ok(()), Err(NidError::Checksum), Ok(())]);
+/// ```
+#[cfg(feature = "rayon")]
+#[must_use]
+pub fn par_validate_batch(nids: &[&str]) -> Vec<Result<(), NidError>> {
+    use rayon::prelude::*;
+
+    /// Numbers per rayon task, large enough to amortise scheduling.
+    const CHUNK: usize = 4096;
+
+    nids.par_chunks(CHUNK)
+        .flat_map_iter(validate_batch)
+        .collect()
+}
+
+/// Check a Kosovo personal number's structure and check digit only.
+///
+/// Kosovo personal numbers encode no dates or codes, so this performs
+/// exactly the checks of [`validate`], including the exception for numbers
+/// starting with `'9'`. It exists so that pre-filtering code can call
+/// `verify_checksum` for every country that provides it; like
+/// [`albania::verify_checksum`](crate::albania::verify_checksum), it never
+/// rejects a number [`validate`] accepts.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::verify_checksum("1234567892").is_ok());
+/// assert_eq!(
+///     nidx::kosovo::verify_checksum("1234567890"),
+///     Err(nidx::kosovo::NidError::Checksum)
+/// );
+/// ```
+#[inline]
+pub const fn verify_checksum(nid: &str) -> Result<(), NidError> {
+    validate(nid)
+}
+
+/// Validate a Kosovo personal number, enforcing the check digit even for
+/// numbers starting with `'9'`.
+///
+/// [`validate`] accepts any number starting with `'9'` without checking its
+/// last digit, which lets mistyped numbers in that class through. Use this
+/// function when the input is known not to contain such numbers.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::validate("9234567890").is_ok());
+/// assert_eq!(
+///     nidx::kosovo::validate_strict("9234567890"),
+///     Err(nidx::kosovo::NidError::Checksum)
+/// );
+/// assert!(nidx::kosovo::validate_strict("1234567892").is_ok());
+/// ```
+#[inline]
+pub const fn validate_strict(nid: &str) -> Result<(), NidError> {
+    validate_impl(nid.as_bytes(), true)
+}
+
+/// Check whether a Kosovo personal number is valid under
+/// [`validate_strict`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::is_valid("9234567890"));
+/// assert!(!nidx::kosovo::is_valid_strict("9234567890"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid_strict(nid: &str) -> bool {
+    validate_strict(nid).is_ok()
+}
+
+const fn validate_impl(bytes: &[u8], strict: bool) -> Result<(), NidError> {
     if bytes.len() != 10 {
         return Err(NidError::Format(FormatKind::InvalidLength));
     }
-    if !bytes.iter().all(|b| b.is_ascii_digit()) {
-        return Err(NidError::Format(FormatKind::NonDigitCharacter));
+    let mut i = 0;
+    while i < 10 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter {
+                found: bytes[i],
+            }));
+        }
+        i += 1;
     }
 
     // Numbers starting with '9' bypass check digit validation.
-    if bytes[0] == b'9' {
+    if !strict && bytes[0] == b'9' {
         return Ok(());
     }
 
-    let sum: u16 = bytes[..9]
-        .iter()
-        .zip(WEIGHTS.iter())
-        .map(|(&b, &w)| (b - b'0') as u16 * w as u16)
-        .sum();
+    let Some(payload) = bytes.first_chunk::<9>() else {
+        unreachable!()
+    };
+    if check_digit(payload) != bytes[9] - b'0' {
+        return Err(NidError::Checksum);
+    }
+
+    Ok(())
+}
 
-    let mut check = 11 - (sum % 11);
-    if check >= 10 {
-        check = 0;
+/// Compute the check digit (`0`–`9`) for the first 9 digits of a Kosovo
+/// personal number.
+///
+/// `digits` holds ASCII digits, as in the string form of the number. This is
+/// a `const fn`, and applies the weighted mod-11 algorithm described in the
+/// [module documentation](self) without the prefix-`9` exception.
+///
+/// # Panics
+///
+/// Panics if any byte of `digits` is not an ASCII digit.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::kosovo::check_digit(b"123456789"), 2);
+/// ```
+#[must_use]
+pub const fn check_digit(digits: &[u8; 9]) -> u8 {
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 9 {
+        assert!(digits[i].is_ascii_digit(), "digits must be ASCII digits");
+        sum += (digits[i] - b'0') as u16 * WEIGHTS[i] as u16;
+        i += 1;
     }
 
-    let expected = (bytes[9] - b'0') as u16;
-    if check != expected {
-        return Err(NidError::Checksum);
+    let check = 11 - (sum % 11);
+    if check >= 10 { 0 } else { check as u8 }
+}
+
+/// Compute the check digit for the first 9 digits of a Kosovo personal number
+/// given as a string.
+///
+/// Returns `None` if `partial` is not exactly 9 ASCII digits.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::kosovo::compute_checksum("123456789"), Some('2'));
+/// assert_eq!(nidx::kosovo::compute_checksum("12345678"), None);
+/// ```
+#[must_use]
+pub fn compute_checksum(partial: &str) -> Option<char> {
+    let digits: &[u8; 9] = partial.as_bytes().try_into().ok()?;
+    if !digits.iter().all(u8::is_ascii_digit) {
+        return None;
     }
+    Some(char::from(b'0' + check_digit(digits)))
+}
 
+/// Grade how far a Kosovo personal number gets through validation, for
+/// scoring pipelines that want more than a yes/no answer.
+///
+/// - [`Confidence::Invalid`]: wrong length or non-digit characters.
+/// - [`Confidence::Structure`]: ten digits, but the check digit does not
+///   match. This includes numbers starting with `9` that [`validate`]
+///   accepts without checking their check digit.
+/// - [`Confidence::Plausible`]: the check digit matches. The number encodes
+///   no personal data that could be implausible, so `plausibility` is not
+///   consulted and [`Confidence::Checksum`] is never returned.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Confidence, Date, Plausibility};
+///
+/// let check = Plausibility::new(Date { year: 2026, month: 10, day: 16 });
+/// assert_eq!(nidx::kosovo::score("1234567892", &check), Confidence::Plausible);
+/// assert_eq!(nidx::kosovo::score("1234567890", &check), Confidence::Structure);
+/// assert_eq!(nidx::kosovo::score("12345", &check), Confidence::Invalid);
+/// ```
+#[must_use]
+pub const fn score(nid: &str, plausibility: &Plausibility) -> Confidence {
+    let _ = plausibility;
+    match validate_strict(nid) {
+        Ok(()) => Confidence::Plausible,
+        Err(NidError::Checksum) => Confidence::Structure,
+        Err(_) => Confidence::Invalid,
+    }
+}
+
+/// Find valid personal numbers one typing mistake away from a rejected one,
+/// for "did you mean" prompts in data-entry forms.
+///
+/// Tries replacing each digit with every other digit and swapping each pair
+/// of adjacent digits, after [`normalize`]. Returns an empty list if `nid`
+/// is already valid or no single edit makes it valid. Since any 9 digits
+/// have a check digit, a number with the right length usually has several
+/// suggestions; present them as candidates, never apply one automatically.
+///
+/// # Examples
+///
+/// ```
+/// let suggestions = nidx::kosovo::suggest_corrections("1234567829");
+/// assert!(suggestions.iter().any(|s| s.nid == "1234567892"));
+/// assert!(nidx::kosovo::suggest_corrections("1234567892").is_empty());
+/// ```
+#[must_use]
+pub fn suggest_corrections(nid: &str) -> Vec<Suggestion> {
+    suggest(&normalize(nid), b"0123456789", is_valid)
+}
+
+/// Validate a Kosovo personal number string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input,
+/// and [`NidError::Placeholder`] if [`ParseOptions::reject_placeholders`] is
+/// set and the number's first nine digits are one digit repeated or a run
+/// such as `123456789`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::kosovo::validate_with("123 456 789 2\n", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    let nid = options.prepare(nid);
+    validate(&nid)?;
+    if options.reject_placeholders && is_placeholder(&nid) {
+        return Err(NidError::Placeholder);
+    }
     Ok(())
 }
 
+/// Whether a valid number is an obvious placeholder: its 9-digit payload is
+/// one digit repeated or a run such as `123456789`.
+fn is_placeholder(nid: &str) -> bool {
+    is_trivial_sequence(&nid.as_bytes()[..9])
+}
+
+/// Validate a Kosovo personal number string, collecting every detectable
+/// issue instead of stopping at the first one.
+///
+/// The format has few independent checks: a wrong length or non-digit
+/// characters make the check digit meaningless, so the report holds at most
+/// one issue. The function exists for parity with the other countries.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::validate_all("1234567892").is_valid());
+/// assert_eq!(
+///     nidx::kosovo::validate_all("1234567890").issues(),
+///     [nidx::kosovo::NidError::Checksum]
+/// );
+/// ```
+#[must_use]
+pub fn validate_all(nid: &str) -> ValidationReport<NidError> {
+    let mut report = ValidationReport::new();
+    if let Err(e) = validate(nid) {
+        report.push(e);
+    }
+    report
+}
+
 /// Check whether a Kosovo personal number string is valid.
 ///
-/// This is a convenience wrapper around [`validate`] that returns a simple boolean.
+/// Returns `true` exactly when [`validate`] succeeds, but checks the digits
+/// in the same pass that sums them and builds no error.
 ///
 /// # Examples
 ///
@@ -138,8 +530,225 @@ pub fn validate(nid: &str) -> Result<(), NidError> {
 /// ```
 #[inline]
 #[must_use]
-pub fn is_valid(nid: &str) -> bool {
-    validate(nid).is_ok()
+pub const fn is_valid(nid: &str) -> bool {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 10 || !bytes[9].is_ascii_digit() {
+        return false;
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 9 {
+        if !bytes[i].is_ascii_digit() {
+            return false;
+        }
+        sum += (bytes[i] - b'0') as u16 * WEIGHTS[i] as u16;
+        i += 1;
+    }
+    // Numbers starting with '9' bypass check digit validation.
+    if bytes[0] == b'9' {
+        return true;
+    }
+    let check = 11 - (sum % 11);
+    (if check >= 10 { 0 } else { check as u8 }) == bytes[9] - b'0'
+}
+
+/// Return the canonical form of a Kosovo personal number: surrounding
+/// whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::kosovo::normalize(" 1234567892\n"), "1234567892");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.trim().to_owned()
+}
+
+/// Generate a random valid Kosovo personal number.
+///
+/// The first digit is never `9`, so the number also passes
+/// [`validate_strict`]. Use [`generate_prefix_9`] for the prefix-`9` class.
+///
+/// # Examples
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let nid = nidx::kosovo::generate(&mut rng);
+/// assert!(nidx::kosovo::is_valid_strict(&nid));
+/// ```
+#[cfg(feature = "rand")]
+#[must_use]
+pub fn generate<R: rand::Rng + ?Sized>(rng: &mut R) -> String {
+    let mut digits = [0u8; 9];
+    digits[0] = b'0' + rng.random_range(0..9);
+    for digit in &mut digits[1..] {
+        *digit = b'0' + rng.random_range(0..10);
+    }
+    let mut nid: String = digits.iter().map(|&b| char::from(b)).collect();
+    nid.push(char::from(b'0' + check_digit(&digits)));
+    nid
+}
+
+/// Generate a random Kosovo personal number starting with `9`.
+///
+/// Numbers in this class are accepted by [`validate`] whatever their last
+/// digit, so the last digit is random too; the result usually fails
+/// [`validate_strict`].
+///
+/// # Examples
+///
+/// ```
+/// use rand::SeedableRng;
+/// use rand::rngs::StdRng;
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let nid = nidx::kosovo::generate_prefix_9(&mut rng);
+/// assert!(nid.starts_with('9'));
+/// assert!(nidx::kosovo::is_valid(&nid));
+/// ```
+#[cfg(feature = "rand")]
+#[must_use]
+pub fn generate_prefix_9<R: rand::Rng + ?Sized>(rng: &mut R) -> String {
+    let mut nid = String::with_capacity(10);
+    nid.push('9');
+    for _ in 1..10 {
+        nid.push(char::from(b'0' + rng.random_range(0..10)));
+    }
+    nid
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Kosovo personal numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::kosovo::Kosovo;
+///
+/// assert_eq!(Kosovo::decode("1234567892"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Kosovo;
+
+impl NationalId for Kosovo {
+    const COUNTRY: Country = Country::Kosovo;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+        validate_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+/// A Kosovo personal number that is known to be valid.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::kosovo::KosovoNid;
+///
+/// let nid: KosovoNid = "1234567892".parse().unwrap();
+/// assert_eq!(nid.as_str(), "1234567892");
+///
+/// assert!("1234567890".parse::<KosovoNid>().is_err());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "String", into = "String"))]
+pub struct KosovoNid(String);
+
+impl KosovoNid {
+    /// Validate `nid` and wrap it.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`validate`].
+    pub fn new(nid: &str) -> Result<Self, NidError> {
+        validate(nid)?;
+        Ok(KosovoNid(nid.to_owned()))
+    }
+
+    /// The personal number as a string slice.
+    #[inline]
+    #[must_use]
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Unwrap the personal number into its string.
+    #[inline]
+    #[must_use]
+    pub fn into_string(self) -> String {
+        self.0
+    }
+}
+
+impl fmt::Display for KosovoNid {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+impl std::str::FromStr for KosovoNid {
+    type Err = NidError;
+
+    #[inline]
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        KosovoNid::new(s)
+    }
+}
+
+impl TryFrom<&str> for KosovoNid {
+    type Error = NidError;
+
+    #[inline]
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        KosovoNid::new(s)
+    }
+}
+
+impl TryFrom<String> for KosovoNid {
+    type Error = NidError;
+
+    #[inline]
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        validate(&s)?;
+        Ok(KosovoNid(s))
+    }
+}
+
+impl From<KosovoNid> for String {
+    #[inline]
+    fn from(nid: KosovoNid) -> Self {
+        nid.0
+    }
+}
+
+impl AsRef<str> for KosovoNid {
+    #[inline]
+    fn as_ref(&self) -> &str {
+        &self.0
+    }
 }
 
 #[cfg(test)]
@@ -150,16 +759,52 @@ mod tests {
 
     /// Builds a valid 10-digit personal number by appending the computed check digit.
     fn make_nid(partial: &[u8; 9]) -> String {
-        let sum: u16 = partial
-            .iter()
-            .zip(WEIGHTS.iter())
-            .map(|(&b, &w)| (b - b'0') as u16 * w as u16)
-            .sum();
-        let mut check = 11 - (sum % 11);
-        if check >= 10 {
-            check = 0;
+        format!(
+            "{}{}",
+            std::str::from_utf8(partial).unwrap(),
+            check_digit(partial)
+        )
+    }
+
+    #[test]
+    fn validate_batch_matches_validate() {
+        let mut nids: Vec<String> = (0..1000u64)
+            .map(|i| crate::generator::generate_seeded(Country::Kosovo, 1, i))
+            .collect();
+        for (i, nid) in nids.iter_mut().enumerate() {
+            let at = i % 10;
+            match i % 7 {
+                1 => nid.replace_range(9.., &(i / 7 % 10).to_string()),
+                2 => nid.replace_range(..1, "9"),
+                3 => nid.truncate(at),
+                4 => nid.replace_range(at..=at, "/"),
+                5 => nid.push('0'),
+                6 => nid.replace_range(at..=at, "\u{e9}"),
+                _ => {}
+            }
+        }
+        let nids: Vec<&str> = nids.iter().map(String::as_str).collect();
+        for len in [0, 1, 15, 16, 17, 999, 1000] {
+            let expected: Vec<_> = nids[..len].iter().map(|nid| validate(nid)).collect();
+            assert_eq!(validate_batch(&nids[..len]), expected);
         }
-        format!("{}{}", std::str::from_utf8(partial).unwrap(), check)
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_validate_batch_matches_validate_batch() {
+        let nids: Vec<String> = (0..10_000u64)
+            .map(|i| {
+                let nid = crate::generator::generate_seeded(Country::Kosovo, 2, i);
+                if i % 3 == 0 {
+                    nid.replace('1', "2")
+                } else {
+                    nid
+                }
+            })
+            .collect();
+        let nids: Vec<&str> = nids.iter().map(String::as_str).collect();
+        assert_eq!(par_validate_batch(&nids), validate_batch(&nids));
     }
 
     #[test]
@@ -207,7 +852,10 @@ mod tests {
     #[test]
     fn error_non_digit() {
         let err = validate("12345678A0").unwrap_err();
-        assert_eq!(err, NidError::Format(FormatKind::NonDigitCharacter));
+        assert_eq!(
+            err,
+            NidError::Format(FormatKind::NonDigitCharacter { found: b'A' })
+        );
     }
 
     #[test]
@@ -239,6 +887,41 @@ mod tests {
         assert_eq!(11 - (sum % 11), 2);
     }
 
+    #[test]
+    fn check_digit_public_api() {
+        assert_eq!(check_digit(b"123456789"), 2);
+        assert_eq!(check_digit(b"111111110"), 0);
+        assert_eq!(compute_checksum("123456789"), Some('2'));
+        assert_eq!(compute_checksum("12345678A"), None);
+        assert_eq!(compute_checksum("1234567890"), None);
+        const CHECK: u8 = check_digit(b"900000000");
+        assert_eq!(CHECK, 8);
+    }
+
+    #[test]
+    #[should_panic(expected = "ASCII digits")]
+    fn check_digit_panics_on_non_digit() {
+        let _ = check_digit(b"12345678A");
+    }
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn generate_produces_valid_numbers() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for _ in 0..1000 {
+            let nid = generate(&mut rng);
+            assert!(is_valid_strict(&nid), "{nid}");
+            assert_ne!(nid.as_bytes()[0], b'9');
+
+            let nid = generate_prefix_9(&mut rng);
+            assert!(is_valid(&nid), "{nid}");
+            assert!(nid.starts_with('9'));
+        }
+    }
+
     #[test]
     fn check_digit_10_maps_to_zero() {
         // Find a 9-digit prefix where 11 - (sum % 11) == 10, i.e. sum % 11 == 1.
@@ -256,6 +939,21 @@ mod tests {
         assert!(validate("9000000001").is_ok());
     }
 
+    #[test]
+    fn strict_enforces_checksum_for_prefix_9() {
+        assert_eq!(validate_strict("9000000001"), Err(NidError::Checksum));
+        let nid = make_nid(b"900000000");
+        assert!(is_valid_strict(&nid));
+        assert!(is_valid(&nid));
+    }
+
+    #[test]
+    fn strict_matches_default_for_other_prefixes() {
+        for nid in [VALID_NID, "1234567890", "12345", "12345678A0"] {
+            assert_eq!(validate_strict(nid), validate(nid));
+        }
+    }
+
     #[test]
     fn prefix_9_still_requires_format() {
         assert!(!is_valid("9short"));
@@ -274,6 +972,195 @@ mod tests {
         assert!(is_valid(&nid));
     }
 
+    #[test]
+    fn normalize_trims_whitespace() {
+        assert_eq!(normalize(" 1234567892 "), VALID_NID);
+        assert_eq!(normalize("1234567892\r\n"), VALID_NID);
+        assert_eq!(normalize(VALID_NID), VALID_NID);
+    }
+
+    #[test]
+    fn validate_with_lenient_strips_separators() {
+        let opts = ParseOptions::lenient();
+        assert!(validate_with("123-456-789-2", &opts).is_ok());
+        assert!(validate_with(" 1234567892\r\n", &opts).is_ok());
+        assert_eq!(
+            validate_with("123-456-789-0", &opts),
+            Err(NidError::Checksum)
+        );
+        assert_eq!(
+            validate_with("123-456-789-2", &ParseOptions::new()),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn suggest_corrections_finds_transposition_and_substitution() {
+        let suggestions = suggest_corrections(" 1234567829 ");
+        assert!(suggestions.contains(&Suggestion {
+            nid: "1234567892".to_owned(),
+            correction: crate::Correction::Transposition { position: 8 },
+        }));
+        assert!(suggestions.iter().all(|s| is_valid(&s.nid)));
+        // Every substitution of the check digit is tried, so exactly one fixes it.
+        let fixes: Vec<_> = suggest_corrections("1234567890")
+            .into_iter()
+            .filter(|s| {
+                matches!(
+                    s.correction,
+                    crate::Correction::Substitution { position: 9, .. }
+                )
+            })
+            .collect();
+        assert_eq!(fixes.len(), 1);
+        assert_eq!(fixes[0].nid, "1234567892");
+    }
+
+    #[test]
+    fn suggest_corrections_ignores_valid_and_unfixable_input() {
+        assert!(suggest_corrections("1234567892").is_empty());
+        assert!(suggest_corrections("12345").is_empty());
+        assert!(suggest_corrections("12345678é2").is_empty());
+    }
+
+    #[test]
+    fn score_grades_validation_steps() {
+        let check = Plausibility::new(crate::Date {
+            year: 2026,
+            month: 10,
+            day: 16,
+        });
+        assert_eq!(score("1234567892", &check), Confidence::Plausible);
+        assert_eq!(score("1234567890", &check), Confidence::Structure);
+        // Accepted by `validate` without a check digit, so only the structure is known.
+        assert!(is_valid("9234567890"));
+        assert_eq!(score("9234567890", &check), Confidence::Structure);
+        assert_eq!(score("123456789X", &check), Confidence::Invalid);
+    }
+
+    #[test]
+    fn validate_with_rejects_placeholders_when_asked() {
+        let options = ParseOptions::lenient().reject_placeholders(true);
+        for nid in ["0000000000", "1111111118", "1234567892", "9876543210"] {
+            assert!(is_valid(nid), "{nid}");
+            assert_eq!(
+                validate_with(nid, &options),
+                Err(NidError::Placeholder),
+                "{nid}"
+            );
+        }
+        assert_eq!(
+            validate_with("123 456 789 2", &options),
+            Err(NidError::Placeholder)
+        );
+        assert_eq!(
+            validate_with("1234567890", &options),
+            Err(NidError::Checksum)
+        );
+        assert!(validate_with("1234567892", &ParseOptions::lenient()).is_ok());
+        assert_eq!(
+            Kosovo::decode_with("0000000000", &options),
+            Err(NidError::Placeholder)
+        );
+        assert_eq!(NidError::Placeholder.error_code(), "XK_PLACEHOLDER");
+    }
+
+    #[test]
+    fn validate_all_reports_single_issue() {
+        assert!(validate_all(VALID_NID).is_valid());
+        assert_eq!(
+            validate_all("12345").issues(),
+            [NidError::Format(FormatKind::InvalidLength)]
+        );
+        assert_eq!(
+            validate_all("12345678A0").issues(),
+            [NidError::Format(FormatKind::NonDigitCharacter {
+                found: b'A'
+            })]
+        );
+        assert_eq!(validate_all("1234567890").issues(), [NidError::Checksum]);
+    }
+
+    #[test]
+    fn kosovo_nid_roundtrip() {
+        let nid: KosovoNid = VALID_NID.parse().unwrap();
+        assert_eq!(nid.as_str(), VALID_NID);
+        assert_eq!(nid.to_string(), VALID_NID);
+        assert_eq!(
+            KosovoNid::try_from(String::from(VALID_NID)),
+            Ok(nid.clone())
+        );
+        assert_eq!(nid.into_string(), VALID_NID);
+    }
+
+    #[test]
+    fn kosovo_nid_rejects_invalid() {
+        assert_eq!(KosovoNid::try_from("1234567890"), Err(NidError::Checksum));
+        assert_eq!(
+            "12345".parse::<KosovoNid>(),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn national_id_impl_matches_validate() {
+        assert_eq!(<Kosovo as NationalId>::decode(VALID_NID), Ok(()));
+        assert_eq!(
+            <Kosovo as NationalId>::validate("1234567890"),
+            validate("1234567890")
+        );
+        assert!(!<Kosovo as NationalId>::is_valid("invalid"));
+        assert_eq!(Kosovo::COUNTRY, Country::Kosovo);
+    }
+
+    #[test]
+    fn is_valid_agrees_with_validate() {
+        for nid in [
+            VALID_NID,
+            "1234567890",
+            "9000000001",
+            "900000000A",
+            "A234567892",
+            "123456789",
+            "12345678920",
+        ] {
+            assert_eq!(is_valid(nid), validate(nid).is_ok(), "{nid}");
+        }
+    }
+
+    #[test]
+    fn validate_bytes_matches_validate() {
+        for nid in [VALID_NID, "1234567890", "12345", "12345678A0", "9000000001"] {
+            assert_eq!(validate_bytes(nid.as_bytes()), validate(nid), "{nid}");
+        }
+        assert_eq!(
+            validate_bytes(b"\xff234567892"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter {
+                found: 0xFF
+            }))
+        );
+    }
+
+    #[test]
+    fn validate_in_const_context() {
+        const _: () = assert!(is_valid(VALID_NID));
+        const CHECKSUM: Result<(), NidError> = validate("1234567890");
+        assert_eq!(CHECKSUM, Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_codes_are_stable() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).error_code(),
+            "XK_FORMAT_LENGTH"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::NonDigitCharacter { found: b'O' }).error_code(),
+            "XK_FORMAT_NON_DIGIT"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "XK_CHECKSUM");
+    }
+
     #[test]
     fn error_display() {
         assert_eq!(
@@ -281,8 +1168,12 @@ mod tests {
             "format error: personal number must be exactly 10 digits"
         );
         assert_eq!(
-            NidError::Format(FormatKind::NonDigitCharacter).to_string(),
-            "format error: all characters must be ASCII digits"
+            NidError::Format(FormatKind::NonDigitCharacter { found: b'O' }).to_string(),
+            "format error: all characters must be ASCII digits, found 'O'"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::NonDigitCharacter { found: b' ' }).to_string(),
+            "format error: all characters must be ASCII digits, found 0x20"
         );
         assert_eq!(NidError::Checksum.to_string(), "checksum validation failed");
     }
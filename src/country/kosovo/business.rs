@@ -0,0 +1,172 @@
+//! Validate Kosovo business registration numbers.
+//!
+//! Businesses registered with the Kosovo Business Registration Agency (ARBK)
+//! receive a 9-digit unique identification number (NUI), which is also used
+//! in public procurement (KRPP) records.
+//!
+//! No check-digit algorithm for the NUI has been published, so validation
+//! covers the format only: a `true` result means the number is well formed,
+//! not that it was issued.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::kosovo::business;
+//!
+//! assert!(business::is_valid("810012345"));
+//! assert!(!business::is_valid("81001234"));
+//! ```
+
+use std::fmt;
+
+/// Specific reason a business registration number was rejected due to
+/// formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 9 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "business registration number must be exactly 9 digits")
+            }
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a business registration number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum BusinessError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+}
+
+impl BusinessError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `XK_BUSINESS_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `XK_BUSINESS_FORMAT_NON_DIGIT` |
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            BusinessError::Format(FormatKind::InvalidLength) => "XK_BUSINESS_FORMAT_LENGTH",
+            BusinessError::Format(FormatKind::NonDigitCharacter) => "XK_BUSINESS_FORMAT_NON_DIGIT",
+        }
+    }
+}
+
+impl fmt::Display for BusinessError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            BusinessError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for BusinessError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            BusinessError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Validate a Kosovo business registration number, returning a
+/// [`BusinessError`] on failure.
+///
+/// # Errors
+///
+/// Returns [`BusinessError::Format`] if the input has wrong length or
+/// non-digit characters.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::business::validate("810012345").is_ok());
+/// assert!(nidx::kosovo::business::validate("81001234X").is_err());
+/// ```
+pub const fn validate(number: &str) -> Result<(), BusinessError> {
+    let bytes = number.as_bytes();
+    if bytes.len() != 9 {
+        return Err(BusinessError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 9 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(BusinessError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Check whether a Kosovo business registration number is well formed.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::business::is_valid("810012345"));
+/// assert!(!nidx::kosovo::business::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(number: &str) -> bool {
+    validate(number).is_ok()
+}
+
+/// Return the canonical form of a business registration number: whitespace
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::kosovo::business::normalize(" 810 012 345\n"), "810012345");
+/// ```
+#[must_use]
+pub fn normalize(number: &str) -> String {
+    number.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_format() {
+        assert_eq!(validate("810012345"), Ok(()));
+        assert_eq!(
+            validate("8100123456"),
+            Err(BusinessError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("81001234A"),
+            Err(BusinessError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        let err = BusinessError::Format(FormatKind::InvalidLength);
+        assert_eq!(
+            err.to_string(),
+            "format error: business registration number must be exactly 9 digits"
+        );
+        assert_eq!(err.error_code(), "XK_BUSINESS_FORMAT_LENGTH");
+    }
+}
@@ -0,0 +1,171 @@
+//! Validate Kosovo fiscal numbers.
+//!
+//! The Tax Administration of Kosovo (ATK) identifies taxpayers by a 9-digit
+//! fiscal number. Businesses registered since the registries were unified use
+//! their ARBK unique identification number (see [`business`](super::business))
+//! as fiscal number; older fiscal numbers have the same shape.
+//!
+//! ATK has not published a check-digit algorithm, so validation covers the
+//! format only: a `true` result means the number is well formed, not that it
+//! was issued.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::kosovo::fiscal;
+//!
+//! assert!(fiscal::is_valid("600123456"));
+//! assert!(!fiscal::is_valid("60012345"));
+//! ```
+
+use std::fmt;
+
+/// Specific reason a fiscal number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 9 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "fiscal number must be exactly 9 digits")
+            }
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a fiscal number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FiscalError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+}
+
+impl FiscalError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `XK_FISCAL_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `XK_FISCAL_FORMAT_NON_DIGIT` |
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            FiscalError::Format(FormatKind::InvalidLength) => "XK_FISCAL_FORMAT_LENGTH",
+            FiscalError::Format(FormatKind::NonDigitCharacter) => "XK_FISCAL_FORMAT_NON_DIGIT",
+        }
+    }
+}
+
+impl fmt::Display for FiscalError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FiscalError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for FiscalError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            FiscalError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Validate a Kosovo fiscal number, returning a [`FiscalError`] on failure.
+///
+/// # Errors
+///
+/// Returns [`FiscalError::Format`] if the input has wrong length or
+/// non-digit characters.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::fiscal::validate("600123456").is_ok());
+/// assert!(nidx::kosovo::fiscal::validate("60012345X").is_err());
+/// ```
+pub const fn validate(number: &str) -> Result<(), FiscalError> {
+    let bytes = number.as_bytes();
+    if bytes.len() != 9 {
+        return Err(FiscalError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 9 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(FiscalError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// Check whether a Kosovo fiscal number is well formed.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::kosovo::fiscal::is_valid("600123456"));
+/// assert!(!nidx::kosovo::fiscal::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(number: &str) -> bool {
+    validate(number).is_ok()
+}
+
+/// Return the canonical form of a fiscal number: whitespace
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::kosovo::fiscal::normalize(" 600 123 456\n"), "600123456");
+/// ```
+#[must_use]
+pub fn normalize(number: &str) -> String {
+    number.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_format() {
+        assert_eq!(validate("600123456"), Ok(()));
+        assert_eq!(
+            validate("6001234566"),
+            Err(FiscalError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("60012345A"),
+            Err(FiscalError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        let err = FiscalError::Format(FormatKind::InvalidLength);
+        assert_eq!(
+            err.to_string(),
+            "format error: fiscal number must be exactly 9 digits"
+        );
+        assert_eq!(err.error_code(), "XK_FISCAL_FORMAT_LENGTH");
+    }
+}
@@ -0,0 +1,68 @@
+//! Lane-parallel validation of Kosovo personal numbers, behind the `simd`
+//! feature.
+//!
+//! A block of [`LANES`] numbers is transposed so that each of the 10
+//! positions becomes an array holding that position's byte for every number.
+//! The digit check and weighted sum then run over fixed-size arrays with no
+//! branches, which the compiler turns into vector instructions without any
+//! `unsafe` code or platform intrinsics. Numbers the fast path cannot accept
+//! are handed to [`validate`] to report the exact error, so results match it
+//! exactly.
+
+use super::{NidError, WEIGHTS, validate};
+
+/// Numbers validated together. 16 lanes of `u16` sums fill a 256-bit
+/// register.
+const LANES: usize = 16;
+
+/// Validate `nids` block by block, appending one result per number to `out`.
+pub(super) fn validate_into(nids: &[&str], out: &mut Vec<Result<(), NidError>>) {
+    let mut blocks = nids.chunks_exact(LANES);
+    for block in &mut blocks {
+        let block: &[&str; LANES] = block.try_into().expect("chunks have LANES numbers");
+        let accepted = accept_block(block);
+        out.extend(
+            block
+                .iter()
+                .zip(accepted)
+                .map(|(nid, ok)| if ok { Ok(()) } else { validate(nid) }),
+        );
+    }
+    out.extend(blocks.remainder().iter().map(|nid| validate(nid)));
+}
+
+/// Which numbers of the block pass [`validate`], computed across lanes.
+fn accept_block(block: &[&str; LANES]) -> [bool; LANES] {
+    // Numbers of the wrong length keep zeros and are rejected below.
+    let mut columns = [[0u8; LANES]; 10];
+    let mut sized = [false; LANES];
+    for (lane, nid) in block.iter().enumerate() {
+        if let Ok(bytes) = <&[u8; 10]>::try_from(nid.as_bytes()) {
+            sized[lane] = true;
+            for (column, &b) in columns.iter_mut().zip(bytes) {
+                column[lane] = b;
+            }
+        }
+    }
+
+    let mut digits = sized;
+    let mut sum = [0u16; LANES];
+    for (position, column) in columns[..9].iter().enumerate() {
+        let weight = u16::from(WEIGHTS[position]);
+        for lane in 0..LANES {
+            let digit = column[lane].wrapping_sub(b'0');
+            digits[lane] &= digit < 10;
+            sum[lane] += u16::from(digit) * weight;
+        }
+    }
+
+    let mut accepted = [false; LANES];
+    for lane in 0..LANES {
+        let given = columns[9][lane].wrapping_sub(b'0');
+        let check = 11 - sum[lane] % 11;
+        let check = if check >= 10 { 0 } else { check as u8 };
+        let bypass = columns[0][lane] == b'9';
+        accepted[lane] = digits[lane] && given < 10 && (bypass || check == given);
+    }
+    accepted
+}
@@ -0,0 +1,537 @@
+//! Validate and decode Latvian personal codes (personas kods).
+//!
+//! The personal code is an 11-digit number, usually written with a hyphen
+//! after the sixth digit (e.g. `161175-19997`); both forms are accepted. Two
+//! schemes are in use, reported by [`NidInfo::scheme`]:
+//!
+//! - **Legacy** codes, issued until mid-2017, encode the date of birth.
+//! - **Opaque** codes, issued since 1 July 2017, start with `32` and carry no
+//!   personal data.
+//!
+//! # Format
+//!
+//! Legacy: `[day (2)][month (2)][year (2)][century][serial (3)][check]`
+//!
+//! - **Century digit**: `0` for 1800–1899, `1` for 1900–1999, `2` for
+//!   2000–2099.
+//! - **Check digit**: the first 10 digits weighted by `10 5 8 4 2 1 6 3 7 9`;
+//!   one plus the sum, mod 11, then mod 10.
+//!
+//! Opaque: `32` followed by 9 digits. Only the shape is checked.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::latvia::Scheme;
+//!
+//! let info = nidx::latvia::decode("161175-19997").unwrap();
+//! assert_eq!(info.scheme, Scheme::Legacy);
+//! assert_eq!(info.birthday.unwrap().to_string(), "1975-11-16");
+//!
+//! let info = nidx::latvia::decode("32579461005").unwrap();
+//! assert_eq!(info.scheme, Scheme::Opaque);
+//! assert_eq!(info.birthday, None);
+//!
+//! assert!(!nidx::latvia::is_valid("161175-19996"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::{CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Latvian personal code format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Latvia,
+    name: "Latvia",
+    alpha2: "LV",
+    alpha3: "LVA",
+    min_length: 11,
+    max_length: 12,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 6),
+        Run::optional(CharClass::Exact(b'-')),
+        Run::exactly(CharClass::Digit, 5),
+    ]],
+    encodes_birthday: true,
+    encodes_sex: false,
+};
+
+const WEIGHTS: [u16; 10] = [10, 5, 8, 4, 2, 1, 6, 3, 7, 9];
+
+/// The numbering scheme a personal code belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Scheme {
+    /// A date-based code with century digit and check digit, issued until
+    /// 30 June 2017.
+    Legacy,
+    /// A `32`-prefixed code without personal data, issued since 1 July 2017.
+    Opaque,
+}
+
+/// Decoded information from a valid personal code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// The scheme the code matched.
+    pub scheme: Scheme,
+    /// Date of birth, present only for [`Scheme::Legacy`] codes.
+    pub birthday: Option<Date>,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The personal code does not encode sex or citizenship, so `sex` and
+    /// `is_national` are `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::latvia::decode("161175-19997").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1975-11-16");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: self.birthday,
+            sex: None,
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday>"`, e.g. `"1975-11-16"`, or nothing for an
+    /// opaque code.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a personal code was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 11 digits, with an optional hyphen after the sixth.
+    InvalidLength,
+    /// A character other than the hyphen is not an ASCII digit.
+    NonDigitCharacter,
+    /// The century digit of a legacy code is not `0`, `1` or `2`.
+    InvalidCenturyDigit,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "personal code must be exactly 11 digits"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the hyphen must be ASCII digits")
+            }
+            FormatKind::InvalidCenturyDigit => write!(f, "century digit must be 0, 1 or 2"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a personal code was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a personal code.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, century digit).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `LV_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `LV_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCenturyDigit`] | `LV_FORMAT_CENTURY_DIGIT` |
+    /// | [`NidError::Checksum`] | `LV_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `LV_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `LV_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::latvia::validate("16117519996").unwrap_err();
+    /// assert_eq!(err.error_code(), "LV_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "LV_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "LV_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidCenturyDigit) => "LV_FORMAT_CENTURY_DIGIT",
+            NidError::Checksum => "LV_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "LV_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "LV_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Decode a Latvian personal code, with or without the hyphen.
+///
+/// This is a `const fn`, so fixed codes can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or an invalid century digit, [`NidError::Checksum`] if the
+/// check digit of a legacy code does not match, and [`NidError::InvalidDate`]
+/// if the date of a legacy code is not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::latvia::decode("29020020121").unwrap();
+/// assert_eq!(info.birthday.unwrap().to_string(), "2000-02-29");
+/// ```
+#[must_use = "this returns the decoded personal code info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let hyphen = src.len() > 6 && src[6] == b'-';
+    if src.len() != if hyphen { 12 } else { 11 } {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut digits = [0u8; 11];
+    let mut n = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if !(hyphen && i == 6) {
+            if !src[i].is_ascii_digit() {
+                return Err(NidError::Format(FormatKind::NonDigitCharacter));
+            }
+            digits[n] = src[i] - b'0';
+            n += 1;
+        }
+        i += 1;
+    }
+
+    if digits[0] == 3 && digits[1] == 2 {
+        return Ok(NidInfo {
+            scheme: Scheme::Opaque,
+            birthday: None,
+        });
+    }
+
+    let century = match digits[6] {
+        0 => 1800,
+        1 => 1900,
+        2 => 2000,
+        _ => return Err(NidError::Format(FormatKind::InvalidCenturyDigit)),
+    };
+    let mut sum: u16 = 1;
+    let mut i = 0;
+    while i < 10 {
+        sum += digits[i] as u16 * WEIGHTS[i];
+        i += 1;
+    }
+    if (sum % 11 % 10) as u8 != digits[10] {
+        return Err(NidError::Checksum);
+    }
+
+    let day = digits[0] * 10 + digits[1];
+    let month = digits[2] * 10 + digits[3];
+    let year = century + (digits[4] * 10 + digits[5]) as u16;
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(if month < 1 || month > 12 {
+            DateKind::MonthOutOfRange { month }
+        } else {
+            DateKind::DayOutOfRange { year, month, day }
+        }));
+    };
+
+    Ok(NidInfo {
+        scheme: Scheme::Legacy,
+        birthday: Some(birthday),
+    })
+}
+
+/// Decode a Latvian personal code string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::latvia::decode_with(" 161175-19997\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.scheme, nidx::latvia::Scheme::Legacy);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded personal code info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Latvian personal code string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::latvia::validate("16117519997").is_ok());
+/// assert!(nidx::latvia::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Latvian personal code string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Latvian personal code string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::latvia::is_valid("16117519997"));
+/// assert!(!nidx::latvia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a personal code: whitespace and the hyphen
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::latvia::normalize(" 161175-19997\n"), "16117519997");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Latvian personal codes.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::latvia::{Latvia, Scheme};
+///
+/// let info = Latvia::decode("161175-19997").unwrap();
+/// assert_eq!(info.scheme, Scheme::Legacy);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Latvia;
+
+impl NationalId for Latvia {
+    const COUNTRY: Country = Country::Latvia;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_legacy() {
+        assert_eq!(
+            decode("161175-19997"),
+            Ok(NidInfo {
+                scheme: Scheme::Legacy,
+                birthday: Some(Date {
+                    year: 1975,
+                    month: 11,
+                    day: 16
+                }),
+            })
+        );
+        assert_eq!(decode("16117519997"), decode("161175-19997"));
+    }
+
+    #[test]
+    fn decode_century_digit() {
+        assert_eq!(
+            decode("010100-01230")
+                .unwrap()
+                .birthday
+                .unwrap()
+                .to_string(),
+            "1800-01-01"
+        );
+        assert_eq!(
+            decode("290200-20121")
+                .unwrap()
+                .birthday
+                .unwrap()
+                .to_string(),
+            "2000-02-29"
+        );
+    }
+
+    #[test]
+    fn decode_opaque() {
+        let expected = Ok(NidInfo {
+            scheme: Scheme::Opaque,
+            birthday: None,
+        });
+        assert_eq!(decode("32579461005"), expected);
+        assert_eq!(decode("325794-61005"), expected);
+        // Opaque codes have no check digit and no century digit.
+        assert_eq!(decode("32000000000"), expected);
+        assert_eq!(decode("32999999999"), expected);
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("1611751999"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        // The hyphen is only allowed after the sixth digit.
+        assert_eq!(
+            decode("16117-519997"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("161175-1999X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("010190-31232"),
+            Err(NidError::Format(FormatKind::InvalidCenturyDigit))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("161175-19996"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("011390-12343"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            decode("290201-20122"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidCenturyDigit).to_string(),
+            "format error: century digit must be 0, 1 or 2"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "LV_CHECKSUM");
+    }
+}
@@ -0,0 +1,834 @@
+//! Validate and decode Malaysian identity card numbers (NRIC, as printed on
+//! the MyKad).
+//!
+//! The NRIC is a 12-digit number, usually written with hyphens as
+//! `YYMMDD-PB-###G`; both forms are accepted. It encodes date of birth, place
+//! of birth and sex. There is no check digit.
+//!
+//! # Format
+//!
+//! `[year (2)][month (2)][day (2)][place of birth (2)][serial (3)][sex]`
+//!
+//! - **Year**: the last two digits of the birth year; `00`–`29` maps to
+//!   2000–2029 and `30`–`99` to 1930–1999.
+//! - **Place of birth**: a Malaysian state or a country or region abroad (see
+//!   [`BirthPlace`]).
+//! - **Sex digit**: odd for males, even for females.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::malaysia::BirthPlace;
+//!
+//! let info = nidx::malaysia::decode("850615-10-1235").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1985-06-15");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert_eq!(info.place_of_birth, BirthPlace::Selangor);
+//!
+//! assert!(nidx::malaysia::is_valid("900101145678"));
+//! assert!(!nidx::malaysia::is_valid("900101-69-5678"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::{
+    CenturyPolicy, CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Sex, Summary,
+};
+
+/// Static facts about the Malaysian NRIC format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Malaysia,
+    name: "Malaysia",
+    alpha2: "MY",
+    alpha3: "MYS",
+    min_length: 12,
+    max_length: 14,
+    shapes: &[
+        &[Run::exactly(CharClass::Digit, 12)],
+        &[
+            Run::exactly(CharClass::Digit, 6),
+            Run::one(CharClass::Exact(b'-')),
+            Run::exactly(CharClass::Digit, 2),
+            Run::one(CharClass::Exact(b'-')),
+            Run::exactly(CharClass::Digit, 4),
+        ],
+    ],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+/// A place of birth, encoded by digits 7–8 of the NRIC.
+///
+/// Codes `01`–`16`, `21`–`59` and `82` are Malaysian states and federal
+/// territories; the other codes from `60` upwards are countries or regions
+/// abroad.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum BirthPlace {
+    /// Codes `01`, `21`, `22`, `23` and `24`.
+    Johor,
+    /// Codes `02`, `25`, `26` and `27`.
+    Kedah,
+    /// Codes `03`, `28` and `29`.
+    Kelantan,
+    /// Codes `04` and `30`.
+    Malacca,
+    /// Codes `05`, `31` and `59`.
+    NegeriSembilan,
+    /// Codes `06`, `32` and `33`.
+    Pahang,
+    /// Codes `07`, `34` and `35`.
+    Penang,
+    /// Codes `08`, `36`, `37`, `38` and `39`.
+    Perak,
+    /// Codes `09` and `40`.
+    Perlis,
+    /// Codes `10`, `41`, `42`, `43` and `44`.
+    Selangor,
+    /// Codes `11`, `45` and `46`.
+    Terengganu,
+    /// Codes `12`, `47`, `48` and `49`.
+    Sabah,
+    /// Codes `13`, `50`, `51`, `52` and `53`.
+    Sarawak,
+    /// Codes `14`, `54`, `55`, `56` and `57`.
+    KualaLumpur,
+    /// Codes `15` and `58`.
+    Labuan,
+    /// Code `16`.
+    Putrajaya,
+    /// Code `82`.
+    UnknownState,
+    /// Code `60`.
+    Brunei,
+    /// Code `61`.
+    Indonesia,
+    /// Code `62`.
+    Cambodia,
+    /// Code `63`.
+    Laos,
+    /// Code `64`.
+    Myanmar,
+    /// Code `65`.
+    Philippines,
+    /// Code `66`.
+    Singapore,
+    /// Code `67`.
+    Thailand,
+    /// Code `68`.
+    Vietnam,
+    /// Codes `71` and `72`.
+    ForeignBefore2001,
+    /// Code `74`.
+    China,
+    /// Code `75`.
+    India,
+    /// Code `76`.
+    Pakistan,
+    /// Code `77`.
+    SaudiArabia,
+    /// Code `78`.
+    SriLanka,
+    /// Code `79`.
+    Bangladesh,
+    /// Code `83`.
+    AsiaPacific,
+    /// Code `84`.
+    SouthAmerica,
+    /// Code `85`.
+    Africa,
+    /// Code `86`.
+    Europe,
+    /// Code `87`.
+    BritainAndIreland,
+    /// Code `88`.
+    MiddleEast,
+    /// Code `89`.
+    FarEast,
+    /// Code `90`.
+    Caribbean,
+    /// Code `91`.
+    NorthAmerica,
+    /// Code `92`.
+    SovietUnion,
+    /// Code `93`.
+    OtherCountry,
+    /// Code `98`.
+    Stateless,
+    /// Code `99`.
+    Unspecified,
+}
+
+impl BirthPlace {
+    /// Look up the place of birth for a two-digit code.
+    ///
+    /// Returns `None` for codes that have never been assigned.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::malaysia::BirthPlace;
+    ///
+    /// assert_eq!(BirthPlace::from_code(14), Some(BirthPlace::KualaLumpur));
+    /// assert_eq!(BirthPlace::from_code(55), Some(BirthPlace::KualaLumpur));
+    /// assert_eq!(BirthPlace::from_code(66), Some(BirthPlace::Singapore));
+    /// assert_eq!(BirthPlace::from_code(17), None);
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: u8) -> Option<BirthPlace> {
+        Some(match code {
+            1 | 21 | 22 | 23 | 24 => BirthPlace::Johor,
+            2 | 25 | 26 | 27 => BirthPlace::Kedah,
+            3 | 28 | 29 => BirthPlace::Kelantan,
+            4 | 30 => BirthPlace::Malacca,
+            5 | 31 | 59 => BirthPlace::NegeriSembilan,
+            6 | 32 | 33 => BirthPlace::Pahang,
+            7 | 34 | 35 => BirthPlace::Penang,
+            8 | 36 | 37 | 38 | 39 => BirthPlace::Perak,
+            9 | 40 => BirthPlace::Perlis,
+            10 | 41 | 42 | 43 | 44 => BirthPlace::Selangor,
+            11 | 45 | 46 => BirthPlace::Terengganu,
+            12 | 47 | 48 | 49 => BirthPlace::Sabah,
+            13 | 50 | 51 | 52 | 53 => BirthPlace::Sarawak,
+            14 | 54 | 55 | 56 | 57 => BirthPlace::KualaLumpur,
+            15 | 58 => BirthPlace::Labuan,
+            16 => BirthPlace::Putrajaya,
+            82 => BirthPlace::UnknownState,
+            60 => BirthPlace::Brunei,
+            61 => BirthPlace::Indonesia,
+            62 => BirthPlace::Cambodia,
+            63 => BirthPlace::Laos,
+            64 => BirthPlace::Myanmar,
+            65 => BirthPlace::Philippines,
+            66 => BirthPlace::Singapore,
+            67 => BirthPlace::Thailand,
+            68 => BirthPlace::Vietnam,
+            71 | 72 => BirthPlace::ForeignBefore2001,
+            74 => BirthPlace::China,
+            75 => BirthPlace::India,
+            76 => BirthPlace::Pakistan,
+            77 => BirthPlace::SaudiArabia,
+            78 => BirthPlace::SriLanka,
+            79 => BirthPlace::Bangladesh,
+            83 => BirthPlace::AsiaPacific,
+            84 => BirthPlace::SouthAmerica,
+            85 => BirthPlace::Africa,
+            86 => BirthPlace::Europe,
+            87 => BirthPlace::BritainAndIreland,
+            88 => BirthPlace::MiddleEast,
+            89 => BirthPlace::FarEast,
+            90 => BirthPlace::Caribbean,
+            91 => BirthPlace::NorthAmerica,
+            92 => BirthPlace::SovietUnion,
+            93 => BirthPlace::OtherCountry,
+            98 => BirthPlace::Stateless,
+            99 => BirthPlace::Unspecified,
+            _ => return None,
+        })
+    }
+
+    /// Whether the place is a Malaysian state or federal territory.
+    #[must_use]
+    pub const fn is_malaysia(self) -> bool {
+        matches!(
+            self,
+            BirthPlace::Johor
+                | BirthPlace::Kedah
+                | BirthPlace::Kelantan
+                | BirthPlace::Malacca
+                | BirthPlace::NegeriSembilan
+                | BirthPlace::Pahang
+                | BirthPlace::Penang
+                | BirthPlace::Perak
+                | BirthPlace::Perlis
+                | BirthPlace::Selangor
+                | BirthPlace::Terengganu
+                | BirthPlace::Sabah
+                | BirthPlace::Sarawak
+                | BirthPlace::KualaLumpur
+                | BirthPlace::Labuan
+                | BirthPlace::Putrajaya
+                | BirthPlace::UnknownState
+        )
+    }
+
+    /// The English name of the place.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            BirthPlace::Johor => "Johor",
+            BirthPlace::Kedah => "Kedah",
+            BirthPlace::Kelantan => "Kelantan",
+            BirthPlace::Malacca => "Malacca",
+            BirthPlace::NegeriSembilan => "Negeri Sembilan",
+            BirthPlace::Pahang => "Pahang",
+            BirthPlace::Penang => "Penang",
+            BirthPlace::Perak => "Perak",
+            BirthPlace::Perlis => "Perlis",
+            BirthPlace::Selangor => "Selangor",
+            BirthPlace::Terengganu => "Terengganu",
+            BirthPlace::Sabah => "Sabah",
+            BirthPlace::Sarawak => "Sarawak",
+            BirthPlace::KualaLumpur => "Kuala Lumpur",
+            BirthPlace::Labuan => "Labuan",
+            BirthPlace::Putrajaya => "Putrajaya",
+            BirthPlace::UnknownState => "Unknown state",
+            BirthPlace::Brunei => "Brunei",
+            BirthPlace::Indonesia => "Indonesia",
+            BirthPlace::Cambodia => "Cambodia",
+            BirthPlace::Laos => "Laos",
+            BirthPlace::Myanmar => "Myanmar",
+            BirthPlace::Philippines => "Philippines",
+            BirthPlace::Singapore => "Singapore",
+            BirthPlace::Thailand => "Thailand",
+            BirthPlace::Vietnam => "Vietnam",
+            BirthPlace::ForeignBefore2001 => "Foreign country (before 2001)",
+            BirthPlace::China => "China",
+            BirthPlace::India => "India",
+            BirthPlace::Pakistan => "Pakistan",
+            BirthPlace::SaudiArabia => "Saudi Arabia",
+            BirthPlace::SriLanka => "Sri Lanka",
+            BirthPlace::Bangladesh => "Bangladesh",
+            BirthPlace::AsiaPacific => "Asia-Pacific",
+            BirthPlace::SouthAmerica => "South America",
+            BirthPlace::Africa => "Africa",
+            BirthPlace::Europe => "Europe",
+            BirthPlace::BritainAndIreland => "Britain and Ireland",
+            BirthPlace::MiddleEast => "Middle East",
+            BirthPlace::FarEast => "Far East",
+            BirthPlace::Caribbean => "Caribbean",
+            BirthPlace::NorthAmerica => "North America",
+            BirthPlace::SovietUnion => "Former Soviet Union",
+            BirthPlace::OtherCountry => "Other country",
+            BirthPlace::Stateless => "Stateless",
+            BirthPlace::Unspecified => "Unspecified nationality",
+        }
+    }
+}
+
+impl fmt::Display for BirthPlace {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Decoded information from a valid NRIC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// Place of birth.
+    pub place_of_birth: BirthPlace,
+    /// The four-digit serial, including the sex digit (digits 9–12).
+    pub serial: u16,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The same numbering is used for citizens and permanent residents, so
+    /// `is_national` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::malaysia::decode("850615-10-1235").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1985-06-15 M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1985-06-15 M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason an NRIC was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is neither 12 digits nor 14 characters with hyphens.
+    InvalidLength,
+    /// A character other than a separator hyphen is not an ASCII digit.
+    NonDigitCharacter,
+    /// The hyphenated form has a character other than `-` at a separator position.
+    InvalidSeparator,
+    /// The place-of-birth code has never been assigned.
+    InvalidPlaceCode {
+        /// The two-digit place-of-birth code that was found.
+        code: u8,
+    },
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "NRIC must be 12 digits or 14 characters with hyphens")
+            }
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the hyphens must be ASCII digits")
+            }
+            FormatKind::InvalidSeparator => {
+                write!(f, "hyphenated form must have hyphens at positions 7 and 10")
+            }
+            FormatKind::InvalidPlaceCode { code } => {
+                write!(f, "place-of-birth code {code:02} is not assigned")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason an NRIC was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding an NRIC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters, place of birth).
+    Format(FormatKind),
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `MY_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `MY_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidSeparator`] | `MY_FORMAT_SEPARATOR` |
+    /// | [`FormatKind::InvalidPlaceCode`] | `MY_FORMAT_PLACE` |
+    /// | [`DateKind::MonthOutOfRange`] | `MY_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `MY_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::malaysia::validate("900101695678").unwrap_err();
+    /// assert_eq!(err.error_code(), "MY_FORMAT_PLACE");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "MY_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "MY_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidSeparator) => "MY_FORMAT_SEPARATOR",
+            NidError::Format(FormatKind::InvalidPlaceCode { .. }) => "MY_FORMAT_PLACE",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "MY_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "MY_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+        }
+    }
+}
+
+/// Decode a Malaysian NRIC, with or without the hyphens.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, illegal
+/// characters, misplaced separators or an unassigned place-of-birth code,
+/// and [`NidError::InvalidDate`] if the encoded date is not a valid calendar
+/// date.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::malaysia::BirthPlace;
+///
+/// let info = nidx::malaysia::decode("000229-71-0001").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2000-02-29");
+/// assert_eq!(info.place_of_birth, BirthPlace::ForeignBefore2001);
+/// ```
+#[must_use = "this returns the decoded NRIC info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    decode_impl(nid, CenturyPolicy::DEFAULT)
+}
+
+/// Decode with `century` expanding the two-digit birth year.
+const fn decode_impl(nid: &str, century: CenturyPolicy) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let hyphens = match src.len() {
+        12 => false,
+        14 => true,
+        _ => return Err(NidError::Format(FormatKind::InvalidLength)),
+    };
+    let mut digits = [0u8; 12];
+    let mut n = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if hyphens && (i == 6 || i == 9) {
+            if src[i] != b'-' {
+                return Err(NidError::Format(FormatKind::InvalidSeparator));
+            }
+        } else if src[i].is_ascii_digit() {
+            digits[n] = src[i] - b'0';
+            n += 1;
+        } else {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+
+    let code = digits[6] * 10 + digits[7];
+    let Some(place_of_birth) = BirthPlace::from_code(code) else {
+        return Err(NidError::Format(FormatKind::InvalidPlaceCode { code }));
+    };
+
+    let yy = digits[0] * 10 + digits[1];
+    let year = century.resolve(yy);
+    let month = digits[2] * 10 + digits[3];
+    let day = digits[4] * 10 + digits[5];
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(if month < 1 || month > 12 {
+            DateKind::MonthOutOfRange { month }
+        } else {
+            DateKind::DayOutOfRange { year, month, day }
+        }));
+    };
+
+    Ok(NidInfo {
+        birthday,
+        sex: if digits[11] % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        },
+        place_of_birth,
+        serial: digits[8] as u16 * 1000
+            + digits[9] as u16 * 100
+            + digits[10] as u16 * 10
+            + digits[11] as u16,
+    })
+}
+
+/// Decode a Malaysian NRIC string after pre-processing it according to
+/// `options`.
+///
+/// The two-digit birth year is expanded with [`ParseOptions::century`].
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::malaysia::decode_with(" 850615 10 1235\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1985-06-15");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded NRIC info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode_impl(&options.prepare(nid), options.century)
+}
+
+/// Validate a Malaysian NRIC string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::malaysia::validate("850615101235").is_ok());
+/// assert!(nidx::malaysia::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Malaysian NRIC string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Malaysian NRIC string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::malaysia::is_valid("850615101235"));
+/// assert!(!nidx::malaysia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an NRIC: whitespace and hyphens removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::malaysia::normalize(" 850615-10-1235\n"), "850615101235");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Malaysian NRIC numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::malaysia::Malaysia;
+///
+/// let info = Malaysia::decode("850615-10-1235").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Malaysia;
+
+impl NationalId for Malaysia {
+    const COUNTRY: Country = Country::Malaysia;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+        decode_with(nid, options)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("850615-10-1235"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1985,
+                    month: 6,
+                    day: 15
+                },
+                sex: Sex::Male,
+                place_of_birth: BirthPlace::Selangor,
+                serial: 1235,
+            })
+        );
+        assert_eq!(decode("850615101235"), decode("850615-10-1235"));
+    }
+
+    #[test]
+    fn decode_century_pivot() {
+        assert_eq!(
+            decode("291231-14-5678").unwrap().birthday.to_string(),
+            "2029-12-31"
+        );
+        assert_eq!(
+            decode("300101-14-5678").unwrap().birthday.to_string(),
+            "1930-01-01"
+        );
+    }
+
+    #[test]
+    fn decode_with_century_policy() {
+        let options = ParseOptions::lenient().century(CenturyPolicy::SlidingWindow {
+            reference_year: 2026,
+        });
+        assert_eq!(
+            decode_with("261231-14-5678", &options)
+                .unwrap()
+                .birthday
+                .year,
+            2026
+        );
+        assert_eq!(
+            decode_with("27 0101 14 5678", &options)
+                .unwrap()
+                .birthday
+                .year,
+            1927
+        );
+        assert_eq!(
+            Malaysia::decode_with("291231-14-5678", &options)
+                .unwrap()
+                .birthday
+                .year,
+            1929
+        );
+    }
+
+    #[test]
+    fn decode_sex_from_last_digit() {
+        assert_eq!(decode("900101-14-5678").unwrap().sex, Sex::Female);
+        assert_eq!(decode("900101-14-5677").unwrap().sex, Sex::Male);
+    }
+
+    #[test]
+    fn birth_place_table() {
+        assert_eq!(BirthPlace::from_code(1), Some(BirthPlace::Johor));
+        assert_eq!(BirthPlace::from_code(24), Some(BirthPlace::Johor));
+        assert_eq!(BirthPlace::from_code(59), Some(BirthPlace::NegeriSembilan));
+        assert_eq!(BirthPlace::from_code(82), Some(BirthPlace::UnknownState));
+        assert_eq!(BirthPlace::from_code(98), Some(BirthPlace::Stateless));
+        for code in [0, 17, 20, 69, 70, 73, 80, 81, 94, 97] {
+            assert_eq!(BirthPlace::from_code(code), None, "{code}");
+        }
+        assert!(BirthPlace::Putrajaya.is_malaysia());
+        assert!(BirthPlace::UnknownState.is_malaysia());
+        assert!(!BirthPlace::Singapore.is_malaysia());
+        assert_eq!(BirthPlace::NegeriSembilan.to_string(), "Negeri Sembilan");
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("90010114567"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("900101-145678"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("900101 14-5678"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            decode("90010114567X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("900101-69-5678"),
+            Err(NidError::Format(FormatKind::InvalidPlaceCode { code: 69 }))
+        );
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("901301-14-5678"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            decode("010229-14-5678"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidPlaceCode { code: 69 }).to_string(),
+            "format error: place-of-birth code 69 is not assigned"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidPlaceCode { code: 69 }).error_code(),
+            "MY_FORMAT_PLACE"
+        );
+    }
+}
@@ -1,2 +1,39 @@
 pub mod albania;
+pub mod australia;
+pub mod austria;
+pub mod bahrain;
+pub mod belarus;
+pub mod botswana;
+pub mod chile;
+pub mod colombia;
+pub mod costa_rica;
+pub mod cyprus;
+pub mod czechia;
+pub mod ecuador;
+pub mod egypt;
+pub mod finland;
+pub mod france;
+pub mod georgia;
+pub mod germany;
+pub mod ireland;
+pub mod italy;
+pub mod jmbg;
+pub mod kenya;
 pub mod kosovo;
+pub mod latvia;
+pub mod malaysia;
+pub mod north_macedonia;
+pub mod peru;
+pub mod poland;
+pub mod portugal;
+pub mod russia;
+pub mod serbia;
+pub mod south_korea;
+pub mod spain;
+pub mod switzerland;
+pub mod turkey;
+pub mod uae;
+pub mod ukraine;
+pub mod venezuela;
+pub mod vietnam;
+pub mod zimbabwe;
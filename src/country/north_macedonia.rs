@@ -0,0 +1,599 @@
+//! Validate and decode North Macedonian unique master citizen numbers (EMBG).
+//!
+//! The EMBG (Единствен матичен број на граѓанинот) is the North Macedonian
+//! variant of the 13-digit Yugoslav JMBG. It encodes date of birth, the region
+//! of birth registration, sex and a check digit.
+//!
+//! # Format
+//!
+//! `[day (2)][month (2)][year (3)][region (2)][serial (3)][check]`
+//!
+//! - **Year**: the last three digits of the birth year; `800`–`999` maps to
+//!   1800–1999 and `000`–`799` to 2000–2799.
+//! - **Region**: `41`–`49`, the North Macedonian registration regions (see
+//!   [`Region`]). Other codes belong to other former Yugoslav republics and
+//!   are rejected.
+//! - **Serial**: `000`–`499` for males, `500`–`999` for females.
+//! - **Check digit**: `11 - (weighted sum mod 11)` with weights
+//!   `7 6 5 4 3 2 7 6 5 4 3 2`, where `10` and `11` map to `0`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::north_macedonia::Region;
+//!
+//! let info = nidx::north_macedonia::decode("0101990450006").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1990-01-01");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert_eq!(info.region, Region::Skopje);
+//!
+//! assert!(nidx::north_macedonia::is_valid("0101990450006"));
+//! assert!(!nidx::north_macedonia::is_valid("0101990710008"));
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::jmbg::{self, JmbgError};
+use crate::{
+    CharClass, Country, Date, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Sex,
+    Summary,
+};
+
+/// Static facts about the North Macedonian EMBG format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::NorthMacedonia,
+    name: "North Macedonia",
+    alpha2: "MK",
+    alpha3: "MKD",
+    min_length: 13,
+    max_length: 13,
+    shapes: &[&[Run::exactly(CharClass::Digit, 13)]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+/// Region codes accepted in a North Macedonian EMBG.
+const REGIONS: &[RangeInclusive<u8>] = &[41..=49];
+
+/// A North Macedonian birth registration region, encoded in digits 8–9.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Region {
+    /// Code `41`.
+    Bitola,
+    /// Code `42`.
+    Kumanovo,
+    /// Code `43`.
+    Ohrid,
+    /// Code `44`.
+    Prilep,
+    /// Code `45`.
+    Skopje,
+    /// Code `46`.
+    Strumica,
+    /// Code `47`.
+    Tetovo,
+    /// Code `48`.
+    Veles,
+    /// Code `49`.
+    Stip,
+}
+
+impl Region {
+    /// Every region, in code order.
+    pub const ALL: &'static [Region] = &[
+        Region::Bitola,
+        Region::Kumanovo,
+        Region::Ohrid,
+        Region::Prilep,
+        Region::Skopje,
+        Region::Strumica,
+        Region::Tetovo,
+        Region::Veles,
+        Region::Stip,
+    ];
+
+    /// Look up a region by its two-digit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::north_macedonia::Region;
+    ///
+    /// assert_eq!(Region::from_code(45), Some(Region::Skopje));
+    /// assert_eq!(Region::from_code(71), None);
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: u8) -> Option<Region> {
+        if code < 41 || code > 49 {
+            return None;
+        }
+        Some(Region::ALL[(code - 41) as usize])
+    }
+
+    /// The two-digit code of the region.
+    #[inline]
+    #[must_use]
+    pub const fn code(self) -> u8 {
+        41 + self as u8
+    }
+
+    /// The name of the region's seat, in Latin script.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Region::Bitola => "Bitola",
+            Region::Kumanovo => "Kumanovo",
+            Region::Ohrid => "Ohrid",
+            Region::Prilep => "Prilep",
+            Region::Skopje => "Skopje",
+            Region::Strumica => "Strumica",
+            Region::Tetovo => "Tetovo",
+            Region::Veles => "Veles",
+            Region::Stip => "Štip",
+        }
+    }
+}
+
+impl fmt::Display for Region {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Decoded information from a valid EMBG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// The region where the birth was registered.
+    pub region: Region,
+    /// The three-digit serial (digits 10–12) distinguishing people born on the
+    /// same day in the same region.
+    pub serial: u16,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The EMBG does not encode citizenship, so `is_national` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::north_macedonia::decode("0101990450006").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1990-01-01 M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1990-01-01 M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason an EMBG was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 13 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The region code is not a North Macedonian region (`41`–`49`).
+    InvalidRegion {
+        /// The two-digit region code that was found.
+        code: u8,
+    },
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "EMBG must be exactly 13 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::InvalidRegion { code } => {
+                write!(f, "region code {code} is not a North Macedonian region")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason an EMBG was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding an EMBG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `MK_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `MK_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidRegion`] | `MK_FORMAT_REGION` |
+    /// | [`NidError::Checksum`] | `MK_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `MK_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `MK_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::north_macedonia::validate("0101990450007").unwrap_err();
+    /// assert_eq!(err.error_code(), "MK_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "MK_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "MK_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidRegion { .. }) => "MK_FORMAT_REGION",
+            NidError::Checksum => "MK_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "MK_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "MK_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+impl NidError {
+    /// Map a rejection from the shared JMBG core onto this module's errors.
+    const fn from_core(err: JmbgError) -> NidError {
+        match err {
+            JmbgError::InvalidLength => NidError::Format(FormatKind::InvalidLength),
+            JmbgError::NonDigitCharacter => NidError::Format(FormatKind::NonDigitCharacter),
+            JmbgError::Checksum => NidError::Checksum,
+            JmbgError::InvalidRegion { code } => {
+                NidError::Format(FormatKind::InvalidRegion { code })
+            }
+            JmbgError::MonthOutOfRange { month } => {
+                NidError::InvalidDate(DateKind::MonthOutOfRange { month })
+            }
+            JmbgError::DayOutOfRange { year, month, day } => {
+                NidError::InvalidDate(DateKind::DayOutOfRange { year, month, day })
+            }
+        }
+    }
+}
+
+/// Decode a North Macedonian EMBG string.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters or a region outside North Macedonia, [`NidError::Checksum`] if
+/// the check digit does not match, and [`NidError::InvalidDate`] if the
+/// encoded date is not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::north_macedonia::Region;
+///
+/// let info = nidx::north_macedonia::decode("2902000485501").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2000-02-29");
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// assert_eq!(info.region, Region::Veles);
+/// assert_eq!(info.serial, 550);
+/// ```
+#[must_use = "this returns the decoded EMBG info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    match jmbg::parse(nid.as_bytes(), REGIONS) {
+        Ok(parts) => Ok(NidInfo {
+            birthday: parts.birthday,
+            sex: parts.sex,
+            region: Region::ALL[(parts.region - 41) as usize],
+            serial: parts.serial,
+        }),
+        Err(err) => Err(NidError::from_core(err)),
+    }
+}
+
+/// Decode a North Macedonian EMBG string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let opts = ParseOptions::lenient();
+/// let info = nidx::north_macedonia::decode_with("010199 045 0006", &opts).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded EMBG info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a North Macedonian EMBG string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::north_macedonia::validate("0101990450006").is_ok());
+/// assert!(nidx::north_macedonia::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a North Macedonian EMBG string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a North Macedonian EMBG string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::north_macedonia::is_valid("0101990450006"));
+/// assert!(!nidx::north_macedonia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an EMBG: surrounding whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::north_macedonia::normalize(" 0101990450006\n"), "0101990450006");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.trim().to_owned()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for North Macedonian EMBGs.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::north_macedonia::{NorthMacedonia, Region};
+///
+/// let info = NorthMacedonia::decode("0101990450006").unwrap();
+/// assert_eq!(info.region, Region::Skopje);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct NorthMacedonia;
+
+impl NationalId for NorthMacedonia {
+    const COUNTRY: Country = Country::NorthMacedonia;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for NorthMacedonia {
+    type Components = NidInfo;
+
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        jmbg::encode(
+            jmbg::Parts {
+                birthday: info.birthday,
+                sex: info.sex,
+                region: info.region.code(),
+                serial: info.serial,
+            },
+            REGIONS,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_NID: &str = "0101990450006";
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode(VALID_NID),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1990,
+                    month: 1,
+                    day: 1
+                },
+                sex: Sex::Male,
+                region: Region::Skopje,
+                serial: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_female_and_region() {
+        let info = decode("1505995495005").unwrap();
+        assert_eq!(info.birthday.to_string(), "1995-05-15");
+        assert_eq!(info.sex, Sex::Female);
+        assert_eq!(info.region, Region::Stip);
+        assert_eq!(info.region.to_string(), "Štip");
+    }
+
+    #[test]
+    fn region_codes_roundtrip() {
+        for &region in Region::ALL {
+            assert_eq!(Region::from_code(region.code()), Some(region));
+        }
+        assert_eq!(Region::Bitola.code(), 41);
+        assert_eq!(Region::Stip.code(), 49);
+        assert_eq!(Region::from_code(40), None);
+        assert_eq!(Region::from_code(50), None);
+    }
+
+    #[test]
+    fn error_region_outside_north_macedonia() {
+        // Valid Serbian JMBG (region 71).
+        assert_eq!(
+            decode("0101990710008"),
+            Err(NidError::Format(FormatKind::InvalidRegion { code: 71 }))
+        );
+    }
+
+    #[test]
+    fn error_wrong_length_and_non_digit() {
+        assert_eq!(
+            decode("010199045000"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("010199045000X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("0101990450007"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("0113990450004"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            decode("2902001485505"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        let err = NidError::Format(FormatKind::InvalidRegion { code: 71 });
+        assert_eq!(
+            err.to_string(),
+            "format error: region code 71 is not a North Macedonian region"
+        );
+        assert_eq!(err.error_code(), "MK_FORMAT_REGION");
+        assert_eq!(NidError::Checksum.error_code(), "MK_CHECKSUM");
+    }
+}
@@ -0,0 +1,491 @@
+//! Validate Peruvian national identity numbers (Documento Nacional de
+//! Identidad, DNI).
+//!
+//! The DNI number, also called the CUI (Código Único de Identificación), is an
+//! 8-digit sequential number issued by RENIEC to Peruvian citizens. The card
+//! prints a verifier after it, which is either a digit or a letter depending
+//! on the card series; both are derived from the same checksum. The verifier
+//! is often left out, so it is optional.
+//!
+//! # Format
+//!
+//! `[number (8)][-][verifier]`
+//!
+//! - **Verifier**: the 8 digits weighted by `3 2 7 6 5 4 3 2`, summed mod 11.
+//!   The remainder indexes `65432110987` for the numeric verifier and
+//!   `KJIHGFEDCBA` for the alphabetic one. A hyphen may precede the verifier.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::peru::is_valid("12345678"));
+//! assert!(nidx::peru::is_valid("12345678-1"));
+//! assert!(nidx::peru::is_valid("12345678E"));
+//! assert!(!nidx::peru::is_valid("12345678-2"));
+//!
+//! let verifier = nidx::peru::verifier("12345678").unwrap();
+//! assert_eq!((verifier.digit, verifier.letter), ('1', 'E'));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Peruvian DNI format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Peru,
+    name: "Peru",
+    alpha2: "PE",
+    alpha3: "PER",
+    min_length: 8,
+    max_length: 10,
+    shapes: &[
+        &[
+            Run::exactly(CharClass::Digit, 8),
+            Run::optional(CharClass::Alphanumeric),
+        ],
+        &[
+            Run::exactly(CharClass::Digit, 8),
+            Run::one(CharClass::Exact(b'-')),
+            Run::one(CharClass::Alphanumeric),
+        ],
+    ],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+const WEIGHTS: [u16; 8] = [3, 2, 7, 6, 5, 4, 3, 2];
+const DIGIT_VERIFIERS: &[u8; 11] = b"65432110987";
+const LETTER_VERIFIERS: &[u8; 11] = b"KJIHGFEDCBA";
+
+/// The two equivalent verifiers of a DNI number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Verifier {
+    /// The numeric verifier.
+    pub digit: char,
+    /// The alphabetic verifier.
+    pub letter: char,
+}
+
+/// Which verifier, if any, followed the DNI number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VerifierKind {
+    /// A numeric verifier.
+    Digit,
+    /// An alphabetic verifier.
+    Letter,
+}
+
+/// Decoded information from a valid DNI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// The verifier that followed the number, or `None` if it was omitted.
+    pub verifier: Option<VerifierKind>,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// DNIs are only issued to Peruvian citizens (foreign residents hold a
+    /// carné de extranjería), so `is_national` is always `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::peru::decode("12345678").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(true),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the summary, e.g. `"national"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a DNI was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 8 digits, optionally followed by a verifier.
+    InvalidLength,
+    /// One of the first 8 characters is not an ASCII digit.
+    NonDigitCharacter,
+    /// The verifier is not a digit or a letter from `A`–`K`.
+    InvalidCheckChar,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "DNI must be 8 digits and an optional verifier"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "the first 8 characters must be ASCII digits")
+            }
+            FormatKind::InvalidCheckChar => write!(f, "verifier must be a digit or a letter A-K"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding a DNI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, misplaced hyphen, invalid verifier).
+    Format(FormatKind),
+    /// The verifier does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `PE_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `PE_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCheckChar`] | `PE_FORMAT_CHECK_CHAR` |
+    /// | [`NidError::Checksum`] | `PE_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::peru::validate("12345678-2").unwrap_err();
+    /// assert_eq!(err.error_code(), "PE_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "PE_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "PE_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidCheckChar) => "PE_FORMAT_CHECK_CHAR",
+            NidError::Checksum => "PE_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Compute both verifiers from the first 8 bytes of `bytes`.
+const fn compute_verifier(bytes: &[u8]) -> Result<Verifier, NidError> {
+    let mut sum = 0u16;
+    let mut i = 0;
+    while i < 8 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        sum += (bytes[i] - b'0') as u16 * WEIGHTS[i];
+        i += 1;
+    }
+    let r = (sum % 11) as usize;
+    Ok(Verifier {
+        digit: DIGIT_VERIFIERS[r] as char,
+        letter: LETTER_VERIFIERS[r] as char,
+    })
+}
+
+/// Compute both verifiers of an 8-digit DNI number.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if `number` is not exactly 8 ASCII digits.
+///
+/// # Examples
+///
+/// ```
+/// let verifier = nidx::peru::verifier("10117410").unwrap();
+/// assert_eq!(verifier.digit, '2');
+/// assert_eq!(verifier.letter, 'G');
+/// ```
+pub const fn verifier(number: &str) -> Result<Verifier, NidError> {
+    let bytes = number.as_bytes();
+    if bytes.len() != 8 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    compute_verifier(bytes)
+}
+
+/// Decode a Peruvian DNI, with or without its verifier.
+///
+/// The verifier may be separated by a hyphen and a letter verifier is
+/// matched case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or an invalid verifier character, and [`NidError::Checksum`]
+/// if the verifier does not match.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::peru::VerifierKind;
+///
+/// let info = nidx::peru::decode("12345678e").unwrap();
+/// assert_eq!(info.verifier, Some(VerifierKind::Letter));
+/// ```
+#[must_use = "this returns the decoded DNI info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    let check = match bytes.len() {
+        8 => None,
+        9 => Some(bytes[8]),
+        10 if bytes[8] == b'-' => Some(bytes[9]),
+        _ => return Err(NidError::Format(FormatKind::InvalidLength)),
+    };
+    let expected = match compute_verifier(bytes) {
+        Ok(expected) => expected,
+        Err(e) => return Err(e),
+    };
+    let verifier = match check {
+        None => None,
+        Some(c) if c.is_ascii_digit() => {
+            if c as char != expected.digit {
+                return Err(NidError::Checksum);
+            }
+            Some(VerifierKind::Digit)
+        }
+        Some(c) if matches!(c.to_ascii_uppercase(), b'A'..=b'K') => {
+            if c.to_ascii_uppercase() as char != expected.letter {
+                return Err(NidError::Checksum);
+            }
+            Some(VerifierKind::Letter)
+        }
+        Some(_) => return Err(NidError::Format(FormatKind::InvalidCheckChar)),
+    };
+    Ok(NidInfo { verifier })
+}
+
+/// Decode a Peruvian DNI string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::peru::decode_with(" 12345678 - E\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.verifier, Some(nidx::peru::VerifierKind::Letter));
+/// ```
+#[inline]
+#[must_use = "this returns the decoded DNI info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Peruvian DNI string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::peru::validate("12345678-1").is_ok());
+/// assert!(nidx::peru::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Peruvian DNI string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Peruvian DNI string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::peru::is_valid("12345678-1"));
+/// assert!(!nidx::peru::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a DNI: whitespace and hyphens removed and a
+/// letter verifier uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::peru::normalize(" 12345678-e"), "12345678E");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Peruvian DNI numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::peru::{Peru, VerifierKind};
+///
+/// let info = Peru::decode("12345678-1").unwrap();
+/// assert_eq!(info.verifier, Some(VerifierKind::Digit));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Peru;
+
+impl NationalId for Peru {
+    const COUNTRY: Country = Country::Peru;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_with_and_without_verifier() {
+        assert_eq!(decode("12345678"), Ok(NidInfo { verifier: None }));
+        assert_eq!(
+            decode("123456781"),
+            Ok(NidInfo {
+                verifier: Some(VerifierKind::Digit)
+            })
+        );
+        assert_eq!(decode("12345678-1"), decode("123456781"));
+        assert_eq!(
+            decode("12345678E"),
+            Ok(NidInfo {
+                verifier: Some(VerifierKind::Letter)
+            })
+        );
+        assert_eq!(decode("12345678-e"), decode("12345678E"));
+    }
+
+    #[test]
+    fn verifier_tables() {
+        assert_eq!(
+            verifier("00000000"),
+            Ok(Verifier {
+                digit: '6',
+                letter: 'K'
+            })
+        );
+        assert_eq!(
+            verifier("44444444"),
+            Ok(Verifier {
+                digit: '0',
+                letter: 'D'
+            })
+        );
+        assert!(decode("44444444-0").is_ok());
+        assert!(decode("44444444D").is_ok());
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("1234567"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("12345678-"),
+            Err(NidError::Format(FormatKind::InvalidCheckChar))
+        );
+        assert_eq!(
+            decode("12345678--1"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("1234567X1"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("12345678Z"),
+            Err(NidError::Format(FormatKind::InvalidCheckChar))
+        );
+        assert_eq!(
+            verifier("1234567"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("12345678-2"), Err(NidError::Checksum));
+        assert_eq!(decode("12345678F"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidCheckChar).to_string(),
+            "format error: verifier must be a digit or a letter A-K"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "PE_CHECKSUM");
+    }
+}
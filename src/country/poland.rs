@@ -0,0 +1,497 @@
+//! Validate and decode Polish national identification numbers (PESEL).
+//!
+//! The PESEL (Powszechny Elektroniczny System Ewidencji Ludności) is an
+//! 11-digit number encoding date of birth and sex, followed by a check digit.
+//!
+//! # Format
+//!
+//! `[year (2)][month (2)][day (2)][serial (3)][sex][check]`
+//!
+//! - **Month**: the century is encoded by adding an offset to the month:
+//!   `+80` for 1800–1899, `+0` for 1900–1999, `+20` for 2000–2099, `+40` for
+//!   2100–2199 and `+60` for 2200–2299.
+//! - **Sex digit**: odd for males, even for females.
+//! - **Check digit**: the first 10 digits weighted by `1 3 7 9 1 3 7 9 1 3`;
+//!   the check digit is `(10 - sum mod 10) mod 10`.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::poland::decode("44051401359").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1944-05-14");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//!
+//! assert!(nidx::poland::is_valid("02270803624"));
+//! assert!(!nidx::poland::is_valid("44051401358"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::{CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Sex, Summary};
+
+/// Static facts about the Polish PESEL format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Poland,
+    name: "Poland",
+    alpha2: "PL",
+    alpha3: "POL",
+    min_length: 11,
+    max_length: 11,
+    shapes: &[&[Run::exactly(CharClass::Digit, 11)]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+const WEIGHTS: [u16; 10] = [1, 3, 7, 9, 1, 3, 7, 9, 1, 3];
+
+/// Decoded information from a valid PESEL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// PESEL numbers are also issued to foreign residents, so `is_national`
+    /// is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::poland::decode("44051401359").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1944-05-14 M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1944-05-14 M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a PESEL was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 11 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "PESEL must be exactly 11 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a PESEL was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month (including the century offset) is not in any of the
+    /// ranges `01`–`12`, `21`–`32`, `41`–`52`, `61`–`72` or `81`–`92`.
+    MonthOutOfRange {
+        /// The encoded month value, including the century offset.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The decoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a PESEL.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `PL_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `PL_FORMAT_NON_DIGIT` |
+    /// | [`NidError::Checksum`] | `PL_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `PL_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `PL_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::poland::validate("44051401358").unwrap_err();
+    /// assert_eq!(err.error_code(), "PL_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "PL_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "PL_FORMAT_NON_DIGIT",
+            NidError::Checksum => "PL_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "PL_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "PL_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Parse the two ASCII digits at `bytes[i..i + 2]`.
+const fn two_digits(bytes: &[u8], i: usize) -> u8 {
+    (bytes[i] - b'0') * 10 + (bytes[i + 1] - b'0')
+}
+
+/// Decode a Polish PESEL string.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters, [`NidError::Checksum`] if the check digit does not match, and
+/// [`NidError::InvalidDate`] if the encoded date is not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::poland::decode("02270803624").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2002-07-08");
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// ```
+#[must_use = "this returns the decoded PESEL info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 11 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        if i < 10 {
+            sum += (bytes[i] - b'0') as u16 * WEIGHTS[i];
+        }
+        i += 1;
+    }
+    if (10 - sum % 10) % 10 != (bytes[10] - b'0') as u16 {
+        return Err(NidError::Checksum);
+    }
+
+    let yy = two_digits(bytes, 0) as u16;
+    let encoded_month = two_digits(bytes, 2);
+    let (century, month) = match encoded_month {
+        81..=92 => (1800, encoded_month - 80),
+        1..=12 => (1900, encoded_month),
+        21..=32 => (2000, encoded_month - 20),
+        41..=52 => (2100, encoded_month - 40),
+        61..=72 => (2200, encoded_month - 60),
+        _ => {
+            return Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: encoded_month,
+            }));
+        }
+    };
+    let year = century + yy;
+    let day = two_digits(bytes, 4);
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+            year,
+            month,
+            day,
+        }));
+    };
+
+    Ok(NidInfo {
+        birthday,
+        sex: if (bytes[9] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        },
+    })
+}
+
+/// Decode a Polish PESEL string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::poland::decode_with(" 44051401359\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1944-05-14");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded PESEL info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Polish PESEL string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::poland::validate("44051401359").is_ok());
+/// assert!(nidx::poland::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Polish PESEL string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Polish PESEL string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::poland::is_valid("44051401359"));
+/// assert!(!nidx::poland::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a PESEL: surrounding whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::poland::normalize(" 44051401359\n"), "44051401359");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.trim().to_owned()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Polish PESEL numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::poland::Poland;
+///
+/// let info = Poland::decode("44051401359").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Poland;
+
+impl NationalId for Poland {
+    const COUNTRY: Country = Country::Poland;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("44051401359"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1944,
+                    month: 5,
+                    day: 14
+                },
+                sex: Sex::Male,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_century_offsets() {
+        assert_eq!(
+            decode("80810101234").unwrap().birthday.to_string(),
+            "1880-01-01"
+        );
+        assert_eq!(
+            decode("00922900123").unwrap().birthday.to_string(),
+            "1800-12-29"
+        );
+        assert_eq!(
+            decode("02270803624").unwrap().birthday.to_string(),
+            "2002-07-08"
+        );
+        assert_eq!(
+            decode("00410101230").unwrap().birthday.to_string(),
+            "2100-01-01"
+        );
+        assert_eq!(
+            decode("00610101243").unwrap().birthday.to_string(),
+            "2200-01-01"
+        );
+    }
+
+    #[test]
+    fn decode_sex_from_tenth_digit() {
+        assert_eq!(decode("02270803624").unwrap().sex, Sex::Female);
+        assert_eq!(decode("00610101243").unwrap().sex, Sex::Female);
+        assert_eq!(decode("00410101230").unwrap().sex, Sex::Male);
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("4405140135"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("4405140135X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("44051401358"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("00130100003"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        // 1900 and 2200 are not leap years.
+        assert_eq!(
+            decode("00022900003"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 1900,
+                month: 2,
+                day: 29
+            }))
+        );
+        assert_eq!(
+            decode("00622900001"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2200,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: PESEL must be exactly 11 digits"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "PL_CHECKSUM");
+    }
+}
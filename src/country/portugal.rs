@@ -0,0 +1,497 @@
+//! Validate Portuguese citizen card (Cartão de Cidadão) document numbers.
+//!
+//! The document number printed on the card is 12 characters long: the holder's
+//! 8-digit civil identification number (NIC) and its check digit, a
+//! two-character version identifying the card issued, and a final check digit
+//! over the whole string. It is usually printed with spaces, e.g.
+//! `00000000 0 ZZ4`.
+//!
+//! # Format
+//!
+//! `[civil number (8)][check][version (2)][check]`
+//!
+//! - **Version**: two characters `A`–`Z` or `0`–`9`.
+//! - **Final check digit**: a Luhn-style sum over the first 11 characters,
+//!   read right to left, where letters count as `A` = 10 … `Z` = 35 and every
+//!   other character starting with the rightmost is doubled (subtracting 9 when
+//!   the result exceeds 9).
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::portugal::decode("000000000ZZ4").unwrap();
+//! assert_eq!(info.civil_number, 0);
+//! assert_eq!(info.version_str(), "ZZ");
+//!
+//! assert!(nidx::portugal::is_valid("123456789ZZ1"));
+//! assert!(!nidx::portugal::is_valid("123456789ZZ2"));
+//! ```
+
+use std::fmt;
+
+use crate::encode::check_range;
+use crate::{
+    CharClass, Country, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Summary,
+};
+
+/// Static facts about the Portuguese Cartão de Cidadão number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Portugal,
+    name: "Portugal",
+    alpha2: "PT",
+    alpha3: "PRT",
+    min_length: 12,
+    max_length: 12,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 9),
+        Run::exactly(CharClass::Alphanumeric, 2),
+        Run::one(CharClass::Digit),
+    ]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Decoded information from a valid Cartão de Cidadão number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// The civil identification number (NIC), characters 1–8.
+    pub civil_number: u32,
+    /// The card version, characters 10–11, as uppercase ASCII.
+    pub version: [u8; 2],
+}
+
+impl NidInfo {
+    /// The card version as a string, e.g. `"ZZ"`.
+    #[must_use]
+    pub const fn version_str(&self) -> &str {
+        match std::str::from_utf8(&self.version) {
+            Ok(s) => s,
+            Err(_) => unreachable!(),
+        }
+    }
+
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The Cartão de Cidadão is only issued to Portuguese citizens, so
+    /// `is_national` is always `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::portugal::decode("123456789ZZ1").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(true));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(true),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the civil number and version, e.g. `"12345678 ZZ"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:08} {}", self.civil_number, self.version_str())
+    }
+}
+
+/// Specific reason a Cartão de Cidadão number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 12 characters.
+    InvalidLength,
+    /// Characters 1–9 or 12 are not all ASCII digits.
+    NonDigitCharacter,
+    /// Characters 10–11 are not ASCII letters or digits.
+    InvalidVersionChar,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "document number must be exactly 12 characters"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "characters 1-9 and 12 must be ASCII digits")
+            }
+            FormatKind::InvalidVersionChar => {
+                write!(f, "characters 10-11 must be ASCII letters or digits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a Cartão de Cidadão number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `PT_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `PT_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidVersionChar`] | `PT_FORMAT_VERSION_CHAR` |
+    /// | [`NidError::Checksum`] | `PT_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::portugal::validate("123456789ZZ2").unwrap_err();
+    /// assert_eq!(err.error_code(), "PT_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "PT_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "PT_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidVersionChar) => "PT_FORMAT_VERSION_CHAR",
+            NidError::Checksum => "PT_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// The value of a character in the check digit sum: `0`–`9` for digits,
+/// `10`–`35` for letters.
+const fn char_value(byte: u8) -> u32 {
+    if byte.is_ascii_digit() {
+        (byte - b'0') as u32
+    } else {
+        (byte - b'A') as u32 + 10
+    }
+}
+
+/// Decode a Portuguese Cartão de Cidadão document number.
+///
+/// The input must not contain spaces; use [`decode_with`] or [`normalize`]
+/// for the printed form. Version letters are treated case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or illegal
+/// characters, and [`NidError::Checksum`] if the final check digit does not
+/// match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::portugal::decode("123456789zz1").unwrap();
+/// assert_eq!(info.civil_number, 12_345_678);
+/// assert_eq!(info.version, *b"ZZ");
+/// ```
+#[must_use = "this returns the decoded document info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    if src.len() != 12 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut bytes = [0u8; 12];
+    let mut i = 0;
+    while i < 12 {
+        bytes[i] = src[i].to_ascii_uppercase();
+        let ok = if i == 9 || i == 10 {
+            bytes[i].is_ascii_alphanumeric()
+        } else {
+            bytes[i].is_ascii_digit()
+        };
+        if !ok {
+            return Err(NidError::Format(if i == 9 || i == 10 {
+                FormatKind::InvalidVersionChar
+            } else {
+                FormatKind::NonDigitCharacter
+            }));
+        }
+        i += 1;
+    }
+
+    let mut sum = 0u32;
+    let mut i = 0;
+    while i < 11 {
+        let mut value = char_value(bytes[10 - i]);
+        if i % 2 == 0 {
+            value *= 2;
+            if value > 9 {
+                value -= 9;
+            }
+        }
+        sum += value;
+        i += 1;
+    }
+    if (10 - sum % 10) % 10 != (bytes[11] - b'0') as u32 {
+        return Err(NidError::Checksum);
+    }
+
+    let mut civil_number = 0u32;
+    let mut i = 0;
+    while i < 8 {
+        civil_number = civil_number * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+    Ok(NidInfo {
+        civil_number,
+        version: [bytes[9], bytes[10]],
+    })
+}
+
+/// Decode a Portuguese Cartão de Cidadão number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::portugal::decode_with("12345678 9 ZZ1", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.civil_number, 12_345_678);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded Cartão de Cidadão number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Portuguese Cartão de Cidadão number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::portugal::validate("123456789ZZ1").is_ok());
+/// assert!(nidx::portugal::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Portuguese Cartão de Cidadão number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Portuguese Cartão de Cidadão number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::portugal::is_valid("123456789ZZ1"));
+/// assert!(!nidx::portugal::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a Cartão de Cidadão number: whitespace
+/// and hyphens removed and letters uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::portugal::normalize("00000000 0 zz4"), "000000000ZZ4");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Portuguese Cartão de Cidadão numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::portugal::Portugal;
+///
+/// let info = Portugal::decode("000000000ZZ4").unwrap();
+/// assert_eq!(info.version_str(), "ZZ");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Portugal;
+
+impl NationalId for Portugal {
+    const COUNTRY: Country = Country::Portugal;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Portugal {
+    type Components = NidInfo;
+
+    /// Build the document number, computing both the check digit of the
+    /// civil number and the final check digit.
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            civil_number,
+            version,
+        } = info;
+        check_range("civil_number", civil_number, 0..=99_999_999)?;
+        if !version
+            .iter()
+            .all(|b| b.is_ascii_digit() || b.is_ascii_uppercase())
+        {
+            return Err(EncodeError::OutOfRange { field: "version" });
+        }
+
+        let civil = format!("{civil_number:08}");
+        let sum: u32 = civil
+            .bytes()
+            .zip((2..=9).rev())
+            .map(|(b, weight)| u32::from(b - b'0') * weight)
+            .sum();
+        let civil_check = match 11 - sum % 11 {
+            10 | 11 => 0,
+            check => check,
+        };
+        let body = format!(
+            "{civil}{civil_check}{}{}",
+            char::from(version[0]),
+            char::from(version[1])
+        );
+        let sum: u32 = body
+            .bytes()
+            .rev()
+            .enumerate()
+            .map(|(i, b)| {
+                let value = char_value(b);
+                match (i % 2 == 0, value * 2) {
+                    (true, doubled) if doubled > 9 => doubled - 9,
+                    (true, doubled) => doubled,
+                    (false, _) => value,
+                }
+            })
+            .sum();
+        Ok(format!("{body}{}", (10 - sum % 10) % 10))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("000000000ZZ4"),
+            Ok(NidInfo {
+                civil_number: 0,
+                version: *b"ZZ",
+            })
+        );
+        assert_eq!(decode("123456789ZZ1").unwrap().civil_number, 12_345_678);
+        assert_eq!(decode("000000000AB7").unwrap().version_str(), "AB");
+    }
+
+    #[test]
+    fn decode_lowercase_version() {
+        assert_eq!(decode("000000000zz4"), decode("000000000ZZ4"));
+    }
+
+    #[test]
+    fn display_shows_number_and_version() {
+        assert_eq!(decode("000000000ZZ4").unwrap().to_string(), "00000000 ZZ");
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("00000000 0 ZZ4"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("0000A0000ZZ4"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("000000000Z-4"),
+            Err(NidError::Format(FormatKind::InvalidVersionChar))
+        );
+        assert_eq!(
+            decode("000000000ZZZ"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("000000000ZZ5"), Err(NidError::Checksum));
+        // A different version changes the final check digit.
+        assert_eq!(decode("000000000ZY4"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidVersionChar).to_string(),
+            "format error: characters 10-11 must be ASCII letters or digits"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "PT_CHECKSUM");
+    }
+}
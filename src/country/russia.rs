@@ -0,0 +1,66 @@
+//! Validate Russian identification numbers.
+//!
+//! Russia has no single national identification number. The internal
+//! passport number carries no check digit, so the identifier most often
+//! collected for individuals is the taxpayer number, covered by [`inn`].
+//! [`Country::Russia`] dispatches to the 12-digit individual form: the crate
+//! level [`decode`](crate::decode), [`detect`](crate::detect) and the
+//! [`Registry`](crate::Registry) accept individual INNs only, while
+//! [`inn::decode_any`] also accepts organisation numbers.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::russia::inn;
+//!
+//! assert!(inn::is_valid("500100732259"));
+//! assert!(!inn::is_valid("500100732258"));
+//! ```
+
+pub mod inn;
+
+use crate::{CharClass, Country, Metadata, NationalId, Run};
+
+/// Static facts about the Russian individual INN format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Russia,
+    name: "Russia",
+    alpha2: "RU",
+    alpha3: "RUS",
+    min_length: 12,
+    max_length: 12,
+    shapes: &[&[Run::exactly(CharClass::Digit, 12)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Zero-sized marker implementing [`NationalId`] for Russian individual
+/// taxpayer numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::russia::Russia;
+///
+/// assert_eq!(Russia::decode("500100732259").unwrap().tax_office, 5001);
+/// assert!(Russia::decode("7707083893").is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Russia;
+
+impl NationalId for Russia {
+    const COUNTRY: Country = Country::Russia;
+    type Info = inn::InnInfo;
+    type Error = inn::InnError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<inn::InnInfo, inn::InnError> {
+        inn::decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        inn::normalize(nid)
+    }
+}
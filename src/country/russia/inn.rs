@@ -0,0 +1,385 @@
+//! Validate Russian taxpayer identification numbers (ИНН, INN).
+//!
+//! The Federal Tax Service assigns a 12-digit INN to every individual
+//! taxpayer and a 10-digit INN to every organisation. Both forms start with
+//! the four-digit code of the tax office that issued the number, followed by
+//! a serial and the check digits. Neither form encodes personal data.
+//!
+//! # Format
+//!
+//! Individuals: `[tax office (4)][serial (6)][check (2)]`
+//!
+//! Organisations: `[tax office (4)][serial (5)][check]`
+//!
+//! - **Check digits**: each is a weighted sum of the preceding digits, mod 11
+//!   and then mod 10. The 11th digit of an individual INN uses the weights
+//!   `7 2 4 10 3 5 9 4 6 8`, the 12th `3 7 2 4 10 3 5 9 4 6 8`. The check
+//!   digit of an organisation INN uses `2 4 10 3 5 9 4 6 8`.
+//!
+//! [`decode`] only accepts individual numbers; [`decode_any`] also accepts
+//! organisation numbers and reports which form was found in
+//! [`InnInfo::kind`].
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::russia::inn::{self, InnKind};
+//!
+//! let info = inn::decode("500100732259").unwrap();
+//! assert_eq!(info.kind, InnKind::Individual);
+//! assert_eq!(info.tax_office, 5001);
+//!
+//! // Organisation numbers are only accepted by `decode_any`.
+//! assert!(!inn::is_valid("7707083893"));
+//! assert_eq!(inn::decode_any("7707083893").unwrap().kind, InnKind::Organization);
+//! ```
+
+use std::fmt;
+
+const WEIGHTS_ORGANIZATION: [u16; 9] = [2, 4, 10, 3, 5, 9, 4, 6, 8];
+const WEIGHTS_FIRST: [u16; 10] = [7, 2, 4, 10, 3, 5, 9, 4, 6, 8];
+const WEIGHTS_SECOND: [u16; 11] = [3, 7, 2, 4, 10, 3, 5, 9, 4, 6, 8];
+
+/// The kind of taxpayer an INN was issued to, given by its length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum InnKind {
+    /// A 12-digit INN issued to a natural person.
+    Individual,
+    /// A 10-digit INN issued to a legal entity.
+    Organization,
+}
+
+/// Decoded information from a valid INN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct InnInfo {
+    /// Whether the INN belongs to an individual or an organisation.
+    pub kind: InnKind,
+    /// The code of the issuing tax office (first four digits). The first two
+    /// digits are the code of the federal subject.
+    pub tax_office: u16,
+}
+
+/// Specific reason an INN was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 12 digits (or 10 digits, where organisation numbers are
+    /// accepted).
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "INN must be 12 digits, or 10 for organisations")
+            }
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating an INN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum InnError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// A check digit does not match the computed value.
+    Checksum,
+}
+
+impl InnError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `RU_INN_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `RU_INN_FORMAT_NON_DIGIT` |
+    /// | [`InnError::Checksum`] | `RU_INN_CHECKSUM` |
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            InnError::Format(FormatKind::InvalidLength) => "RU_INN_FORMAT_LENGTH",
+            InnError::Format(FormatKind::NonDigitCharacter) => "RU_INN_FORMAT_NON_DIGIT",
+            InnError::Checksum => "RU_INN_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for InnError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InnError::Format(kind) => write!(f, "format error: {kind}"),
+            InnError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for InnError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            InnError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Compute the check digit over `bytes[..weights.len()]`.
+const fn check_digit(bytes: &[u8], weights: &[u16]) -> u8 {
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < weights.len() {
+        sum += (bytes[i] - b'0') as u16 * weights[i];
+        i += 1;
+    }
+    (sum % 11 % 10) as u8
+}
+
+/// Decode an INN of either kind; `accept_organization` enables the 10-digit form.
+const fn decode_inner(inn: &str, accept_organization: bool) -> Result<InnInfo, InnError> {
+    let bytes = inn.as_bytes();
+    let kind = match bytes.len() {
+        12 => InnKind::Individual,
+        10 if accept_organization => InnKind::Organization,
+        _ => return Err(InnError::Format(FormatKind::InvalidLength)),
+    };
+    let mut i = 0;
+    while i < bytes.len() {
+        if !bytes[i].is_ascii_digit() {
+            return Err(InnError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+
+    let valid = match kind {
+        InnKind::Individual => {
+            check_digit(bytes, &WEIGHTS_FIRST) == bytes[10] - b'0'
+                && check_digit(bytes, &WEIGHTS_SECOND) == bytes[11] - b'0'
+        }
+        InnKind::Organization => check_digit(bytes, &WEIGHTS_ORGANIZATION) == bytes[9] - b'0',
+    };
+    if !valid {
+        return Err(InnError::Checksum);
+    }
+
+    let mut tax_office = 0;
+    let mut i = 0;
+    while i < 4 {
+        tax_office = tax_office * 10 + (bytes[i] - b'0') as u16;
+        i += 1;
+    }
+    Ok(InnInfo { kind, tax_office })
+}
+
+/// Decode a 12-digit individual INN.
+///
+/// # Errors
+///
+/// Returns [`InnError::Format`] if the input is not 12 ASCII digits, and
+/// [`InnError::Checksum`] if either check digit does not match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::russia::inn::decode("773370857141").unwrap();
+/// assert_eq!(info.tax_office, 7733);
+/// ```
+#[must_use = "this returns the decoded INN info; use `is_valid` if you only need a bool"]
+pub const fn decode(inn: &str) -> Result<InnInfo, InnError> {
+    decode_inner(inn, false)
+}
+
+/// Decode an INN, accepting both the 12-digit individual and the 10-digit
+/// organisation form.
+///
+/// # Errors
+///
+/// Returns [`InnError::Format`] if the input is not 10 or 12 ASCII digits,
+/// and [`InnError::Checksum`] if a check digit does not match.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::russia::inn::{self, InnKind};
+///
+/// assert_eq!(inn::decode_any("7830002293").unwrap().kind, InnKind::Organization);
+/// assert_eq!(inn::decode_any("500100732259").unwrap().kind, InnKind::Individual);
+/// ```
+#[must_use = "this returns the decoded INN info; use `is_valid_any` if you only need a bool"]
+pub const fn decode_any(inn: &str) -> Result<InnInfo, InnError> {
+    decode_inner(inn, true)
+}
+
+/// Validate a 12-digit individual INN, returning an [`InnError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::russia::inn::validate("500100732259").is_ok());
+/// assert!(nidx::russia::inn::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(inn: &str) -> Result<(), InnError> {
+    match decode(inn) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate an individual or organisation INN, returning an [`InnError`] on
+/// failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode_any`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::russia::inn::validate_any("7707083893").is_ok());
+/// assert!(nidx::russia::inn::validate_any("7707083894").is_err());
+/// ```
+#[inline]
+pub const fn validate_any(inn: &str) -> Result<(), InnError> {
+    match decode_any(inn) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Check whether a 12-digit individual INN is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::russia::inn::is_valid("500100732259"));
+/// assert!(!nidx::russia::inn::is_valid("500100732258"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(inn: &str) -> bool {
+    validate(inn).is_ok()
+}
+
+/// Check whether an individual or organisation INN is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::russia::inn::is_valid_any("7707083893"));
+/// assert!(nidx::russia::inn::is_valid_any("500100732259"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid_any(inn: &str) -> bool {
+    validate_any(inn).is_ok()
+}
+
+/// Return the canonical form of an INN: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::russia::inn::normalize(" 5001 007322 59\n"), "500100732259");
+/// ```
+#[must_use]
+pub fn normalize(inn: &str) -> String {
+    inn.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("500100732259"),
+            Ok(InnInfo {
+                kind: InnKind::Individual,
+                tax_office: 5001
+            })
+        );
+        assert!(decode("773370857141").is_ok());
+        assert!(decode("123456789047").is_ok());
+    }
+
+    #[test]
+    fn decode_any_organization() {
+        assert_eq!(
+            decode_any("7707083893"),
+            Ok(InnInfo {
+                kind: InnKind::Organization,
+                tax_office: 7707
+            })
+        );
+        assert_eq!(decode_any("500100732259"), decode("500100732259"));
+    }
+
+    #[test]
+    fn decode_rejects_organization() {
+        assert_eq!(
+            decode("7707083893"),
+            Err(InnError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode_any("50010073225"),
+            Err(InnError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("50010073225X"),
+            Err(InnError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode_any("770708389X"),
+            Err(InnError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        // First check digit wrong.
+        assert_eq!(decode("500100732169"), Err(InnError::Checksum));
+        // Second check digit wrong.
+        assert_eq!(decode("500100732258"), Err(InnError::Checksum));
+        assert_eq!(decode_any("7707083894"), Err(InnError::Checksum));
+    }
+
+    #[test]
+    fn normalize_strips_whitespace() {
+        assert_eq!(normalize("7707 083 893"), "7707083893");
+        assert!(is_valid(&normalize(" 500100732259\n")));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            InnError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: INN must be 12 digits, or 10 for organisations"
+        );
+        assert_eq!(InnError::Checksum.error_code(), "RU_INN_CHECKSUM");
+        assert_eq!(
+            InnError::Format(FormatKind::NonDigitCharacter).error_code(),
+            "RU_INN_FORMAT_NON_DIGIT"
+        );
+    }
+}
@@ -0,0 +1,524 @@
+//! Validate and decode Serbian unique master citizen numbers (JMBG).
+//!
+//! The JMBG (Jedinstveni matični broj građana) is a 13-digit number inherited
+//! from the former Yugoslavia that encodes date of birth, the political region
+//! of birth registration, sex and a check digit.
+//!
+//! # Format
+//!
+//! `[day (2)][month (2)][year (3)][region (2)][serial (3)][check]`
+//!
+//! - **Year**: the last three digits of the birth year; `800`–`999` maps to
+//!   1800–1999 and `000`–`799` to 2000–2799.
+//! - **Region**: the political region where the birth was registered:
+//!   `70`–`79` for central Serbia, `80`–`89` for Vojvodina, `90`–`99` for
+//!   Kosovo, or `00`–`09` for foreigners. Codes of the other former Yugoslav
+//!   republics are rejected; [`jmbg::Area`] maps a code to its area.
+//! - **Serial**: `000`–`499` for males, `500`–`999` for females.
+//! - **Check digit**: `11 - (weighted sum mod 11)` with weights
+//!   `7 6 5 4 3 2 7 6 5 4 3 2`, where `10` and `11` map to `0`.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::serbia::decode("0101990710008").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1990-01-01");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert_eq!(info.region, 71);
+//!
+//! assert!(nidx::serbia::is_valid("0101990710008"));
+//! assert!(!nidx::serbia::is_valid("0101990710009"));
+//! ```
+
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use super::jmbg::{self, JmbgError};
+use crate::{
+    CharClass, Country, Date, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Sex,
+    Summary,
+};
+
+/// Static facts about the Serbian JMBG format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Serbia,
+    name: "Serbia",
+    alpha2: "RS",
+    alpha3: "SRB",
+    min_length: 13,
+    max_length: 13,
+    shapes: &[&[Run::exactly(CharClass::Digit, 13)]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+/// Region codes accepted in a Serbian JMBG: foreigners and Serbia's own areas.
+const REGIONS: &[RangeInclusive<u8>] = &[0..=9, 70..=99];
+
+/// Decoded information from a valid JMBG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// The two-digit political region code (digits 8–9) where the birth was
+    /// registered; see [`jmbg::Area`].
+    pub region: u8,
+    /// The three-digit serial (digits 10–12) distinguishing people born on the
+    /// same day in the same region.
+    pub serial: u16,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// The JMBG does not encode citizenship, so `is_national` is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::serbia::decode("0101990710008").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1990-01-01 M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1990-01-01 M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a JMBG was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 13 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The region code is not a Serbian region (`70`–`99`) or a foreigner
+    /// code (`00`–`09`).
+    InvalidRegion {
+        /// The two-digit region code that was found.
+        code: u8,
+    },
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "JMBG must be exactly 13 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::InvalidRegion { code } => {
+                write!(f, "region code {code} is not a Serbian region")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason a JMBG was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding a JMBG.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `RS_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `RS_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidRegion`] | `RS_FORMAT_REGION` |
+    /// | [`NidError::Checksum`] | `RS_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `RS_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `RS_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::serbia::validate("0101990710009").unwrap_err();
+    /// assert_eq!(err.error_code(), "RS_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "RS_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "RS_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidRegion { .. }) => "RS_FORMAT_REGION",
+            NidError::Checksum => "RS_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "RS_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "RS_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+impl NidError {
+    /// Map a rejection from the shared JMBG core onto this module's errors.
+    const fn from_core(err: JmbgError) -> NidError {
+        match err {
+            JmbgError::InvalidLength => NidError::Format(FormatKind::InvalidLength),
+            JmbgError::NonDigitCharacter => NidError::Format(FormatKind::NonDigitCharacter),
+            JmbgError::Checksum => NidError::Checksum,
+            JmbgError::InvalidRegion { code } => {
+                NidError::Format(FormatKind::InvalidRegion { code })
+            }
+            JmbgError::MonthOutOfRange { month } => {
+                NidError::InvalidDate(DateKind::MonthOutOfRange { month })
+            }
+            JmbgError::DayOutOfRange { year, month, day } => {
+                NidError::InvalidDate(DateKind::DayOutOfRange { year, month, day })
+            }
+        }
+    }
+}
+
+/// Decode a Serbian JMBG string.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters or a region outside Serbia, [`NidError::Checksum`] if the check
+/// digit does not match, and [`NidError::InvalidDate`] if the encoded date is
+/// not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::serbia::decode("2902000805506").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2000-02-29");
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// assert_eq!(info.region, 80);
+/// assert_eq!(info.serial, 550);
+/// ```
+#[must_use = "this returns the decoded JMBG info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    match jmbg::parse(nid.as_bytes(), REGIONS) {
+        Ok(parts) => Ok(NidInfo {
+            birthday: parts.birthday,
+            sex: parts.sex,
+            region: parts.region,
+            serial: parts.serial,
+        }),
+        Err(err) => Err(NidError::from_core(err)),
+    }
+}
+
+/// Decode a Serbian JMBG string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::serbia::decode_with("010199 071 0008", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded JMBG info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Serbian JMBG string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::serbia::validate("0101990710008").is_ok());
+/// assert!(nidx::serbia::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Serbian JMBG string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Serbian JMBG string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::serbia::is_valid("0101990710008"));
+/// assert!(!nidx::serbia::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a JMBG: surrounding whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::serbia::normalize(" 0101990710008\n"), "0101990710008");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.trim().to_owned()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Serbian JMBGs.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::serbia::Serbia;
+///
+/// let info = Serbia::decode("0101990710008").unwrap();
+/// assert_eq!(info.region, 71);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Serbia;
+
+impl NationalId for Serbia {
+    const COUNTRY: Country = Country::Serbia;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Serbia {
+    type Components = NidInfo;
+
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        jmbg::encode(
+            jmbg::Parts {
+                birthday: info.birthday,
+                sex: info.sex,
+                region: info.region,
+                serial: info.serial,
+            },
+            REGIONS,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const VALID_NID: &str = "0101990710008";
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode(VALID_NID),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1990,
+                    month: 1,
+                    day: 1
+                },
+                sex: Sex::Male,
+                region: 71,
+                serial: 0,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_century_and_sex() {
+        let info = decode("1505002951230").unwrap();
+        assert_eq!(info.birthday.to_string(), "2002-05-15");
+        assert_eq!(info.sex, Sex::Male);
+        assert_eq!(info.region, 95);
+
+        let info = decode("2902000805506").unwrap();
+        assert_eq!(info.birthday.to_string(), "2000-02-29");
+        assert_eq!(info.sex, Sex::Female);
+    }
+
+    #[test]
+    fn regions_limited_to_serbia_and_foreigners() {
+        assert_eq!(decode("1505002061234").unwrap().region, 6);
+        let err = decode("1505002451237").unwrap_err();
+        assert_eq!(
+            err,
+            NidError::Format(FormatKind::InvalidRegion { code: 45 })
+        );
+        assert_eq!(err.error_code(), "RS_FORMAT_REGION");
+    }
+
+    #[test]
+    fn check_digit_10_or_11_maps_to_zero() {
+        assert!(is_valid("3112999719990"));
+    }
+
+    #[test]
+    fn error_wrong_length() {
+        assert_eq!(
+            decode("010199071000"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("01019907100080"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+    }
+
+    #[test]
+    fn error_non_digit() {
+        assert_eq!(
+            decode("010199071000X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("0101990710009"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        // Valid checksums, impossible dates.
+        assert_eq!(
+            decode("0113990710006"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            decode("2902001805500"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: JMBG must be exactly 13 digits"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "RS_CHECKSUM");
+        assert_eq!(
+            NidError::InvalidDate(DateKind::MonthOutOfRange { month: 13 }).error_code(),
+            "RS_DATE_MONTH"
+        );
+    }
+
+    #[test]
+    fn info_display() {
+        assert_eq!(decode(VALID_NID).unwrap().to_string(), "1990-01-01 M");
+    }
+}
@@ -0,0 +1,550 @@
+//! Validate and decode South Korean resident registration numbers (RRN,
+//! 주민등록번호).
+//!
+//! The RRN is a 13-digit number, usually written with a hyphen after the sixth
+//! digit (e.g. `900101-1234568`); both forms are accepted. It encodes date of
+//! birth, sex, century and whether the holder is a foreign resident.
+//!
+//! # Format
+//!
+//! `[year (2)][month (2)][day (2)][sex/century][tail (5)][check]`
+//!
+//! - **Sex/century digit**: `9`/`0` for men/women born 1800–1899, `1`/`2` for
+//!   1900–1999 and `3`/`4` for 2000–2099; `5`/`6` and `7`/`8` are the same for
+//!   foreign residents born 1900–1999 and 2000–2099.
+//! - **Check digit**: the first 12 digits weighted by
+//!   `2 3 4 5 6 7 8 9 2 3 4 5`; `(11 - sum mod 11) mod 10`.
+//!
+//! Numbers issued since October 2020 have a random tail whose last digit is
+//! not a check digit. [`decode`] enforces the check digit; use
+//! [`decode_with_policy`] with [`CheckDigitPolicy::Ignore`] to accept them.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::south_korea::decode("900101-1234568").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1990-01-01");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//! assert!(info.is_national);
+//!
+//! assert!(nidx::south_korea::is_valid("0002294234566"));
+//! assert!(!nidx::south_korea::is_valid("900101-1234567"));
+//! ```
+
+use std::fmt;
+
+use crate::date::validate_date;
+use crate::{CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Sex, Summary};
+
+/// Static facts about the South Korean RRN format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::SouthKorea,
+    name: "South Korea",
+    alpha2: "KR",
+    alpha3: "KOR",
+    min_length: 13,
+    max_length: 14,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 6),
+        Run::optional(CharClass::Exact(b'-')),
+        Run::exactly(CharClass::Digit, 7),
+    ]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+const WEIGHTS: [u16; 12] = [2, 3, 4, 5, 6, 7, 8, 9, 2, 3, 4, 5];
+
+/// How [`decode_with_policy`] treats the last digit.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub enum CheckDigitPolicy {
+    /// Reject numbers whose last digit does not match the check digit.
+    #[default]
+    Enforce,
+    /// Accept any last digit, as numbers issued since October 2020 require.
+    /// [`NidInfo::check_digit_matches`] still reports the outcome.
+    Ignore,
+}
+
+/// Decoded information from a valid RRN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+    /// `true` for citizens, `false` for foreign residents (sex/century digit
+    /// `5`–`8`).
+    pub is_national: bool,
+    /// Whether the last digit matches the weighted check digit. Always `true`
+    /// under [`CheckDigitPolicy::Enforce`].
+    pub check_digit_matches: bool,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::south_korea::decode("850315-5123458").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1985-03-15 M foreign");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: Some(self.is_national),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex> <national|foreign>"`, e.g.
+    /// `"1990-01-01 M national"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason an RRN was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 13 digits, with an optional hyphen after the sixth.
+    InvalidLength,
+    /// A character other than the hyphen is not an ASCII digit.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "RRN must be exactly 13 digits"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the hyphen must be ASCII digits")
+            }
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Specific reason an RRN was rejected due to an invalid date.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum DateKind {
+    /// The encoded month is outside 1–12.
+    MonthOutOfRange {
+        /// The encoded month value.
+        month: u8,
+    },
+    /// The encoded day is outside the valid range for the given year and month.
+    DayOutOfRange {
+        /// The decoded year.
+        year: u16,
+        /// The encoded month.
+        month: u8,
+        /// The encoded day.
+        day: u8,
+    },
+}
+
+impl fmt::Display for DateKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateKind::MonthOutOfRange { month } => write!(f, "month {month} out of range"),
+            DateKind::DayOutOfRange { year, month, day } => {
+                write!(f, "day {day} is out of range for {year}-{month:02}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DateKind {}
+
+/// Errors that can occur when decoding an RRN.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+    /// The encoded date is not a valid calendar date.
+    InvalidDate(DateKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `KR_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `KR_FORMAT_NON_DIGIT` |
+    /// | [`NidError::Checksum`] | `KR_CHECKSUM` |
+    /// | [`DateKind::MonthOutOfRange`] | `KR_DATE_MONTH` |
+    /// | [`DateKind::DayOutOfRange`] | `KR_DATE_DAY` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::south_korea::validate("9001011234567").unwrap_err();
+    /// assert_eq!(err.error_code(), "KR_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "KR_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "KR_FORMAT_NON_DIGIT",
+            NidError::Checksum => "KR_CHECKSUM",
+            NidError::InvalidDate(DateKind::MonthOutOfRange { .. }) => "KR_DATE_MONTH",
+            NidError::InvalidDate(DateKind::DayOutOfRange { .. }) => "KR_DATE_DAY",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+            NidError::InvalidDate(kind) => write!(f, "invalid date: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            NidError::InvalidDate(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Decode a South Korean RRN, with or without the hyphen, enforcing the
+/// check digit.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters, [`NidError::Checksum`] if the check digit does not match, and
+/// [`NidError::InvalidDate`] if the encoded date is not a valid calendar date.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::south_korea::decode("000229-4234566").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2000-02-29");
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded RRN info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    decode_with_policy(nid, CheckDigitPolicy::Enforce)
+}
+
+/// Decode a South Korean RRN, treating the last digit according to
+/// `policy`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], except that
+/// [`NidError::Checksum`] is never returned under
+/// [`CheckDigitPolicy::Ignore`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::south_korea::{self, CheckDigitPolicy};
+///
+/// // A post-2020 number with a random last digit.
+/// assert!(south_korea::decode("900101-1234567").is_err());
+/// let info = south_korea::decode_with_policy("900101-1234567", CheckDigitPolicy::Ignore).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// assert!(!info.check_digit_matches);
+/// ```
+#[must_use = "this returns the decoded RRN info"]
+pub const fn decode_with_policy(nid: &str, policy: CheckDigitPolicy) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let hyphen = src.len() > 6 && src[6] == b'-';
+    if src.len() != if hyphen { 14 } else { 13 } {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut digits = [0u8; 13];
+    let mut n = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if !(hyphen && i == 6) {
+            if !src[i].is_ascii_digit() {
+                return Err(NidError::Format(FormatKind::NonDigitCharacter));
+            }
+            digits[n] = src[i] - b'0';
+            n += 1;
+        }
+        i += 1;
+    }
+
+    let mut sum: u16 = 0;
+    let mut i = 0;
+    while i < 12 {
+        sum += digits[i] as u16 * WEIGHTS[i];
+        i += 1;
+    }
+    let check_digit_matches = (11 - sum % 11) % 10 == digits[12] as u16;
+    if !check_digit_matches && matches!(policy, CheckDigitPolicy::Enforce) {
+        return Err(NidError::Checksum);
+    }
+
+    let (century, sex, is_national) = match digits[6] {
+        9 => (1800, Sex::Male, true),
+        0 => (1800, Sex::Female, true),
+        1 => (1900, Sex::Male, true),
+        2 => (1900, Sex::Female, true),
+        3 => (2000, Sex::Male, true),
+        4 => (2000, Sex::Female, true),
+        5 => (1900, Sex::Male, false),
+        6 => (1900, Sex::Female, false),
+        7 => (2000, Sex::Male, false),
+        _ => (2000, Sex::Female, false),
+    };
+    let year = century + (digits[0] * 10 + digits[1]) as u16;
+    let month = digits[2] * 10 + digits[3];
+    let day = digits[4] * 10 + digits[5];
+    let Some(birthday) = validate_date(year, month, day) else {
+        return Err(NidError::InvalidDate(if month < 1 || month > 12 {
+            DateKind::MonthOutOfRange { month }
+        } else {
+            DateKind::DayOutOfRange { year, month, day }
+        }));
+    };
+
+    Ok(NidInfo {
+        birthday,
+        sex,
+        is_national,
+        check_digit_matches,
+    })
+}
+
+/// Decode a South Korean RRN string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::south_korea::decode_with(" 900101-1234568\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded RRN info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a South Korean RRN string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::south_korea::validate("9001011234568").is_ok());
+/// assert!(nidx::south_korea::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a South Korean RRN string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a South Korean RRN string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::south_korea::is_valid("9001011234568"));
+/// assert!(!nidx::south_korea::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an RRN: whitespace and the hyphen removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::south_korea::normalize(" 900101-1234568\n"), "9001011234568");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for South Korean resident registration numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::south_korea::SouthKorea;
+///
+/// let info = SouthKorea::decode("900101-1234568").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct SouthKorea;
+
+impl NationalId for SouthKorea {
+    const COUNTRY: Country = Country::SouthKorea;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("900101-1234568"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1990,
+                    month: 1,
+                    day: 1
+                },
+                sex: Sex::Male,
+                is_national: true,
+                check_digit_matches: true,
+            })
+        );
+        assert_eq!(decode("9001011234568"), decode("900101-1234568"));
+    }
+
+    #[test]
+    fn decode_sex_century_digit() {
+        let cases = [
+            ("991231-9123451", "1899-12-31", Sex::Male, true),
+            ("000101-0123452", "1800-01-01", Sex::Female, true),
+            ("900101-2234561", "1990-01-01", Sex::Female, true),
+            ("000229-3234563", "2000-02-29", Sex::Male, true),
+            ("000229-4234566", "2000-02-29", Sex::Female, true),
+            ("850315-5123458", "1985-03-15", Sex::Male, false),
+            ("030715-8123458", "2003-07-15", Sex::Female, false),
+        ];
+        for (nid, birthday, sex, is_national) in cases {
+            let info = decode(nid).unwrap();
+            assert_eq!(info.birthday.to_string(), birthday, "{nid}");
+            assert_eq!(info.sex, sex, "{nid}");
+            assert_eq!(info.is_national, is_national, "{nid}");
+        }
+    }
+
+    #[test]
+    fn check_digit_policy() {
+        assert_eq!(decode("900101-1234567"), Err(NidError::Checksum));
+        let info = decode_with_policy("900101-1234567", CheckDigitPolicy::Ignore).unwrap();
+        assert!(!info.check_digit_matches);
+        assert_eq!(info.sex, Sex::Male);
+        let info = decode_with_policy("900101-1234568", CheckDigitPolicy::Ignore).unwrap();
+        assert!(info.check_digit_matches);
+        assert_eq!(CheckDigitPolicy::default(), CheckDigitPolicy::Enforce);
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("900101-123456"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("90010-11234568"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("900101-123456X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_invalid_date() {
+        assert_eq!(
+            decode("901301-1234565"),
+            Err(NidError::InvalidDate(DateKind::MonthOutOfRange {
+                month: 13
+            }))
+        );
+        assert_eq!(
+            decode("010229-3123451"),
+            Err(NidError::InvalidDate(DateKind::DayOutOfRange {
+                year: 2001,
+                month: 2,
+                day: 29
+            }))
+        );
+        // Dates are still checked when the check digit is ignored.
+        assert!(decode_with_policy("901301-1234560", CheckDigitPolicy::Ignore).is_err());
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: RRN must be exactly 13 digits"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "KR_CHECKSUM");
+    }
+}
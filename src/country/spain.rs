@@ -0,0 +1,444 @@
+//! Validate Spanish national identity (DNI) and foreigner identity (NIE)
+//! numbers.
+//!
+//! Both documents are 9 characters long and end in a control letter. The DNI,
+//! issued to Spanish nationals, is 8 digits followed by the letter. The NIE,
+//! issued to foreign residents, replaces the first digit with `X`, `Y` or `Z`
+//! (standing for `0`, `1` and `2`). [`NidInfo::kind`] reports which document
+//! matched.
+//!
+//! # Format
+//!
+//! `[digits (8)][letter]` or `[X|Y|Z][digits (7)][letter]`
+//!
+//! - **Control letter**: the 8-digit number (with the NIE prefix replaced by
+//!   its digit) mod 23, looked up in `TRWAGMYFPDXBNJZSQVHLCKE`.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::spain::DocumentKind;
+//!
+//! assert_eq!(nidx::spain::decode("12345678Z").unwrap().kind, DocumentKind::Dni);
+//! assert_eq!(nidx::spain::decode("X1234567L").unwrap().kind, DocumentKind::Nie);
+//!
+//! assert!(!nidx::spain::is_valid("12345678A"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run, Summary};
+
+/// Static facts about the Spanish DNI/NIE format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Spain,
+    name: "Spain",
+    alpha2: "ES",
+    alpha3: "ESP",
+    min_length: 9,
+    max_length: 9,
+    shapes: &[&[
+        Run::one(CharClass::Alphanumeric),
+        Run::exactly(CharClass::Digit, 7),
+        Run::one(CharClass::Letter),
+    ]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Control letters, indexed by the number mod 23.
+const CONTROL_CHARS: &[u8; 23] = b"TRWAGMYFPDXBNJZSQVHLCKE";
+
+/// The kind of document a number belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DocumentKind {
+    /// Documento Nacional de Identidad, issued to Spanish nationals.
+    Dni,
+    /// Número de Identidad de Extranjero, issued to foreign residents.
+    Nie,
+}
+
+impl fmt::Display for DocumentKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DocumentKind::Dni => write!(f, "DNI"),
+            DocumentKind::Nie => write!(f, "NIE"),
+        }
+    }
+}
+
+/// Decoded information from a valid DNI or NIE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Which document the number belongs to.
+    pub kind: DocumentKind,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// DNIs are only issued to nationals and NIEs only to foreigners, so
+    /// `is_national` follows from the document kind.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::spain::decode("X1234567L").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(matches!(self.kind, DocumentKind::Dni)),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the document kind, e.g. `"DNI"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.kind, f)
+    }
+}
+
+/// Specific reason a DNI or NIE was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 9 characters.
+    InvalidLength,
+    /// The number part contains a character that is not an ASCII digit (or, in first position, `X`, `Y` or `Z`).
+    NonDigitCharacter,
+    /// The 9th character is not a control letter.
+    InvalidCheckChar,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "DNI/NIE must be exactly 9 characters"),
+            FormatKind::NonDigitCharacter => write!(
+                f,
+                "characters 1-8 must be digits, with an optional X/Y/Z prefix"
+            ),
+            FormatKind::InvalidCheckChar => write!(f, "invalid control letter"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a DNI or NIE.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters).
+    Format(FormatKind),
+    /// The control letter does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `ES_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `ES_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCheckChar`] | `ES_FORMAT_CHECK_CHAR` |
+    /// | [`NidError::Checksum`] | `ES_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::spain::validate("12345678T").unwrap_err();
+    /// assert_eq!(err.error_code(), "ES_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "ES_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "ES_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidCheckChar) => "ES_FORMAT_CHECK_CHAR",
+            NidError::Checksum => "ES_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Decode a Spanish DNI or NIE string.
+///
+/// The input is treated case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or illegal
+/// characters, and [`NidError::Checksum`] if the control letter does not
+/// match.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::spain::DocumentKind;
+///
+/// let info = nidx::spain::decode("y1234567x").unwrap();
+/// assert_eq!(info.kind, DocumentKind::Nie);
+/// ```
+#[must_use = "this returns the decoded DNI/NIE info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 9 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+
+    let (kind, mut number) = match bytes[0].to_ascii_uppercase() {
+        b'X' => (DocumentKind::Nie, 0u32),
+        b'Y' => (DocumentKind::Nie, 1),
+        b'Z' => (DocumentKind::Nie, 2),
+        b @ b'0'..=b'9' => (DocumentKind::Dni, (b - b'0') as u32),
+        _ => return Err(NidError::Format(FormatKind::NonDigitCharacter)),
+    };
+    let mut i = 1;
+    while i < 8 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        number = number * 10 + (bytes[i] - b'0') as u32;
+        i += 1;
+    }
+
+    let check = bytes[8].to_ascii_uppercase();
+    let mut known = false;
+    let mut i = 0;
+    while i < CONTROL_CHARS.len() {
+        if CONTROL_CHARS[i] == check {
+            known = true;
+        }
+        i += 1;
+    }
+    if !known {
+        return Err(NidError::Format(FormatKind::InvalidCheckChar));
+    }
+    if CONTROL_CHARS[(number % 23) as usize] != check {
+        return Err(NidError::Checksum);
+    }
+
+    Ok(NidInfo { kind })
+}
+
+/// Decode a Spanish DNI or NIE string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::spain::decode_with("12345678-z", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.kind, nidx::spain::DocumentKind::Dni);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded DNI/NIE info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Spanish DNI or NIE string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::spain::validate("12345678Z").is_ok());
+/// assert!(nidx::spain::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Spanish DNI or NIE string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Spanish DNI or NIE string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::spain::is_valid("12345678Z"));
+/// assert!(!nidx::spain::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a DNI or NIE: whitespace and hyphens
+/// removed and letters uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::spain::normalize(" x-1234567-l"), "X1234567L");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Spanish DNIs and NIEs.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::spain::{DocumentKind, Spain};
+///
+/// let info = Spain::decode("12345678Z").unwrap();
+/// assert_eq!(info.kind, DocumentKind::Dni);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Spain;
+
+impl NationalId for Spain {
+    const COUNTRY: Country = Country::Spain;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_dni() {
+        assert_eq!(
+            decode("12345678Z"),
+            Ok(NidInfo {
+                kind: DocumentKind::Dni
+            })
+        );
+        assert_eq!(decode("00000000T").unwrap().kind, DocumentKind::Dni);
+    }
+
+    #[test]
+    fn decode_nie_prefixes() {
+        assert_eq!(decode("X1234567L").unwrap().kind, DocumentKind::Nie);
+        assert_eq!(decode("Y1234567X").unwrap().kind, DocumentKind::Nie);
+        assert_eq!(decode("Z1234567R").unwrap().kind, DocumentKind::Nie);
+    }
+
+    #[test]
+    fn decode_lowercase_input() {
+        assert_eq!(decode("x1234567l"), decode("X1234567L"));
+    }
+
+    #[test]
+    fn summary_reflects_document_kind() {
+        assert_eq!(
+            decode("12345678Z").unwrap().summary().is_national,
+            Some(true)
+        );
+        assert_eq!(
+            decode("X1234567L").unwrap().summary().is_national,
+            Some(false)
+        );
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("1234567Z"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("A1234567L"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("X12345X7L"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("12345678I"),
+            Err(NidError::Format(FormatKind::InvalidCheckChar))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("12345678T"), Err(NidError::Checksum));
+        // Same digits, but the NIE prefix changes the control letter.
+        assert_eq!(decode("X1234567X"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidCheckChar).to_string(),
+            "format error: invalid control letter"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "ES_CHECKSUM");
+    }
+}
@@ -0,0 +1,376 @@
+//! Validate Swiss social security numbers (AHV/AVS number).
+//!
+//! The AHV number (Alters- und Hinterlassenenversicherung, AVS in French and
+//! Italian) is a 13-digit number starting with the country code `756`. It is
+//! usually written with dots, e.g. `756.9217.0769.85`; both the dotted and the
+//! plain form are accepted. Since 2008 the number is random and carries no
+//! personal data.
+//!
+//! # Format
+//!
+//! `756.[digits (4)].[digits (4)].[digits (1)][check]`
+//!
+//! - **Prefix**: always `756`, the ISO 3166-1 numeric code of Switzerland.
+//! - **Check digit**: the EAN-13 check digit, weighting the first 12 digits
+//!   alternately by 1 and 3.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::switzerland::is_valid("756.9217.0769.85"));
+//! assert!(nidx::switzerland::is_valid("7569217076985"));
+//! assert!(!nidx::switzerland::is_valid("756.9217.0769.86"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run};
+
+/// Static facts about the Swiss AHV number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Switzerland,
+    name: "Switzerland",
+    alpha2: "CH",
+    alpha3: "CHE",
+    min_length: 13,
+    max_length: 16,
+    shapes: &[
+        &[Run::exactly(CharClass::Digit, 13)],
+        &[
+            Run::exactly(CharClass::Digit, 3),
+            Run::one(CharClass::Exact(b'.')),
+            Run::exactly(CharClass::Digit, 4),
+            Run::one(CharClass::Exact(b'.')),
+            Run::exactly(CharClass::Digit, 4),
+            Run::one(CharClass::Exact(b'.')),
+            Run::exactly(CharClass::Digit, 2),
+        ],
+    ],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Positions of the dots in the dotted form.
+const DOT_POSITIONS: [usize; 3] = [3, 8, 13];
+
+/// Specific reason an AHV number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is neither 13 digits nor 16 characters in dotted form.
+    InvalidLength,
+    /// A character other than a separator dot is not an ASCII digit.
+    NonDigitCharacter,
+    /// The dotted form has a character other than `.` at a separator position.
+    InvalidSeparator,
+    /// The number does not start with `756`.
+    InvalidPrefix,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "AHV number must be 13 digits or 16 characters with dots")
+            }
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the dots must be ASCII digits")
+            }
+            FormatKind::InvalidSeparator => {
+                write!(f, "dotted form must have dots at positions 4, 9 and 14")
+            }
+            FormatKind::InvalidPrefix => write!(f, "AHV number must start with 756"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating an AHV number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, illegal characters, wrong prefix).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `CH_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `CH_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidSeparator`] | `CH_FORMAT_SEPARATOR` |
+    /// | [`FormatKind::InvalidPrefix`] | `CH_FORMAT_PREFIX` |
+    /// | [`NidError::Checksum`] | `CH_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::switzerland::validate("756.9217.0769.86").unwrap_err();
+    /// assert_eq!(err.error_code(), "CH_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "CH_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "CH_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidSeparator) => "CH_FORMAT_SEPARATOR",
+            NidError::Format(FormatKind::InvalidPrefix) => "CH_FORMAT_PREFIX",
+            NidError::Checksum => "CH_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Validate a Swiss AHV number, in dotted or plain form.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, illegal
+/// characters, misplaced separators or a prefix other than `756`, and
+/// [`NidError::Checksum`] if the check digit does not match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::switzerland::validate("756.1234.5678.97").is_ok());
+/// assert!(nidx::switzerland::validate("invalid").is_err());
+/// ```
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    let bytes = nid.as_bytes();
+    let dotted = match bytes.len() {
+        13 => false,
+        16 => true,
+        _ => return Err(NidError::Format(FormatKind::InvalidLength)),
+    };
+
+    let mut digits = [0u8; 13];
+    let mut n = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if dotted && (i == DOT_POSITIONS[0] || i == DOT_POSITIONS[1] || i == DOT_POSITIONS[2]) {
+            if b != b'.' {
+                return Err(NidError::Format(FormatKind::InvalidSeparator));
+            }
+        } else if b.is_ascii_digit() {
+            digits[n] = b - b'0';
+            n += 1;
+        } else {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+
+    if digits[0] != 7 || digits[1] != 5 || digits[2] != 6 {
+        return Err(NidError::Format(FormatKind::InvalidPrefix));
+    }
+    let mut sum = 0u32;
+    let mut i = 0;
+    while i < 12 {
+        sum += digits[i] as u32 * if i % 2 == 0 { 1 } else { 3 };
+        i += 1;
+    }
+    if (10 - sum % 10) % 10 != digits[12] as u32 {
+        return Err(NidError::Checksum);
+    }
+    Ok(())
+}
+
+/// Validate a Swiss AHV number string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::switzerland::validate_with(" 756 9217 0769 85 ", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    validate(&options.prepare(nid))
+}
+
+/// Check whether a Swiss AHV number string is valid.
+///
+/// Returns `true` exactly when [`validate`] succeeds, but sums the digits as
+/// it reads them instead of collecting them first, and builds no error.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::switzerland::is_valid("756.9217.0769.85"));
+/// assert!(!nidx::switzerland::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    let bytes = nid.as_bytes();
+    let dotted = match bytes.len() {
+        13 => false,
+        16 => true,
+        _ => return false,
+    };
+    if bytes[0] != b'7' || bytes[1] != b'5' || bytes[2] != b'6' {
+        return false;
+    }
+    let mut sum = 0u32;
+    let mut check = 0u32;
+    let mut n = 0;
+    let mut i = 0;
+    while i < bytes.len() {
+        let b = bytes[i];
+        if dotted && (i == DOT_POSITIONS[0] || i == DOT_POSITIONS[1] || i == DOT_POSITIONS[2]) {
+            if b != b'.' {
+                return false;
+            }
+        } else if b.is_ascii_digit() {
+            let digit = (b - b'0') as u32;
+            if n < 12 {
+                sum += digit * if n % 2 == 0 { 1 } else { 3 };
+            } else {
+                check = digit;
+            }
+            n += 1;
+        } else {
+            return false;
+        }
+        i += 1;
+    }
+    (10 - sum % 10) % 10 == check
+}
+
+/// Return the canonical form of an AHV number: whitespace and dots
+/// removed, leaving the 13 digits.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::switzerland::normalize(" 756.9217.0769.85\n"), "7569217076985");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '.')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Swiss AHV numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::switzerland::Switzerland;
+///
+/// assert_eq!(Switzerland::decode("756.9217.0769.85"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Switzerland;
+
+impl NationalId for Switzerland {
+    const COUNTRY: Country = Country::Switzerland;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_dotted_and_plain() {
+        assert_eq!(validate("756.9217.0769.85"), Ok(()));
+        assert_eq!(validate("7569217076985"), Ok(()));
+        assert_eq!(validate("756.1234.5678.97"), Ok(()));
+        assert_eq!(validate("7560000000002"), Ok(()));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            validate("756.9217.0769.8"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("756921707698X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            validate("756.92170.769.85"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            validate("756-9217-0769-85"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        // Check digit is valid, but the prefix is not Switzerland.
+        assert_eq!(
+            validate("1239217076983"),
+            Err(NidError::Format(FormatKind::InvalidPrefix))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(validate("756.9217.0769.86"), Err(NidError::Checksum));
+        assert_eq!(validate("7569217076986"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn normalize_strips_dots() {
+        assert!(is_valid(&normalize("756.9217.0769.85")));
+        assert_eq!(normalize("756.9217.0769.85").len(), METADATA.min_length);
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidPrefix).to_string(),
+            "format error: AHV number must start with 756"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "CH_CHECKSUM");
+    }
+}
@@ -0,0 +1,321 @@
+//! Validate Turkish identity numbers (T.C. Kimlik Numarası, TCKN).
+//!
+//! Every Turkish citizen is assigned an 11-digit TCKN at birth registration.
+//! The number is sequential and carries no personal data.
+//!
+//! # Format
+//!
+//! `[digits (9)][check (2)]`
+//!
+//! - **First digit**: never `0`.
+//! - **Digit 10**: seven times the sum of the odd-position digits (1, 3, 5, 7,
+//!   9) minus the sum of the even-position digits (2, 4, 6, 8), mod 10.
+//! - **Digit 11**: the sum of the first 10 digits, mod 10.
+//!
+//! # Examples
+//!
+//! ```
+//! assert!(nidx::turkey::is_valid("10000000146"));
+//! assert!(!nidx::turkey::is_valid("10000000147"));
+//!
+//! // Leading zero.
+//! assert!(!nidx::turkey::is_valid("01234567890"));
+//! ```
+
+use std::fmt;
+
+use crate::{CharClass, Country, Metadata, NationalId, ParseOptions, Run};
+
+/// Static facts about the Turkish T.C. Kimlik No format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Turkey,
+    name: "Turkey",
+    alpha2: "TR",
+    alpha3: "TUR",
+    min_length: 11,
+    max_length: 11,
+    shapes: &[&[Run::exactly(CharClass::Digit, 11)]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Specific reason a T.C. Kimlik No was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 11 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The first digit is `0`.
+    LeadingZero,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "TCKN must be exactly 11 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::LeadingZero => write!(f, "first digit must not be 0"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when validating a T.C. Kimlik No.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, leading zero).
+    Format(FormatKind),
+    /// One of the two check digits does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `TR_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `TR_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::LeadingZero`] | `TR_FORMAT_LEADING_ZERO` |
+    /// | [`NidError::Checksum`] | `TR_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::turkey::validate("10000000147").unwrap_err();
+    /// assert_eq!(err.error_code(), "TR_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "TR_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "TR_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::LeadingZero) => "TR_FORMAT_LEADING_ZERO",
+            NidError::Checksum => "TR_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Compute the two check digits from the first 9 digits.
+const fn check_digits(bytes: &[u8]) -> (u8, u8) {
+    let mut odd = 0i32;
+    let mut even = 0i32;
+    let mut i = 0;
+    while i < 9 {
+        let digit = (bytes[i] - b'0') as i32;
+        if i % 2 == 0 {
+            odd += digit;
+        } else {
+            even += digit;
+        }
+        i += 1;
+    }
+    let tenth = (odd * 7 - even).rem_euclid(10);
+    let eleventh = (odd + even + tenth) % 10;
+    (tenth as u8, eleventh as u8)
+}
+
+/// Validate a Turkish identity number.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or a leading zero, and [`NidError::Checksum`] if either check
+/// digit does not match.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::turkey::validate("12345678950").is_ok());
+/// assert!(nidx::turkey::validate("invalid").is_err());
+/// ```
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut i = 0;
+    while i < 11 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        i += 1;
+    }
+    if bytes[0] == b'0' {
+        return Err(NidError::Format(FormatKind::LeadingZero));
+    }
+    let (tenth, eleventh) = check_digits(bytes);
+    if tenth != bytes[9] - b'0' || eleventh != bytes[10] - b'0' {
+        return Err(NidError::Checksum);
+    }
+    Ok(())
+}
+
+/// Validate a Turkish identity number string after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// assert!(nidx::turkey::validate_with("100 000 001 46", &ParseOptions::lenient()).is_ok());
+/// ```
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    validate(&options.prepare(nid))
+}
+
+/// Check whether a Turkish identity number string is valid.
+///
+/// Returns `true` exactly when [`validate`] succeeds, without building an
+/// error for the first failed check.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::turkey::is_valid("10000000146"));
+/// assert!(!nidx::turkey::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 11 || bytes[0] == b'0' {
+        return false;
+    }
+    let mut i = 0;
+    while i < 11 {
+        if !bytes[i].is_ascii_digit() {
+            return false;
+        }
+        i += 1;
+    }
+    let (tenth, eleventh) = check_digits(bytes);
+    tenth == bytes[9] - b'0' && eleventh == bytes[10] - b'0'
+}
+
+/// Return the canonical form of a TCKN: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::turkey::normalize("100 000 001 46"), "10000000146");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Turkish identity numbers.
+///
+/// The format carries no decodable data, so [`NationalId::Info`] is `()`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::turkey::Turkey;
+///
+/// assert_eq!(Turkey::decode("10000000146"), Ok(()));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Turkey;
+
+impl NationalId for Turkey {
+    const COUNTRY: Country = Country::Turkey;
+    type Info = ();
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<(), NidError> {
+        validate(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validate_valid() {
+        assert_eq!(validate("10000000146"), Ok(()));
+        assert_eq!(validate("12345678950"), Ok(()));
+        assert_eq!(validate("76543210930"), Ok(()));
+        assert_eq!(validate("99999999990"), Ok(()));
+    }
+
+    #[test]
+    fn tenth_digit_wraps_negative_difference() {
+        // 1 * 7 - 9 = -2, so the tenth digit is 8.
+        assert_eq!(validate("19000000088"), Ok(()));
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            validate("1000000014"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            validate("1000000014X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            validate("01234567890"),
+            Err(NidError::Format(FormatKind::LeadingZero))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        // Bad tenth digit.
+        assert_eq!(validate("10000000156"), Err(NidError::Checksum));
+        // Bad eleventh digit.
+        assert_eq!(validate("10000000147"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::LeadingZero).to_string(),
+            "format error: first digit must not be 0"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "TR_CHECKSUM");
+    }
+}
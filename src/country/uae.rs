@@ -0,0 +1,487 @@
+//! Validate and decode Emirates ID numbers.
+//!
+//! The Federal Authority for Identity and Citizenship issues a 15-digit
+//! Emirates ID to every citizen and resident of the United Arab Emirates. It
+//! is usually displayed in hyphenated groups, e.g. `784-1990-1234567-6`; both
+//! forms are accepted.
+//!
+//! # Format
+//!
+//! `[784][birth year (4)][serial (7)][check]`
+//!
+//! - **Prefix**: always `784`, the ISO 3166-1 numeric code of the UAE.
+//! - **Birth year**: the holder's four-digit year of birth, 1900–2099.
+//! - **Check digit**: Luhn check digit over the first 14 digits.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::uae::decode("784-1990-1234567-6").unwrap();
+//! assert_eq!(info.birth_year, 1990);
+//!
+//! assert!(nidx::uae::is_valid("784199012345676"));
+//! assert!(!nidx::uae::is_valid("784199012345677"));
+//! ```
+
+use std::fmt;
+
+use crate::encode::check_range;
+use crate::{
+    CharClass, Country, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Summary,
+};
+
+/// Static facts about the Emirati Emirates ID format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Uae,
+    name: "United Arab Emirates",
+    alpha2: "AE",
+    alpha3: "ARE",
+    min_length: 15,
+    max_length: 18,
+    shapes: &[
+        &[Run::exactly(CharClass::Digit, 15)],
+        &[
+            Run::exactly(CharClass::Digit, 3),
+            Run::one(CharClass::Exact(b'-')),
+            Run::exactly(CharClass::Digit, 4),
+            Run::one(CharClass::Exact(b'-')),
+            Run::exactly(CharClass::Digit, 7),
+            Run::one(CharClass::Exact(b'-')),
+            Run::one(CharClass::Digit),
+        ],
+    ],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Length of the hyphenated display form, `784-YYYY-NNNNNNN-C`.
+const HYPHENATED_LEN: usize = 18;
+
+/// Positions of the hyphens in the display form.
+const HYPHENS: [usize; 3] = [3, 8, 16];
+
+/// Decoded information from a valid Emirates ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Year of birth.
+    pub birth_year: u16,
+    /// Serial number (digits 8–14).
+    pub serial: u32,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Only the birth year is encoded and Emirates IDs are issued to
+    /// citizens and residents alike, so every field is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::uae::decode("784199012345676").unwrap().summary();
+    /// assert_eq!(summary.birthday, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the birth year, e.g. `"1990"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.birth_year)
+    }
+}
+
+/// Specific reason an Emirates ID was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not 15 digits, or 18 characters in the hyphenated form.
+    InvalidLength,
+    /// An 18-character input does not have hyphens after the prefix, the birth year and the serial.
+    InvalidSeparator,
+    /// A character other than the hyphens is not an ASCII digit.
+    NonDigitCharacter,
+    /// The first three digits are not `784`.
+    InvalidPrefix,
+    /// The birth year is outside 1900–2099.
+    InvalidBirthYear {
+        /// The encoded year.
+        year: u16,
+    },
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "Emirates ID must be 15 digits"),
+            FormatKind::InvalidSeparator => {
+                write!(f, "hyphens must follow the prefix, birth year and serial")
+            }
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters except the hyphens must be ASCII digits")
+            }
+            FormatKind::InvalidPrefix => write!(f, "Emirates ID must start with 784"),
+            FormatKind::InvalidBirthYear { year } => write!(f, "birth year {year} out of range"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding an Emirates ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, misplaced hyphens, non-digit characters, prefix or birth year out of range).
+    Format(FormatKind),
+    /// The Luhn check digit does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `AE_FORMAT_LENGTH` |
+    /// | [`FormatKind::InvalidSeparator`] | `AE_FORMAT_SEPARATOR` |
+    /// | [`FormatKind::NonDigitCharacter`] | `AE_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidPrefix`] | `AE_FORMAT_PREFIX` |
+    /// | [`FormatKind::InvalidBirthYear`] | `AE_FORMAT_BIRTH_YEAR` |
+    /// | [`NidError::Checksum`] | `AE_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::uae::validate("784199012345677").unwrap_err();
+    /// assert_eq!(err.error_code(), "AE_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "AE_FORMAT_LENGTH",
+            NidError::Format(FormatKind::InvalidSeparator) => "AE_FORMAT_SEPARATOR",
+            NidError::Format(FormatKind::NonDigitCharacter) => "AE_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidPrefix) => "AE_FORMAT_PREFIX",
+            NidError::Format(FormatKind::InvalidBirthYear { .. }) => "AE_FORMAT_BIRTH_YEAR",
+            NidError::Checksum => "AE_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Whether the Luhn sum over all 15 digits is divisible by 10.
+const fn luhn_valid(digits: &[u8; 15]) -> bool {
+    let mut sum = 0u16;
+    let mut i = 0;
+    while i < 15 {
+        let mut d = digits[14 - i] as u16;
+        if i % 2 == 1 {
+            d *= 2;
+            if d > 9 {
+                d -= 9;
+            }
+        }
+        sum += d;
+        i += 1;
+    }
+    sum % 10 == 0
+}
+
+/// Decode an Emirates ID, with or without hyphens.
+///
+/// This is a `const fn`, so fixed numbers can be checked at compile time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, misplaced
+/// hyphens, non-digit characters, a prefix other than `784` or a birth year
+/// out of range, and [`NidError::Checksum`] if the check digit does not
+/// match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::uae::decode("784198512345673").unwrap();
+/// assert_eq!(info.birth_year, 1985);
+/// assert_eq!(info.serial, 1234567);
+/// ```
+#[must_use = "this returns the decoded Emirates ID info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    let hyphenated = src.len() == HYPHENATED_LEN;
+    if !hyphenated && src.len() != 15 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut digits = [0u8; 15];
+    let mut n = 0;
+    let mut i = 0;
+    while i < src.len() {
+        let is_hyphen_slot = hyphenated && (i == HYPHENS[0] || i == HYPHENS[1] || i == HYPHENS[2]);
+        if is_hyphen_slot {
+            if src[i] != b'-' {
+                return Err(NidError::Format(FormatKind::InvalidSeparator));
+            }
+        } else {
+            if !src[i].is_ascii_digit() {
+                return Err(NidError::Format(FormatKind::NonDigitCharacter));
+            }
+            digits[n] = src[i] - b'0';
+            n += 1;
+        }
+        i += 1;
+    }
+
+    if digits[0] != 7 || digits[1] != 8 || digits[2] != 4 {
+        return Err(NidError::Format(FormatKind::InvalidPrefix));
+    }
+    let year =
+        digits[3] as u16 * 1000 + digits[4] as u16 * 100 + digits[5] as u16 * 10 + digits[6] as u16;
+    if year < 1900 || year > 2099 {
+        return Err(NidError::Format(FormatKind::InvalidBirthYear { year }));
+    }
+    if !luhn_valid(&digits) {
+        return Err(NidError::Checksum);
+    }
+
+    let mut serial = 0u32;
+    let mut i = 7;
+    while i < 14 {
+        serial = serial * 10 + digits[i] as u32;
+        i += 1;
+    }
+    Ok(NidInfo {
+        birth_year: year,
+        serial,
+    })
+}
+
+/// Decode an Emirates ID string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::uae::decode_with(" 784 1990 1234567 6\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birth_year, 1990);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded Emirates ID info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate an Emirates ID string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::uae::validate("784199012345676").is_ok());
+/// assert!(nidx::uae::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate an Emirates ID string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether an Emirates ID string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::uae::is_valid("784199012345676"));
+/// assert!(!nidx::uae::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an Emirates ID: whitespace and hyphens
+/// removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::uae::normalize("784-1990-1234567-6"), "784199012345676");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Emirates ID numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::uae::Uae;
+///
+/// let info = Uae::decode("784-1990-1234567-6").unwrap();
+/// assert_eq!(info.birth_year, 1990);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Uae;
+
+impl NationalId for Uae {
+    const COUNTRY: Country = Country::Uae;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Uae {
+    type Components = NidInfo;
+
+    /// Build the ID number without hyphens.
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo { birth_year, serial } = info;
+        check_range("birth_year", birth_year, 1900..=2099)?;
+        check_range("serial", serial, 0..=9_999_999)?;
+
+        let payload = format!("784{birth_year}{serial:07}");
+        let mut digits = [0u8; 15];
+        for (digit, b) in digits.iter_mut().zip(payload.bytes()) {
+            *digit = b - b'0';
+        }
+        let check = (0..=9)
+            .find(|&check| {
+                digits[14] = check;
+                luhn_valid(&digits)
+            })
+            .expect("one check digit completes the Luhn sum");
+        Ok(format!("{payload}{check}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("784199012345676"),
+            Ok(NidInfo {
+                birth_year: 1990,
+                serial: 1234567,
+            })
+        );
+        assert_eq!(decode("784-1990-1234567-6"), decode("784199012345676"));
+        assert_eq!(decode("784200012345672").unwrap().birth_year, 2000);
+        assert_eq!(decode("784190000000019").unwrap().serial, 1);
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("78419901234567"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("784-1990-1234567-"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("7841-990-1234567-6"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            decode("78419901234567X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("785199012345676"),
+            Err(NidError::Format(FormatKind::InvalidPrefix))
+        );
+        assert_eq!(
+            decode("784189912345676"),
+            Err(NidError::Format(FormatKind::InvalidBirthYear {
+                year: 1899
+            }))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("784199012345677"), Err(NidError::Checksum));
+        assert_eq!(decode("784-1990-1234567-0"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidBirthYear { year: 1899 }).to_string(),
+            "format error: birth year 1899 out of range"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "AE_CHECKSUM");
+    }
+}
@@ -0,0 +1,403 @@
+//! Validate and decode Ukrainian individual tax numbers (RNTRC).
+//!
+//! The RNTRC (registration number of the taxpayer's registration card, РНОКПП)
+//! is a 10-digit number encoding date of birth and sex, followed by a check
+//! digit.
+//!
+//! # Format
+//!
+//! `[days (5)][serial (3)][sex][check]`
+//!
+//! - **Days**: the date of birth as a number of days since 1899-12-31, so
+//!   `00001` is 1900-01-01.
+//! - **Sex digit**: odd for males, even for females.
+//! - **Check digit**: the first 9 digits weighted by `-1 5 7 9 4 6 10 5 7`,
+//!   summed mod 11, then mod 10.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::ukraine::decode("3287301233").unwrap();
+//! assert_eq!(info.birthday.to_string(), "1990-01-01");
+//! assert_eq!(info.sex, nidx::Sex::Male);
+//!
+//! assert!(nidx::ukraine::is_valid("3287301240"));
+//! assert!(!nidx::ukraine::is_valid("3287301234"));
+//! ```
+
+use std::fmt;
+
+use crate::date::{civil_from_days, days_from_civil};
+use crate::{CharClass, Country, Date, Metadata, NationalId, ParseOptions, Run, Sex, Summary};
+
+/// Static facts about the Ukrainian RNTRC format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Ukraine,
+    name: "Ukraine",
+    alpha2: "UA",
+    alpha3: "UKR",
+    min_length: 10,
+    max_length: 10,
+    shapes: &[&[Run::exactly(CharClass::Digit, 10)]],
+    encodes_birthday: true,
+    encodes_sex: true,
+};
+
+const WEIGHTS: [i32; 9] = [-1, 5, 7, 9, 4, 6, 10, 5, 7];
+
+/// Day 0 of the RNTRC date count, as days since 1970-01-01.
+const EPOCH: i32 = days_from_civil(Date {
+    year: 1899,
+    month: 12,
+    day: 31,
+});
+
+/// Decoded information from a valid RNTRC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Date of birth.
+    pub birthday: Date,
+    /// Biological sex.
+    pub sex: Sex,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// RNTRC numbers are also issued to foreign taxpayers, so `is_national`
+    /// is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::ukraine::decode("3287301233").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "1990-01-01 M");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: Some(self.birthday),
+            sex: Some(self.sex),
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birthday> <sex>"`, e.g. `"1990-01-01 M"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason an RNTRC was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 10 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => write!(f, "RNTRC must be exactly 10 digits"),
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding an RNTRC.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters).
+    Format(FormatKind),
+    /// The check digit does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `UA_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `UA_FORMAT_NON_DIGIT` |
+    /// | [`NidError::Checksum`] | `UA_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::ukraine::validate("3287301234").unwrap_err();
+    /// assert_eq!(err.error_code(), "UA_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "UA_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "UA_FORMAT_NON_DIGIT",
+            NidError::Checksum => "UA_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Decode a Ukrainian RNTRC string.
+///
+/// Every 5-digit day count maps to a date, so decoding cannot fail on the
+/// date. This is a `const fn`, so fixed numbers can be checked at compile
+/// time.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length or non-digit
+/// characters, and [`NidError::Checksum`] if the check digit does not match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::ukraine::decode("3658412356").unwrap();
+/// assert_eq!(info.birthday.to_string(), "2000-02-29");
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[must_use = "this returns the decoded RNTRC info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 10 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut sum: i32 = 0;
+    let mut days: i32 = 0;
+    let mut i = 0;
+    while i < 10 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        let digit = (bytes[i] - b'0') as i32;
+        if i < 9 {
+            sum += digit * WEIGHTS[i];
+        }
+        if i < 5 {
+            days = days * 10 + digit;
+        }
+        i += 1;
+    }
+    if sum.rem_euclid(11) % 10 != (bytes[9] - b'0') as i32 {
+        return Err(NidError::Checksum);
+    }
+
+    Ok(NidInfo {
+        birthday: civil_from_days(EPOCH + days),
+        sex: if (bytes[8] - b'0') % 2 == 1 {
+            Sex::Male
+        } else {
+            Sex::Female
+        },
+    })
+}
+
+/// Decode a Ukrainian RNTRC string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::ukraine::decode_with(" 3287301233\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+/// ```
+#[inline]
+#[must_use = "this returns the decoded RNTRC info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Ukrainian RNTRC string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::ukraine::validate("3287301233").is_ok());
+/// assert!(nidx::ukraine::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Ukrainian RNTRC string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Ukrainian RNTRC string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::ukraine::is_valid("3287301233"));
+/// assert!(!nidx::ukraine::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of an RNTRC: surrounding whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::ukraine::normalize(" 3287301233\n"), "3287301233");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.trim().to_owned()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Ukrainian RNTRC numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::ukraine::Ukraine;
+///
+/// let info = Ukraine::decode("3287301240").unwrap();
+/// assert_eq!(info.sex, nidx::Sex::Female);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Ukraine;
+
+impl NationalId for Ukraine {
+    const COUNTRY: Country = Country::Ukraine;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("3287301233"),
+            Ok(NidInfo {
+                birthday: Date {
+                    year: 1990,
+                    month: 1,
+                    day: 1
+                },
+                sex: Sex::Male,
+            })
+        );
+        assert_eq!(decode("3287301240").unwrap().sex, Sex::Female);
+    }
+
+    #[test]
+    fn decode_day_count() {
+        assert_eq!(
+            decode("0000100010").unwrap().birthday.to_string(),
+            "1900-01-01"
+        );
+        assert_eq!(
+            decode("3658412356").unwrap().birthday.to_string(),
+            "2000-02-29"
+        );
+        assert_eq!(
+            decode("5478800010").unwrap().birthday.to_string(),
+            "2050-01-01"
+        );
+    }
+
+    #[test]
+    fn negative_weighted_sum() {
+        // Only the first digit (weight -1) is non-zero.
+        assert!(decode("9000000002").is_ok());
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("328730123"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("32873012X3"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("3287301234"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).to_string(),
+            "format error: RNTRC must be exactly 10 digits"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "UA_CHECKSUM");
+    }
+}
@@ -0,0 +1,424 @@
+//! Validate and decode Venezuelan identity numbers (cédula de identidad).
+//!
+//! SAIME issues a sequential cédula number to citizens and foreign residents.
+//! It is always written with a nationality prefix, e.g. `V-12345678` for a
+//! Venezuelan or `E-81234567` for a foreigner. The number has no check digit.
+//!
+//! # Format
+//!
+//! `[nationality][-][number (1–9)]`
+//!
+//! - **Nationality**: `V` (venezolano) or `E` (extranjero), in either case.
+//!   The hyphen after it is optional.
+//! - **Number**: up to 9 digits, not all zeros.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::venezuela::decode("V-12345678").unwrap();
+//! assert!(info.is_national);
+//! assert_eq!(info.number, 12345678);
+//!
+//! assert!(nidx::venezuela::is_valid("E81234567"));
+//! assert!(!nidx::venezuela::is_valid("12345678"));
+//! ```
+
+use std::fmt;
+
+use crate::encode::check_range;
+use crate::{
+    CharClass, Country, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Summary,
+};
+
+/// Static facts about the Venezuelan cédula format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Venezuela,
+    name: "Venezuela",
+    alpha2: "VE",
+    alpha3: "VEN",
+    min_length: 2,
+    max_length: 11,
+    shapes: &[&[
+        Run::one(CharClass::Letter),
+        Run::optional(CharClass::Exact(b'-')),
+        Run::between(CharClass::Digit, 1, 9),
+    ]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Maximum number of digits after the prefix.
+const MAX_DIGITS: usize = 9;
+
+/// Decoded information from a valid cédula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Whether the holder is Venezuelan (`V` prefix) rather than a
+    /// foreigner (`E` prefix).
+    pub is_national: bool,
+    /// The cédula number without its prefix.
+    pub number: u32,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::venezuela::decode("E-81234567").unwrap().summary();
+    /// assert_eq!(summary.is_national, Some(false));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: Some(self.is_national),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the national status, e.g. `"national"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.summary(), f)
+    }
+}
+
+/// Specific reason a cédula was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// The first character is not `V` or `E`.
+    InvalidPrefix,
+    /// The number after the prefix is empty or longer than 9 digits.
+    InvalidLength,
+    /// A character after the prefix and optional hyphen is not an ASCII digit.
+    NonDigitCharacter,
+    /// Every digit is `0`.
+    AllZeros,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidPrefix => write!(f, "cédula must start with V or E"),
+            FormatKind::InvalidLength => write!(f, "number must be 1 to 9 digits"),
+            FormatKind::NonDigitCharacter => {
+                write!(f, "all characters after the prefix must be ASCII digits")
+            }
+            FormatKind::AllZeros => write!(f, "number must not be all zeros"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding a cédula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (missing prefix, wrong length, non-digit characters, all zeros).
+    Format(FormatKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidPrefix`] | `VE_FORMAT_PREFIX` |
+    /// | [`FormatKind::InvalidLength`] | `VE_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `VE_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::AllZeros`] | `VE_FORMAT_ALL_ZEROS` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::venezuela::validate("12345678").unwrap_err();
+    /// assert_eq!(err.error_code(), "VE_FORMAT_PREFIX");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidPrefix) => "VE_FORMAT_PREFIX",
+            NidError::Format(FormatKind::InvalidLength) => "VE_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "VE_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::AllZeros) => "VE_FORMAT_ALL_ZEROS",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Decode a Venezuelan cédula, with or without the hyphen after the prefix.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the prefix is missing, the number is
+/// empty, longer than 9 digits, contains a non-digit character or is all
+/// zeros.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::venezuela::decode("e-81234567").unwrap();
+/// assert!(!info.is_national);
+/// ```
+#[must_use = "this returns the decoded cédula info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    if src.is_empty() {
+        return Err(NidError::Format(FormatKind::InvalidPrefix));
+    }
+    let is_national = match src[0].to_ascii_uppercase() {
+        b'V' => true,
+        b'E' => false,
+        _ => return Err(NidError::Format(FormatKind::InvalidPrefix)),
+    };
+    let start = if src.len() > 1 && src[1] == b'-' {
+        2
+    } else {
+        1
+    };
+    let len = src.len() - start;
+    if len == 0 || len > MAX_DIGITS {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut number = 0u32;
+    let mut i = start;
+    while i < src.len() {
+        if !src[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        number = number * 10 + (src[i] - b'0') as u32;
+        i += 1;
+    }
+    if number == 0 {
+        return Err(NidError::Format(FormatKind::AllZeros));
+    }
+    Ok(NidInfo {
+        is_national,
+        number,
+    })
+}
+
+/// Decode a Venezuelan cédula string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::venezuela::decode_with(" V-12.345.678\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.number, 12345678);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded cédula info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Venezuelan cédula string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::venezuela::validate("V-12345678").is_ok());
+/// assert!(nidx::venezuela::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Venezuelan cédula string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Venezuelan cédula string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::venezuela::is_valid("V-12345678"));
+/// assert!(!nidx::venezuela::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a cédula: whitespace, hyphens and dots
+/// removed and the prefix uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::venezuela::normalize("v-12.345.678"), "V12345678");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-' && *c != '.')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Venezuelan cédula numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::venezuela::Venezuela;
+///
+/// let info = Venezuela::decode("V-12345678").unwrap();
+/// assert!(info.is_national);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Venezuela;
+
+impl NationalId for Venezuela {
+    const COUNTRY: Country = Country::Venezuela;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Venezuela {
+    type Components = NidInfo;
+
+    /// Build the cédula without a hyphen, e.g. `V12345678`.
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            is_national,
+            number,
+        } = info;
+        check_range("number", number, 1..=999_999_999)?;
+        let prefix = if is_national { 'V' } else { 'E' };
+        Ok(format!("{prefix}{number}"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("V-12345678"),
+            Ok(NidInfo {
+                is_national: true,
+                number: 12345678,
+            })
+        );
+        assert_eq!(
+            decode("E81234567"),
+            Ok(NidInfo {
+                is_national: false,
+                number: 81234567,
+            })
+        );
+        assert_eq!(decode("v12345678"), decode("V-12345678"));
+        assert_eq!(decode("V-1").unwrap().number, 1);
+        assert_eq!(decode("E-123456789").unwrap().number, 123456789);
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(decode(""), Err(NidError::Format(FormatKind::InvalidPrefix)));
+        assert_eq!(
+            decode("12345678"),
+            Err(NidError::Format(FormatKind::InvalidPrefix))
+        );
+        assert_eq!(
+            decode("P-12345678"),
+            Err(NidError::Format(FormatKind::InvalidPrefix))
+        );
+        assert_eq!(
+            decode("V-"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("V-1234567890"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("V--1234567"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("V-12.3456"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("V-0000"),
+            Err(NidError::Format(FormatKind::AllZeros))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidPrefix).to_string(),
+            "format error: cédula must start with V or E"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::AllZeros).error_code(),
+            "VE_FORMAT_ALL_ZEROS"
+        );
+    }
+}
@@ -0,0 +1,871 @@
+//! Validate and decode Vietnamese citizen identity numbers (số định danh cá
+//! nhân), printed on the citizen identity card (CCCD).
+//!
+//! Every Vietnamese citizen is assigned a 12-digit personal identification
+//! number that encodes the province of birth registration, sex, century and
+//! year of birth, followed by a random serial. The number has no check digit.
+//!
+//! # Format
+//!
+//! `[province (3)][sex/century][year (2)][serial (6)]`
+//!
+//! - **Province**: one of the 63 provincial codes, `001` (Hà Nội) to `096`
+//!   (Cà Mau); see [`Province`].
+//! - **Sex/century digit**: even for males and odd for females; `0`/`1` for
+//!   births in 1900–1999, `2`/`3` for 2000–2099, and so on up to `8`/`9` for
+//!   2300–2399.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::vietnam::Province;
+//!
+//! let info = nidx::vietnam::decode("079305123456").unwrap();
+//! assert_eq!(info.province, Province::HoChiMinhCity);
+//! assert_eq!(info.birth_year, 2005);
+//! assert_eq!(info.sex, nidx::Sex::Female);
+//!
+//! assert!(!nidx::vietnam::is_valid("003090012345"));
+//! ```
+
+use std::fmt;
+
+use crate::encode::{check_range, is_female};
+use crate::{
+    CharClass, Country, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Sex,
+    Summary,
+};
+
+/// Static facts about the Vietnamese citizen identity number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Vietnam,
+    name: "Vietnam",
+    alpha2: "VN",
+    alpha3: "VNM",
+    min_length: 12,
+    max_length: 12,
+    shapes: &[&[Run::exactly(CharClass::Digit, 12)]],
+    encodes_birthday: false,
+    encodes_sex: true,
+};
+
+/// A Vietnamese province or centrally governed city, encoded in the first
+/// three digits.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Province {
+    /// Code `001`.
+    HaNoi,
+    /// Code `002`.
+    HaGiang,
+    /// Code `004`.
+    CaoBang,
+    /// Code `006`.
+    BacKan,
+    /// Code `008`.
+    TuyenQuang,
+    /// Code `010`.
+    LaoCai,
+    /// Code `011`.
+    DienBien,
+    /// Code `012`.
+    LaiChau,
+    /// Code `014`.
+    SonLa,
+    /// Code `015`.
+    YenBai,
+    /// Code `017`.
+    HoaBinh,
+    /// Code `019`.
+    ThaiNguyen,
+    /// Code `020`.
+    LangSon,
+    /// Code `022`.
+    QuangNinh,
+    /// Code `024`.
+    BacGiang,
+    /// Code `025`.
+    PhuTho,
+    /// Code `026`.
+    VinhPhuc,
+    /// Code `027`.
+    BacNinh,
+    /// Code `030`.
+    HaiDuong,
+    /// Code `031`.
+    HaiPhong,
+    /// Code `033`.
+    HungYen,
+    /// Code `034`.
+    ThaiBinh,
+    /// Code `035`.
+    HaNam,
+    /// Code `036`.
+    NamDinh,
+    /// Code `037`.
+    NinhBinh,
+    /// Code `038`.
+    ThanhHoa,
+    /// Code `040`.
+    NgheAn,
+    /// Code `042`.
+    HaTinh,
+    /// Code `044`.
+    QuangBinh,
+    /// Code `045`.
+    QuangTri,
+    /// Code `046`.
+    ThuaThienHue,
+    /// Code `048`.
+    DaNang,
+    /// Code `049`.
+    QuangNam,
+    /// Code `051`.
+    QuangNgai,
+    /// Code `052`.
+    BinhDinh,
+    /// Code `054`.
+    PhuYen,
+    /// Code `056`.
+    KhanhHoa,
+    /// Code `058`.
+    NinhThuan,
+    /// Code `060`.
+    BinhThuan,
+    /// Code `062`.
+    KonTum,
+    /// Code `064`.
+    GiaLai,
+    /// Code `066`.
+    DakLak,
+    /// Code `067`.
+    DakNong,
+    /// Code `068`.
+    LamDong,
+    /// Code `070`.
+    BinhPhuoc,
+    /// Code `072`.
+    TayNinh,
+    /// Code `074`.
+    BinhDuong,
+    /// Code `075`.
+    DongNai,
+    /// Code `077`.
+    BaRiaVungTau,
+    /// Code `079`.
+    HoChiMinhCity,
+    /// Code `080`.
+    LongAn,
+    /// Code `082`.
+    TienGiang,
+    /// Code `083`.
+    BenTre,
+    /// Code `084`.
+    TraVinh,
+    /// Code `086`.
+    VinhLong,
+    /// Code `087`.
+    DongThap,
+    /// Code `089`.
+    AnGiang,
+    /// Code `091`.
+    KienGiang,
+    /// Code `092`.
+    CanTho,
+    /// Code `093`.
+    HauGiang,
+    /// Code `094`.
+    SocTrang,
+    /// Code `095`.
+    BacLieu,
+    /// Code `096`.
+    CaMau,
+}
+
+impl Province {
+    /// Every province, in code order.
+    pub const ALL: &'static [Province] = &[
+        Province::HaNoi,
+        Province::HaGiang,
+        Province::CaoBang,
+        Province::BacKan,
+        Province::TuyenQuang,
+        Province::LaoCai,
+        Province::DienBien,
+        Province::LaiChau,
+        Province::SonLa,
+        Province::YenBai,
+        Province::HoaBinh,
+        Province::ThaiNguyen,
+        Province::LangSon,
+        Province::QuangNinh,
+        Province::BacGiang,
+        Province::PhuTho,
+        Province::VinhPhuc,
+        Province::BacNinh,
+        Province::HaiDuong,
+        Province::HaiPhong,
+        Province::HungYen,
+        Province::ThaiBinh,
+        Province::HaNam,
+        Province::NamDinh,
+        Province::NinhBinh,
+        Province::ThanhHoa,
+        Province::NgheAn,
+        Province::HaTinh,
+        Province::QuangBinh,
+        Province::QuangTri,
+        Province::ThuaThienHue,
+        Province::DaNang,
+        Province::QuangNam,
+        Province::QuangNgai,
+        Province::BinhDinh,
+        Province::PhuYen,
+        Province::KhanhHoa,
+        Province::NinhThuan,
+        Province::BinhThuan,
+        Province::KonTum,
+        Province::GiaLai,
+        Province::DakLak,
+        Province::DakNong,
+        Province::LamDong,
+        Province::BinhPhuoc,
+        Province::TayNinh,
+        Province::BinhDuong,
+        Province::DongNai,
+        Province::BaRiaVungTau,
+        Province::HoChiMinhCity,
+        Province::LongAn,
+        Province::TienGiang,
+        Province::BenTre,
+        Province::TraVinh,
+        Province::VinhLong,
+        Province::DongThap,
+        Province::AnGiang,
+        Province::KienGiang,
+        Province::CanTho,
+        Province::HauGiang,
+        Province::SocTrang,
+        Province::BacLieu,
+        Province::CaMau,
+    ];
+
+    /// Look up a province by its three-digit code.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::vietnam::Province;
+    ///
+    /// assert_eq!(Province::from_code(1), Some(Province::HaNoi));
+    /// assert_eq!(Province::from_code(79), Some(Province::HoChiMinhCity));
+    /// assert_eq!(Province::from_code(3), None);
+    /// ```
+    #[must_use]
+    pub const fn from_code(code: u16) -> Option<Province> {
+        Some(match code {
+            1 => Province::HaNoi,
+            2 => Province::HaGiang,
+            4 => Province::CaoBang,
+            6 => Province::BacKan,
+            8 => Province::TuyenQuang,
+            10 => Province::LaoCai,
+            11 => Province::DienBien,
+            12 => Province::LaiChau,
+            14 => Province::SonLa,
+            15 => Province::YenBai,
+            17 => Province::HoaBinh,
+            19 => Province::ThaiNguyen,
+            20 => Province::LangSon,
+            22 => Province::QuangNinh,
+            24 => Province::BacGiang,
+            25 => Province::PhuTho,
+            26 => Province::VinhPhuc,
+            27 => Province::BacNinh,
+            30 => Province::HaiDuong,
+            31 => Province::HaiPhong,
+            33 => Province::HungYen,
+            34 => Province::ThaiBinh,
+            35 => Province::HaNam,
+            36 => Province::NamDinh,
+            37 => Province::NinhBinh,
+            38 => Province::ThanhHoa,
+            40 => Province::NgheAn,
+            42 => Province::HaTinh,
+            44 => Province::QuangBinh,
+            45 => Province::QuangTri,
+            46 => Province::ThuaThienHue,
+            48 => Province::DaNang,
+            49 => Province::QuangNam,
+            51 => Province::QuangNgai,
+            52 => Province::BinhDinh,
+            54 => Province::PhuYen,
+            56 => Province::KhanhHoa,
+            58 => Province::NinhThuan,
+            60 => Province::BinhThuan,
+            62 => Province::KonTum,
+            64 => Province::GiaLai,
+            66 => Province::DakLak,
+            67 => Province::DakNong,
+            68 => Province::LamDong,
+            70 => Province::BinhPhuoc,
+            72 => Province::TayNinh,
+            74 => Province::BinhDuong,
+            75 => Province::DongNai,
+            77 => Province::BaRiaVungTau,
+            79 => Province::HoChiMinhCity,
+            80 => Province::LongAn,
+            82 => Province::TienGiang,
+            83 => Province::BenTre,
+            84 => Province::TraVinh,
+            86 => Province::VinhLong,
+            87 => Province::DongThap,
+            89 => Province::AnGiang,
+            91 => Province::KienGiang,
+            92 => Province::CanTho,
+            93 => Province::HauGiang,
+            94 => Province::SocTrang,
+            95 => Province::BacLieu,
+            96 => Province::CaMau,
+            _ => return None,
+        })
+    }
+
+    /// The three-digit code of the province.
+    #[must_use]
+    pub const fn code(self) -> u16 {
+        match self {
+            Province::HaNoi => 1,
+            Province::HaGiang => 2,
+            Province::CaoBang => 4,
+            Province::BacKan => 6,
+            Province::TuyenQuang => 8,
+            Province::LaoCai => 10,
+            Province::DienBien => 11,
+            Province::LaiChau => 12,
+            Province::SonLa => 14,
+            Province::YenBai => 15,
+            Province::HoaBinh => 17,
+            Province::ThaiNguyen => 19,
+            Province::LangSon => 20,
+            Province::QuangNinh => 22,
+            Province::BacGiang => 24,
+            Province::PhuTho => 25,
+            Province::VinhPhuc => 26,
+            Province::BacNinh => 27,
+            Province::HaiDuong => 30,
+            Province::HaiPhong => 31,
+            Province::HungYen => 33,
+            Province::ThaiBinh => 34,
+            Province::HaNam => 35,
+            Province::NamDinh => 36,
+            Province::NinhBinh => 37,
+            Province::ThanhHoa => 38,
+            Province::NgheAn => 40,
+            Province::HaTinh => 42,
+            Province::QuangBinh => 44,
+            Province::QuangTri => 45,
+            Province::ThuaThienHue => 46,
+            Province::DaNang => 48,
+            Province::QuangNam => 49,
+            Province::QuangNgai => 51,
+            Province::BinhDinh => 52,
+            Province::PhuYen => 54,
+            Province::KhanhHoa => 56,
+            Province::NinhThuan => 58,
+            Province::BinhThuan => 60,
+            Province::KonTum => 62,
+            Province::GiaLai => 64,
+            Province::DakLak => 66,
+            Province::DakNong => 67,
+            Province::LamDong => 68,
+            Province::BinhPhuoc => 70,
+            Province::TayNinh => 72,
+            Province::BinhDuong => 74,
+            Province::DongNai => 75,
+            Province::BaRiaVungTau => 77,
+            Province::HoChiMinhCity => 79,
+            Province::LongAn => 80,
+            Province::TienGiang => 82,
+            Province::BenTre => 83,
+            Province::TraVinh => 84,
+            Province::VinhLong => 86,
+            Province::DongThap => 87,
+            Province::AnGiang => 89,
+            Province::KienGiang => 91,
+            Province::CanTho => 92,
+            Province::HauGiang => 93,
+            Province::SocTrang => 94,
+            Province::BacLieu => 95,
+            Province::CaMau => 96,
+        }
+    }
+
+    /// The Vietnamese name of the province.
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Province::HaNoi => "Hà Nội",
+            Province::HaGiang => "Hà Giang",
+            Province::CaoBang => "Cao Bằng",
+            Province::BacKan => "Bắc Kạn",
+            Province::TuyenQuang => "Tuyên Quang",
+            Province::LaoCai => "Lào Cai",
+            Province::DienBien => "Điện Biên",
+            Province::LaiChau => "Lai Châu",
+            Province::SonLa => "Sơn La",
+            Province::YenBai => "Yên Bái",
+            Province::HoaBinh => "Hòa Bình",
+            Province::ThaiNguyen => "Thái Nguyên",
+            Province::LangSon => "Lạng Sơn",
+            Province::QuangNinh => "Quảng Ninh",
+            Province::BacGiang => "Bắc Giang",
+            Province::PhuTho => "Phú Thọ",
+            Province::VinhPhuc => "Vĩnh Phúc",
+            Province::BacNinh => "Bắc Ninh",
+            Province::HaiDuong => "Hải Dương",
+            Province::HaiPhong => "Hải Phòng",
+            Province::HungYen => "Hưng Yên",
+            Province::ThaiBinh => "Thái Bình",
+            Province::HaNam => "Hà Nam",
+            Province::NamDinh => "Nam Định",
+            Province::NinhBinh => "Ninh Bình",
+            Province::ThanhHoa => "Thanh Hóa",
+            Province::NgheAn => "Nghệ An",
+            Province::HaTinh => "Hà Tĩnh",
+            Province::QuangBinh => "Quảng Bình",
+            Province::QuangTri => "Quảng Trị",
+            Province::ThuaThienHue => "Thừa Thiên Huế",
+            Province::DaNang => "Đà Nẵng",
+            Province::QuangNam => "Quảng Nam",
+            Province::QuangNgai => "Quảng Ngãi",
+            Province::BinhDinh => "Bình Định",
+            Province::PhuYen => "Phú Yên",
+            Province::KhanhHoa => "Khánh Hòa",
+            Province::NinhThuan => "Ninh Thuận",
+            Province::BinhThuan => "Bình Thuận",
+            Province::KonTum => "Kon Tum",
+            Province::GiaLai => "Gia Lai",
+            Province::DakLak => "Đắk Lắk",
+            Province::DakNong => "Đắk Nông",
+            Province::LamDong => "Lâm Đồng",
+            Province::BinhPhuoc => "Bình Phước",
+            Province::TayNinh => "Tây Ninh",
+            Province::BinhDuong => "Bình Dương",
+            Province::DongNai => "Đồng Nai",
+            Province::BaRiaVungTau => "Bà Rịa – Vũng Tàu",
+            Province::HoChiMinhCity => "Thành phố Hồ Chí Minh",
+            Province::LongAn => "Long An",
+            Province::TienGiang => "Tiền Giang",
+            Province::BenTre => "Bến Tre",
+            Province::TraVinh => "Trà Vinh",
+            Province::VinhLong => "Vĩnh Long",
+            Province::DongThap => "Đồng Tháp",
+            Province::AnGiang => "An Giang",
+            Province::KienGiang => "Kiên Giang",
+            Province::CanTho => "Cần Thơ",
+            Province::HauGiang => "Hậu Giang",
+            Province::SocTrang => "Sóc Trăng",
+            Province::BacLieu => "Bạc Liêu",
+            Province::CaMau => "Cà Mau",
+        }
+    }
+}
+
+impl fmt::Display for Province {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Decoded information from a valid citizen identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Province of birth registration.
+    pub province: Province,
+    /// Biological sex.
+    pub sex: Sex,
+    /// Year of birth.
+    pub birth_year: u16,
+    /// Random serial (last 6 digits).
+    pub serial: u32,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Only the birth year is encoded, so `birthday` is `None`. The number
+    /// is only assigned to citizens, so `is_national` is always
+    /// `Some(true)`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::vietnam::decode("001090012345").unwrap().summary();
+    /// assert_eq!(summary.to_string(), "M national");
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: Some(self.sex),
+            is_national: Some(true),
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as `"<birth year> <sex> <province>"`, e.g. `"1990 M Hà Nội"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} {} {}", self.birth_year, self.sex, self.province)
+    }
+}
+
+/// Specific reason a citizen identity number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input is not exactly 12 characters.
+    InvalidLength,
+    /// Not all characters are ASCII digits.
+    NonDigitCharacter,
+    /// The first three digits are not a known province code.
+    InvalidProvince {
+        /// The encoded province code.
+        code: u16,
+    },
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "citizen identity number must be exactly 12 digits")
+            }
+            FormatKind::NonDigitCharacter => write!(f, "all characters must be ASCII digits"),
+            FormatKind::InvalidProvince { code } => write!(f, "unknown province code {code:03}"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding a citizen identity number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, non-digit characters, unknown province).
+    Format(FormatKind),
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `VN_FORMAT_LENGTH` |
+    /// | [`FormatKind::NonDigitCharacter`] | `VN_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidProvince`] | `VN_FORMAT_PROVINCE` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::vietnam::validate("003090012345").unwrap_err();
+    /// assert_eq!(err.error_code(), "VN_FORMAT_PROVINCE");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "VN_FORMAT_LENGTH",
+            NidError::Format(FormatKind::NonDigitCharacter) => "VN_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidProvince { .. }) => "VN_FORMAT_PROVINCE",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+        }
+    }
+}
+
+/// Decode a Vietnamese citizen identity number.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, non-digit
+/// characters or an unknown province code.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::vietnam::decode("001090012345").unwrap();
+/// assert_eq!(info.birth_year, 1990);
+/// assert_eq!(info.sex, nidx::Sex::Male);
+/// ```
+#[must_use = "this returns the decoded citizen identity info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let bytes = nid.as_bytes();
+    if bytes.len() != 12 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut digits = [0u8; 12];
+    let mut i = 0;
+    while i < 12 {
+        if !bytes[i].is_ascii_digit() {
+            return Err(NidError::Format(FormatKind::NonDigitCharacter));
+        }
+        digits[i] = bytes[i] - b'0';
+        i += 1;
+    }
+
+    let code = digits[0] as u16 * 100 + digits[1] as u16 * 10 + digits[2] as u16;
+    let Some(province) = Province::from_code(code) else {
+        return Err(NidError::Format(FormatKind::InvalidProvince { code }));
+    };
+    let sex = if digits[3] % 2 == 0 {
+        Sex::Male
+    } else {
+        Sex::Female
+    };
+    let birth_year = 1900 + (digits[3] / 2) as u16 * 100 + (digits[4] * 10 + digits[5]) as u16;
+    let mut serial = 0u32;
+    let mut i = 6;
+    while i < 12 {
+        serial = serial * 10 + digits[i] as u32;
+        i += 1;
+    }
+    Ok(NidInfo {
+        province,
+        sex,
+        birth_year,
+        serial,
+    })
+}
+
+/// Decode a Vietnamese citizen identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::vietnam::decode_with(" 001 090 012345\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.birth_year, 1990);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded citizen identity number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Vietnamese citizen identity number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::vietnam::validate("001090012345").is_ok());
+/// assert!(nidx::vietnam::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Vietnamese citizen identity number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Vietnamese citizen identity number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::vietnam::is_valid("001090012345"));
+/// assert!(!nidx::vietnam::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a citizen identity number: whitespace removed.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::vietnam::normalize(" 001 090 012345\n"), "001090012345");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars().filter(|c| !c.is_whitespace()).collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Vietnamese citizen identity numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::vietnam::{Province, Vietnam};
+///
+/// let info = Vietnam::decode("001090012345").unwrap();
+/// assert_eq!(info.province, Province::HaNoi);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Vietnam;
+
+impl NationalId for Vietnam {
+    const COUNTRY: Country = Country::Vietnam;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Vietnam {
+    type Components = NidInfo;
+
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            province,
+            sex,
+            birth_year,
+            serial,
+        } = info;
+        check_range("birth_year", birth_year, 1900..=2399)?;
+        check_range("serial", serial, 0..=999_999)?;
+        let digit = 2 * (birth_year / 100 - 19) + u16::from(is_female(sex)?);
+        Ok(format!(
+            "{:03}{digit}{:02}{serial:06}",
+            province.code(),
+            birth_year % 100
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("001090012345"),
+            Ok(NidInfo {
+                province: Province::HaNoi,
+                sex: Sex::Male,
+                birth_year: 1990,
+                serial: 12345,
+            })
+        );
+        assert_eq!(decode("001090012345").unwrap().to_string(), "1990 M Hà Nội");
+    }
+
+    #[test]
+    fn decode_sex_and_century() {
+        let cases = [
+            ('0', 1985, Sex::Male),
+            ('1', 1985, Sex::Female),
+            ('2', 2085, Sex::Male),
+            ('3', 2085, Sex::Female),
+            ('4', 2185, Sex::Male),
+            ('9', 2385, Sex::Female),
+        ];
+        for (digit, year, sex) in cases {
+            let info = decode(&format!("096{digit}85000001")).unwrap();
+            assert_eq!(info.province, Province::CaMau);
+            assert_eq!(info.birth_year, year);
+            assert_eq!(info.sex, sex);
+        }
+    }
+
+    #[test]
+    fn province_table_roundtrip() {
+        assert_eq!(Province::ALL.len(), 63);
+        for &province in Province::ALL {
+            assert_eq!(Province::from_code(province.code()), Some(province));
+        }
+        assert!(Province::ALL.windows(2).all(|w| w[0].code() < w[1].code()));
+        assert_eq!(Province::DaNang.name(), "Đà Nẵng");
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("00109001234"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("00109001234X"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("000090012345"),
+            Err(NidError::Format(FormatKind::InvalidProvince { code: 0 }))
+        );
+        assert_eq!(
+            decode("097090012345"),
+            Err(NidError::Format(FormatKind::InvalidProvince { code: 97 }))
+        );
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidProvince { code: 3 }).to_string(),
+            "format error: unknown province code 003"
+        );
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidLength).error_code(),
+            "VN_FORMAT_LENGTH"
+        );
+    }
+}
@@ -0,0 +1,533 @@
+//! Validate and decode Zimbabwean national registration numbers.
+//!
+//! The Registrar General assigns every citizen and resident a registration
+//! number, printed on the national ID card as e.g. `63-123456 B 42`. It
+//! records the district where the number was issued and the holder's district
+//! of origin, around a serial and a check letter.
+//!
+//! # Format
+//!
+//! `[registration district (2)][serial (6–7)][check letter][district of origin (2)]`
+//!
+//! - **District codes**: two-digit codes assigned by the Registrar General,
+//!   `01`–`99`. [`NidInfo`] exposes both codes.
+//! - **Check letter**: the registration district and serial read as one
+//!   number, mod 23, indexes `ZABCDEFGHJKLMNPQRSTVWXY` (`I`, `O` and `U` are
+//!   not used).
+//! - **Separators**: a hyphen may follow the registration district, the serial
+//!   and the check letter.
+//!
+//! # Examples
+//!
+//! ```
+//! let info = nidx::zimbabwe::decode("63-123456B42").unwrap();
+//! assert_eq!(info.registration_district, 63);
+//! assert_eq!(info.origin_district, 42);
+//!
+//! assert!(nidx::zimbabwe::is_valid("63123456B42"));
+//! assert!(!nidx::zimbabwe::is_valid("63123456C42"));
+//! ```
+
+use std::fmt;
+
+use crate::encode::check_range;
+use crate::{
+    CharClass, Country, EncodeError, EncodeId, Metadata, NationalId, ParseOptions, Run, Summary,
+};
+
+/// Static facts about the Zimbabwean registration number format.
+pub const METADATA: Metadata = Metadata {
+    country: Country::Zimbabwe,
+    name: "Zimbabwe",
+    alpha2: "ZW",
+    alpha3: "ZWE",
+    min_length: 11,
+    max_length: 15,
+    shapes: &[&[
+        Run::exactly(CharClass::Digit, 2),
+        Run::optional(CharClass::Exact(b'-')),
+        Run::between(CharClass::Digit, 6, 7),
+        Run::optional(CharClass::Exact(b'-')),
+        Run::one(CharClass::Letter),
+        Run::optional(CharClass::Exact(b'-')),
+        Run::exactly(CharClass::Digit, 2),
+    ]],
+    encodes_birthday: false,
+    encodes_sex: false,
+};
+
+/// Check letters indexed by the remainder mod 23.
+const CHECK_LETTERS: &[u8; 23] = b"ZABCDEFGHJKLMNPQRSTVWXY";
+
+/// Longest accepted input: 12 characters plus three hyphens.
+const MAX_LEN: usize = 15;
+
+/// Decoded information from a valid registration number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NidInfo {
+    /// Code of the district where the number was registered.
+    pub registration_district: u8,
+    /// Serial number within the registration district.
+    pub serial: u32,
+    /// Code of the holder's district of origin.
+    pub origin_district: u8,
+}
+
+impl NidInfo {
+    /// The decoded fields as a country-independent [`Summary`].
+    ///
+    /// Registration numbers carry no personal data and are issued to
+    /// citizens and residents alike, so every field is `None`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let summary = nidx::zimbabwe::decode("63123456B42").unwrap().summary();
+    /// assert_eq!(summary.is_national, None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        Summary {
+            birthday: None,
+            sex: None,
+            is_national: None,
+        }
+    }
+}
+
+impl fmt::Display for NidInfo {
+    /// Formats as the two district codes, e.g. `"63/42"`.
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{:02}/{:02}",
+            self.registration_district, self.origin_district
+        )
+    }
+}
+
+/// Specific reason a registration number was rejected due to formatting.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum FormatKind {
+    /// Input, without hyphens, is not 11 or 12 characters.
+    InvalidLength,
+    /// A hyphen appears other than after the registration district, serial or check letter.
+    InvalidSeparator,
+    /// A character other than the check letter and hyphens is not an ASCII digit.
+    NonDigitCharacter,
+    /// The check character is not a letter used by the check alphabet.
+    InvalidCheckChar,
+    /// A district code is `00`.
+    InvalidDistrict,
+}
+
+impl fmt::Display for FormatKind {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FormatKind::InvalidLength => {
+                write!(f, "registration number must be 11 or 12 characters")
+            }
+            FormatKind::InvalidSeparator => write!(
+                f,
+                "hyphens may only follow the district, serial and check letter"
+            ),
+            FormatKind::NonDigitCharacter => write!(
+                f,
+                "all characters except the check letter must be ASCII digits"
+            ),
+            FormatKind::InvalidCheckChar => write!(f, "invalid check letter"),
+            FormatKind::InvalidDistrict => write!(f, "district codes must not be 00"),
+        }
+    }
+}
+
+impl std::error::Error for FormatKind {}
+
+/// Errors that can occur when decoding a registration number.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum NidError {
+    /// The input string has an invalid format (wrong length, misplaced hyphens, illegal characters, district code `00`).
+    Format(FormatKind),
+    /// The check letter does not match the computed value.
+    Checksum,
+}
+
+impl NidError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`FormatKind::InvalidLength`] | `ZW_FORMAT_LENGTH` |
+    /// | [`FormatKind::InvalidSeparator`] | `ZW_FORMAT_SEPARATOR` |
+    /// | [`FormatKind::NonDigitCharacter`] | `ZW_FORMAT_NON_DIGIT` |
+    /// | [`FormatKind::InvalidCheckChar`] | `ZW_FORMAT_CHECK_CHAR` |
+    /// | [`FormatKind::InvalidDistrict`] | `ZW_FORMAT_DISTRICT` |
+    /// | [`NidError::Checksum`] | `ZW_CHECKSUM` |
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// let err = nidx::zimbabwe::validate("63123456C42").unwrap_err();
+    /// assert_eq!(err.error_code(), "ZW_CHECKSUM");
+    /// ```
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            NidError::Format(FormatKind::InvalidLength) => "ZW_FORMAT_LENGTH",
+            NidError::Format(FormatKind::InvalidSeparator) => "ZW_FORMAT_SEPARATOR",
+            NidError::Format(FormatKind::NonDigitCharacter) => "ZW_FORMAT_NON_DIGIT",
+            NidError::Format(FormatKind::InvalidCheckChar) => "ZW_FORMAT_CHECK_CHAR",
+            NidError::Format(FormatKind::InvalidDistrict) => "ZW_FORMAT_DISTRICT",
+            NidError::Checksum => "ZW_CHECKSUM",
+        }
+    }
+}
+
+impl fmt::Display for NidError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NidError::Format(kind) => write!(f, "format error: {kind}"),
+            NidError::Checksum => write!(f, "checksum validation failed"),
+        }
+    }
+}
+
+impl std::error::Error for NidError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            NidError::Format(kind) => Some(kind),
+            _ => None,
+        }
+    }
+}
+
+/// Whether `b` is one of the letters in [`CHECK_LETTERS`].
+const fn is_check_letter(b: u8) -> bool {
+    let mut i = 0;
+    while i < CHECK_LETTERS.len() {
+        if CHECK_LETTERS[i] == b {
+            return true;
+        }
+        i += 1;
+    }
+    false
+}
+
+/// Decode a Zimbabwean registration number, with or without hyphens.
+///
+/// The check letter is matched case-insensitively.
+///
+/// # Errors
+///
+/// Returns [`NidError::Format`] if the input has wrong length, misplaced
+/// hyphens, illegal characters or a district code of `00`, and
+/// [`NidError::Checksum`] if the check letter does not match.
+///
+/// # Examples
+///
+/// ```
+/// let info = nidx::zimbabwe::decode("08-1234567-r-08").unwrap();
+/// assert_eq!(info.serial, 1234567);
+/// ```
+#[must_use = "this returns the decoded registration number info; use `is_valid` if you only need a bool"]
+pub const fn decode(nid: &str) -> Result<NidInfo, NidError> {
+    let src = nid.as_bytes();
+    if src.len() > MAX_LEN {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let mut bytes = [0u8; MAX_LEN];
+    // Bit `n` is set when a hyphen precedes compact position `n`.
+    let mut hyphens = 0u16;
+    let mut len = 0;
+    let mut i = 0;
+    while i < src.len() {
+        if src[i] == b'-' {
+            if len == 0 || hyphens & (1 << len) != 0 {
+                return Err(NidError::Format(FormatKind::InvalidSeparator));
+            }
+            hyphens |= 1 << len;
+        } else {
+            bytes[len] = src[i].to_ascii_uppercase();
+            len += 1;
+        }
+        i += 1;
+    }
+    if len != 11 && len != 12 {
+        return Err(NidError::Format(FormatKind::InvalidLength));
+    }
+    let check_pos = len - 3;
+    let allowed = (1 << 2) | (1 << check_pos) | (1 << (check_pos + 1));
+    if hyphens & !allowed != 0 {
+        return Err(NidError::Format(FormatKind::InvalidSeparator));
+    }
+
+    let mut number = 0u32;
+    let mut i = 0;
+    while i < len {
+        if i != check_pos {
+            if !bytes[i].is_ascii_digit() {
+                return Err(NidError::Format(FormatKind::NonDigitCharacter));
+            }
+            if i < check_pos {
+                number = number * 10 + (bytes[i] - b'0') as u32;
+            }
+        }
+        i += 1;
+    }
+    let check = bytes[check_pos];
+    if !is_check_letter(check) {
+        return Err(NidError::Format(FormatKind::InvalidCheckChar));
+    }
+
+    let registration_district = (bytes[0] - b'0') * 10 + (bytes[1] - b'0');
+    let origin_district = (bytes[len - 2] - b'0') * 10 + (bytes[len - 1] - b'0');
+    if registration_district == 0 || origin_district == 0 {
+        return Err(NidError::Format(FormatKind::InvalidDistrict));
+    }
+    if CHECK_LETTERS[(number % 23) as usize] != check {
+        return Err(NidError::Checksum);
+    }
+
+    let divisor = if len == 12 { 10_000_000 } else { 1_000_000 };
+    Ok(NidInfo {
+        registration_district,
+        serial: number % divisor,
+        origin_district,
+    })
+}
+
+/// Decode a Zimbabwean registration number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`], for the pre-processed input.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let info = nidx::zimbabwe::decode_with(" 63-123456 B 42\n", &ParseOptions::lenient()).unwrap();
+/// assert_eq!(info.registration_district, 63);
+/// ```
+#[inline]
+#[must_use = "this returns the decoded registration number info; use `validate_with` if you only need a check"]
+pub fn decode_with(nid: &str, options: &ParseOptions) -> Result<NidInfo, NidError> {
+    decode(&options.prepare(nid))
+}
+
+/// Validate a Zimbabwean registration number string, returning a [`NidError`] on failure.
+///
+/// # Errors
+///
+/// Returns the same errors as [`decode`].
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::zimbabwe::validate("63123456B42").is_ok());
+/// assert!(nidx::zimbabwe::validate("invalid").is_err());
+/// ```
+#[inline]
+pub const fn validate(nid: &str) -> Result<(), NidError> {
+    match decode(nid) {
+        Ok(_) => Ok(()),
+        Err(e) => Err(e),
+    }
+}
+
+/// Validate a Zimbabwean registration number string after pre-processing it according to
+/// `options`.
+///
+/// # Errors
+///
+/// Returns the same errors as [`validate`], for the pre-processed input.
+#[inline]
+pub fn validate_with(nid: &str, options: &ParseOptions) -> Result<(), NidError> {
+    decode_with(nid, options).map(|_| ())
+}
+
+/// Check whether a Zimbabwean registration number string is valid.
+///
+/// # Examples
+///
+/// ```
+/// assert!(nidx::zimbabwe::is_valid("63123456B42"));
+/// assert!(!nidx::zimbabwe::is_valid("invalid"));
+/// ```
+#[inline]
+#[must_use]
+pub const fn is_valid(nid: &str) -> bool {
+    validate(nid).is_ok()
+}
+
+/// Return the canonical form of a registration number: whitespace and
+/// hyphens removed and the check letter uppercased.
+///
+/// This does not validate the input; pair it with [`validate`] before storing.
+///
+/// # Examples
+///
+/// ```
+/// assert_eq!(nidx::zimbabwe::normalize("63-123456 b 42"), "63123456B42");
+/// ```
+#[must_use]
+pub fn normalize(nid: &str) -> String {
+    nid.chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect()
+}
+
+/// Zero-sized marker implementing [`NationalId`] for Zimbabwean registration numbers.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::zimbabwe::Zimbabwe;
+///
+/// let info = Zimbabwe::decode("63-123456B42").unwrap();
+/// assert_eq!(info.origin_district, 42);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Zimbabwe;
+
+impl NationalId for Zimbabwe {
+    const COUNTRY: Country = Country::Zimbabwe;
+    type Info = NidInfo;
+    type Error = NidError;
+
+    #[inline]
+    fn decode(nid: &str) -> Result<NidInfo, NidError> {
+        decode(nid)
+    }
+
+    #[inline]
+    fn normalize(nid: &str) -> String {
+        normalize(nid)
+    }
+}
+
+impl EncodeId for Zimbabwe {
+    type Components = NidInfo;
+
+    /// Build the ID number without hyphens, with a six-digit serial when it
+    /// fits and seven digits otherwise.
+    fn encode(info: NidInfo) -> Result<String, EncodeError> {
+        let NidInfo {
+            registration_district,
+            serial,
+            origin_district,
+        } = info;
+        check_range("registration_district", registration_district, 1..=99)?;
+        check_range("serial", serial, 0..=9_999_999)?;
+        check_range("origin_district", origin_district, 1..=99)?;
+
+        let (serial, divisor) = if serial < 1_000_000 {
+            (format!("{serial:06}"), 1_000_000)
+        } else {
+            (serial.to_string(), 10_000_000)
+        };
+        let number = u32::from(registration_district) * divisor + info.serial;
+        let check = char::from(CHECK_LETTERS[(number % 23) as usize]);
+        Ok(format!(
+            "{registration_district:02}{serial}{check}{origin_district:02}"
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_valid() {
+        assert_eq!(
+            decode("63123456B42"),
+            Ok(NidInfo {
+                registration_district: 63,
+                serial: 123456,
+                origin_district: 42,
+            })
+        );
+        assert_eq!(
+            decode("081234567R08"),
+            Ok(NidInfo {
+                registration_district: 8,
+                serial: 1234567,
+                origin_district: 8,
+            })
+        );
+    }
+
+    #[test]
+    fn decode_hyphens_and_case() {
+        assert_eq!(decode("63-123456B42"), decode("63123456B42"));
+        assert_eq!(decode("63-123456-B-42"), decode("63123456B42"));
+        assert_eq!(decode("63123456b42"), decode("63123456B42"));
+    }
+
+    #[test]
+    fn remainder_zero_maps_to_z() {
+        assert!(decode("42000001Z42").is_ok());
+    }
+
+    #[test]
+    fn error_format() {
+        assert_eq!(
+            decode("6312345B42"),
+            Err(NidError::Format(FormatKind::InvalidLength))
+        );
+        assert_eq!(
+            decode("63-123-456B42"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            decode("63--123456B42"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            decode("-63123456B42"),
+            Err(NidError::Format(FormatKind::InvalidSeparator))
+        );
+        assert_eq!(
+            decode("63123X56B42"),
+            Err(NidError::Format(FormatKind::NonDigitCharacter))
+        );
+        assert_eq!(
+            decode("63123456I42"),
+            Err(NidError::Format(FormatKind::InvalidCheckChar))
+        );
+        assert_eq!(
+            decode("00123456B42"),
+            Err(NidError::Format(FormatKind::InvalidDistrict))
+        );
+        assert_eq!(
+            decode("63123456B00"),
+            Err(NidError::Format(FormatKind::InvalidDistrict))
+        );
+    }
+
+    #[test]
+    fn error_checksum() {
+        assert_eq!(decode("63123456C42"), Err(NidError::Checksum));
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        assert_eq!(
+            NidError::Format(FormatKind::InvalidDistrict).to_string(),
+            "format error: district codes must not be 00"
+        );
+        assert_eq!(NidError::Checksum.error_code(), "ZW_CHECKSUM");
+    }
+}
@@ -1,12 +1,12 @@
 use crate::Date;
 
 #[must_use]
-pub(crate) fn is_leap_year(year: u16) -> bool {
+pub(crate) const fn is_leap_year(year: u16) -> bool {
     (year % 4 == 0 && year % 100 != 0) || year % 400 == 0
 }
 
 #[must_use]
-pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
+pub(crate) const fn days_in_month(year: u16, month: u8) -> u8 {
     match month {
         1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
         4 | 6 | 9 | 11 => 30,
@@ -16,8 +16,8 @@ pub(crate) fn days_in_month(year: u16, month: u8) -> u8 {
     }
 }
 
-pub(crate) fn validate_date(year: u16, month: u8, day: u8) -> Option<Date> {
-    if !(1..=12).contains(&month) {
+pub(crate) const fn validate_date(year: u16, month: u8, day: u8) -> Option<Date> {
+    if month < 1 || month > 12 {
         return None;
     }
     if day < 1 || day > days_in_month(year, month) {
@@ -25,3 +25,77 @@ pub(crate) fn validate_date(year: u16, month: u8, day: u8) -> Option<Date> {
     }
     Some(Date { year, month, day })
 }
+
+/// Days since 1970-01-01 for a valid date (proleptic Gregorian calendar).
+pub(crate) const fn days_from_civil(date: Date) -> i32 {
+    let month = date.month as i32;
+    let year = date.year as i32 - if month <= 2 { 1 } else { 0 };
+    let era = year / 400;
+    let year_of_era = year - era * 400;
+    let shifted_month = if month > 2 { month - 3 } else { month + 9 };
+    let day_of_year = (153 * shifted_month + 2) / 5 + date.day as i32 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Inverse of [`days_from_civil`] for dates from year 0 onwards.
+pub(crate) const fn civil_from_days(days: i32) -> Date {
+    let days = days + 719_468;
+    let era = days / 146_097;
+    let day_of_era = days - era * 146_097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    Date {
+        year: year as u16,
+        month: month as u8,
+        day: day as u8,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn day_numbers_roundtrip() {
+        let epoch = Date {
+            year: 1970,
+            month: 1,
+            day: 1,
+        };
+        assert_eq!(days_from_civil(epoch), 0);
+        assert_eq!(civil_from_days(0), epoch);
+
+        let leap_day = Date {
+            year: 2000,
+            month: 2,
+            day: 29,
+        };
+        assert_eq!(days_from_civil(leap_day), 11_016);
+
+        let start = days_from_civil(Date {
+            year: 1800,
+            month: 1,
+            day: 1,
+        });
+        let end = days_from_civil(Date {
+            year: 2099,
+            month: 12,
+            day: 31,
+        });
+        for days in start..=end {
+            let date = civil_from_days(days);
+            assert!(validate_date(date.year, date.month, date.day).is_some());
+            assert_eq!(days_from_civil(date), days);
+        }
+    }
+}
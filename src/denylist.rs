@@ -0,0 +1,216 @@
+use std::collections::HashSet;
+
+use crate::{Country, Error, ParseOptions};
+
+/// Hashes a canonical ID, given as UTF-8 bytes.
+type Hasher = fn(&[u8]) -> Vec<u8>;
+
+/// A set of revoked or known-fraudulent ID numbers to reject on top of the
+/// format rules.
+///
+/// Entries are stored per [`Country`] in the canonical form returned by
+/// [`normalize`](crate::normalize), so lookups ignore case and surrounding
+/// whitespace just as validation does. Lists that may not hold IDs in clear
+/// text can be loaded as digests instead: set the hash function with
+/// [`with_hasher`](Denylist::with_hasher) and add entries with
+/// [`insert_hashed`](Denylist::insert_hashed). The crate has no hashing
+/// dependency, so the function is supplied by the caller and applied to the
+/// canonical ID as UTF-8 bytes.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, Denylist, Error, ParseOptions};
+///
+/// let mut denylist = Denylist::new();
+/// denylist.insert(Country::Kosovo, "1234567892");
+///
+/// assert_eq!(
+///     denylist.validate(Country::Kosovo, "1234567892"),
+///     Err(Error::Denied(Country::Kosovo))
+/// );
+/// assert!(denylist.validate(Country::Albania, "J00101999W").is_ok());
+///
+/// // Invalid numbers fail validation before the denylist is consulted.
+/// assert!(matches!(denylist.validate(Country::Kosovo, "1234567890"), Err(Error::Kosovo(_))));
+///
+/// let lenient = ParseOptions::lenient();
+/// assert_eq!(
+///     denylist.validate_with(Country::Kosovo, "123-456-789-2", &lenient),
+///     Err(Error::Denied(Country::Kosovo))
+/// );
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct Denylist {
+    exact: HashSet<(Country, String)>,
+    hashed: HashSet<(Country, Vec<u8>)>,
+    hasher: Option<Hasher>,
+}
+
+impl Denylist {
+    /// An empty denylist.
+    #[inline]
+    #[must_use]
+    pub fn new() -> Self {
+        Denylist::default()
+    }
+
+    /// Set the hash function used to look up entries added with
+    /// [`insert_hashed`](Self::insert_hashed).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::{Country, Denylist, Error};
+    ///
+    /// // Stand-in for a real digest such as SHA-256.
+    /// fn digest(id: &[u8]) -> Vec<u8> {
+    ///     id.iter().rev().copied().collect()
+    /// }
+    ///
+    /// let mut denylist = Denylist::new().with_hasher(digest);
+    /// denylist.insert_hashed(Country::Albania, digest(b"J00101999W"));
+    /// assert_eq!(
+    ///     denylist.validate(Country::Albania, "j00101999w"),
+    ///     Err(Error::Denied(Country::Albania))
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn with_hasher(mut self, hasher: fn(&[u8]) -> Vec<u8>) -> Self {
+        self.hasher = Some(hasher);
+        self
+    }
+
+    /// Add an ID number, returning `false` if it was already listed.
+    pub fn insert(&mut self, country: Country, nid: &str) -> bool {
+        self.exact.insert((country, crate::normalize(country, nid)))
+    }
+
+    /// Add the digest of a canonical ID number, as computed by the function
+    /// passed to [`with_hasher`](Self::with_hasher), returning `false` if it
+    /// was already listed.
+    pub fn insert_hashed(&mut self, country: Country, digest: impl Into<Vec<u8>>) -> bool {
+        self.hashed.insert((country, digest.into()))
+    }
+
+    /// `true` if the ID number, or its digest, is listed.
+    ///
+    /// This does not validate the ID.
+    #[must_use]
+    pub fn contains(&self, country: Country, nid: &str) -> bool {
+        let nid = crate::normalize(country, nid);
+        if self.hashed.is_empty() {
+            return self.exact.contains(&(country, nid));
+        }
+        let digest = self.hasher.map(|hasher| hasher(nid.as_bytes()));
+        self.exact.contains(&(country, nid))
+            || digest.is_some_and(|digest| self.hashed.contains(&(country, digest)))
+    }
+
+    /// The number of entries, exact and hashed.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.exact.len() + self.hashed.len()
+    }
+
+    /// `true` if the denylist has no entries.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.exact.is_empty() && self.hashed.is_empty()
+    }
+
+    /// Validate an ID issued by the given country, then reject it if it is
+    /// listed.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`validate`](crate::validate), and
+    /// [`Error::Denied`] if the ID is valid but listed.
+    pub fn validate(&self, country: Country, nid: &str) -> Result<(), Error> {
+        crate::validate(country, nid)?;
+        self.check(country, nid)
+    }
+
+    /// Like [`validate`](Self::validate), after pre-processing the input
+    /// according to `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`decode_with`](crate::decode_with), and
+    /// [`Error::Denied`] if the ID is valid but listed.
+    pub fn validate_with(
+        &self,
+        country: Country,
+        nid: &str,
+        options: &ParseOptions,
+    ) -> Result<(), Error> {
+        crate::decode_with(country, nid, options)?;
+        self.check(country, &options.prepare(nid))
+    }
+
+    fn check(&self, country: Country, nid: &str) -> Result<(), Error> {
+        if self.contains(country, nid) {
+            Err(Error::Denied(country))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+impl Extend<(Country, String)> for Denylist {
+    fn extend<T: IntoIterator<Item = (Country, String)>>(&mut self, iter: T) {
+        for (country, nid) in iter {
+            self.insert(country, &nid);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn digest(id: &[u8]) -> Vec<u8> {
+        id.iter().map(|b| b ^ 0x5a).collect()
+    }
+
+    #[test]
+    fn entries_are_normalized() {
+        let mut denylist = Denylist::new();
+        assert!(denylist.insert(Country::Albania, " j00101999w\n"));
+        assert!(!denylist.insert(Country::Albania, "J00101999W"));
+        assert!(denylist.contains(Country::Albania, "J00101999W"));
+        assert!(!denylist.contains(Country::Kosovo, "J00101999W"));
+        assert_eq!(denylist.len(), 1);
+    }
+
+    #[test]
+    fn hashed_entries_need_the_hasher() {
+        let mut denylist = Denylist::new();
+        denylist.insert_hashed(Country::Kosovo, digest(b"1234567892"));
+        assert!(!denylist.contains(Country::Kosovo, "1234567892"));
+
+        let mut denylist = denylist.with_hasher(digest);
+        assert!(denylist.contains(Country::Kosovo, " 1234567892 "));
+        assert!(!denylist.contains(Country::Kosovo, "1234567893"));
+        denylist.extend([(Country::Albania, "J00101999W".to_owned())]);
+        assert_eq!(denylist.len(), 2);
+        assert!(!denylist.is_empty());
+    }
+
+    #[test]
+    fn validate_checks_format_first() {
+        let mut denylist = Denylist::new();
+        denylist.insert(Country::Kosovo, "1234567890");
+        assert_eq!(
+            denylist.validate(Country::Kosovo, "1234567890"),
+            Err(Error::Kosovo(crate::kosovo::NidError::Checksum))
+        );
+        let err = Error::Denied(Country::Kosovo);
+        assert_eq!(err.error_code(), "DENIED");
+        assert_eq!(err.country(), Country::Kosovo);
+        assert_eq!(err.to_string(), "kosovo: number is on the denylist");
+    }
+}
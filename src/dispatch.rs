@@ -0,0 +1,964 @@
+//! Country-agnostic entry points that dispatch on a runtime [`Country`] value.
+
+use crate::era::{self, Era};
+use crate::placeholder::has_trivial_payload;
+use crate::{
+    Confidence, Country, Date, Error, MatchReport, ParseOptions, Plausibility, Sex, Summary,
+    albania, australia, austria, bahrain, belarus, botswana, chile, colombia, costa_rica, cyprus,
+    czechia, ecuador, egypt, finland, france, georgia, germany, ireland, italy, kenya, kosovo,
+    latvia, malaysia, north_macedonia, peru, poland, portugal, russia::inn, serbia, south_korea,
+    spain, switzerland, turkey, uae, ukraine, venezuela, vietnam, zimbabwe,
+};
+
+/// The summary of a format that carries no decodable data.
+const NO_SUMMARY: Summary = Summary {
+    birthday: None,
+    sex: None,
+    is_national: None,
+};
+
+/// The result of decoding an ID through [`decode`](crate::decode).
+///
+/// Each variant carries the country-specific information, if the country's
+/// format encodes any.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(tag = "country", rename_all = "snake_case"))]
+pub enum DecodedId {
+    /// A valid Albanian NID.
+    Albania(albania::NidInfo),
+    /// A valid Kosovo personal number. The format carries no decodable data.
+    Kosovo,
+    /// A valid Serbian JMBG.
+    Serbia(serbia::NidInfo),
+    /// A valid North Macedonian EMBG.
+    NorthMacedonia(north_macedonia::NidInfo),
+    /// A valid Italian codice fiscale.
+    Italy(italy::NidInfo),
+    /// A valid Spanish DNI or NIE.
+    Spain(spain::NidInfo),
+    /// A valid Portuguese Cartão de Cidadão number.
+    Portugal(portugal::NidInfo),
+    /// A valid French NIR.
+    France(france::NidInfo),
+    /// A valid German tax identification number. The format carries no decodable data.
+    Germany,
+    /// A valid Swiss AHV number. The format carries no decodable data.
+    Switzerland,
+    /// A valid Austrian social insurance number.
+    Austria(austria::NidInfo),
+    /// A valid Polish PESEL.
+    Poland(poland::NidInfo),
+    /// A valid Czech birth number.
+    Czechia(czechia::NidInfo),
+    /// A valid Ukrainian RNTRC.
+    Ukraine(ukraine::NidInfo),
+    /// A valid Turkish identity number. The format carries no decodable data.
+    Turkey,
+    /// A valid Finnish HETU.
+    Finland(finland::NidInfo),
+    /// A valid Latvian personal code.
+    Latvia(latvia::NidInfo),
+    /// A valid Irish PPSN. The format carries no decodable data.
+    Ireland,
+    /// A valid Chilean RUN. The format carries no decodable data.
+    Chile,
+    /// A valid South Korean RRN.
+    SouthKorea(south_korea::NidInfo),
+    /// A valid Malaysian NRIC.
+    Malaysia(malaysia::NidInfo),
+    /// A valid Egyptian national ID number.
+    Egypt(egypt::NidInfo),
+    /// A valid Australian TFN. The format carries no decodable data.
+    Australia,
+    /// A valid Belarusian identification number.
+    Belarus(belarus::NidInfo),
+    /// A valid Russian individual taxpayer number.
+    Russia(inn::InnInfo),
+    /// A valid Georgian personal number. The format carries no decodable data.
+    Georgia,
+    /// A valid Cypriot identity number.
+    Cyprus(cyprus::NidInfo),
+    /// A valid Emirates ID.
+    Uae(uae::NidInfo),
+    /// A valid Bahraini CPR number.
+    Bahrain(bahrain::NidInfo),
+    /// A valid Kenyan national ID number.
+    Kenya(kenya::NidInfo),
+    /// A valid Zimbabwean registration number.
+    Zimbabwe(zimbabwe::NidInfo),
+    /// A valid Botswana Omang number.
+    Botswana(botswana::NidInfo),
+    /// A valid Peruvian DNI.
+    Peru(peru::NidInfo),
+    /// A valid Ecuadorian cédula.
+    Ecuador(ecuador::NidInfo),
+    /// A valid Venezuelan cédula.
+    Venezuela(venezuela::NidInfo),
+    /// A valid Colombian identity number.
+    Colombia(colombia::NidInfo),
+    /// A valid Costa Rican identity number.
+    CostaRica(costa_rica::NidInfo),
+    /// A valid Vietnamese citizen identity number.
+    Vietnam(vietnam::NidInfo),
+}
+
+impl DecodedId {
+    /// The country that issued the ID.
+    #[inline]
+    #[must_use]
+    pub const fn country(&self) -> Country {
+        match self {
+            DecodedId::Albania(_) => Country::Albania,
+            DecodedId::Kosovo => Country::Kosovo,
+            DecodedId::Serbia(_) => Country::Serbia,
+            DecodedId::NorthMacedonia(_) => Country::NorthMacedonia,
+            DecodedId::Italy(_) => Country::Italy,
+            DecodedId::Spain(_) => Country::Spain,
+            DecodedId::Portugal(_) => Country::Portugal,
+            DecodedId::France(_) => Country::France,
+            DecodedId::Germany => Country::Germany,
+            DecodedId::Switzerland => Country::Switzerland,
+            DecodedId::Austria(_) => Country::Austria,
+            DecodedId::Poland(_) => Country::Poland,
+            DecodedId::Czechia(_) => Country::Czechia,
+            DecodedId::Ukraine(_) => Country::Ukraine,
+            DecodedId::Turkey => Country::Turkey,
+            DecodedId::Finland(_) => Country::Finland,
+            DecodedId::Latvia(_) => Country::Latvia,
+            DecodedId::Ireland => Country::Ireland,
+            DecodedId::Chile => Country::Chile,
+            DecodedId::SouthKorea(_) => Country::SouthKorea,
+            DecodedId::Malaysia(_) => Country::Malaysia,
+            DecodedId::Egypt(_) => Country::Egypt,
+            DecodedId::Australia => Country::Australia,
+            DecodedId::Belarus(_) => Country::Belarus,
+            DecodedId::Russia(_) => Country::Russia,
+            DecodedId::Georgia => Country::Georgia,
+            DecodedId::Cyprus(_) => Country::Cyprus,
+            DecodedId::Uae(_) => Country::Uae,
+            DecodedId::Bahrain(_) => Country::Bahrain,
+            DecodedId::Kenya(_) => Country::Kenya,
+            DecodedId::Zimbabwe(_) => Country::Zimbabwe,
+            DecodedId::Botswana(_) => Country::Botswana,
+            DecodedId::Peru(_) => Country::Peru,
+            DecodedId::Ecuador(_) => Country::Ecuador,
+            DecodedId::Venezuela(_) => Country::Venezuela,
+            DecodedId::Colombia(_) => Country::Colombia,
+            DecodedId::CostaRica(_) => Country::CostaRica,
+            DecodedId::Vietnam(_) => Country::Vietnam,
+        }
+    }
+
+    /// The numbering scheme the ID belongs to and the period it was issued
+    /// in.
+    #[inline]
+    #[must_use]
+    pub const fn era(&self) -> Era {
+        era::of(self)
+    }
+
+    /// The date of birth, if the format encodes one.
+    #[inline]
+    #[must_use]
+    pub const fn birthday(&self) -> Option<Date> {
+        self.summary().birthday
+    }
+
+    /// The biological sex, if the format encodes it.
+    #[inline]
+    #[must_use]
+    pub const fn sex(&self) -> Option<Sex> {
+        self.summary().sex
+    }
+
+    /// The decoded fields as a country-independent [`Summary`].
+    #[inline]
+    #[must_use]
+    pub const fn summary(&self) -> Summary {
+        match self {
+            DecodedId::Albania(info) => info.summary(),
+            DecodedId::Kosovo => NO_SUMMARY,
+            DecodedId::Serbia(info) => info.summary(),
+            DecodedId::NorthMacedonia(info) => info.summary(),
+            DecodedId::Italy(info) => info.summary(),
+            DecodedId::Spain(info) => info.summary(),
+            DecodedId::Portugal(info) => info.summary(),
+            DecodedId::France(info) => info.summary(),
+            DecodedId::Germany => NO_SUMMARY,
+            DecodedId::Switzerland => NO_SUMMARY,
+            DecodedId::Austria(info) => info.summary(),
+            DecodedId::Poland(info) => info.summary(),
+            DecodedId::Czechia(info) => info.summary(),
+            DecodedId::Ukraine(info) => info.summary(),
+            DecodedId::Turkey => NO_SUMMARY,
+            DecodedId::Finland(info) => info.summary(),
+            DecodedId::Latvia(info) => info.summary(),
+            DecodedId::Ireland => NO_SUMMARY,
+            DecodedId::Chile => NO_SUMMARY,
+            DecodedId::SouthKorea(info) => info.summary(),
+            DecodedId::Malaysia(info) => info.summary(),
+            DecodedId::Egypt(info) => info.summary(),
+            DecodedId::Australia => NO_SUMMARY,
+            DecodedId::Belarus(info) => info.summary(),
+            DecodedId::Russia(_) => NO_SUMMARY,
+            DecodedId::Georgia => NO_SUMMARY,
+            DecodedId::Cyprus(info) => info.summary(),
+            DecodedId::Uae(info) => info.summary(),
+            DecodedId::Bahrain(info) => info.summary(),
+            DecodedId::Kenya(info) => info.summary(),
+            DecodedId::Zimbabwe(info) => info.summary(),
+            DecodedId::Botswana(info) => info.summary(),
+            DecodedId::Peru(info) => info.summary(),
+            DecodedId::Ecuador(info) => info.summary(),
+            DecodedId::Venezuela(info) => info.summary(),
+            DecodedId::Colombia(info) => info.summary(),
+            DecodedId::CostaRica(info) => info.summary(),
+            DecodedId::Vietnam(info) => info.summary(),
+        }
+    }
+}
+
+/// Decode an ID issued by the given country.
+///
+/// # Errors
+///
+/// Returns an [`Error`] wrapping the country module's error if the ID is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, DecodedId};
+///
+/// let id = nidx::decode(Country::Albania, "J00101999W").unwrap();
+/// assert_eq!(id.country(), Country::Albania);
+/// assert_eq!(id.birthday().unwrap().to_string(), "1990-01-01");
+///
+/// assert_eq!(nidx::decode(Country::Kosovo, "1234567892"), Ok(DecodedId::Kosovo));
+/// ```
+#[must_use = "this returns the decoded ID; use `is_valid` if you only need a bool"]
+pub fn decode(country: Country, nid: &str) -> Result<DecodedId, Error> {
+    match country {
+        Country::Albania => albania::decode(nid)
+            .map(DecodedId::Albania)
+            .map_err(Error::from),
+        Country::Kosovo => kosovo::validate(nid)
+            .map(|()| DecodedId::Kosovo)
+            .map_err(Error::from),
+        Country::Serbia => serbia::decode(nid)
+            .map(DecodedId::Serbia)
+            .map_err(Error::from),
+        Country::NorthMacedonia => north_macedonia::decode(nid)
+            .map(DecodedId::NorthMacedonia)
+            .map_err(Error::from),
+        Country::Italy => italy::decode(nid)
+            .map(DecodedId::Italy)
+            .map_err(Error::from),
+        Country::Spain => spain::decode(nid)
+            .map(DecodedId::Spain)
+            .map_err(Error::from),
+        Country::Portugal => portugal::decode(nid)
+            .map(DecodedId::Portugal)
+            .map_err(Error::from),
+        Country::France => france::decode(nid)
+            .map(DecodedId::France)
+            .map_err(Error::from),
+        Country::Germany => germany::validate(nid)
+            .map(|()| DecodedId::Germany)
+            .map_err(Error::from),
+        Country::Switzerland => switzerland::validate(nid)
+            .map(|()| DecodedId::Switzerland)
+            .map_err(Error::from),
+        Country::Austria => austria::decode(nid)
+            .map(DecodedId::Austria)
+            .map_err(Error::from),
+        Country::Poland => poland::decode(nid)
+            .map(DecodedId::Poland)
+            .map_err(Error::from),
+        Country::Czechia => czechia::decode(nid)
+            .map(DecodedId::Czechia)
+            .map_err(Error::from),
+        Country::Ukraine => ukraine::decode(nid)
+            .map(DecodedId::Ukraine)
+            .map_err(Error::from),
+        Country::Turkey => turkey::validate(nid)
+            .map(|()| DecodedId::Turkey)
+            .map_err(Error::from),
+        Country::Finland => finland::decode(nid)
+            .map(DecodedId::Finland)
+            .map_err(Error::from),
+        Country::Latvia => latvia::decode(nid)
+            .map(DecodedId::Latvia)
+            .map_err(Error::from),
+        Country::Ireland => ireland::validate(nid)
+            .map(|()| DecodedId::Ireland)
+            .map_err(Error::from),
+        Country::Chile => chile::validate(nid)
+            .map(|()| DecodedId::Chile)
+            .map_err(Error::from),
+        Country::SouthKorea => south_korea::decode(nid)
+            .map(DecodedId::SouthKorea)
+            .map_err(Error::from),
+        Country::Malaysia => malaysia::decode(nid)
+            .map(DecodedId::Malaysia)
+            .map_err(Error::from),
+        Country::Egypt => egypt::decode(nid)
+            .map(DecodedId::Egypt)
+            .map_err(Error::from),
+        Country::Australia => australia::validate(nid)
+            .map(|()| DecodedId::Australia)
+            .map_err(Error::from),
+        Country::Belarus => belarus::decode(nid)
+            .map(DecodedId::Belarus)
+            .map_err(Error::from),
+        Country::Russia => inn::decode(nid).map(DecodedId::Russia).map_err(Error::from),
+        Country::Georgia => georgia::validate(nid)
+            .map(|()| DecodedId::Georgia)
+            .map_err(Error::from),
+        Country::Cyprus => cyprus::decode(nid)
+            .map(DecodedId::Cyprus)
+            .map_err(Error::from),
+        Country::Uae => uae::decode(nid).map(DecodedId::Uae).map_err(Error::from),
+        Country::Bahrain => bahrain::decode(nid)
+            .map(DecodedId::Bahrain)
+            .map_err(Error::from),
+        Country::Kenya => kenya::decode(nid)
+            .map(DecodedId::Kenya)
+            .map_err(Error::from),
+        Country::Zimbabwe => zimbabwe::decode(nid)
+            .map(DecodedId::Zimbabwe)
+            .map_err(Error::from),
+        Country::Botswana => botswana::decode(nid)
+            .map(DecodedId::Botswana)
+            .map_err(Error::from),
+        Country::Peru => peru::decode(nid).map(DecodedId::Peru).map_err(Error::from),
+        Country::Ecuador => ecuador::decode(nid)
+            .map(DecodedId::Ecuador)
+            .map_err(Error::from),
+        Country::Venezuela => venezuela::decode(nid)
+            .map(DecodedId::Venezuela)
+            .map_err(Error::from),
+        Country::Colombia => colombia::decode(nid)
+            .map(DecodedId::Colombia)
+            .map_err(Error::from),
+        Country::CostaRica => costa_rica::decode(nid)
+            .map(DecodedId::CostaRica)
+            .map_err(Error::from),
+        Country::Vietnam => vietnam::decode(nid)
+            .map(DecodedId::Vietnam)
+            .map_err(Error::from),
+    }
+}
+
+/// Decode an ID issued by the given country after pre-processing it according
+/// to `options`.
+///
+/// # Errors
+///
+/// Returns an [`Error`] wrapping the country module's error if the
+/// pre-processed ID is invalid, and [`Error::Placeholder`] if
+/// [`ParseOptions::reject_placeholders`] is set and the ID's digits, without
+/// a trailing check digit, are one digit repeated or a run such as
+/// `12345678`. Albania and Kosovo report placeholders with their own
+/// `NidError::Placeholder` instead, using their stricter rules.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, Error, ParseOptions};
+///
+/// let opts = ParseOptions::lenient();
+/// assert!(nidx::decode_with(Country::Kosovo, "123-456-789-2", &opts).is_ok());
+///
+/// let opts = ParseOptions::new().reject_placeholders(true);
+/// assert_eq!(
+///     nidx::decode_with(Country::Kenya, "12345678", &opts),
+///     Err(Error::Placeholder(Country::Kenya))
+/// );
+/// ```
+#[inline]
+#[must_use = "this returns the decoded ID; use `is_valid` if you only need a bool"]
+pub fn decode_with(
+    country: Country,
+    nid: &str,
+    options: &ParseOptions,
+) -> Result<DecodedId, Error> {
+    let decoded = match country {
+        Country::Albania => albania::decode_with(nid, options)
+            .map(DecodedId::Albania)
+            .map_err(Error::from),
+        Country::Kosovo => kosovo::validate_with(nid, options)
+            .map(|()| DecodedId::Kosovo)
+            .map_err(Error::from),
+        Country::Finland => finland::decode_with(nid, options)
+            .map(DecodedId::Finland)
+            .map_err(Error::from),
+        Country::Colombia => colombia::decode_with(nid, options)
+            .map(DecodedId::Colombia)
+            .map_err(Error::from),
+        Country::Italy => italy::decode_with(nid, options)
+            .map(DecodedId::Italy)
+            .map_err(Error::from),
+        Country::Austria => austria::decode_with(nid, options)
+            .map(DecodedId::Austria)
+            .map_err(Error::from),
+        Country::France => france::decode_with(nid, options)
+            .map(DecodedId::France)
+            .map_err(Error::from),
+        Country::Malaysia => malaysia::decode_with(nid, options)
+            .map(DecodedId::Malaysia)
+            .map_err(Error::from),
+        Country::Bahrain => bahrain::decode_with(nid, options)
+            .map(DecodedId::Bahrain)
+            .map_err(Error::from),
+        _ => decode(country, &options.prepare(nid)),
+    }?;
+    // Albania and Kosovo apply their own, stricter rules in `decode_with`.
+    if options.reject_placeholders
+        && !matches!(country, Country::Albania | Country::Kosovo)
+        && has_trivial_payload(nid)
+    {
+        return Err(Error::Placeholder(country));
+    }
+    Ok(decoded)
+}
+
+/// Validate an ID issued by the given country, returning an [`Error`] on failure.
+///
+/// # Errors
+///
+/// Returns an [`Error`] wrapping the country module's error if the ID is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+///
+/// assert!(nidx::validate(Country::Kosovo, "1234567892").is_ok());
+/// assert!(nidx::validate(Country::Albania, "1234567892").is_err());
+/// ```
+#[inline]
+pub fn validate(country: Country, nid: &str) -> Result<(), Error> {
+    decode(country, nid).map(|_| ())
+}
+
+/// Check whether an ID issued by the given country is valid.
+///
+/// This calls the country module's `is_valid` directly, skipping the
+/// [`DecodedId`] and [`Error`] wrappers that [`decode`] builds. Albania and
+/// the checksum-only formats of Australia, Georgia, Germany, Ireland, Kosovo,
+/// Switzerland and Turkey have validation-only paths that build no decoded
+/// information or error; the other countries' `is_valid` run their `decode`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+///
+/// assert!(nidx::is_valid(Country::Albania, "J00101999W"));
+/// assert!(!nidx::is_valid(Country::Kosovo, "J00101999W"));
+/// ```
+#[inline]
+#[must_use]
+pub fn is_valid(country: Country, nid: &str) -> bool {
+    match country {
+        Country::Albania => albania::is_valid(nid),
+        Country::Kosovo => kosovo::is_valid(nid),
+        Country::Serbia => serbia::is_valid(nid),
+        Country::NorthMacedonia => north_macedonia::is_valid(nid),
+        Country::Italy => italy::is_valid(nid),
+        Country::Spain => spain::is_valid(nid),
+        Country::Portugal => portugal::is_valid(nid),
+        Country::France => france::is_valid(nid),
+        Country::Germany => germany::is_valid(nid),
+        Country::Switzerland => switzerland::is_valid(nid),
+        Country::Austria => austria::is_valid(nid),
+        Country::Poland => poland::is_valid(nid),
+        Country::Czechia => czechia::is_valid(nid),
+        Country::Ukraine => ukraine::is_valid(nid),
+        Country::Turkey => turkey::is_valid(nid),
+        Country::Finland => finland::is_valid(nid),
+        Country::Latvia => latvia::is_valid(nid),
+        Country::Ireland => ireland::is_valid(nid),
+        Country::Chile => chile::is_valid(nid),
+        Country::SouthKorea => south_korea::is_valid(nid),
+        Country::Malaysia => malaysia::is_valid(nid),
+        Country::Egypt => egypt::is_valid(nid),
+        Country::Australia => australia::is_valid(nid),
+        Country::Belarus => belarus::is_valid(nid),
+        Country::Russia => inn::is_valid(nid),
+        Country::Georgia => georgia::is_valid(nid),
+        Country::Cyprus => cyprus::is_valid(nid),
+        Country::Uae => uae::is_valid(nid),
+        Country::Bahrain => bahrain::is_valid(nid),
+        Country::Kenya => kenya::is_valid(nid),
+        Country::Zimbabwe => zimbabwe::is_valid(nid),
+        Country::Botswana => botswana::is_valid(nid),
+        Country::Peru => peru::is_valid(nid),
+        Country::Ecuador => ecuador::is_valid(nid),
+        Country::Venezuela => venezuela::is_valid(nid),
+        Country::Colombia => colombia::is_valid(nid),
+        Country::CostaRica => costa_rica::is_valid(nid),
+        Country::Vietnam => vietnam::is_valid(nid),
+    }
+}
+
+/// Grade how far an ID issued by the given country gets through validation,
+/// for scoring pipelines that want more than a yes/no answer.
+///
+/// - [`Confidence::Invalid`]: the country rejects the ID for any reason
+///   other than its check character.
+/// - [`Confidence::Structure`]: the ID is rejected with the country's
+///   checksum error (an `error_code` ending in `_CHECKSUM`).
+/// - [`Confidence::Checksum`]: valid, but `plausibility` rejects the birthday
+///   it encodes.
+/// - [`Confidence::Plausible`]: valid, with a plausible birthday or none at
+///   all.
+///
+/// Albania and Kosovo are graded by [`albania::score`] and [`kosovo::score`],
+/// which separate the structure from the check character more precisely.
+/// [`Confidence::Issued`] is never returned; it needs a table of issued
+/// serials, see `albania::serial_ranges::score`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Confidence, Country, Date, Plausibility};
+///
+/// let check = Plausibility::new(Date { year: 2026, month: 10, day: 16 });
+/// assert_eq!(nidx::score(Country::Poland, "44051401359", &check), Confidence::Plausible);
+/// assert_eq!(nidx::score(Country::Poland, "44051401358", &check), Confidence::Structure);
+/// assert_eq!(nidx::score(Country::Germany, "65929970489", &check), Confidence::Plausible);
+/// assert_eq!(nidx::score(Country::Germany, "invalid", &check), Confidence::Invalid);
+/// ```
+#[must_use]
+pub fn score(country: Country, nid: &str, plausibility: &Plausibility) -> Confidence {
+    match country {
+        Country::Albania => albania::score(nid, plausibility),
+        Country::Kosovo => kosovo::score(nid, plausibility),
+        _ => match decode(country, nid) {
+            Ok(id) => match plausibility.check_summary(&id.summary()) {
+                Ok(()) => Confidence::Plausible,
+                Err(_) => Confidence::Checksum,
+            },
+            Err(e) if e.error_code().ends_with("_CHECKSUM") => Confidence::Structure,
+            Err(_) => Confidence::Invalid,
+        },
+    }
+}
+
+/// Return the canonical form of an ID issued by the given country, without
+/// validating it.
+///
+/// See the country modules' `normalize` functions for what each one does.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+///
+/// assert_eq!(nidx::normalize(Country::Albania, " j00101999w "), "J00101999W");
+/// assert_eq!(nidx::normalize(Country::Kosovo, "1234567892\n"), "1234567892");
+/// ```
+#[must_use]
+pub fn normalize(country: Country, nid: &str) -> String {
+    match country {
+        Country::Albania => albania::normalize(nid),
+        Country::Kosovo => kosovo::normalize(nid),
+        Country::Serbia => serbia::normalize(nid),
+        Country::NorthMacedonia => north_macedonia::normalize(nid),
+        Country::Italy => italy::normalize(nid),
+        Country::Spain => spain::normalize(nid),
+        Country::Portugal => portugal::normalize(nid),
+        Country::France => france::normalize(nid),
+        Country::Germany => germany::normalize(nid),
+        Country::Switzerland => switzerland::normalize(nid),
+        Country::Austria => austria::normalize(nid),
+        Country::Poland => poland::normalize(nid),
+        Country::Czechia => czechia::normalize(nid),
+        Country::Ukraine => ukraine::normalize(nid),
+        Country::Turkey => turkey::normalize(nid),
+        Country::Finland => finland::normalize(nid),
+        Country::Latvia => latvia::normalize(nid),
+        Country::Ireland => ireland::normalize(nid),
+        Country::Chile => chile::normalize(nid),
+        Country::SouthKorea => south_korea::normalize(nid),
+        Country::Malaysia => malaysia::normalize(nid),
+        Country::Egypt => egypt::normalize(nid),
+        Country::Australia => australia::normalize(nid),
+        Country::Belarus => belarus::normalize(nid),
+        Country::Russia => inn::normalize(nid),
+        Country::Georgia => georgia::normalize(nid),
+        Country::Cyprus => cyprus::normalize(nid),
+        Country::Uae => uae::normalize(nid),
+        Country::Bahrain => bahrain::normalize(nid),
+        Country::Kenya => kenya::normalize(nid),
+        Country::Zimbabwe => zimbabwe::normalize(nid),
+        Country::Botswana => botswana::normalize(nid),
+        Country::Peru => peru::normalize(nid),
+        Country::Ecuador => ecuador::normalize(nid),
+        Country::Venezuela => venezuela::normalize(nid),
+        Country::Colombia => colombia::normalize(nid),
+        Country::CostaRica => costa_rica::normalize(nid),
+        Country::Vietnam => vietnam::normalize(nid),
+    }
+}
+
+/// Decode an ID issued by the given country and check it against declared
+/// personal data.
+///
+/// Works for every country: fields the country's format does not encode are
+/// reported as [`MatchReport::unverified`] rather than compared.
+///
+/// # Errors
+///
+/// Returns an [`Error`] wrapping the country module's error if the ID is invalid.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, Date, Field, Sex};
+///
+/// let birthday = Date { year: 1990, month: 1, day: 1 };
+/// let report = nidx::verify_matches(Country::Albania, "J00101999W", birthday, Sex::Male).unwrap();
+/// assert!(report.is_full_match());
+///
+/// let report = nidx::verify_matches(Country::Kosovo, "1234567892", birthday, Sex::Male).unwrap();
+/// assert!(report.is_match());
+/// assert_eq!(report.unverified(), [Field::Birthday, Field::Sex]);
+/// ```
+pub fn verify_matches(
+    country: Country,
+    nid: &str,
+    expected_birthday: Date,
+    expected_sex: Sex,
+) -> Result<MatchReport, Error> {
+    let id = decode(country, nid)?;
+    Ok(MatchReport::compare(
+        &id.summary(),
+        expected_birthday,
+        expected_sex,
+    ))
+}
+
+/// Validate an ID against the rules in force on the date `as_of`, e.g. the
+/// issue date of a historical document, and return the [`Era`] of its scheme.
+///
+/// The ID must be valid under the current rules, and must not belong to a
+/// scheme introduced after `as_of` or encode a birthday after it.
+///
+/// # Errors
+///
+/// Returns an [`Error`] wrapping the country module's error if the ID is
+/// invalid, or [`Error::NotYetIssued`] if it could not have been issued by
+/// `as_of`.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, Date, Error};
+///
+/// let as_of = Date { year: 2015, month: 3, day: 1 };
+/// let era = nidx::validate_as_of(Country::Latvia, "161175-19997", as_of).unwrap();
+/// assert_eq!(era.name, "legacy");
+///
+/// assert_eq!(
+///     nidx::validate_as_of(Country::Latvia, "32579461005", as_of),
+///     Err(Error::NotYetIssued {
+///         country: Country::Latvia,
+///         as_of,
+///         earliest: Date { year: 2017, month: 7, day: 1 },
+///     })
+/// );
+/// ```
+pub fn validate_as_of(country: Country, nid: &str, as_of: Date) -> Result<Era, Error> {
+    let id = decode(country, nid)?;
+    match era::earliest_issue(&id) {
+        Some(earliest) if earliest > as_of => Err(Error::NotYetIssued {
+            country,
+            as_of,
+            earliest,
+        }),
+        _ => Ok(id.era()),
+    }
+}
+
+/// Detect which supported countries the input is a valid ID for.
+///
+/// Every country's validator is tried in [`Country::ALL`] order, and the
+/// countries that accept the input are returned. An empty vector means the
+/// input is not valid anywhere.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+///
+/// assert_eq!(nidx::detect("J00101999W"), vec![Country::Albania]);
+/// assert_eq!(nidx::detect("1234567892"), vec![Country::Kosovo]);
+/// assert!(nidx::detect("invalid").is_empty());
+/// ```
+#[must_use]
+pub fn detect(nid: &str) -> Vec<Country> {
+    Country::ALL
+        .iter()
+        .copied()
+        .filter(|&country| is_valid(country, nid))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn score_grades_every_fixture() {
+        let check = Plausibility::new(Date {
+            year: 2026,
+            month: 10,
+            day: 16,
+        });
+        for fixture in crate::fixtures::ALL {
+            let confidence = score(fixture.country, fixture.nid, &check);
+            let expected = match fixture.expected {
+                // Valid without a check digit, so only the structure is known.
+                Ok(_)
+                    if fixture.country == Country::Kosovo
+                        && !kosovo::is_valid_strict(fixture.nid) =>
+                {
+                    Confidence::Structure
+                }
+                Ok(_) => Confidence::Plausible,
+                Err(code) if code.ends_with("_CHECKSUM") => Confidence::Structure,
+                Err(_) => Confidence::Invalid,
+            };
+            assert_eq!(confidence, expected, "{}: {}", fixture.country, fixture.nid);
+        }
+    }
+
+    #[test]
+    fn is_valid_agrees_with_decode() {
+        for &country in Country::ALL {
+            let samples = crate::fixtures::for_country(country)
+                .map(|fixture| fixture.nid.to_owned())
+                .chain((0..200).map(|index| crate::generator::generate_seeded(country, 9, index)));
+            for nid in samples {
+                let mut variants = vec![nid.to_lowercase(), format!("{nid}0")];
+                for at in 0..nid.len() {
+                    for replacement in ["0", "7", "9", "A", "z", "-", ".", "\u{e9}"] {
+                        if nid.is_char_boundary(at) && nid.is_char_boundary(at + 1) {
+                            let mut variant = nid.clone();
+                            variant.replace_range(at..=at, replacement);
+                            variants.push(variant);
+                        }
+                    }
+                }
+                variants.push(nid);
+                for variant in variants {
+                    assert_eq!(
+                        is_valid(country, &variant),
+                        decode(country, &variant).is_ok(),
+                        "{country}: {variant}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn decode_albania() {
+        let id = decode(Country::Albania, "J00101999W").unwrap();
+        assert_eq!(
+            id,
+            DecodedId::Albania(albania::decode("J00101999W").unwrap())
+        );
+        assert_eq!(id.country(), Country::Albania);
+        assert_eq!(id.sex(), Some(Sex::Male));
+    }
+
+    #[test]
+    fn decode_kosovo() {
+        let id = decode(Country::Kosovo, "1234567892").unwrap();
+        assert_eq!(id, DecodedId::Kosovo);
+        assert_eq!(id.country(), Country::Kosovo);
+        assert_eq!(id.birthday(), None);
+        assert_eq!(id.sex(), None);
+    }
+
+    #[test]
+    fn summary_per_country() {
+        let id = decode(Country::Albania, "J00101999W").unwrap();
+        assert_eq!(id.summary().to_string(), "1990-01-01 M national");
+        let id = decode(Country::Kosovo, "1234567892").unwrap();
+        assert_eq!(id.summary(), Summary::default());
+        assert_eq!(id.summary().to_string(), "");
+    }
+
+    #[test]
+    fn decode_wraps_country_error() {
+        assert_eq!(
+            decode(Country::Albania, "short"),
+            Err(Error::Albania(albania::NidError::Format(
+                albania::FormatKind::InvalidLength
+            )))
+        );
+        assert_eq!(
+            decode(Country::Kosovo, "1234567890"),
+            Err(Error::Kosovo(kosovo::NidError::Checksum))
+        );
+    }
+
+    #[test]
+    fn is_valid_dispatches() {
+        assert!(is_valid(Country::Albania, "J00101999W"));
+        assert!(!is_valid(Country::Albania, "1234567892"));
+        assert!(is_valid(Country::Kosovo, "1234567892"));
+        assert!(!is_valid(Country::Kosovo, "J00101999W"));
+    }
+
+    #[test]
+    fn decode_with_applies_options() {
+        let opts = ParseOptions::lenient();
+        assert_eq!(
+            decode_with(Country::Albania, "J0 0101 999W", &opts),
+            decode(Country::Albania, "J00101999W")
+        );
+        assert!(decode_with(Country::Albania, "J0 0101 999W", &ParseOptions::new()).is_err());
+    }
+
+    #[test]
+    fn decode_with_keeps_finnish_century_sign() {
+        assert_eq!(
+            decode_with(Country::Finland, " 131052-308T\n", &ParseOptions::lenient()),
+            decode(Country::Finland, "131052-308T")
+        );
+    }
+
+    #[test]
+    fn decode_with_keeps_colombian_nit_hyphen() {
+        assert_eq!(
+            decode_with(Country::Colombia, "79.555.123-7", &ParseOptions::lenient()),
+            decode(Country::Colombia, "79555123-7")
+        );
+    }
+
+    #[test]
+    fn decode_with_applies_century_policy() {
+        let options = ParseOptions::new().century(crate::CenturyPolicy::Pivot(90));
+        let id = decode_with(Country::Bahrain, "850612349", &options).unwrap();
+        let DecodedId::Bahrain(info) = id else {
+            panic!("expected a Bahraini ID");
+        };
+        assert_eq!(info.birth_year, 2085);
+        let id = decode_with(Country::Malaysia, "850615-10-1235", &options).unwrap();
+        assert_eq!(id.summary().birthday.map(|d| d.year), Some(2085));
+    }
+
+    #[test]
+    fn verify_matches_dispatches() {
+        let birthday = Date {
+            year: 1985,
+            month: 12,
+            day: 10,
+        };
+        let report = verify_matches(Country::Italy, "RSSMRA85T10A562S", birthday, Sex::Female);
+        assert_eq!(
+            report.unwrap().mismatches(),
+            [crate::Mismatch::Sex {
+                expected: Sex::Female,
+                found: Sex::Male,
+            }]
+        );
+        assert!(verify_matches(Country::Italy, "invalid", birthday, Sex::Male).is_err());
+    }
+
+    #[test]
+    fn decode_with_rejects_placeholders() {
+        let options = ParseOptions::new().reject_placeholders(true);
+        assert_eq!(
+            decode_with(Country::Kosovo, "1234567892", &options),
+            Err(Error::Kosovo(kosovo::NidError::Placeholder))
+        );
+        assert_eq!(
+            decode_with(Country::Albania, "J00101999W", &options),
+            Err(Error::Albania(albania::NidError::Placeholder))
+        );
+        assert_eq!(
+            decode_with(Country::Chile, "11.111.111-1", &options),
+            Err(Error::Placeholder(Country::Chile))
+        );
+        assert_eq!(
+            decode_with(Country::Chile, "11.111.111-1", &ParseOptions::new()).map(|_| ()),
+            Ok(())
+        );
+        assert!(decode_with(Country::Kenya, "31415926", &options).is_ok());
+    }
+
+    #[test]
+    fn validate_as_of_checks_issue_dates() {
+        let as_of = Date {
+            year: 1989,
+            month: 12,
+            day: 31,
+        };
+        assert_eq!(
+            validate_as_of(Country::Albania, "J00101999W", as_of),
+            Err(Error::NotYetIssued {
+                country: Country::Albania,
+                as_of,
+                earliest: Date {
+                    year: 1990,
+                    month: 1,
+                    day: 1,
+                },
+            })
+        );
+        let era = validate_as_of(Country::Czechia, "530101123", as_of).unwrap();
+        assert_eq!(era.issued_until.unwrap().year, 1953);
+        assert!(validate_as_of(Country::Kenya, "123456789", as_of).is_err());
+        assert_eq!(
+            validate_as_of(Country::Kenya, "12345678", as_of)
+                .unwrap()
+                .name,
+            "legacy"
+        );
+        assert!(validate_as_of(Country::Kosovo, "1234567892", as_of).is_ok());
+        assert!(validate_as_of(Country::Kosovo, "1234567890", as_of).is_err());
+        let err = validate_as_of(Country::Kenya, "123456789", as_of).unwrap_err();
+        assert_eq!(err.error_code(), "NOT_YET_ISSUED");
+        assert_eq!(
+            err.to_string(),
+            "kenya: number not issued before 2023-01-01, checked as of 1989-12-31"
+        );
+    }
+
+    #[test]
+    fn normalize_dispatches() {
+        assert_eq!(normalize(Country::Albania, "j00101999w"), "J00101999W");
+        assert_eq!(normalize(Country::Kosovo, " 1234567892 "), "1234567892");
+    }
+
+    #[test]
+    fn detect_finds_issuing_country() {
+        assert_eq!(detect("J00101999W"), vec![Country::Albania]);
+        assert_eq!(detect("j00101999w"), vec![Country::Albania]);
+        assert_eq!(detect("1234567892"), vec![Country::Kosovo]);
+    }
+
+    #[test]
+    fn detect_rejects_everywhere() {
+        assert!(detect("").is_empty());
+        assert!(detect("1234567890").is_empty());
+        assert!(detect("J00101999A").is_empty());
+    }
+
+    #[test]
+    fn country_name_roundtrip() {
+        for &country in Country::ALL {
+            assert_eq!(country.to_string().parse::<Country>(), Ok(country));
+        }
+        assert_eq!("ALBANIA".parse::<Country>(), Ok(Country::Albania));
+        assert!("atlantis".parse::<Country>().is_err());
+    }
+}
@@ -0,0 +1,193 @@
+use std::collections::HashMap;
+use std::hash::Hash;
+
+use crate::{Country, ParseOptions};
+
+/// An ID that appears in more than one row of a batch.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Duplicate<R> {
+    /// The issuing country.
+    pub country: Country,
+    /// The canonical form of the ID.
+    pub nid: String,
+    /// The record ids of the rows holding the ID, in input order.
+    pub records: Vec<R>,
+}
+
+/// A record that holds more than one distinct ID for the same country.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Conflict<R> {
+    /// The record id.
+    pub record: R,
+    /// The issuing country.
+    pub country: Country,
+    /// The distinct canonical IDs held by the record, in input order.
+    pub nids: Vec<String>,
+}
+
+/// The result of [`find_duplicates`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct DuplicateReport<R> {
+    duplicates: Vec<Duplicate<R>>,
+    conflicts: Vec<Conflict<R>>,
+}
+
+impl<R> DuplicateReport<R> {
+    /// `true` if the batch has neither duplicates nor conflicts.
+    #[inline]
+    #[must_use]
+    pub fn is_clean(&self) -> bool {
+        self.duplicates.is_empty() && self.conflicts.is_empty()
+    }
+
+    /// The IDs that appear in more than one row, in order of first
+    /// appearance.
+    #[inline]
+    #[must_use]
+    pub fn duplicates(&self) -> &[Duplicate<R>] {
+        &self.duplicates
+    }
+
+    /// The records holding more than one distinct ID for a country, in order
+    /// of first appearance.
+    #[inline]
+    #[must_use]
+    pub fn conflicts(&self) -> &[Conflict<R>] {
+        &self.conflicts
+    }
+}
+
+/// Find duplicate and conflicting IDs in a batch of `(record id, country,
+/// nid)` rows.
+///
+/// IDs are compared in canonical form: whitespace and `-`/`.`/`/`
+/// separators are stripped as with [`ParseOptions::lenient`], then the
+/// country's [`normalize`](crate::normalize) is applied, so `j00101999w` and
+/// `J0-0101-999W` are the same ID. The IDs are not validated; run
+/// [`validate`](crate::validate) first if invalid rows should be excluded.
+///
+/// A *duplicate* is an ID held by more than one row; a *conflict* is a
+/// record id that holds more than one distinct ID for the same country.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+///
+/// let rows = [
+///     ("alice", Country::Albania, "J00101999W"),
+///     ("bob", Country::Albania, "j0 0101 999w"),
+///     ("alice", Country::Albania, "H55312123U"),
+///     ("carol", Country::Kosovo, "1234567892"),
+/// ];
+/// let report = nidx::find_duplicates(rows);
+///
+/// let [duplicate] = report.duplicates() else { panic!() };
+/// assert_eq!(duplicate.nid, "J00101999W");
+/// assert_eq!(duplicate.records, ["alice", "bob"]);
+///
+/// let [conflict] = report.conflicts() else { panic!() };
+/// assert_eq!(conflict.record, "alice");
+/// assert_eq!(conflict.nids, ["J00101999W", "H55312123U"]);
+/// ```
+pub fn find_duplicates<R, S, I>(rows: I) -> DuplicateReport<R>
+where
+    R: Clone + Eq + Hash,
+    S: AsRef<str>,
+    I: IntoIterator<Item = (R, Country, S)>,
+{
+    let options = ParseOptions::lenient();
+    let mut by_id: HashMap<(Country, String), usize> = HashMap::new();
+    let mut groups: Vec<Duplicate<R>> = Vec::new();
+    let mut by_record: HashMap<(R, Country), usize> = HashMap::new();
+    let mut holdings: Vec<Conflict<R>> = Vec::new();
+
+    for (record, country, nid) in rows {
+        let nid = crate::normalize(country, &options.prepare(nid.as_ref()));
+
+        let index = *by_record
+            .entry((record.clone(), country))
+            .or_insert_with(|| {
+                holdings.push(Conflict {
+                    record: record.clone(),
+                    country,
+                    nids: Vec::new(),
+                });
+                holdings.len() - 1
+            });
+        let nids = &mut holdings[index].nids;
+        if !nids.contains(&nid) {
+            nids.push(nid.clone());
+        }
+
+        let index = *by_id.entry((country, nid)).or_insert_with_key(|(_, nid)| {
+            groups.push(Duplicate {
+                country,
+                nid: nid.clone(),
+                records: Vec::new(),
+            });
+            groups.len() - 1
+        });
+        groups[index].records.push(record);
+    }
+
+    groups.retain(|group| group.records.len() > 1);
+    holdings.retain(|holding| holding.nids.len() > 1);
+    DuplicateReport {
+        duplicates: groups,
+        conflicts: holdings,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn canonical_forms_collide() {
+        let report = find_duplicates([
+            (1, Country::Czechia, "780123/1230"),
+            (2, Country::Czechia, "7801231230"),
+            (3, Country::Kosovo, "7801231230"),
+            (2, Country::Czechia, " 780123-1230 "),
+        ]);
+        assert_eq!(
+            report.duplicates(),
+            [Duplicate {
+                country: Country::Czechia,
+                nid: "7801231230".to_owned(),
+                records: vec![1, 2, 2],
+            }]
+        );
+        assert!(report.conflicts().is_empty());
+    }
+
+    #[test]
+    fn conflicts_are_per_country() {
+        let report = find_duplicates(vec![
+            ("a", Country::Albania, "J00101999W".to_owned()),
+            ("a", Country::Kosovo, "1234567892".to_owned()),
+            ("a", Country::Albania, "H55312123U".to_owned()),
+            ("a", Country::Albania, "h55312123u".to_owned()),
+        ]);
+        assert_eq!(
+            report.conflicts(),
+            [Conflict {
+                record: "a",
+                country: Country::Albania,
+                nids: vec!["J00101999W".to_owned(), "H55312123U".to_owned()],
+            }]
+        );
+        assert_eq!(report.duplicates()[0].records, ["a", "a"]);
+        assert!(crate::albania::is_valid("H55312123U"));
+    }
+
+    #[test]
+    fn clean_batch() {
+        let report = find_duplicates([
+            (1, Country::Albania, "J00101999W"),
+            (2, Country::Kosovo, "1234567892"),
+        ]);
+        assert!(report.is_clean());
+    }
+}
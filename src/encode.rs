@@ -0,0 +1,249 @@
+use std::fmt;
+use std::ops::RangeInclusive;
+
+use crate::date::validate_date;
+use crate::{Date, NationalId, Sex};
+
+/// Build IDs from the components they encode.
+///
+/// Implemented by the marker types of countries whose IDs are fully
+/// determined by their decoded information: Albania, Serbia, North
+/// Macedonia, France, Portugal, Finland, Belarus, Egypt, the UAE, Bahrain,
+/// Zimbabwe, Venezuela and Vietnam. For each of them `Components` is the
+/// decoded [`NationalId::Info`], and every `x` that encodes decodes back to
+/// itself: `T::decode(&T::encode(x)?) == Ok(x)`. Countries whose decoded
+/// information leaves out part of the ID, such as the Polish serial or the
+/// Italian name letters, do not implement it.
+///
+/// Where the format offers several spellings of the same information, such
+/// as Finnish century signs or the temporary French sex digits, the result
+/// uses the canonical one.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::serbia::{NidInfo, Serbia};
+/// use nidx::{Date, EncodeId, NationalId, Sex};
+///
+/// let info = NidInfo {
+///     birthday: Date { year: 1990, month: 1, day: 1 },
+///     sex: Sex::Male,
+///     region: 71,
+///     serial: 0,
+/// };
+/// let nid = Serbia::encode(info).unwrap();
+/// assert_eq!(nid, "0101990710008");
+/// assert_eq!(Serbia::decode(&nid), Ok(info));
+/// ```
+pub trait EncodeId: NationalId {
+    /// The fields an ID is built from.
+    type Components;
+
+    /// Build the canonical ID holding `components`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an [`EncodeError`] if the format cannot hold the components,
+    /// e.g. an invalid birthday or a serial with too many digits.
+    fn encode(components: Self::Components) -> Result<String, EncodeError>;
+}
+
+/// Errors returned by [`EncodeId::encode`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum EncodeError {
+    /// The birthday is not a valid calendar date.
+    InvalidDate,
+    /// A component is outside the range the format can hold, such as a birth
+    /// year a two-digit year field would read as another century.
+    OutOfRange {
+        /// The name of the component, e.g. `"serial"`.
+        field: &'static str,
+    },
+    /// The format only encodes [`Sex::Male`] and [`Sex::Female`].
+    UnsupportedSex {
+        /// The requested sex.
+        sex: Sex,
+    },
+    /// Two components contradict each other, such as a sex that the serial
+    /// does not encode.
+    Inconsistent {
+        /// The name of the component that disagrees, e.g. `"sex"`.
+        field: &'static str,
+    },
+}
+
+impl fmt::Display for EncodeError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EncodeError::InvalidDate => write!(f, "birthday is not a valid calendar date"),
+            EncodeError::OutOfRange { field } => {
+                write!(f, "{field} is outside the range the format can hold")
+            }
+            EncodeError::UnsupportedSex { sex } => {
+                write!(f, "sex {sex} cannot be encoded, only M and F")
+            }
+            EncodeError::Inconsistent { field } => {
+                write!(f, "{field} contradicts the other components")
+            }
+        }
+    }
+}
+
+impl std::error::Error for EncodeError {}
+
+/// Whether `sex` is encoded as female, failing for sexes other than male and
+/// female.
+pub(crate) fn is_female(sex: Sex) -> Result<bool, EncodeError> {
+    match sex {
+        Sex::Male => Ok(false),
+        Sex::Female => Ok(true),
+        sex => Err(EncodeError::UnsupportedSex { sex }),
+    }
+}
+
+/// Check that `birthday` exists and its year lies within `years`.
+pub(crate) fn check_birthday(
+    birthday: Date,
+    years: RangeInclusive<u16>,
+) -> Result<(), EncodeError> {
+    let Date { year, month, day } = birthday;
+    if validate_date(year, month, day).is_none() {
+        return Err(EncodeError::InvalidDate);
+    }
+    check_range("birthday", year, years)
+}
+
+/// Check that the component `field` lies within `range`.
+pub(crate) fn check_range<T: PartialOrd>(
+    field: &'static str,
+    value: T,
+    range: RangeInclusive<T>,
+) -> Result<(), EncodeError> {
+    if range.contains(&value) {
+        Ok(())
+    } else {
+        Err(EncodeError::OutOfRange { field })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fmt::Debug;
+
+    use super::*;
+    use crate::{
+        albania, bahrain, belarus, egypt, finland, france, north_macedonia, portugal, serbia, uae,
+        venezuela, vietnam, zimbabwe,
+    };
+
+    /// Decode sample IDs of `T`, then check that their components encode to
+    /// an ID decoding to the same components.
+    fn roundtrip<T>()
+    where
+        T: EncodeId<Components = <T as NationalId>::Info>,
+        T::Info: Copy + PartialEq + Debug,
+        T::Error: PartialEq,
+    {
+        let fixtures = crate::fixtures::for_country(T::COUNTRY)
+            .filter(|fixture| fixture.is_valid())
+            .map(|fixture| fixture.nid.to_owned());
+        let generated =
+            (0..500).map(|index| crate::generator::generate_seeded(T::COUNTRY, 3, index));
+        for nid in fixtures.chain(generated) {
+            let info = T::decode(&T::normalize(&nid)).unwrap();
+            let encoded = T::encode(info).unwrap();
+            assert_eq!(T::decode(&encoded), Ok(info), "{nid} -> {encoded}");
+            assert_eq!(T::normalize(&encoded), encoded);
+        }
+    }
+
+    #[test]
+    fn every_implementation_roundtrips() {
+        roundtrip::<albania::Albania>();
+        roundtrip::<serbia::Serbia>();
+        roundtrip::<north_macedonia::NorthMacedonia>();
+        roundtrip::<france::France>();
+        roundtrip::<portugal::Portugal>();
+        roundtrip::<finland::Finland>();
+        roundtrip::<belarus::Belarus>();
+        roundtrip::<egypt::Egypt>();
+        roundtrip::<uae::Uae>();
+        roundtrip::<bahrain::Bahrain>();
+        roundtrip::<zimbabwe::Zimbabwe>();
+        roundtrip::<venezuela::Venezuela>();
+        roundtrip::<vietnam::Vietnam>();
+    }
+
+    #[test]
+    fn canonical_spellings() {
+        let info = finland::decode("010594Y9021").unwrap();
+        assert_eq!(finland::Finland::encode(info).unwrap(), "010594-9021");
+        let info = france::decode("785057800608482").unwrap();
+        assert_eq!(france::France::encode(info).unwrap(), "185057800608491");
+    }
+
+    #[test]
+    fn rejects_components_the_format_cannot_hold() {
+        let info = serbia::decode("0101990710008").unwrap();
+        assert_eq!(
+            serbia::Serbia::encode(serbia::NidInfo {
+                sex: Sex::Female,
+                ..info
+            }),
+            Err(EncodeError::Inconsistent { field: "sex" })
+        );
+        assert_eq!(
+            serbia::Serbia::encode(serbia::NidInfo {
+                serial: 1000,
+                ..info
+            }),
+            Err(EncodeError::OutOfRange { field: "serial" })
+        );
+        assert_eq!(
+            serbia::Serbia::encode(serbia::NidInfo { region: 45, ..info }),
+            Err(EncodeError::OutOfRange { field: "region" })
+        );
+        assert_eq!(
+            serbia::Serbia::encode(serbia::NidInfo {
+                birthday: Date {
+                    year: 1990,
+                    month: 2,
+                    day: 30,
+                },
+                ..info
+            }),
+            Err(EncodeError::InvalidDate)
+        );
+
+        let info = albania::decode("J00101999W").unwrap();
+        assert_eq!(
+            albania::Albania::encode(albania::NidInfo {
+                month_code: 51,
+                ..info
+            }),
+            Err(EncodeError::Inconsistent {
+                field: "month_code"
+            })
+        );
+        assert_eq!(
+            albania::Albania::encode(albania::NidInfo {
+                sex: Sex::Other,
+                ..info
+            }),
+            Err(EncodeError::UnsupportedSex { sex: Sex::Other })
+        );
+
+        let info = bahrain::decode("850612349").unwrap();
+        assert_eq!(
+            bahrain::Bahrain::encode(bahrain::NidInfo {
+                birth_year: 1885,
+                ..info
+            }),
+            Err(EncodeError::OutOfRange {
+                field: "birth_year"
+            })
+        );
+    }
+}
@@ -0,0 +1,266 @@
+use std::iter;
+
+use crate::date::days_from_civil;
+use crate::{Country, Date, Sex, albania};
+
+const DIGITS: &[u8] = b"0123456789";
+
+/// The characters an ID may hold at each position, one alphabet per
+/// position.
+#[derive(Debug, Clone, Default)]
+struct Layout(Vec<Vec<u8>>);
+
+impl Layout {
+    fn fixed(mut self, chars: &str) -> Self {
+        self.0.extend(chars.bytes().map(|b| vec![b]));
+        self
+    }
+
+    fn any(mut self, alphabet: &[u8], count: usize) -> Self {
+        self.0.extend(iter::repeat_n(alphabet.to_vec(), count));
+        self
+    }
+}
+
+/// Iterate over every valid ID of `country` encoding `birthday`, in canonical
+/// form, or `None` if the country's space is too large to enumerate.
+///
+/// The iterator walks every candidate in the layouts that can encode the
+/// birthday, such as all serial numbers and both sex markers, and yields
+/// those that pass validation and decode to `birthday`. It is exhaustive, so
+/// differential tests can compare nidx against a reference implementation on
+/// every ID of a date rather than a sample. At most about a million
+/// candidates are checked per birthday.
+///
+/// Enumeration is supported for Albania, Serbia, North Macedonia, Austria,
+/// Poland, Czechia, Ukraine, Finland, Latvia (legacy numbers) and Malaysia.
+/// Other countries return `None`: their IDs encode no full date, or free
+/// fields such as name letters make the space intractable. A birthday the
+/// format cannot encode gives an empty iterator.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, Date};
+///
+/// let birthday = Date { year: 1990, month: 1, day: 1 };
+/// let nids: Vec<String> = nidx::enumerate(Country::Albania, birthday).unwrap().collect();
+/// assert_eq!(nids.len(), 4000);
+/// assert!(nids.contains(&"J00101999W".to_owned()));
+///
+/// assert!(nidx::enumerate(Country::Italy, birthday).is_none());
+/// ```
+#[must_use]
+pub fn enumerate(country: Country, birthday: Date) -> Option<Enumerate> {
+    let Date { year, month, day } = birthday;
+    let yy = year % 100;
+    let yymmdd = |month: u8| format!("{yy:02}{month:02}{day:02}");
+    let ddmmyy = format!("{day:02}{month:02}{yy:02}");
+
+    let layouts: Vec<Layout> = match country {
+        Country::Albania => [Sex::Male, Sex::Female]
+            .into_iter()
+            .flat_map(|sex| [(sex, true), (sex, false)])
+            .filter_map(|(sex, is_national)| albania::encode(birthday, sex, is_national, 0).ok())
+            .map(|nid| {
+                Layout::default()
+                    .fixed(&nid[..6])
+                    .any(DIGITS, 3)
+                    .any(b"WABCDEFGHIJKLMNOPQRSTUV", 1)
+            })
+            .collect(),
+        Country::Serbia | Country::NorthMacedonia => vec![
+            Layout::default()
+                .fixed(&format!("{day:02}{month:02}{:03}", year % 1000))
+                .any(DIGITS, 6),
+        ],
+        Country::Austria => vec![Layout::default().any(DIGITS, 4).fixed(&ddmmyy)],
+        Country::Poland => match year / 100 {
+            18 => Some(80),
+            19 => Some(0),
+            20 => Some(20),
+            21 => Some(40),
+            22 => Some(60),
+            _ => None,
+        }
+        .map(|offset| {
+            Layout::default()
+                .fixed(&yymmdd(month + offset))
+                .any(DIGITS, 5)
+        })
+        .into_iter()
+        .collect(),
+        Country::Czechia => [0, 50, 20, 70]
+            .into_iter()
+            .flat_map(|offset| {
+                [3, 4].map(|count| {
+                    Layout::default()
+                        .fixed(&yymmdd(month + offset))
+                        .any(DIGITS, count)
+                })
+            })
+            .collect(),
+        Country::Ukraine => {
+            let days = days_from_civil(birthday)
+                - days_from_civil(Date {
+                    year: 1899,
+                    month: 12,
+                    day: 31,
+                });
+            (1..=99_999)
+                .contains(&days)
+                .then(|| {
+                    Layout::default()
+                        .fixed(&format!("{days:05}"))
+                        .any(DIGITS, 5)
+                })
+                .into_iter()
+                .collect()
+        }
+        Country::Finland => vec![
+            Layout::default()
+                .fixed(&ddmmyy)
+                .any(b"+-ABCDEFUVWXY", 1)
+                .any(DIGITS, 3)
+                .any(b"0123456789ABCDEFHJKLMNPRSTUVWXY", 1),
+        ],
+        Country::Latvia => vec![Layout::default().fixed(&ddmmyy).any(DIGITS, 5)],
+        Country::Malaysia => vec![Layout::default().fixed(&yymmdd(month)).any(DIGITS, 6)],
+        _ => return None,
+    };
+
+    let first = layouts.first().map_or(0, |layout| layout.0.len());
+    Some(Enumerate {
+        country,
+        birthday,
+        layouts,
+        layout: 0,
+        odometer: vec![0; first],
+        buffer: Vec::new(),
+    })
+}
+
+/// Iterator over the valid IDs of a birthday, returned by [`enumerate`].
+#[derive(Debug, Clone)]
+pub struct Enumerate {
+    country: Country,
+    birthday: Date,
+    layouts: Vec<Layout>,
+    layout: usize,
+    odometer: Vec<usize>,
+    buffer: Vec<u8>,
+}
+
+impl Iterator for Enumerate {
+    type Item = String;
+
+    fn next(&mut self) -> Option<String> {
+        while let Some(layout) = self.layouts.get(self.layout) {
+            self.buffer.clear();
+            self.buffer.extend(
+                layout
+                    .0
+                    .iter()
+                    .zip(&self.odometer)
+                    .map(|(alphabet, &i)| alphabet[i]),
+            );
+
+            // Advance to the next candidate, moving on to the next layout
+            // once every position has wrapped around.
+            let wrapped = self
+                .odometer
+                .iter_mut()
+                .zip(&layout.0)
+                .rev()
+                .all(|(i, alphabet)| {
+                    *i = (*i + 1) % alphabet.len();
+                    *i == 0
+                });
+            if wrapped {
+                self.layout += 1;
+                let len = self.layouts.get(self.layout).map_or(0, |next| next.0.len());
+                self.odometer.clear();
+                self.odometer.resize(len, 0);
+            }
+
+            let nid = std::str::from_utf8(&self.buffer).expect("layouts are ASCII");
+            if crate::decode(self.country, nid).is_ok_and(|id| id.birthday() == Some(self.birthday))
+            {
+                return Some(nid.to_owned());
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SUPPORTED: [Country; 10] = [
+        Country::Albania,
+        Country::Serbia,
+        Country::NorthMacedonia,
+        Country::Austria,
+        Country::Poland,
+        Country::Czechia,
+        Country::Ukraine,
+        Country::Finland,
+        Country::Latvia,
+        Country::Malaysia,
+    ];
+
+    #[test]
+    fn yields_valid_canonical_ids_of_the_birthday() {
+        let birthday = Date {
+            year: 2000,
+            month: 2,
+            day: 29,
+        };
+        for country in SUPPORTED {
+            let nids: Vec<String> = enumerate(country, birthday).unwrap().collect();
+            assert!(!nids.is_empty(), "{country}");
+            for nid in &nids {
+                assert_eq!(&crate::normalize(country, nid), nid);
+                let id = crate::decode(country, nid).unwrap();
+                assert_eq!(id.birthday(), Some(birthday), "{country}: {nid}");
+            }
+        }
+    }
+
+    #[test]
+    fn contains_every_generated_id() {
+        for country in SUPPORTED {
+            for index in 0..2 {
+                let nid = crate::generator::generate_seeded(country, 3, index);
+                let nid = crate::normalize(country, &nid);
+                let birthday = crate::decode(country, &nid).unwrap().birthday().unwrap();
+                assert!(
+                    enumerate(country, birthday)
+                        .unwrap()
+                        .any(|found| found == nid),
+                    "{country}: {nid}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn counts_match_the_format() {
+        let birthday = Date {
+            year: 1990,
+            month: 1,
+            day: 1,
+        };
+        let count = |country| enumerate(country, birthday).unwrap().count();
+        assert_eq!(count(Country::Albania), 4000);
+        assert_eq!(count(Country::Poland), 10_000);
+        let old = Date {
+            year: 1799,
+            month: 12,
+            day: 31,
+        };
+        assert_eq!(enumerate(Country::Albania, old).unwrap().count(), 0);
+        assert!(enumerate(Country::Kosovo, birthday).is_none());
+    }
+}
@@ -0,0 +1,157 @@
+use std::fmt;
+
+use crate::{Date, DecodedId, colombia, kenya, latvia};
+
+/// A numbering scheme of a country, with the period it was issued in.
+///
+/// Returned by [`DecodedId::era`] and [`validate_as_of`](crate::validate_as_of).
+/// Formats that never changed have a single era named `"current"` with no
+/// known dates. When only the year of a change is known, the era is taken to
+/// begin on 1 January so that older documents are not rejected by mistake.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, Date};
+///
+/// let id = nidx::decode(Country::Latvia, "32579461005").unwrap();
+/// let era = id.era();
+/// assert_eq!(era.name, "opaque");
+/// assert_eq!(era.issued_from, Some(Date { year: 2017, month: 7, day: 1 }));
+/// assert_eq!(era.issued_until, None);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Era {
+    /// A short name for the scheme, e.g. `"legacy"` or `"Maisha Namba"`.
+    pub name: &'static str,
+    /// The first day numbers of this scheme were issued, if known.
+    pub issued_from: Option<Date>,
+    /// The last day numbers of this scheme were issued, or `None` if they
+    /// still are. Numbers issued earlier remain valid.
+    pub issued_until: Option<Date>,
+}
+
+impl fmt::Display for Era {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name)
+    }
+}
+
+const fn date(year: u16, month: u8, day: u8) -> Option<Date> {
+    Some(Date { year, month, day })
+}
+
+/// The single era of a format that never changed.
+const CURRENT: Era = Era {
+    name: "current",
+    issued_from: None,
+    issued_until: None,
+};
+
+const CZECHIA_9_DIGIT: Era = Era {
+    name: "9-digit",
+    issued_from: None,
+    issued_until: date(1953, 12, 31),
+};
+
+const CZECHIA_10_DIGIT: Era = Era {
+    name: "10-digit",
+    issued_from: date(1954, 1, 1),
+    issued_until: None,
+};
+
+const LATVIA_LEGACY: Era = Era {
+    name: "legacy",
+    issued_from: None,
+    issued_until: date(2017, 6, 30),
+};
+
+const LATVIA_OPAQUE: Era = Era {
+    name: "opaque",
+    issued_from: date(2017, 7, 1),
+    issued_until: None,
+};
+
+const KENYA_LEGACY: Era = Era {
+    name: "legacy",
+    issued_from: None,
+    issued_until: None,
+};
+
+const KENYA_MAISHA_NAMBA: Era = Era {
+    name: "Maisha Namba",
+    issued_from: date(2023, 1, 1),
+    issued_until: None,
+};
+
+const COLOMBIA_CEDULA: Era = Era {
+    name: "cédula",
+    issued_from: None,
+    issued_until: None,
+};
+
+const COLOMBIA_NUIP: Era = Era {
+    name: "NUIP",
+    issued_from: None,
+    issued_until: None,
+};
+
+/// The era of the scheme a decoded ID belongs to.
+pub(crate) const fn of(id: &DecodedId) -> Era {
+    match id {
+        DecodedId::Czechia(info) if info.birthday.year < 1954 => CZECHIA_9_DIGIT,
+        DecodedId::Czechia(_) => CZECHIA_10_DIGIT,
+        DecodedId::Latvia(info) => match info.scheme {
+            latvia::Scheme::Legacy => LATVIA_LEGACY,
+            latvia::Scheme::Opaque => LATVIA_OPAQUE,
+        },
+        DecodedId::Kenya(info) => match info.generation {
+            kenya::Generation::Legacy => KENYA_LEGACY,
+            kenya::Generation::MaishaNamba => KENYA_MAISHA_NAMBA,
+        },
+        DecodedId::Colombia(info) => match info.class {
+            colombia::DocumentClass::Cedula => COLOMBIA_CEDULA,
+            colombia::DocumentClass::Nuip => COLOMBIA_NUIP,
+        },
+        _ => CURRENT,
+    }
+}
+
+/// The earliest date on which `id` could have been issued, if any is known:
+/// the start of its era or the encoded birthday, whichever is later.
+pub(crate) fn earliest_issue(id: &DecodedId) -> Option<Date> {
+    of(id).issued_from.max(id.birthday())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Country;
+
+    fn era(country: Country, nid: &str) -> Era {
+        of(&crate::decode(country, nid).unwrap())
+    }
+
+    #[test]
+    fn eras_follow_scheme() {
+        assert_eq!(era(Country::Latvia, "161175-19997"), LATVIA_LEGACY);
+        assert_eq!(era(Country::Kenya, "123456789"), KENYA_MAISHA_NAMBA);
+        assert_eq!(era(Country::Kenya, "12345678"), KENYA_LEGACY);
+        assert_eq!(era(Country::Colombia, "1020304050").to_string(), "NUIP");
+        assert_eq!(era(Country::Czechia, "530101123"), CZECHIA_9_DIGIT);
+        assert_eq!(era(Country::Czechia, "7801231230"), CZECHIA_10_DIGIT);
+        assert_eq!(era(Country::Albania, "J00101999W"), CURRENT);
+    }
+
+    #[test]
+    fn earliest_issue_takes_later_date() {
+        let id = crate::decode(Country::Latvia, "32579461005").unwrap();
+        assert_eq!(earliest_issue(&id), date(2017, 7, 1));
+        let id = crate::decode(Country::Czechia, "0421231239").unwrap();
+        assert_eq!(earliest_issue(&id), date(2004, 1, 23));
+        let id = crate::decode(Country::Kosovo, "1234567892").unwrap();
+        assert_eq!(earliest_issue(&id), None);
+    }
+}
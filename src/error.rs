@@ -0,0 +1,664 @@
+use std::fmt;
+
+use crate::{
+    Country, Date, albania, australia, austria, bahrain, belarus, botswana, chile, colombia,
+    costa_rica, cyprus, czechia, ecuador, egypt, finland, france, georgia, germany, ireland, italy,
+    kenya, kosovo, latvia, malaysia, north_macedonia, peru, poland, portugal, russia::inn, serbia,
+    south_korea, spain, switzerland, turkey, uae, ukraine, venezuela, vietnam, zimbabwe,
+};
+
+/// Crate-level error wrapping the error of any country module.
+///
+/// Functions that handle IDs from several countries can return this single
+/// type; every country's `NidError` converts into it with `?`. The original
+/// error stays available by matching on the variant or through
+/// [`source`](std::error::Error::source).
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Country, Error};
+///
+/// fn check_both(al: &str, xk: &str) -> Result<(), Error> {
+///     nidx::albania::validate(al)?;
+///     nidx::kosovo::validate(xk)?;
+///     Ok(())
+/// }
+///
+/// let err = check_both("J00101999W", "1234567890").unwrap_err();
+/// assert_eq!(err.country(), Country::Kosovo);
+/// assert_eq!(err.to_string(), "kosovo: checksum validation failed");
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum Error {
+    /// The ID is not a valid Albanian NID.
+    Albania(albania::NidError),
+    /// The ID is not a valid Kosovo personal number.
+    Kosovo(kosovo::NidError),
+    /// The ID is not a valid Serbian JMBG.
+    Serbia(serbia::NidError),
+    /// The ID is not a valid North Macedonian EMBG.
+    NorthMacedonia(north_macedonia::NidError),
+    /// The ID is not a valid Italian codice fiscale.
+    Italy(italy::NidError),
+    /// The ID is not a valid Spanish DNI or NIE.
+    Spain(spain::NidError),
+    /// The ID is not a valid Portuguese Cartão de Cidadão number.
+    Portugal(portugal::NidError),
+    /// The ID is not a valid French NIR.
+    France(france::NidError),
+    /// The ID is not a valid German tax identification number.
+    Germany(germany::NidError),
+    /// The ID is not a valid Swiss AHV number.
+    Switzerland(switzerland::NidError),
+    /// The ID is not a valid Austrian social insurance number.
+    Austria(austria::NidError),
+    /// The ID is not a valid Polish PESEL.
+    Poland(poland::NidError),
+    /// The ID is not a valid Czech birth number.
+    Czechia(czechia::NidError),
+    /// The ID is not a valid Ukrainian RNTRC.
+    Ukraine(ukraine::NidError),
+    /// The ID is not a valid Turkish identity number.
+    Turkey(turkey::NidError),
+    /// The ID is not a valid Finnish HETU.
+    Finland(finland::NidError),
+    /// The ID is not a valid Latvian personal code.
+    Latvia(latvia::NidError),
+    /// The ID is not a valid Irish PPSN.
+    Ireland(ireland::NidError),
+    /// The ID is not a valid Chilean RUN.
+    Chile(chile::NidError),
+    /// The ID is not a valid South Korean RRN.
+    SouthKorea(south_korea::NidError),
+    /// The ID is not a valid Malaysian NRIC.
+    Malaysia(malaysia::NidError),
+    /// The ID is not a valid Egyptian national ID number.
+    Egypt(egypt::NidError),
+    /// The ID is not a valid Australian TFN.
+    Australia(australia::NidError),
+    /// The ID is not a valid Belarusian identification number.
+    Belarus(belarus::NidError),
+    /// The ID is not a valid Russian individual taxpayer number.
+    Russia(inn::InnError),
+    /// The ID is not a valid Georgian personal number.
+    Georgia(georgia::NidError),
+    /// The ID is not a valid Cypriot identity number.
+    Cyprus(cyprus::NidError),
+    /// The ID is not a valid Emirates ID.
+    Uae(uae::NidError),
+    /// The ID is not a valid Bahraini CPR number.
+    Bahrain(bahrain::NidError),
+    /// The ID is not a valid Kenyan national ID number.
+    Kenya(kenya::NidError),
+    /// The ID is not a valid Zimbabwean registration number.
+    Zimbabwe(zimbabwe::NidError),
+    /// The ID is not a valid Botswana Omang number.
+    Botswana(botswana::NidError),
+    /// The ID is not a valid Peruvian DNI.
+    Peru(peru::NidError),
+    /// The ID is not a valid Ecuadorian cédula.
+    Ecuador(ecuador::NidError),
+    /// The ID is not a valid Venezuelan cédula.
+    Venezuela(venezuela::NidError),
+    /// The ID is not a valid Colombian identity number.
+    Colombia(colombia::NidError),
+    /// The ID is not a valid Costa Rican identity number.
+    CostaRica(costa_rica::NidError),
+    /// The ID is not a valid Vietnamese citizen identity number.
+    Vietnam(vietnam::NidError),
+    /// The ID is valid but listed in a [`Denylist`](crate::Denylist) of
+    /// voided numbers.
+    Denied(Country),
+    /// The ID is valid but an obvious placeholder, rejected by
+    /// [`decode_with`](crate::decode_with) because
+    /// [`ParseOptions::reject_placeholders`](crate::ParseOptions::reject_placeholders)
+    /// is set.
+    Placeholder(Country),
+    /// The ID is valid but could not have been issued by the date given to
+    /// [`validate_as_of`](crate::validate_as_of): its scheme was introduced,
+    /// or its holder born, later.
+    NotYetIssued {
+        /// The issuing country.
+        country: Country,
+        /// The date the ID was checked against.
+        as_of: Date,
+        /// The earliest date the ID could have been issued.
+        earliest: Date,
+    },
+}
+
+impl Error {
+    /// The country whose validation rules rejected the ID, or whose
+    /// denylist entry or issue dates ruled it out.
+    #[inline]
+    #[must_use]
+    pub const fn country(&self) -> Country {
+        match self {
+            Error::Albania(_) => Country::Albania,
+            Error::Kosovo(_) => Country::Kosovo,
+            Error::Serbia(_) => Country::Serbia,
+            Error::NorthMacedonia(_) => Country::NorthMacedonia,
+            Error::Italy(_) => Country::Italy,
+            Error::Spain(_) => Country::Spain,
+            Error::Portugal(_) => Country::Portugal,
+            Error::France(_) => Country::France,
+            Error::Germany(_) => Country::Germany,
+            Error::Switzerland(_) => Country::Switzerland,
+            Error::Austria(_) => Country::Austria,
+            Error::Poland(_) => Country::Poland,
+            Error::Czechia(_) => Country::Czechia,
+            Error::Ukraine(_) => Country::Ukraine,
+            Error::Turkey(_) => Country::Turkey,
+            Error::Finland(_) => Country::Finland,
+            Error::Latvia(_) => Country::Latvia,
+            Error::Ireland(_) => Country::Ireland,
+            Error::Chile(_) => Country::Chile,
+            Error::SouthKorea(_) => Country::SouthKorea,
+            Error::Malaysia(_) => Country::Malaysia,
+            Error::Egypt(_) => Country::Egypt,
+            Error::Australia(_) => Country::Australia,
+            Error::Belarus(_) => Country::Belarus,
+            Error::Russia(_) => Country::Russia,
+            Error::Georgia(_) => Country::Georgia,
+            Error::Cyprus(_) => Country::Cyprus,
+            Error::Uae(_) => Country::Uae,
+            Error::Bahrain(_) => Country::Bahrain,
+            Error::Kenya(_) => Country::Kenya,
+            Error::Zimbabwe(_) => Country::Zimbabwe,
+            Error::Botswana(_) => Country::Botswana,
+            Error::Peru(_) => Country::Peru,
+            Error::Ecuador(_) => Country::Ecuador,
+            Error::Venezuela(_) => Country::Venezuela,
+            Error::Colombia(_) => Country::Colombia,
+            Error::CostaRica(_) => Country::CostaRica,
+            Error::Vietnam(_) => Country::Vietnam,
+            Error::Denied(country)
+            | Error::Placeholder(country)
+            | Error::NotYetIssued { country, .. } => *country,
+        }
+    }
+
+    /// The stable, machine-readable code of the wrapped country error.
+    ///
+    /// Codes are prefixed with the country's ISO alpha-2 code (e.g.
+    /// `AL_CHECKSUM`, `XK_FORMAT_LENGTH`); see the country modules'
+    /// `NidError::error_code` for the full lists. [`Error::Denied`] has the
+    /// code `DENIED`, [`Error::Placeholder`] `PLACEHOLDER` and
+    /// [`Error::NotYetIssued`] `NOT_YET_ISSUED`.
+    #[inline]
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            Error::Albania(e) => e.error_code(),
+            Error::Kosovo(e) => e.error_code(),
+            Error::Serbia(e) => e.error_code(),
+            Error::NorthMacedonia(e) => e.error_code(),
+            Error::Italy(e) => e.error_code(),
+            Error::Spain(e) => e.error_code(),
+            Error::Portugal(e) => e.error_code(),
+            Error::France(e) => e.error_code(),
+            Error::Germany(e) => e.error_code(),
+            Error::Switzerland(e) => e.error_code(),
+            Error::Austria(e) => e.error_code(),
+            Error::Poland(e) => e.error_code(),
+            Error::Czechia(e) => e.error_code(),
+            Error::Ukraine(e) => e.error_code(),
+            Error::Turkey(e) => e.error_code(),
+            Error::Finland(e) => e.error_code(),
+            Error::Latvia(e) => e.error_code(),
+            Error::Ireland(e) => e.error_code(),
+            Error::Chile(e) => e.error_code(),
+            Error::SouthKorea(e) => e.error_code(),
+            Error::Malaysia(e) => e.error_code(),
+            Error::Egypt(e) => e.error_code(),
+            Error::Australia(e) => e.error_code(),
+            Error::Belarus(e) => e.error_code(),
+            Error::Russia(e) => e.error_code(),
+            Error::Georgia(e) => e.error_code(),
+            Error::Cyprus(e) => e.error_code(),
+            Error::Uae(e) => e.error_code(),
+            Error::Bahrain(e) => e.error_code(),
+            Error::Kenya(e) => e.error_code(),
+            Error::Zimbabwe(e) => e.error_code(),
+            Error::Botswana(e) => e.error_code(),
+            Error::Peru(e) => e.error_code(),
+            Error::Ecuador(e) => e.error_code(),
+            Error::Venezuela(e) => e.error_code(),
+            Error::Colombia(e) => e.error_code(),
+            Error::CostaRica(e) => e.error_code(),
+            Error::Vietnam(e) => e.error_code(),
+            Error::Denied(_) => "DENIED",
+            Error::Placeholder(_) => "PLACEHOLDER",
+            Error::NotYetIssued { .. } => "NOT_YET_ISSUED",
+        }
+    }
+}
+
+impl fmt::Display for Error {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Albania(e) => write!(f, "albania: {e}"),
+            Error::Kosovo(e) => write!(f, "kosovo: {e}"),
+            Error::Serbia(e) => write!(f, "serbia: {e}"),
+            Error::NorthMacedonia(e) => write!(f, "north_macedonia: {e}"),
+            Error::Italy(e) => write!(f, "italy: {e}"),
+            Error::Spain(e) => write!(f, "spain: {e}"),
+            Error::Portugal(e) => write!(f, "portugal: {e}"),
+            Error::France(e) => write!(f, "france: {e}"),
+            Error::Germany(e) => write!(f, "germany: {e}"),
+            Error::Switzerland(e) => write!(f, "switzerland: {e}"),
+            Error::Austria(e) => write!(f, "austria: {e}"),
+            Error::Poland(e) => write!(f, "poland: {e}"),
+            Error::Czechia(e) => write!(f, "czechia: {e}"),
+            Error::Ukraine(e) => write!(f, "ukraine: {e}"),
+            Error::Turkey(e) => write!(f, "turkey: {e}"),
+            Error::Finland(e) => write!(f, "finland: {e}"),
+            Error::Latvia(e) => write!(f, "latvia: {e}"),
+            Error::Ireland(e) => write!(f, "ireland: {e}"),
+            Error::Chile(e) => write!(f, "chile: {e}"),
+            Error::SouthKorea(e) => write!(f, "south_korea: {e}"),
+            Error::Malaysia(e) => write!(f, "malaysia: {e}"),
+            Error::Egypt(e) => write!(f, "egypt: {e}"),
+            Error::Australia(e) => write!(f, "australia: {e}"),
+            Error::Belarus(e) => write!(f, "belarus: {e}"),
+            Error::Russia(e) => write!(f, "russia: {e}"),
+            Error::Georgia(e) => write!(f, "georgia: {e}"),
+            Error::Cyprus(e) => write!(f, "cyprus: {e}"),
+            Error::Uae(e) => write!(f, "uae: {e}"),
+            Error::Bahrain(e) => write!(f, "bahrain: {e}"),
+            Error::Kenya(e) => write!(f, "kenya: {e}"),
+            Error::Zimbabwe(e) => write!(f, "zimbabwe: {e}"),
+            Error::Botswana(e) => write!(f, "botswana: {e}"),
+            Error::Peru(e) => write!(f, "peru: {e}"),
+            Error::Ecuador(e) => write!(f, "ecuador: {e}"),
+            Error::Venezuela(e) => write!(f, "venezuela: {e}"),
+            Error::Colombia(e) => write!(f, "colombia: {e}"),
+            Error::CostaRica(e) => write!(f, "costa_rica: {e}"),
+            Error::Vietnam(e) => write!(f, "vietnam: {e}"),
+            Error::Denied(country) => write!(f, "{country}: number is on the denylist"),
+            Error::Placeholder(country) => write!(f, "{country}: number is a placeholder"),
+            Error::NotYetIssued {
+                country,
+                as_of,
+                earliest,
+            } => write!(
+                f,
+                "{country}: number not issued before {earliest}, checked as of {as_of}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Albania(e) => Some(e),
+            Error::Kosovo(e) => Some(e),
+            Error::Serbia(e) => Some(e),
+            Error::NorthMacedonia(e) => Some(e),
+            Error::Italy(e) => Some(e),
+            Error::Spain(e) => Some(e),
+            Error::Portugal(e) => Some(e),
+            Error::France(e) => Some(e),
+            Error::Germany(e) => Some(e),
+            Error::Switzerland(e) => Some(e),
+            Error::Austria(e) => Some(e),
+            Error::Poland(e) => Some(e),
+            Error::Czechia(e) => Some(e),
+            Error::Ukraine(e) => Some(e),
+            Error::Turkey(e) => Some(e),
+            Error::Finland(e) => Some(e),
+            Error::Latvia(e) => Some(e),
+            Error::Ireland(e) => Some(e),
+            Error::Chile(e) => Some(e),
+            Error::SouthKorea(e) => Some(e),
+            Error::Malaysia(e) => Some(e),
+            Error::Egypt(e) => Some(e),
+            Error::Australia(e) => Some(e),
+            Error::Belarus(e) => Some(e),
+            Error::Russia(e) => Some(e),
+            Error::Georgia(e) => Some(e),
+            Error::Cyprus(e) => Some(e),
+            Error::Uae(e) => Some(e),
+            Error::Bahrain(e) => Some(e),
+            Error::Kenya(e) => Some(e),
+            Error::Zimbabwe(e) => Some(e),
+            Error::Botswana(e) => Some(e),
+            Error::Peru(e) => Some(e),
+            Error::Ecuador(e) => Some(e),
+            Error::Venezuela(e) => Some(e),
+            Error::Colombia(e) => Some(e),
+            Error::CostaRica(e) => Some(e),
+            Error::Vietnam(e) => Some(e),
+            Error::Denied(_) | Error::Placeholder(_) | Error::NotYetIssued { .. } => None,
+        }
+    }
+}
+
+impl From<albania::NidError> for Error {
+    #[inline]
+    fn from(e: albania::NidError) -> Self {
+        Error::Albania(e)
+    }
+}
+
+impl From<kosovo::NidError> for Error {
+    #[inline]
+    fn from(e: kosovo::NidError) -> Self {
+        Error::Kosovo(e)
+    }
+}
+
+impl From<serbia::NidError> for Error {
+    #[inline]
+    fn from(e: serbia::NidError) -> Self {
+        Error::Serbia(e)
+    }
+}
+
+impl From<north_macedonia::NidError> for Error {
+    #[inline]
+    fn from(e: north_macedonia::NidError) -> Self {
+        Error::NorthMacedonia(e)
+    }
+}
+
+impl From<italy::NidError> for Error {
+    #[inline]
+    fn from(e: italy::NidError) -> Self {
+        Error::Italy(e)
+    }
+}
+
+impl From<spain::NidError> for Error {
+    #[inline]
+    fn from(e: spain::NidError) -> Self {
+        Error::Spain(e)
+    }
+}
+
+impl From<portugal::NidError> for Error {
+    #[inline]
+    fn from(e: portugal::NidError) -> Self {
+        Error::Portugal(e)
+    }
+}
+
+impl From<france::NidError> for Error {
+    #[inline]
+    fn from(e: france::NidError) -> Self {
+        Error::France(e)
+    }
+}
+
+impl From<germany::NidError> for Error {
+    #[inline]
+    fn from(e: germany::NidError) -> Self {
+        Error::Germany(e)
+    }
+}
+
+impl From<switzerland::NidError> for Error {
+    #[inline]
+    fn from(e: switzerland::NidError) -> Self {
+        Error::Switzerland(e)
+    }
+}
+
+impl From<austria::NidError> for Error {
+    #[inline]
+    fn from(e: austria::NidError) -> Self {
+        Error::Austria(e)
+    }
+}
+
+impl From<poland::NidError> for Error {
+    #[inline]
+    fn from(e: poland::NidError) -> Self {
+        Error::Poland(e)
+    }
+}
+
+impl From<czechia::NidError> for Error {
+    #[inline]
+    fn from(e: czechia::NidError) -> Self {
+        Error::Czechia(e)
+    }
+}
+
+impl From<ukraine::NidError> for Error {
+    #[inline]
+    fn from(e: ukraine::NidError) -> Self {
+        Error::Ukraine(e)
+    }
+}
+
+impl From<turkey::NidError> for Error {
+    #[inline]
+    fn from(e: turkey::NidError) -> Self {
+        Error::Turkey(e)
+    }
+}
+
+impl From<finland::NidError> for Error {
+    #[inline]
+    fn from(e: finland::NidError) -> Self {
+        Error::Finland(e)
+    }
+}
+
+impl From<latvia::NidError> for Error {
+    #[inline]
+    fn from(e: latvia::NidError) -> Self {
+        Error::Latvia(e)
+    }
+}
+
+impl From<ireland::NidError> for Error {
+    #[inline]
+    fn from(e: ireland::NidError) -> Self {
+        Error::Ireland(e)
+    }
+}
+
+impl From<chile::NidError> for Error {
+    #[inline]
+    fn from(e: chile::NidError) -> Self {
+        Error::Chile(e)
+    }
+}
+
+impl From<south_korea::NidError> for Error {
+    #[inline]
+    fn from(e: south_korea::NidError) -> Self {
+        Error::SouthKorea(e)
+    }
+}
+
+impl From<malaysia::NidError> for Error {
+    #[inline]
+    fn from(e: malaysia::NidError) -> Self {
+        Error::Malaysia(e)
+    }
+}
+
+impl From<egypt::NidError> for Error {
+    #[inline]
+    fn from(e: egypt::NidError) -> Self {
+        Error::Egypt(e)
+    }
+}
+
+impl From<australia::NidError> for Error {
+    #[inline]
+    fn from(e: australia::NidError) -> Self {
+        Error::Australia(e)
+    }
+}
+
+impl From<belarus::NidError> for Error {
+    #[inline]
+    fn from(e: belarus::NidError) -> Self {
+        Error::Belarus(e)
+    }
+}
+
+impl From<inn::InnError> for Error {
+    #[inline]
+    fn from(e: inn::InnError) -> Self {
+        Error::Russia(e)
+    }
+}
+
+impl From<georgia::NidError> for Error {
+    #[inline]
+    fn from(e: georgia::NidError) -> Self {
+        Error::Georgia(e)
+    }
+}
+
+impl From<cyprus::NidError> for Error {
+    #[inline]
+    fn from(e: cyprus::NidError) -> Self {
+        Error::Cyprus(e)
+    }
+}
+
+impl From<uae::NidError> for Error {
+    #[inline]
+    fn from(e: uae::NidError) -> Self {
+        Error::Uae(e)
+    }
+}
+
+impl From<bahrain::NidError> for Error {
+    #[inline]
+    fn from(e: bahrain::NidError) -> Self {
+        Error::Bahrain(e)
+    }
+}
+
+impl From<kenya::NidError> for Error {
+    #[inline]
+    fn from(e: kenya::NidError) -> Self {
+        Error::Kenya(e)
+    }
+}
+
+impl From<zimbabwe::NidError> for Error {
+    #[inline]
+    fn from(e: zimbabwe::NidError) -> Self {
+        Error::Zimbabwe(e)
+    }
+}
+
+impl From<botswana::NidError> for Error {
+    #[inline]
+    fn from(e: botswana::NidError) -> Self {
+        Error::Botswana(e)
+    }
+}
+
+impl From<peru::NidError> for Error {
+    #[inline]
+    fn from(e: peru::NidError) -> Self {
+        Error::Peru(e)
+    }
+}
+
+impl From<ecuador::NidError> for Error {
+    #[inline]
+    fn from(e: ecuador::NidError) -> Self {
+        Error::Ecuador(e)
+    }
+}
+
+impl From<venezuela::NidError> for Error {
+    #[inline]
+    fn from(e: venezuela::NidError) -> Self {
+        Error::Venezuela(e)
+    }
+}
+
+impl From<colombia::NidError> for Error {
+    #[inline]
+    fn from(e: colombia::NidError) -> Self {
+        Error::Colombia(e)
+    }
+}
+
+impl From<costa_rica::NidError> for Error {
+    #[inline]
+    fn from(e: costa_rica::NidError) -> Self {
+        Error::CostaRica(e)
+    }
+}
+
+impl From<vietnam::NidError> for Error {
+    #[inline]
+    fn from(e: vietnam::NidError) -> Self {
+        Error::Vietnam(e)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::error::Error as _;
+
+    use super::*;
+
+    #[test]
+    fn from_country_errors() {
+        let err = Error::from(albania::NidError::Checksum);
+        assert_eq!(err, Error::Albania(albania::NidError::Checksum));
+        assert_eq!(err.country(), Country::Albania);
+
+        let err: Error = kosovo::NidError::Checksum.into();
+        assert_eq!(err, Error::Kosovo(kosovo::NidError::Checksum));
+        assert_eq!(err.country(), Country::Kosovo);
+    }
+
+    #[test]
+    fn error_code_delegates() {
+        assert_eq!(
+            Error::Albania(albania::NidError::Checksum).error_code(),
+            "AL_CHECKSUM"
+        );
+        assert_eq!(
+            Error::Kosovo(kosovo::NidError::Checksum).error_code(),
+            "XK_CHECKSUM"
+        );
+    }
+
+    #[test]
+    fn source_is_country_error() {
+        let err = Error::Albania(albania::NidError::Format(
+            albania::FormatKind::InvalidLength,
+        ));
+        let source = err.source().unwrap();
+        assert_eq!(
+            source.downcast_ref::<albania::NidError>(),
+            Some(&albania::NidError::Format(
+                albania::FormatKind::InvalidLength
+            ))
+        );
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            Error::Kosovo(kosovo::NidError::Checksum).to_string(),
+            "kosovo: checksum validation failed"
+        );
+        assert_eq!(
+            Error::Albania(albania::NidError::Format(
+                albania::FormatKind::InvalidLength
+            ))
+            .to_string(),
+            "albania: format error: NID must be exactly 10 characters"
+        );
+    }
+}
@@ -0,0 +1,432 @@
+//! A vetted corpus of sample IDs with their expected decode results.
+//!
+//! Every supported country has at least two valid and one invalid sample,
+//! taken from the examples in this crate's documentation and checked by its
+//! tests. Integration tests in other codebases can run against [`ALL`]
+//! instead of keeping their own lists, and pick up new countries as they are
+//! added. Samples are written as they appear in documentation, so some
+//! contain separators or lower-case letters that validation accepts.
+//!
+//! This module requires the `fixtures` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::Country;
+//! use nidx::fixtures;
+//!
+//! for fixture in fixtures::ALL {
+//!     match fixture.expected {
+//!         Ok(summary) => {
+//!             let id = nidx::decode(fixture.country, fixture.nid).unwrap();
+//!             assert_eq!(id.summary(), summary);
+//!         }
+//!         Err(code) => {
+//!             let err = nidx::decode(fixture.country, fixture.nid).unwrap_err();
+//!             assert_eq!(err.error_code(), code);
+//!         }
+//!     }
+//! }
+//!
+//! assert!(fixtures::for_country(Country::Kosovo).any(|fixture| fixture.is_valid()));
+//! ```
+
+use crate::{Country, Date, Sex, Summary};
+
+/// A sample ID and the result of decoding it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Fixture {
+    /// The issuing country.
+    pub country: Country,
+    /// The sample ID.
+    pub nid: &'static str,
+    /// The [`Summary`] of the decoded ID, or the
+    /// [error code](crate::Error::error_code) decoding fails with.
+    pub expected: Result<Summary, &'static str>,
+}
+
+impl Fixture {
+    /// `true` if the sample is a valid ID.
+    #[inline]
+    #[must_use]
+    pub const fn is_valid(&self) -> bool {
+        self.expected.is_ok()
+    }
+}
+
+/// The samples of one country, valid ones first.
+pub fn for_country(country: Country) -> impl Iterator<Item = &'static Fixture> {
+    ALL.iter().filter(move |fixture| fixture.country == country)
+}
+
+const fn date(year: u16, month: u8, day: u8) -> Option<Date> {
+    Some(Date { year, month, day })
+}
+
+const fn valid(
+    country: Country,
+    nid: &'static str,
+    birthday: Option<Date>,
+    sex: Option<Sex>,
+    is_national: Option<bool>,
+) -> Fixture {
+    Fixture {
+        country,
+        nid,
+        expected: Ok(Summary {
+            birthday,
+            sex,
+            is_national,
+        }),
+    }
+}
+
+const fn invalid(country: Country, nid: &'static str, error_code: &'static str) -> Fixture {
+    Fixture {
+        country,
+        nid,
+        expected: Err(error_code),
+    }
+}
+
+/// Every sample, grouped by country in the order of [`Country::ALL`].
+pub const ALL: &[Fixture] = &[
+    valid(
+        Country::Albania,
+        "J00101999W",
+        date(1990, 1, 1),
+        Some(Sex::Male),
+        Some(true),
+    ),
+    valid(
+        Country::Albania,
+        "H55312123U",
+        date(1975, 3, 12),
+        Some(Sex::Female),
+        Some(true),
+    ),
+    invalid(Country::Albania, "J00101999X", "AL_FORMAT_CHECKSUM_CHAR"),
+    invalid(Country::Albania, "J00230123C", "AL_DATE_DAY"),
+    valid(Country::Kosovo, "1234567892", None, None, None),
+    valid(Country::Kosovo, "9123456780", None, None, None),
+    invalid(Country::Kosovo, "1234567890", "XK_CHECKSUM"),
+    invalid(Country::Kosovo, "12345678A2", "XK_FORMAT_NON_DIGIT"),
+    valid(
+        Country::Serbia,
+        "0101990710008",
+        date(1990, 1, 1),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::Serbia,
+        "2902000805506",
+        date(2000, 2, 29),
+        Some(Sex::Female),
+        None,
+    ),
+    invalid(Country::Serbia, "0101990710009", "RS_CHECKSUM"),
+    valid(
+        Country::NorthMacedonia,
+        "0101990450006",
+        date(1990, 1, 1),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::NorthMacedonia,
+        "2902000485501",
+        date(2000, 2, 29),
+        Some(Sex::Female),
+        None,
+    ),
+    invalid(Country::NorthMacedonia, "0101990450007", "MK_CHECKSUM"),
+    valid(
+        Country::Italy,
+        "RSSMRA85T10A562S",
+        date(1985, 12, 10),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::Italy,
+        "BNCLRA90A50Z112S",
+        date(1990, 1, 10),
+        Some(Sex::Female),
+        None,
+    ),
+    invalid(Country::Italy, "RSSMRA85T10A562A", "IT_CHECKSUM"),
+    valid(Country::Spain, "12345678Z", None, None, Some(true)),
+    valid(Country::Spain, "X1234567L", None, None, Some(false)),
+    invalid(Country::Spain, "12345678T", "ES_CHECKSUM"),
+    valid(Country::Portugal, "000000000ZZ4", None, None, Some(true)),
+    valid(Country::Portugal, "123456789ZZ1", None, None, Some(true)),
+    invalid(Country::Portugal, "123456789ZZ2", "PT_CHECKSUM"),
+    valid(
+        Country::France,
+        "185057800608491",
+        None,
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::France,
+        "290069912345619",
+        None,
+        Some(Sex::Female),
+        None,
+    ),
+    invalid(Country::France, "185057800608492", "FR_CHECKSUM"),
+    valid(Country::Germany, "65929970489", None, None, None),
+    valid(Country::Germany, "86095742719", None, None, None),
+    invalid(Country::Germany, "86095742718", "DE_CHECKSUM"),
+    valid(Country::Switzerland, "756.1234.5678.97", None, None, None),
+    valid(Country::Switzerland, "756.9217.0769.85", None, None, None),
+    invalid(Country::Switzerland, "756.9217.0769.86", "CH_CHECKSUM"),
+    valid(Country::Austria, "1237010180", date(1980, 1, 1), None, None),
+    valid(Country::Austria, "7894131385", None, None, None),
+    invalid(Country::Austria, "1238010180", "AT_CHECKSUM"),
+    valid(
+        Country::Poland,
+        "44051401359",
+        date(1944, 5, 14),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::Poland,
+        "02270803624",
+        date(2002, 7, 8),
+        Some(Sex::Female),
+        None,
+    ),
+    invalid(Country::Poland, "44051401358", "PL_CHECKSUM"),
+    valid(
+        Country::Czechia,
+        "7801231230",
+        date(1978, 1, 23),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::Czechia,
+        "0421231239",
+        date(2004, 1, 23),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::Czechia,
+        "530101123",
+        date(1953, 1, 1),
+        Some(Sex::Male),
+        None,
+    ),
+    invalid(Country::Czechia, "7801231231", "CZ_CHECKSUM"),
+    valid(
+        Country::Ukraine,
+        "3287301233",
+        date(1990, 1, 1),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::Ukraine,
+        "3658412356",
+        date(2000, 2, 29),
+        Some(Sex::Male),
+        None,
+    ),
+    invalid(Country::Ukraine, "3287301234", "UA_CHECKSUM"),
+    valid(Country::Turkey, "12345678950", None, None, None),
+    valid(Country::Turkey, "10000000146", None, None, None),
+    invalid(Country::Turkey, "10000000147", "TR_CHECKSUM"),
+    valid(
+        Country::Finland,
+        "131052-308T",
+        date(1952, 10, 13),
+        Some(Sex::Female),
+        None,
+    ),
+    valid(
+        Country::Finland,
+        "010594Y9021",
+        date(1994, 5, 1),
+        Some(Sex::Female),
+        None,
+    ),
+    invalid(Country::Finland, "131052-308U", "FI_CHECKSUM"),
+    valid(
+        Country::Latvia,
+        "161175-19997",
+        date(1975, 11, 16),
+        None,
+        None,
+    ),
+    valid(Country::Latvia, "32579461005", None, None, None),
+    invalid(Country::Latvia, "16117519996", "LV_CHECKSUM"),
+    valid(Country::Ireland, "6433435F", None, None, None),
+    valid(Country::Ireland, "1234567FA", None, None, None),
+    invalid(Country::Ireland, "6433435E", "IE_CHECKSUM"),
+    valid(Country::Chile, "12.345.678-5", None, None, None),
+    valid(Country::Chile, "7.654.321-6", None, None, None),
+    invalid(Country::Chile, "12345678-4", "CL_CHECKSUM"),
+    valid(
+        Country::SouthKorea,
+        "900101-1234568",
+        date(1990, 1, 1),
+        Some(Sex::Male),
+        Some(true),
+    ),
+    valid(
+        Country::SouthKorea,
+        "000229-4234566",
+        date(2000, 2, 29),
+        Some(Sex::Female),
+        Some(true),
+    ),
+    invalid(Country::SouthKorea, "9001011234567", "KR_CHECKSUM"),
+    valid(
+        Country::Malaysia,
+        "850615-10-1235",
+        date(1985, 6, 15),
+        Some(Sex::Male),
+        None,
+    ),
+    valid(
+        Country::Malaysia,
+        "000229-71-0001",
+        date(2000, 2, 29),
+        Some(Sex::Male),
+        None,
+    ),
+    invalid(Country::Malaysia, "900101695678", "MY_FORMAT_PLACE"),
+    valid(
+        Country::Egypt,
+        "29001010100211",
+        date(1990, 1, 1),
+        Some(Sex::Male),
+        Some(true),
+    ),
+    valid(
+        Country::Egypt,
+        "30002298800133",
+        date(2000, 2, 29),
+        Some(Sex::Male),
+        Some(true),
+    ),
+    invalid(Country::Egypt, "29001010100212", "EG_CHECKSUM"),
+    valid(Country::Australia, "123456782", None, None, None),
+    valid(Country::Australia, "876543210", None, None, None),
+    invalid(Country::Australia, "123456789", "AU_CHECKSUM"),
+    valid(
+        Country::Belarus,
+        "3140391A001PB8",
+        date(1991, 3, 14),
+        Some(Sex::Male),
+        Some(true),
+    ),
+    valid(
+        Country::Belarus,
+        "3010180E014BA5",
+        date(1980, 1, 1),
+        Some(Sex::Male),
+        Some(false),
+    ),
+    invalid(Country::Belarus, "3140391A001PB7", "BY_CHECKSUM"),
+    valid(Country::Russia, "500100732259", None, None, None),
+    valid(Country::Russia, "773370857141", None, None, None),
+    invalid(Country::Russia, "500100732258", "RU_INN_CHECKSUM"),
+    valid(Country::Georgia, "01001011234", None, None, None),
+    valid(Country::Georgia, "61001045678", None, None, None),
+    invalid(Country::Georgia, "00001011234", "GE_FORMAT_REGION"),
+    valid(Country::Cyprus, "1234567", None, None, Some(true)),
+    valid(Country::Cyprus, "ARC123456", None, None, Some(false)),
+    invalid(Country::Cyprus, "12345X7", "CY_FORMAT_NON_DIGIT"),
+    valid(Country::Uae, "784199012345676", None, None, None),
+    valid(Country::Uae, "784198512345673", None, None, None),
+    invalid(Country::Uae, "784199012345677", "AE_CHECKSUM"),
+    valid(Country::Bahrain, "850612349", None, None, None),
+    valid(Country::Bahrain, "051204568", None, None, None),
+    invalid(Country::Bahrain, "850612348", "BH_CHECKSUM"),
+    valid(Country::Kenya, "12345678", None, None, Some(true)),
+    valid(Country::Kenya, "123456789", None, None, Some(true)),
+    invalid(Country::Kenya, "123456", "KE_FORMAT_LENGTH"),
+    valid(Country::Zimbabwe, "63123456B42", None, None, None),
+    valid(Country::Zimbabwe, "08-1234567-r-08", None, None, None),
+    invalid(Country::Zimbabwe, "63123456C42", "ZW_CHECKSUM"),
+    valid(
+        Country::Botswana,
+        "123412345",
+        None,
+        Some(Sex::Male),
+        Some(true),
+    ),
+    valid(
+        Country::Botswana,
+        "987621234",
+        None,
+        Some(Sex::Female),
+        Some(true),
+    ),
+    invalid(Country::Botswana, "123432345", "BW_FORMAT_SEX_DIGIT"),
+    valid(Country::Peru, "12345678", None, None, Some(true)),
+    valid(Country::Peru, "12345678-1", None, None, Some(true)),
+    invalid(Country::Peru, "12345678-2", "PE_CHECKSUM"),
+    valid(Country::Ecuador, "1710034065", None, None, Some(true)),
+    valid(Country::Ecuador, "0926687856", None, None, Some(true)),
+    invalid(Country::Ecuador, "1710034066", "EC_CHECKSUM"),
+    valid(Country::Venezuela, "V-12345678", None, None, Some(true)),
+    valid(Country::Venezuela, "E-81234567", None, None, Some(false)),
+    invalid(Country::Venezuela, "12345678", "VE_FORMAT_PREFIX"),
+    valid(Country::Colombia, "79555123", None, None, Some(true)),
+    valid(Country::Colombia, "1020304050", None, None, Some(true)),
+    invalid(Country::Colombia, "79555123-8", "CO_CHECKSUM"),
+    valid(Country::CostaRica, "1-0234-0567", None, None, Some(true)),
+    valid(Country::CostaRica, "155812345678", None, None, Some(false)),
+    invalid(Country::CostaRica, "1-0234-056", "CR_FORMAT_LENGTH"),
+    valid(
+        Country::Vietnam,
+        "079305123456",
+        None,
+        Some(Sex::Female),
+        Some(true),
+    ),
+    valid(
+        Country::Vietnam,
+        "001090012345",
+        None,
+        Some(Sex::Male),
+        Some(true),
+    ),
+    invalid(Country::Vietnam, "003090012345", "VN_FORMAT_PROVINCE"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn fixtures_decode_as_expected() {
+        for fixture in ALL {
+            let result = crate::decode(fixture.country, fixture.nid);
+            assert_eq!(
+                result.map(|id| id.summary()).map_err(|e| e.error_code()),
+                fixture.expected,
+                "{}: {}",
+                fixture.country,
+                fixture.nid
+            );
+        }
+    }
+
+    #[test]
+    fn every_country_has_samples() {
+        for &country in Country::ALL {
+            let valid = for_country(country).filter(|f| f.is_valid()).count();
+            let invalid = for_country(country).filter(|f| !f.is_valid()).count();
+            assert!(valid >= 2 && invalid >= 1, "{country}");
+        }
+    }
+}
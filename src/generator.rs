@@ -0,0 +1,960 @@
+//! Generate random valid IDs for every supported country.
+//!
+//! [`generate`] and [`generate_seeded`] produce IDs that pass the country's
+//! validation, with real calendar dates and check characters. Birthdays are
+//! drawn from 1940-01-01 to 2009-12-31, as with `albania::generate`; the
+//! holders are not real people, which makes the output suitable for seeding
+//! staging databases, load tests and demo data. Fields without a date
+//! (serials, regions, names) are drawn uniformly from the values the
+//! validators accept.
+//!
+//! [`generate`] takes any [`rand::Rng`] and requires the `rand` feature.
+//! [`generate_seeded`] has its own number generator and is always available.
+//! [`Generator`] adds constraints on sex, age or birthday and nationality,
+//! for datasets shaped like a real population rather than uniform noise.
+//!
+//! # Examples
+//!
+//! ```
+//! # #[cfg(feature = "rand")] {
+//! use nidx::Country;
+//! use rand::SeedableRng;
+//! use rand::rngs::StdRng;
+//!
+//! let mut rng = StdRng::seed_from_u64(7);
+//! for &country in Country::ALL {
+//!     let nid = nidx::generator::generate(country, &mut rng);
+//!     assert!(nidx::is_valid(country, &nid), "{country}: {nid}");
+//! }
+//! # }
+//! ```
+
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+#[cfg(feature = "rand")]
+use rand::Rng;
+
+use crate::date::{civil_from_days, days_from_civil, validate_date};
+#[cfg(feature = "rand")]
+use crate::kosovo;
+use crate::{Country, Date, Field, Sex, albania, vietnam};
+
+/// Earliest birthday drawn when none is requested.
+const EARLIEST: Date = Date {
+    year: 1940,
+    month: 1,
+    day: 1,
+};
+
+/// Latest birthday drawn when none is requested.
+const LATEST: Date = Date {
+    year: 2009,
+    month: 12,
+    day: 31,
+};
+
+/// Earliest birthday a [`Generator`] can be asked for.
+const MIN_BIRTHDAY: Date = Date {
+    year: 1900,
+    month: 1,
+    day: 1,
+};
+
+/// Latest birthday a [`Generator`] can be asked for.
+const MAX_BIRTHDAY: Date = Date {
+    year: 2099,
+    month: 12,
+    day: 31,
+};
+
+/// Candidates drawn before giving up on a set of constraints.
+const MAX_ATTEMPTS: u32 = 10_000;
+
+const DIGITS: &[u8] = b"0123456789";
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+/// Generate a random valid ID for `country`.
+///
+/// Albanian and Kosovo IDs come from [`albania::generate`] and
+/// [`kosovo::generate`]. The output for a given `rng` state may change
+/// between releases; use [`generate_seeded`] for reproducible IDs.
+#[cfg(feature = "rand")]
+#[must_use]
+pub fn generate<R: Rng + ?Sized>(country: Country, rng: &mut R) -> String {
+    match country {
+        Country::Albania => albania::generate(rng),
+        Country::Kosovo => kosovo::generate(rng),
+        _ => draw(country, &mut Random(rng), &Spec::ANY).expect("unconstrained IDs can be drawn"),
+    }
+}
+
+/// Generate the valid ID for `country` at position `index` of the sequence
+/// selected by `seed`.
+///
+/// The same arguments always produce the same ID, on every platform and in
+/// every release, so the output can be pinned in snapshot tests and
+/// documentation. It changes only if the country's format rules change, which
+/// the changelog lists as a breaking change. Different `index` values give
+/// independent IDs, so fixtures can be built one row at a time without
+/// carrying generator state.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+/// use nidx::generator::generate_seeded;
+///
+/// assert_eq!(generate_seeded(Country::Albania, 7, 0), "E23311857I");
+/// assert_eq!(generate_seeded(Country::Kosovo, 7, 0), "2774252668");
+/// assert_eq!(generate_seeded(Country::Kosovo, 7, 1), "0882606503");
+/// ```
+#[must_use]
+pub fn generate_seeded(country: Country, seed: u64, index: u64) -> String {
+    draw(
+        country,
+        &mut SplitMix64::new(country, seed, index),
+        &Spec::ANY,
+    )
+    .expect("unconstrained IDs can be drawn")
+}
+
+/// Errors returned by [`Generator::build`] and [`Generator::build_seeded`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum GenerateError {
+    /// The country's IDs do not encode a constrained field.
+    NotEncoded(Field),
+    /// The age and birthday constraints leave no date between 1900-01-01 and
+    /// 2099-12-31.
+    EmptyBirthdayRange,
+    /// No ID satisfying the constraints was found, for example a sex other
+    /// than male or female, or a nationality the generator does not produce
+    /// for this country.
+    Unsatisfiable,
+}
+
+impl fmt::Display for GenerateError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GenerateError::NotEncoded(field) => write!(f, "{field} is not encoded in the ID"),
+            GenerateError::EmptyBirthdayRange => {
+                write!(
+                    f,
+                    "no birthday between 1900-01-01 and 2099-12-31 satisfies the constraints"
+                )
+            }
+            GenerateError::Unsatisfiable => write!(f, "no ID satisfies the constraints"),
+        }
+    }
+}
+
+impl std::error::Error for GenerateError {}
+
+/// A builder for valid IDs with constrained demographics.
+///
+/// Constraints apply to the fields the ID encodes: asking for the sex or age
+/// of a country whose IDs do not encode them fails with
+/// [`GenerateError::NotEncoded`]. Without an age or birthday constraint,
+/// birthdays are drawn from 1940-01-01 to 2009-12-31 as with [`generate`];
+/// constrained birthdays must lie between 1900-01-01 and 2099-12-31. Ages are
+/// counted in whole years on the [`as_of`](Self::as_of) date, today's UTC
+/// date by default.
+///
+/// With no constraints, [`build_seeded`](Self::build_seeded) returns the same
+/// IDs as [`generate_seeded`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::generator::Generator;
+/// use nidx::{Date, Sex};
+///
+/// let as_of = Date { year: 2026, month: 1, day: 1 };
+/// let generator = Generator::albania()
+///     .sex(Sex::Female)
+///     .age_between(18, 65)
+///     .foreigner()
+///     .as_of(as_of);
+/// let nid = generator.build_seeded(7, 0).unwrap();
+///
+/// let info = nidx::albania::decode(&nid).unwrap();
+/// assert_eq!(info.sex, Sex::Female);
+/// assert!(!info.is_national);
+/// assert!(info.birthday > Date { year: 1960, month: 1, day: 1 });
+/// assert!(info.birthday <= Date { year: 2008, month: 1, day: 1 });
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[must_use]
+pub struct Generator {
+    country: Country,
+    sex: Option<Sex>,
+    is_national: Option<bool>,
+    ages: Option<(u16, u16)>,
+    born: Option<(Date, Date)>,
+    as_of: Option<Date>,
+}
+
+impl Generator {
+    /// A generator for `country` with no constraints.
+    #[inline]
+    pub const fn new(country: Country) -> Self {
+        Generator {
+            country,
+            sex: None,
+            is_national: None,
+            ages: None,
+            born: None,
+            as_of: None,
+        }
+    }
+
+    /// A generator for Albanian NIDs.
+    #[inline]
+    pub const fn albania() -> Self {
+        Generator::new(Country::Albania)
+    }
+
+    /// Only produce IDs of holders of the given sex.
+    #[inline]
+    pub const fn sex(mut self, sex: Sex) -> Self {
+        self.sex = Some(sex);
+        self
+    }
+
+    /// Only produce IDs of holders aged `min` to `max` years, inclusive.
+    #[inline]
+    pub const fn age_between(mut self, min: u16, max: u16) -> Self {
+        self.ages = Some((min, max));
+        self
+    }
+
+    /// Only produce IDs of holders born between `earliest` and `latest`,
+    /// inclusive. Combined with [`age_between`](Self::age_between), both
+    /// apply.
+    #[inline]
+    pub const fn born_between(mut self, earliest: Date, latest: Date) -> Self {
+        self.born = Some((earliest, latest));
+        self
+    }
+
+    /// Only produce IDs that mark the holder as a national.
+    #[inline]
+    pub const fn national(mut self) -> Self {
+        self.is_national = Some(true);
+        self
+    }
+
+    /// Only produce IDs that mark the holder as a foreign resident.
+    #[inline]
+    pub const fn foreigner(mut self) -> Self {
+        self.is_national = Some(false);
+        self
+    }
+
+    /// Count ages on `date` instead of today.
+    #[inline]
+    pub const fn as_of(mut self, date: Date) -> Self {
+        self.as_of = Some(date);
+        self
+    }
+
+    /// Generate a random ID satisfying the constraints.
+    ///
+    /// # Errors
+    ///
+    /// See [`GenerateError`].
+    #[cfg(feature = "rand")]
+    pub fn build<R: Rng + ?Sized>(&self, rng: &mut R) -> Result<String, GenerateError> {
+        draw(self.country, &mut Random(rng), &self.spec()?)
+    }
+
+    /// Generate the ID at position `index` of the sequence selected by
+    /// `seed`, with the stability guarantees of [`generate_seeded`].
+    ///
+    /// # Errors
+    ///
+    /// See [`GenerateError`].
+    pub fn build_seeded(&self, seed: u64, index: u64) -> Result<String, GenerateError> {
+        let mut source = SplitMix64::new(self.country, seed, index);
+        draw(self.country, &mut source, &self.spec()?)
+    }
+
+    fn spec(&self) -> Result<Spec, GenerateError> {
+        let metadata = self.country.metadata();
+        if self.sex.is_some() && !metadata.encodes_sex {
+            return Err(GenerateError::NotEncoded(Field::Sex));
+        }
+        if self.ages.is_none() && self.born.is_none() {
+            return Ok(Spec {
+                sex: self.sex,
+                is_national: self.is_national,
+                ..Spec::ANY
+            });
+        }
+        if !metadata.encodes_birthday {
+            return Err(GenerateError::NotEncoded(Field::Birthday));
+        }
+
+        let mut first = days_from_civil(MIN_BIRTHDAY);
+        let mut last = days_from_civil(MAX_BIRTHDAY);
+        if let Some((earliest, latest)) = self.born {
+            first = first.max(days_from_civil(earliest));
+            last = last.min(days_from_civil(latest));
+        }
+        if let Some((min, max)) = self.ages {
+            let as_of = self.as_of.unwrap_or_else(today);
+            first = first.max(days_from_civil(years_before(as_of, max.saturating_add(1))) + 1);
+            last = last.min(days_from_civil(years_before(as_of, min)));
+        }
+        if first > last {
+            return Err(GenerateError::EmptyBirthdayRange);
+        }
+        Ok(Spec {
+            first,
+            last,
+            check_birthday: true,
+            sex: self.sex,
+            is_national: self.is_national,
+        })
+    }
+}
+
+/// Today's date in UTC.
+fn today() -> Date {
+    let days = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() / 86_400);
+    let epoch = days_from_civil(Date {
+        year: 1970,
+        month: 1,
+        day: 1,
+    });
+    civil_from_days(epoch + days as i32)
+}
+
+/// The same day `years` years before `date`, or 28 February for 29 February
+/// in a common year.
+fn years_before(date: Date, years: u16) -> Date {
+    let year = date.year.saturating_sub(years);
+    validate_date(year, date.month, date.day).unwrap_or(Date {
+        year,
+        month: date.month,
+        day: date.day - 1,
+    })
+}
+
+/// Constraints on drawn IDs, with birthdays as day numbers.
+struct Spec {
+    first: i32,
+    last: i32,
+    /// Whether decoded birthdays must lie in `first..=last`, rather than
+    /// only being drawn from it.
+    check_birthday: bool,
+    sex: Option<Sex>,
+    is_national: Option<bool>,
+}
+
+impl Spec {
+    const ANY: Spec = Spec {
+        first: days_from_civil(EARLIEST),
+        last: days_from_civil(LATEST),
+        check_birthday: false,
+        sex: None,
+        is_national: None,
+    };
+
+    /// Whether a valid ID satisfies the constraints.
+    fn accepts(&self, country: Country, nid: &str) -> bool {
+        if !self.check_birthday && self.sex.is_none() && self.is_national.is_none() {
+            return true;
+        }
+        let Ok(id) = crate::decode(country, nid) else {
+            return false;
+        };
+        let summary = id.summary();
+        (!self.check_birthday
+            || summary.birthday.is_some_and(|birthday| {
+                (self.first..=self.last).contains(&days_from_civil(birthday))
+            }))
+            && self.sex.is_none_or(|sex| summary.sex == Some(sex))
+            && self
+                .is_national
+                .is_none_or(|is_national| summary.is_national == Some(is_national))
+    }
+}
+
+/// A source of uniformly distributed numbers.
+trait Draw {
+    /// A number from `low` to `high`, inclusive.
+    fn between(&mut self, low: u32, high: u32) -> u32;
+
+    /// `true` or `false` with equal probability.
+    fn coin(&mut self) -> bool {
+        self.between(0, 1) == 1
+    }
+}
+
+/// Draws from a caller's [`rand::Rng`].
+#[cfg(feature = "rand")]
+struct Random<'a, R: ?Sized>(&'a mut R);
+
+#[cfg(feature = "rand")]
+impl<R: Rng + ?Sized> Draw for Random<'_, R> {
+    fn between(&mut self, low: u32, high: u32) -> u32 {
+        self.0.random_range(low..=high)
+    }
+}
+
+/// The SplitMix64 generator, whose output is fixed by its published
+/// definition and therefore stable across releases and platforms.
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    const GAMMA: u64 = 0x9E37_79B9_7F4A_7C15;
+
+    /// A generator for one position of a seeded sequence. The country is
+    /// mixed in by its ISO code, which unlike its position in [`Country::ALL`]
+    /// never changes.
+    fn new(country: Country, seed: u64, index: u64) -> Self {
+        let code = country
+            .alpha2()
+            .bytes()
+            .fold(0, |acc, b| (acc << 8) | u64::from(b));
+        let stream = Self::mix(index.wrapping_add(Self::GAMMA)) ^ Self::mix(code);
+        SplitMix64(Self::mix(seed ^ stream))
+    }
+
+    const fn mix(mut z: u64) -> u64 {
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(Self::GAMMA);
+        Self::mix(self.0)
+    }
+}
+
+impl Draw for SplitMix64 {
+    fn between(&mut self, low: u32, high: u32) -> u32 {
+        let span = u128::from(high - low) + 1;
+        low + ((u128::from(self.next()) * span) >> 64) as u32
+    }
+}
+
+/// Draw candidates for `country` until one validates and satisfies `spec`.
+fn draw<D: Draw>(country: Country, source: &mut D, spec: &Spec) -> Result<String, GenerateError> {
+    for _ in 0..MAX_ATTEMPTS {
+        let (head, checks, tail) = template(country, source, spec);
+        if let Some(nid) = complete(country, &head, checks, &tail) {
+            if spec.accepts(country, &nid) {
+                return Ok(nid);
+            }
+        }
+    }
+    Err(GenerateError::Unsatisfiable)
+}
+
+/// Try every character of `checks` between `head` and `tail`, returning the
+/// first valid ID. Without `checks`, `head` and `tail` are only validated.
+fn complete(country: Country, head: &str, checks: &[u8], tail: &str) -> Option<String> {
+    if checks.is_empty() {
+        let nid = format!("{head}{tail}");
+        return crate::is_valid(country, &nid).then_some(nid);
+    }
+    checks.iter().find_map(|&check| {
+        let nid = format!("{head}{}{tail}", char::from(check));
+        crate::is_valid(country, &nid).then_some(nid)
+    })
+}
+
+/// A candidate ID split around its check character: the characters before
+/// it, the characters it may take, and the characters after it.
+///
+/// Fields with a restricted but unpublished set of values are drawn from a
+/// wider range; [`draw`] retries until the candidate validates. The order of
+/// draws is part of the [`generate_seeded`] output: append new draws after
+/// the existing ones of a country rather than reordering them; constrained
+/// values are drawn all the same and then overridden.
+fn template<D: Draw>(country: Country, d: &mut D, spec: &Spec) -> (String, &'static [u8], String) {
+    let birthday =
+        civil_from_days(spec.first + d.between(0, (spec.last - spec.first) as u32) as i32);
+    let Date { year, month, day } = birthday;
+    let yy = year % 100;
+    let female = match (d.coin(), spec.sex) {
+        (_, Some(Sex::Female)) => true,
+        (_, Some(Sex::Male)) => false,
+        (female, _) => female,
+    };
+    let sex_digit = 2 * d.between(0, 4) + u32::from(!female);
+    let none = String::new();
+
+    match country {
+        Country::Albania => {
+            let sex = if female { Sex::Female } else { Sex::Male };
+            let coin = d.coin();
+            let is_national = spec.is_national.unwrap_or(coin);
+            let nid = albania::encode(birthday, sex, is_national, d.between(0, 999) as u16)
+                .expect("the birthday range is encodable");
+            (nid, &[], none)
+        }
+        Country::Kosovo => (format!("{}{}", d.between(0, 8), digits(d, 8)), DIGITS, none),
+        Country::Serbia | Country::NorthMacedonia => {
+            let region = if country == Country::Serbia {
+                d.between(70, 89)
+            } else {
+                d.between(41, 49)
+            };
+            let serial = if female {
+                d.between(500, 999)
+            } else {
+                d.between(0, 499)
+            };
+            let head = format!("{day:02}{month:02}{:03}{region:02}{serial:03}", year % 1000);
+            (head, DIGITS, none)
+        }
+        Country::Italy => {
+            let names = letters(d, 6);
+            let month_letter = char::from(b"ABCDEHLMPRST"[usize::from(month) - 1]);
+            let day = if female { day + 40 } else { day };
+            let place = char::from(b'A' + d.between(0, 12) as u8);
+            let head = format!(
+                "{names}{yy:02}{month_letter}{day:02}{place}{}",
+                digits(d, 3)
+            );
+            (head, LETTERS, none)
+        }
+        Country::Spain => (digits(d, 8), b"TRWAGMYFPDXBNJZSQVHLCKE", none),
+        Country::Portugal => {
+            let version: String = (0..2)
+                .map(|_| {
+                    char::from(b"0123456789ABCDEFGHIJKLMNOPQRSTUVWXYZ"[d.between(0, 35) as usize])
+                })
+                .collect();
+            (format!("{}{version}", digits(d, 9)), DIGITS, none)
+        }
+        Country::France => {
+            let department = match d.between(1, 95) {
+                20 => 21,
+                department => department,
+            };
+            let number = format!(
+                "{}{yy:02}{month:02}{department:02}{:03}{:03}",
+                if female { 2 } else { 1 },
+                d.between(1, 990),
+                d.between(1, 999)
+            );
+            let value: u64 = number.parse().expect("13 digits");
+            (format!("{number}{:02}", 97 - value % 97), &[], none)
+        }
+        Country::Germany => {
+            let mut pool = *b"0123456789";
+            for i in (1..pool.len()).rev() {
+                pool.swap(i, d.between(0, i as u32) as usize);
+            }
+            let mut head = pool[..9].to_vec();
+            let repeated = head[d.between(0, 8) as usize];
+            head.insert(d.between(0, 9) as usize, repeated);
+            (String::from_utf8(head).expect("ASCII"), DIGITS, none)
+        }
+        Country::Switzerland => (format!("756{}", digits(d, 9)), DIGITS, none),
+        Country::Austria => {
+            let head = d.between(100, 999).to_string();
+            (head, DIGITS, format!("{day:02}{month:02}{yy:02}"))
+        }
+        Country::Poland => {
+            let month = if year >= 2000 { month + 20 } else { month };
+            let head = format!("{yy:02}{month:02}{day:02}{}{sex_digit}", digits(d, 3));
+            (head, DIGITS, none)
+        }
+        Country::Czechia => {
+            let month = if female { month + 50 } else { month };
+            let head = format!("{yy:02}{month:02}{day:02}{}", digits(d, 3));
+            if year < 1954 {
+                (head, &[], none)
+            } else {
+                (head, DIGITS, none)
+            }
+        }
+        Country::Ukraine => {
+            let days = days_from_civil(birthday)
+                - days_from_civil(Date {
+                    year: 1899,
+                    month: 12,
+                    day: 31,
+                });
+            (
+                format!("{days:05}{}{sex_digit}", digits(d, 3)),
+                DIGITS,
+                none,
+            )
+        }
+        Country::Turkey => {
+            let mut t = [0u32; 11];
+            t[0] = d.between(1, 9);
+            for digit in &mut t[1..9] {
+                *digit = d.between(0, 9);
+            }
+            let odd = t[0] + t[2] + t[4] + t[6] + t[8];
+            let even = t[1] + t[3] + t[5] + t[7];
+            t[9] = (7 * odd + 10 * even - even) % 10;
+            t[10] = t[..10].iter().sum::<u32>() % 10;
+            (t.iter().map(u32::to_string).collect(), &[], none)
+        }
+        Country::Finland => {
+            let sign = if year >= 2000 { 'A' } else { '-' };
+            let individual = 2 * d.between(1, 449) + u32::from(!female);
+            let head = format!("{day:02}{month:02}{yy:02}{sign}{individual:03}");
+            (head, b"0123456789ABCDEFHJKLMNPRSTUVWXY", none)
+        }
+        Country::Latvia => {
+            if d.coin() {
+                (format!("32{}", digits(d, 9)), &[], none)
+            } else {
+                let century = year / 100 - 18;
+                let head = format!("{day:02}{month:02}{yy:02}{century}{}", digits(d, 3));
+                (head, DIGITS, none)
+            }
+        }
+        Country::Ireland => (digits(d, 7), LETTERS, "A".to_owned()),
+        Country::Chile => (
+            d.between(1_000_000, 25_999_999).to_string(),
+            b"0123456789K",
+            none,
+        ),
+        Country::SouthKorea => {
+            let century = if year >= 2000 { 3 } else { 1 };
+            let head = format!(
+                "{yy:02}{month:02}{day:02}{}{}",
+                century + u8::from(female),
+                digits(d, 5)
+            );
+            (head, DIGITS, none)
+        }
+        Country::Malaysia => {
+            let head = format!(
+                "{yy:02}{month:02}{day:02}{:02}{}{sex_digit}",
+                d.between(1, 16),
+                digits(d, 3)
+            );
+            (head, &[], none)
+        }
+        Country::Egypt => {
+            let century = if year >= 2000 { 3 } else { 2 };
+            let head = format!(
+                "{century}{yy:02}{month:02}{day:02}{:02}{}{sex_digit}",
+                d.between(1, 35),
+                digits(d, 3)
+            );
+            (head, DIGITS, none)
+        }
+        Country::Australia => (digits(d, 8), DIGITS, none),
+        Country::Belarus => {
+            let century = if year >= 2000 { 5 } else { 3 };
+            let head = format!(
+                "{}{day:02}{month:02}{yy:02}{}{}PB",
+                century + u8::from(female),
+                letters(d, 1),
+                digits(d, 3)
+            );
+            (head, DIGITS, none)
+        }
+        Country::Russia => {
+            // The first check digit is computed here; `complete` finds the second.
+            let mut head = format!("{:02}{}", d.between(1, 99), digits(d, 8));
+            let weights = [7, 2, 4, 10, 3, 5, 9, 4, 6, 8];
+            let sum: u32 = head
+                .bytes()
+                .zip(weights)
+                .map(|(b, w)| u32::from(b - b'0') * w)
+                .sum();
+            head.push(char::from(b'0' + (sum % 11 % 10) as u8));
+            (head, DIGITS, none)
+        }
+        Country::Georgia => (
+            format!("{:02}{}", d.between(1, 60), digits(d, 9)),
+            &[],
+            none,
+        ),
+        Country::Cyprus => (d.between(1, 9_999_999).to_string(), &[], none),
+        Country::Uae => (format!("784{year}{}", digits(d, 7)), DIGITS, none),
+        Country::Bahrain => (format!("{yy:02}{month:02}{}", digits(d, 4)), DIGITS, none),
+        Country::Kenya => (d.between(10_000_000, 39_999_999).to_string(), &[], none),
+        Country::Zimbabwe => (
+            format!("{:02}{}", d.between(1, 99), digits(d, 6)),
+            b"ZABCDEFGHJKLMNPQRSTVWXY",
+            format!("{:02}", d.between(1, 99)),
+        ),
+        Country::Botswana => (
+            format!(
+                "{}{}{}",
+                digits(d, 4),
+                if female { 2 } else { 1 },
+                digits(d, 4)
+            ),
+            &[],
+            none,
+        ),
+        Country::Peru => (digits(d, 8), DIGITS, none),
+        Country::Ecuador => (
+            format!("{:02}{}{}", d.between(1, 24), d.between(0, 5), digits(d, 6)),
+            DIGITS,
+            none,
+        ),
+        Country::Venezuela => (
+            format!(
+                "{}{}",
+                if d.between(0, 9) == 0 { 'E' } else { 'V' },
+                d.between(1, 35_000_000)
+            ),
+            &[],
+            none,
+        ),
+        Country::Colombia => {
+            let number = if d.coin() {
+                d.between(10_000_000, 99_999_999)
+            } else {
+                d.between(1_000_000_000, 1_199_999_999)
+            };
+            (number.to_string(), &[], none)
+        }
+        Country::CostaRica => (format!("{}{}", d.between(1, 7), digits(d, 8)), &[], none),
+        Country::Vietnam => {
+            let provinces = vietnam::Province::ALL;
+            let province = provinces[d.between(0, provinces.len() as u32 - 1) as usize];
+            let century = 2 * (year / 100 - 19);
+            let head = format!(
+                "{:03}{}{yy:02}{}",
+                province.code(),
+                century + u16::from(female),
+                digits(d, 6)
+            );
+            (head, &[], none)
+        }
+    }
+}
+
+fn digits<D: Draw>(d: &mut D, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(DIGITS[d.between(0, 9) as usize]))
+        .collect()
+}
+
+fn letters<D: Draw>(d: &mut D, count: usize) -> String {
+    (0..count)
+        .map(|_| char::from(LETTERS[d.between(0, 25) as usize]))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(feature = "rand")]
+    #[test]
+    fn every_country_generates_valid_ids() {
+        use rand::SeedableRng;
+        use rand::rngs::StdRng;
+
+        let mut rng = StdRng::seed_from_u64(0);
+        for &country in Country::ALL {
+            for _ in 0..200 {
+                let nid = generate(country, &mut rng);
+                assert!(crate::is_valid(country, &nid), "{country}: {nid}");
+            }
+        }
+    }
+
+    #[test]
+    fn seeded_ids_are_valid_and_in_range() {
+        for &country in Country::ALL {
+            for index in 0..200 {
+                let nid = generate_seeded(country, 1, index);
+                let id = crate::decode(country, &nid).unwrap_or_else(|e| panic!("{nid}: {e}"));
+                if let Some(birthday) = id.birthday() {
+                    assert!((EARLIEST..=LATEST).contains(&birthday), "{country}: {nid}");
+                }
+            }
+        }
+    }
+
+    /// Pins the seeded output. A change here breaks downstream snapshots and
+    /// must be listed in the changelog as breaking.
+    #[test]
+    fn seeded_output_is_stable() {
+        let expected = [
+            (Country::Albania, "G73114454W"),
+            (Country::Kosovo, "3870249003"),
+            (Country::Serbia, "2904944755148"),
+            (Country::NorthMacedonia, "3108992480102"),
+            (Country::Italy, "JAYZMR02C12G193L"),
+            (Country::Spain, "48915993F"),
+            (Country::Portugal, "818811780RD7"),
+            (Country::France, "264124165048022"),
+            (Country::Germany, "60153849371"),
+            (Country::Switzerland, "7560132236775"),
+            (Country::Austria, "5186300804"),
+            (Country::Poland, "94042052954"),
+            (Country::Czechia, "8962108617"),
+            (Country::Ukraine, "1509320050"),
+            (Country::Turkey, "21168141510"),
+            (Country::Finland, "120906A102U"),
+            (Country::Latvia, "32569998418"),
+            (Country::Ireland, "6405825TA"),
+            (Country::Chile, "160355808"),
+            (Country::SouthKorea, "7605071842883"),
+            (Country::Malaysia, "870224034964"),
+            (Country::Egypt, "29207072624354"),
+            (Country::Australia, "547482143"),
+            (Country::Belarus, "3110162A001PB3"),
+            (Country::Russia, "339805742200"),
+            (Country::Georgia, "52705475972"),
+            (Country::Cyprus, "3423926"),
+            (Country::Uae, "784195486111842"),
+            (Country::Bahrain, "411006444"),
+            (Country::Kenya, "21791956"),
+            (Country::Zimbabwe, "07483756R35"),
+            (Country::Botswana, "152712988"),
+            (Country::Peru, "111303041"),
+            (Country::Ecuador, "1040853762"),
+            (Country::Venezuela, "V16745806"),
+            (Country::Colombia, "1050374601"),
+            (Country::CostaRica, "767030397"),
+            (Country::Vietnam, "068085200687"),
+        ];
+        assert_eq!(expected.len(), Country::ALL.len());
+        for (country, nid) in expected {
+            assert_eq!(generate_seeded(country, 42, 0), nid, "{country}");
+        }
+    }
+
+    const AS_OF: Date = Date {
+        year: 2026,
+        month: 3,
+        day: 1,
+    };
+
+    #[test]
+    fn unconstrained_builder_matches_generate_seeded() {
+        for &country in Country::ALL {
+            assert_eq!(
+                Generator::new(country).build_seeded(42, 3),
+                Ok(generate_seeded(country, 42, 3))
+            );
+        }
+    }
+
+    #[test]
+    fn builder_honours_sex_and_age() {
+        for &country in Country::ALL {
+            let metadata = country.metadata();
+            if !metadata.encodes_sex || !metadata.encodes_birthday {
+                continue;
+            }
+            let generator = Generator::new(country)
+                .sex(Sex::Female)
+                .age_between(70, 70)
+                .as_of(AS_OF);
+            for index in 0..20 {
+                let nid = generator.build_seeded(5, index).unwrap();
+                let summary = crate::decode(country, &nid).unwrap().summary();
+                assert_eq!(summary.sex, Some(Sex::Female), "{country}: {nid}");
+                let birthday = summary.birthday.unwrap();
+                assert!(
+                    birthday
+                        > Date {
+                            year: 1955,
+                            ..AS_OF
+                        }
+                        && birthday
+                            <= Date {
+                                year: 1956,
+                                ..AS_OF
+                            },
+                    "{country}: {nid}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn builder_honours_nationality() {
+        let generator = Generator::albania().foreigner();
+        for index in 0..50 {
+            let nid = generator.build_seeded(0, index).unwrap();
+            assert!(!albania::decode(&nid).unwrap().is_national);
+        }
+        let nid = Generator::new(Country::Spain)
+            .national()
+            .build_seeded(0, 0)
+            .unwrap();
+        assert_eq!(
+            crate::decode(Country::Spain, &nid)
+                .unwrap()
+                .summary()
+                .is_national,
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn builder_reports_impossible_constraints() {
+        assert_eq!(
+            Generator::new(Country::Kosovo)
+                .sex(Sex::Male)
+                .build_seeded(0, 0),
+            Err(GenerateError::NotEncoded(Field::Sex))
+        );
+        assert_eq!(
+            Generator::new(Country::Kenya)
+                .age_between(18, 65)
+                .build_seeded(0, 0),
+            Err(GenerateError::NotEncoded(Field::Birthday))
+        );
+        assert_eq!(
+            Generator::albania()
+                .age_between(65, 18)
+                .as_of(AS_OF)
+                .build_seeded(0, 0),
+            Err(GenerateError::EmptyBirthdayRange)
+        );
+        assert_eq!(
+            Generator::albania().sex(Sex::Other).build_seeded(0, 0),
+            Err(GenerateError::Unsatisfiable)
+        );
+        assert_eq!(
+            GenerateError::NotEncoded(Field::Sex).to_string(),
+            "sex is not encoded in the ID"
+        );
+    }
+
+    #[test]
+    fn years_before_clamps_leap_day() {
+        let leap = Date {
+            year: 2024,
+            month: 2,
+            day: 29,
+        };
+        assert_eq!(
+            years_before(leap, 1),
+            Date {
+                year: 2023,
+                month: 2,
+                day: 28
+            }
+        );
+        assert_eq!(years_before(leap, 4), Date { year: 2020, ..leap });
+        assert!(today().year >= 2025);
+    }
+
+    #[test]
+    fn split_mix_matches_reference() {
+        // First outputs of the reference implementation seeded with 1234567.
+        let mut rng = SplitMix64(1_234_567);
+        assert_eq!(rng.next(), 6_457_827_717_110_365_317);
+        assert_eq!(rng.next(), 3_203_168_211_198_807_973);
+    }
+}
@@ -10,6 +10,42 @@
 //! |---------|--------|
 //! | Albania | [`albania`] |
 //! | Kosovo  | [`kosovo`]  |
+//! | Serbia | [`serbia`] |
+//! | North Macedonia | [`north_macedonia`] |
+//! | Italy | [`italy`] |
+//! | Spain | [`spain`] |
+//! | Portugal | [`portugal`] |
+//! | France | [`france`] |
+//! | Germany | [`germany`] |
+//! | Switzerland | [`switzerland`] |
+//! | Austria | [`austria`] |
+//! | Poland | [`poland`] |
+//! | Czechia | [`czechia`] |
+//! | Ukraine | [`ukraine`] |
+//! | Turkey | [`turkey`] |
+//! | Finland | [`finland`] |
+//! | Latvia | [`latvia`] |
+//! | Ireland | [`ireland`] |
+//! | Chile | [`chile`] |
+//! | South Korea | [`south_korea`] |
+//! | Malaysia | [`malaysia`] |
+//! | Egypt | [`egypt`] |
+//! | Australia | [`australia`] |
+//! | Belarus | [`belarus`] |
+//! | Russia | [`russia`] |
+//! | Georgia | [`georgia`] |
+//! | Cyprus | [`cyprus`] |
+//! | United Arab Emirates | [`uae`] |
+//! | Bahrain | [`bahrain`] |
+//! | Kenya | [`kenya`] |
+//! | Zimbabwe | [`zimbabwe`] |
+//! | Botswana | [`botswana`] |
+//! | Peru | [`peru`] |
+//! | Ecuador | [`ecuador`] |
+//! | Venezuela | [`venezuela`] |
+//! | Colombia | [`colombia`] |
+//! | Costa Rica | [`costa_rica`] |
+//! | Vietnam | [`vietnam`] |
 //!
 //! # Examples
 //!
@@ -20,13 +56,113 @@
 //! assert_eq!(info.sex, nidx::Sex::Male);
 //! assert!(info.is_national);
 //! ```
+//!
+//! When the issuing country is only known at runtime, use the top-level
+//! [`decode`], [`validate`] and [`is_valid`] functions with a [`Country`]:
+//!
+//! ```
+//! use nidx::Country;
+//!
+//! let country: Country = "albania".parse().unwrap();
+//! let id = nidx::decode(country, "J00101999W").unwrap();
+//! assert_eq!(id.sex(), Some(nidx::Sex::Male));
+//! ```
 
+mod century;
+mod confidence;
 mod date;
+mod denylist;
+mod dispatch;
+mod duplicates;
+mod encode;
+mod enumerate;
+mod era;
+mod error;
+mod matching;
+mod metadata;
+mod national_id;
+mod options;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod placeholder;
+mod plausibility;
+mod registry;
+mod report;
+mod suggest;
 mod types;
 
+#[cfg(feature = "arbitrary")]
+pub mod arbitrary;
 #[doc(hidden)]
 pub mod country;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
+pub mod generator;
+pub mod near_miss;
+#[cfg(feature = "proptest")]
+pub mod proptest;
+#[cfg(feature = "quickcheck")]
+pub mod quickcheck;
 
+pub use century::CenturyPolicy;
+pub use confidence::Confidence;
 pub use country::albania;
+pub use country::australia;
+pub use country::austria;
+pub use country::bahrain;
+pub use country::belarus;
+pub use country::botswana;
+pub use country::chile;
+pub use country::colombia;
+pub use country::costa_rica;
+pub use country::cyprus;
+pub use country::czechia;
+pub use country::ecuador;
+pub use country::egypt;
+pub use country::finland;
+pub use country::france;
+pub use country::georgia;
+pub use country::germany;
+pub use country::ireland;
+pub use country::italy;
+pub use country::jmbg;
+pub use country::kenya;
 pub use country::kosovo;
-pub use types::{Date, Sex};
+pub use country::latvia;
+pub use country::malaysia;
+pub use country::north_macedonia;
+pub use country::peru;
+pub use country::poland;
+pub use country::portugal;
+pub use country::russia;
+pub use country::serbia;
+pub use country::south_korea;
+pub use country::spain;
+pub use country::switzerland;
+pub use country::turkey;
+pub use country::uae;
+pub use country::ukraine;
+pub use country::venezuela;
+pub use country::vietnam;
+pub use country::zimbabwe;
+pub use denylist::Denylist;
+pub use dispatch::{
+    DecodedId, decode, decode_with, detect, is_valid, normalize, score, validate, validate_as_of,
+    verify_matches,
+};
+pub use duplicates::{Conflict, Duplicate, DuplicateReport, find_duplicates};
+pub use encode::{EncodeError, EncodeId};
+pub use enumerate::{Enumerate, enumerate};
+pub use era::Era;
+pub use error::Error;
+pub use matching::{Field, MatchReport, Mismatch};
+pub use metadata::{CharClass, Metadata, Run};
+pub use national_id::NationalId;
+pub use options::ParseOptions;
+#[cfg(feature = "rayon")]
+pub use parallel::par_validate_iter;
+pub use plausibility::{Plausibility, PlausibilityError};
+pub use registry::{BoxedError, BoxedInfo, Decoder, Registry, RegistryError};
+pub use report::ValidationReport;
+pub use suggest::{Correction, Suggestion};
+pub use types::{Country, Date, ParseCountryError, Sex, Summary};
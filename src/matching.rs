@@ -0,0 +1,218 @@
+use std::fmt;
+
+use crate::{Date, Sex, Summary};
+
+/// A personal data field that an ID can be checked against.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Field {
+    /// Date of birth.
+    Birthday,
+    /// Sex.
+    Sex,
+}
+
+impl fmt::Display for Field {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Field::Birthday => "birthday",
+            Field::Sex => "sex",
+        })
+    }
+}
+
+/// A declared value that disagrees with the value encoded in an ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Mismatch {
+    /// The declared date of birth differs from the encoded one.
+    Birthday {
+        /// The declared date of birth.
+        expected: Date,
+        /// The date of birth encoded in the ID.
+        found: Date,
+    },
+    /// The declared sex differs from the encoded one.
+    Sex {
+        /// The declared sex.
+        expected: Sex,
+        /// The sex encoded in the ID.
+        found: Sex,
+    },
+}
+
+impl Mismatch {
+    /// The field that disagrees.
+    #[inline]
+    #[must_use]
+    pub const fn field(&self) -> Field {
+        match self {
+            Mismatch::Birthday { .. } => Field::Birthday,
+            Mismatch::Sex { .. } => Field::Sex,
+        }
+    }
+}
+
+impl fmt::Display for Mismatch {
+    /// Formats as e.g. `"birthday: expected 1990-01-02, found 1990-01-01"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Mismatch::Birthday { expected, found } => {
+                write!(f, "birthday: expected {expected}, found {found}")
+            }
+            Mismatch::Sex { expected, found } => {
+                write!(f, "sex: expected {expected}, found {found}")
+            }
+        }
+    }
+}
+
+/// The result of checking a valid ID against declared personal data, as
+/// returned by [`albania::verify_matches`](crate::albania::verify_matches)
+/// and the top-level [`verify_matches`](crate::verify_matches).
+///
+/// Fields the ID's format does not encode cannot be checked and are listed
+/// in [`unverified`](Self::unverified) rather than treated as matches, so a
+/// KYC flow can decide whether to accept them. The same goes for a declared
+/// sex of [`Sex::Unspecified`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Date, Mismatch, Sex};
+///
+/// let declared = Date { year: 1990, month: 1, day: 2 };
+/// let report = nidx::albania::verify_matches("J00101999W", declared, Sex::Male).unwrap();
+/// assert!(!report.is_match());
+/// assert_eq!(
+///     report.mismatches(),
+///     [Mismatch::Birthday { expected: declared, found: Date { year: 1990, month: 1, day: 1 } }]
+/// );
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default)]
+pub struct MatchReport {
+    mismatches: Vec<Mismatch>,
+    unverified: Vec<Field>,
+}
+
+impl MatchReport {
+    /// Compare the fields of `summary` with the declared values.
+    pub(crate) fn compare(summary: &Summary, birthday: Date, sex: Sex) -> Self {
+        let mut report = MatchReport::default();
+        match summary.birthday {
+            Some(found) if found != birthday => report.mismatches.push(Mismatch::Birthday {
+                expected: birthday,
+                found,
+            }),
+            Some(_) => {}
+            None => report.unverified.push(Field::Birthday),
+        }
+        match summary.sex {
+            _ if sex == Sex::Unspecified => report.unverified.push(Field::Sex),
+            Some(found) if found != sex => report.mismatches.push(Mismatch::Sex {
+                expected: sex,
+                found,
+            }),
+            Some(_) => {}
+            None => report.unverified.push(Field::Sex),
+        }
+        report
+    }
+
+    /// `true` if no checked field disagrees. Unverified fields do not count
+    /// as disagreeing.
+    #[inline]
+    #[must_use]
+    pub fn is_match(&self) -> bool {
+        self.mismatches.is_empty()
+    }
+
+    /// `true` if every field was checked and none disagrees.
+    #[inline]
+    #[must_use]
+    pub fn is_full_match(&self) -> bool {
+        self.mismatches.is_empty() && self.unverified.is_empty()
+    }
+
+    /// The fields that disagree, in the order birthday, sex.
+    #[inline]
+    #[must_use]
+    pub fn mismatches(&self) -> &[Mismatch] {
+        &self.mismatches
+    }
+
+    /// The fields the ID does not encode, which could not be checked.
+    #[inline]
+    #[must_use]
+    pub fn unverified(&self) -> &[Field] {
+        &self.unverified
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const BIRTHDAY: Date = Date {
+        year: 1990,
+        month: 1,
+        day: 1,
+    };
+
+    #[test]
+    fn compare_reports_each_field() {
+        let summary = Summary {
+            birthday: Some(BIRTHDAY),
+            sex: Some(Sex::Female),
+            is_national: None,
+        };
+        let report = MatchReport::compare(&summary, BIRTHDAY, Sex::Female);
+        assert!(report.is_full_match());
+
+        let other = Date { day: 2, ..BIRTHDAY };
+        let report = MatchReport::compare(&summary, other, Sex::Male);
+        assert_eq!(
+            report.mismatches(),
+            [
+                Mismatch::Birthday {
+                    expected: other,
+                    found: BIRTHDAY,
+                },
+                Mismatch::Sex {
+                    expected: Sex::Male,
+                    found: Sex::Female,
+                },
+            ]
+        );
+        assert_eq!(report.mismatches()[1].field(), Field::Sex);
+        assert_eq!(
+            report.mismatches()[1].to_string(),
+            "sex: expected M, found F"
+        );
+    }
+
+    #[test]
+    fn compare_lists_fields_not_encoded() {
+        let summary = Summary {
+            birthday: None,
+            sex: Some(Sex::Male),
+            is_national: None,
+        };
+        let report = MatchReport::compare(&summary, BIRTHDAY, Sex::Male);
+        assert!(report.is_match());
+        assert!(!report.is_full_match());
+        assert_eq!(report.unverified(), [Field::Birthday]);
+
+        let report = MatchReport::compare(&summary, BIRTHDAY, Sex::Unspecified);
+        assert_eq!(report.unverified(), [Field::Birthday, Field::Sex]);
+        let report = MatchReport::compare(&summary, BIRTHDAY, Sex::Other);
+        assert_eq!(
+            report.mismatches(),
+            [Mismatch::Sex {
+                expected: Sex::Other,
+                found: Sex::Male,
+            }]
+        );
+    }
+}
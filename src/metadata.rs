@@ -0,0 +1,214 @@
+use crate::Country;
+
+/// The class of characters accepted at one position of an ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum CharClass {
+    /// An ASCII digit `0`–`9`.
+    Digit,
+    /// An ASCII letter, in either case.
+    Letter,
+    /// An ASCII digit or letter.
+    Alphanumeric,
+    /// Any printable ASCII character other than space, such as a separator
+    /// that carries meaning.
+    Graphic,
+    /// Exactly this ASCII character, compared ignoring ASCII case; used for
+    /// fixed separators and prefixes.
+    Exact(u8),
+}
+
+impl CharClass {
+    /// Whether `c` belongs to this class.
+    #[inline]
+    #[must_use]
+    pub const fn matches(self, c: u8) -> bool {
+        match self {
+            CharClass::Digit => c.is_ascii_digit(),
+            CharClass::Letter => c.is_ascii_alphabetic(),
+            CharClass::Alphanumeric => c.is_ascii_alphanumeric(),
+            CharClass::Graphic => c.is_ascii_graphic(),
+            CharClass::Exact(expected) => c.eq_ignore_ascii_case(&expected),
+        }
+    }
+}
+
+/// A run of between `min` and `max` consecutive characters of one class.
+///
+/// A shape is a sequence of runs; see [`Metadata::shapes`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Run {
+    /// The class every character in the run belongs to.
+    pub class: CharClass,
+    /// The fewest characters the run may span.
+    pub min: u8,
+    /// The most characters the run may span.
+    pub max: u8,
+}
+
+impl Run {
+    /// A run of exactly one character.
+    #[must_use]
+    pub const fn one(class: CharClass) -> Self {
+        Self::between(class, 1, 1)
+    }
+
+    /// A run of zero or one character.
+    #[must_use]
+    pub const fn optional(class: CharClass) -> Self {
+        Self::between(class, 0, 1)
+    }
+
+    /// A run of exactly `n` characters.
+    #[must_use]
+    pub const fn exactly(class: CharClass, n: u8) -> Self {
+        Self::between(class, n, n)
+    }
+
+    /// A run of `min` to `max` characters, inclusive.
+    #[must_use]
+    pub const fn between(class: CharClass, min: u8, max: u8) -> Self {
+        Self { class, min, max }
+    }
+}
+
+/// Whether `input` is exactly covered by `runs`, trying every split.
+fn matches_runs(runs: &[Run], input: &[u8]) -> bool {
+    let Some((run, rest)) = runs.split_first() else {
+        return input.is_empty();
+    };
+    let limit = usize::from(run.max).min(input.len());
+    let taken = input[..limit]
+        .iter()
+        .take_while(|&&b| run.class.matches(b))
+        .count();
+    (usize::from(run.min)..=taken)
+        .rev()
+        .any(|n| matches_runs(rest, &input[n..]))
+}
+
+/// Static facts about a country's ID format.
+///
+/// Useful for building form hints (`maxlength`, input mode) and for cheaply
+/// pre-filtering input before full validation. Obtain it from a country
+/// module's `METADATA` constant or from [`Country::metadata`].
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+///
+/// let meta = Country::Albania.metadata();
+/// assert_eq!(meta.alpha3, "ALB");
+/// assert_eq!((meta.min_length, meta.max_length), (10, 10));
+/// assert!(meta.encodes_birthday);
+/// assert!(meta.matches_shape("J00101999W"));
+/// assert!(!meta.matches_shape("1234567892"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct Metadata {
+    /// The country this metadata describes.
+    pub country: Country,
+    /// The English short name of the country.
+    pub name: &'static str,
+    /// The ISO 3166-1 alpha-2 code (`XK` for Kosovo, which has no official code).
+    pub alpha2: &'static str,
+    /// The ISO 3166-1 alpha-3 code (`XKX` for Kosovo, which has no official code).
+    pub alpha3: &'static str,
+    /// The fewest characters in any accepted written form of an ID.
+    pub min_length: usize,
+    /// The most characters in any accepted written form of an ID, separators
+    /// included.
+    pub max_length: usize,
+    /// Every accepted written form, each a sequence of [`Run`]s.
+    pub shapes: &'static [&'static [Run]],
+    /// Whether a date of birth can be decoded from the ID.
+    pub encodes_birthday: bool,
+    /// Whether the holder's sex can be decoded from the ID.
+    pub encodes_sex: bool,
+}
+
+impl Metadata {
+    /// Whether `nid` has an accepted length and matches one of the
+    /// [`shapes`](Self::shapes).
+    ///
+    /// This is a cheap pre-filter: a `true` result does not mean the ID is
+    /// valid, but a `false` result means the country's `validate` rejects it.
+    #[must_use]
+    pub fn matches_shape(&self, nid: &str) -> bool {
+        let bytes = nid.as_bytes();
+        (self.min_length..=self.max_length).contains(&bytes.len())
+            && self.shapes.iter().any(|shape| matches_runs(shape, bytes))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lengths_match_shapes() {
+        for &country in Country::ALL {
+            let meta = country.metadata();
+            let span = |shape: &[Run], f: fn(&Run) -> u8| -> usize {
+                shape.iter().map(|run| usize::from(f(run))).sum()
+            };
+            let min = meta.shapes.iter().map(|s| span(s, |r| r.min)).min();
+            let max = meta.shapes.iter().map(|s| span(s, |r| r.max)).max();
+            assert_eq!(min, Some(meta.min_length), "{country}");
+            assert_eq!(max, Some(meta.max_length), "{country}");
+            assert_eq!(meta.country, country);
+        }
+    }
+
+    #[test]
+    fn matches_shape() {
+        let meta = Country::Kosovo.metadata();
+        assert!(meta.matches_shape("1234567890"));
+        assert!(!meta.matches_shape("123456789"));
+        assert!(!meta.matches_shape("12345678A0"));
+    }
+
+    #[test]
+    fn matches_shape_accepts_every_valid_id() {
+        for &country in Country::ALL {
+            let meta = country.metadata();
+            let fixtures = crate::fixtures::for_country(country)
+                .filter(|fixture| fixture.is_valid())
+                .map(|fixture| fixture.nid.to_owned());
+            let generated =
+                (0..2000).map(|index| crate::generator::generate_seeded(country, 12, index));
+            for nid in fixtures.chain(generated) {
+                assert!(crate::is_valid(country, &nid), "{country}: {nid}");
+                assert!(meta.matches_shape(&nid), "{country}: {nid}");
+            }
+        }
+    }
+
+    #[test]
+    fn matches_shape_backtracks_across_runs() {
+        let runs = [
+            Run::between(CharClass::Digit, 1, 3),
+            Run::exactly(CharClass::Digit, 2),
+            Run::optional(CharClass::Exact(b'-')),
+        ];
+        assert!(matches_runs(&runs, b"123"));
+        assert!(matches_runs(&runs, b"12345-"));
+        assert!(!matches_runs(&runs, b"12"));
+        assert!(!matches_runs(&runs, b"123456"));
+    }
+
+    #[test]
+    fn char_class_matches() {
+        assert!(CharClass::Digit.matches(b'7'));
+        assert!(!CharClass::Digit.matches(b'a'));
+        assert!(CharClass::Letter.matches(b'a'));
+        assert!(CharClass::Alphanumeric.matches(b'Z'));
+        assert!(!CharClass::Alphanumeric.matches(b'-'));
+        assert!(CharClass::Graphic.matches(b'-'));
+        assert!(!CharClass::Graphic.matches(b' '));
+        assert!(CharClass::Exact(b'A').matches(b'a'));
+        assert!(!CharClass::Exact(b'-').matches(b'.'));
+    }
+}
@@ -0,0 +1,75 @@
+use crate::{Country, ParseOptions};
+
+/// Common interface over every supported national ID format.
+///
+/// Each country module exposes a zero-sized type implementing this trait
+/// (e.g. [`albania::Albania`](crate::albania::Albania)), so code that handles
+/// IDs generically can be written once and instantiated per country.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::NationalId;
+/// use nidx::albania::Albania;
+/// use nidx::kosovo::Kosovo;
+///
+/// fn count_valid<T: NationalId>(ids: &[&str]) -> usize {
+///     ids.iter().filter(|id| T::is_valid(id)).count()
+/// }
+///
+/// assert_eq!(count_valid::<Albania>(&["J00101999W", "1234567892"]), 1);
+/// assert_eq!(count_valid::<Kosovo>(&["J00101999W", "1234567892"]), 1);
+/// ```
+pub trait NationalId {
+    /// The country that issues this kind of ID.
+    const COUNTRY: Country;
+
+    /// Information decoded from a valid ID. `()` for formats that carry no data.
+    type Info;
+
+    /// The reason an ID was rejected.
+    type Error: std::error::Error;
+
+    /// Validate and decode an ID.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the ID is invalid.
+    fn decode(nid: &str) -> Result<Self::Info, Self::Error>;
+
+    /// Validate and decode an ID after pre-processing it according to `options`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the pre-processed ID is invalid.
+    #[inline]
+    fn decode_with(nid: &str, options: &ParseOptions) -> Result<Self::Info, Self::Error> {
+        Self::decode(&options.prepare(nid))
+    }
+
+    /// Validate an ID without returning the decoded information.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`Self::Error`] if the ID is invalid.
+    #[inline]
+    fn validate(nid: &str) -> Result<(), Self::Error> {
+        Self::decode(nid).map(|_| ())
+    }
+
+    /// Check whether an ID is valid.
+    #[inline]
+    #[must_use]
+    fn is_valid(nid: &str) -> bool {
+        Self::validate(nid).is_ok()
+    }
+
+    /// Return the canonical form of an ID without validating it.
+    ///
+    /// The default implementation only strips surrounding whitespace.
+    #[inline]
+    #[must_use]
+    fn normalize(nid: &str) -> String {
+        nid.trim().to_owned()
+    }
+}
@@ -0,0 +1,350 @@
+//! Near-valid IDs for negative test suites.
+//!
+//! [`near_misses`] takes a valid ID and corrupts it the way people mistype
+//! IDs: a different check character, two adjacent characters swapped, a
+//! birth month off by one. Each variant is labelled with the error it must
+//! produce, so a country module can be tested against every near miss of its
+//! sample IDs rather than a few hand-picked typos.
+//!
+//! Labels hold by construction rather than by running the validators: a
+//! corruption is only applied where the country's checksum is known to catch
+//! it.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::Country;
+//! use nidx::near_miss::{Corruption, ExpectedError, near_misses};
+//!
+//! let misses = near_misses(Country::Albania, "J00101999W").unwrap();
+//! assert_eq!(misses[0].corruption, Corruption::CheckCharacter);
+//! assert_eq!(misses[0].nid, "J00101999A");
+//! assert_eq!(misses[0].expected, ExpectedError::Checksum);
+//!
+//! for miss in &misses {
+//!     let error = nidx::decode(Country::Albania, &miss.nid).unwrap_err();
+//!     assert!(miss.expected.matches(&error), "{}", miss.nid);
+//! }
+//! ```
+
+use crate::{Country, Error};
+
+const DIGITS: &[u8] = b"0123456789";
+const LETTERS: &[u8] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ";
+const MOD_23_LETTERS: &[u8] = b"WABCDEFGHIJKLMNOPQRSTUV";
+
+/// Italian month letters, January to December.
+const ITALY_MONTHS: &[u8] = b"ABCDEHLMPRST";
+
+/// How a [`NearMiss`] was derived from the valid ID.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Corruption {
+    /// The check character was replaced by another one of its alphabet.
+    CheckCharacter,
+    /// The characters at `at` and `at + 1` were swapped.
+    Transposition {
+        /// Index of the first swapped character in the canonical ID.
+        at: usize,
+    },
+    /// The birth month was moved by `delta`, keeping the check character.
+    Month {
+        /// `-1` or `1`.
+        delta: i8,
+    },
+}
+
+/// The error a [`NearMiss`] must be rejected with.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum ExpectedError {
+    /// A checksum error: the ID is well-formed and its date exists, but its
+    /// check character does not match.
+    Checksum,
+    /// Any error. Swapping characters may break the format or the date
+    /// before the checksum is reached.
+    Any,
+}
+
+impl ExpectedError {
+    /// Whether `error` is of the expected kind.
+    #[must_use]
+    pub fn matches(self, error: &Error) -> bool {
+        match self {
+            ExpectedError::Checksum => error.error_code().ends_with("_CHECKSUM"),
+            ExpectedError::Any => true,
+        }
+    }
+}
+
+/// A corrupted variant of a valid ID.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub struct NearMiss {
+    /// How the ID was corrupted.
+    pub corruption: Corruption,
+    /// The corrupted ID, in canonical form.
+    pub nid: String,
+    /// The error validation must report.
+    pub expected: ExpectedError,
+}
+
+/// Every near miss of the valid ID `nid`, or the error `nid` is rejected
+/// with.
+///
+/// Variants are derived from the canonical form and listed in a stable
+/// order: check character replacements in alphabet order, then
+/// transpositions from left to right, then the month moved back and
+/// forward.
+///
+/// - **Check character**: every other character of the check alphabet, for
+///   countries whose IDs carry one. Expects [`ExpectedError::Checksum`].
+/// - **Transposition**: each pair of adjacent, different characters outside
+///   the check character, for Albania, Spain, France, Austria, Finland,
+///   Ireland, Chile and Australia, whose checksums detect every adjacent
+///   transposition. Expects [`ExpectedError::Any`].
+/// - **Month**: the birth month one before and after, for Albania, Serbia,
+///   North Macedonia, Italy, France, Austria, Poland, Finland, Latvia
+///   (legacy numbers), South Korea, Egypt and Bahrain, whose checksums catch
+///   the change before the date is validated. Expects
+///   [`ExpectedError::Checksum`].
+///
+/// Countries whose IDs have no check character give an empty list.
+///
+/// # Errors
+///
+/// Returns the validation error of `nid` if it is not a valid ID of
+/// `country`.
+pub fn near_misses(country: Country, nid: &str) -> Result<Vec<NearMiss>, Error> {
+    crate::decode(country, nid)?;
+    let nid = crate::normalize(country, nid);
+    let bytes = nid.as_bytes();
+    let check = check_character(country, bytes);
+    let mut misses = Vec::new();
+
+    if let Some((at, alphabet)) = check {
+        for &c in alphabet.iter().filter(|&&c| c != bytes[at]) {
+            let mut corrupted = bytes.to_vec();
+            corrupted[at] = c;
+            misses.push(NearMiss::new(
+                Corruption::CheckCharacter,
+                corrupted,
+                ExpectedError::Checksum,
+            ));
+        }
+    }
+
+    if detects_transpositions(country) {
+        for at in 0..bytes.len().saturating_sub(1) {
+            let touches_check = check.is_some_and(|(c, _)| c == at || c == at + 1);
+            if bytes[at] != bytes[at + 1] && !touches_check {
+                let mut corrupted = bytes.to_vec();
+                corrupted.swap(at, at + 1);
+                misses.push(NearMiss::new(
+                    Corruption::Transposition { at },
+                    corrupted,
+                    ExpectedError::Any,
+                ));
+            }
+        }
+    }
+
+    if let Some(month) = month_field(country, bytes) {
+        for delta in [-1, 1] {
+            if let Some(corrupted) = month.shift(bytes, delta) {
+                misses.push(NearMiss::new(
+                    Corruption::Month { delta },
+                    corrupted,
+                    ExpectedError::Checksum,
+                ));
+            }
+        }
+    }
+
+    Ok(misses)
+}
+
+impl NearMiss {
+    fn new(corruption: Corruption, nid: Vec<u8>, expected: ExpectedError) -> Self {
+        NearMiss {
+            corruption,
+            nid: String::from_utf8(nid).expect("canonical IDs are ASCII"),
+            expected,
+        }
+    }
+}
+
+/// Index and alphabet of the check character of a canonical ID, if it has
+/// one.
+fn check_character(country: Country, nid: &[u8]) -> Option<(usize, &'static [u8])> {
+    let last = nid.len() - 1;
+    match country {
+        Country::Albania => Some((last, MOD_23_LETTERS)),
+        Country::Kosovo if nid[0] == b'9' => None,
+        Country::Czechia if nid.len() == 9 => None,
+        Country::Latvia if nid.starts_with(b"32") => None,
+        Country::Peru if nid.len() == 8 => None,
+        Country::Peru if nid[last].is_ascii_uppercase() => Some((last, b"KJIHGFEDCBA")),
+        Country::Kosovo
+        | Country::Serbia
+        | Country::NorthMacedonia
+        | Country::Portugal
+        | Country::France
+        | Country::Germany
+        | Country::Switzerland
+        | Country::Poland
+        | Country::Czechia
+        | Country::Ukraine
+        | Country::Turkey
+        | Country::Latvia
+        | Country::SouthKorea
+        | Country::Egypt
+        | Country::Australia
+        | Country::Belarus
+        | Country::Russia
+        | Country::Uae
+        | Country::Bahrain
+        | Country::Peru
+        | Country::Ecuador => Some((last, DIGITS)),
+        Country::Italy => Some((last, LETTERS)),
+        Country::Spain => Some((last, b"TRWAGMYFPDXBNJZSQVHLCKE")),
+        Country::Austria => Some((3, DIGITS)),
+        Country::Finland => Some((last, b"0123456789ABCDEFHJKLMNPRSTUVWXY")),
+        Country::Ireland => Some((7, MOD_23_LETTERS)),
+        Country::Chile => Some((last, b"0123456789K")),
+        Country::Zimbabwe => Some((last - 2, b"ZABCDEFGHJKLMNPQRSTVWXY")),
+        _ => None,
+    }
+}
+
+/// Whether the country's checksum detects every swap of two adjacent
+/// characters outside the check character.
+fn detects_transpositions(country: Country) -> bool {
+    matches!(
+        country,
+        Country::Albania
+            | Country::Spain
+            | Country::France
+            | Country::Austria
+            | Country::Finland
+            | Country::Ireland
+            | Country::Chile
+            | Country::Australia
+    )
+}
+
+/// Where a canonical ID holds its birth month.
+#[derive(Debug, Clone, Copy)]
+enum MonthField {
+    /// Two digits at the index, possibly offset by a century or sex code.
+    Digits(usize),
+    /// An Italian month letter at the index.
+    Letter(usize),
+}
+
+impl MonthField {
+    /// `nid` with the month moved by `delta`, or `None` if the field cannot
+    /// hold the result.
+    fn shift(self, nid: &[u8], delta: i8) -> Option<Vec<u8>> {
+        let mut corrupted = nid.to_vec();
+        match self {
+            MonthField::Digits(at) => {
+                let value = (nid[at] - b'0') * 10 + (nid[at + 1] - b'0');
+                let value = value.checked_add_signed(delta).filter(|&v| v <= 99)?;
+                corrupted[at] = b'0' + value / 10;
+                corrupted[at + 1] = b'0' + value % 10;
+            }
+            MonthField::Letter(at) => {
+                let month = ITALY_MONTHS.iter().position(|&c| c == nid[at])?;
+                let month = month.checked_add_signed(delta.into())?;
+                corrupted[at] = *ITALY_MONTHS.get(month)?;
+            }
+        }
+        Some(corrupted)
+    }
+}
+
+/// The month field of a canonical ID, for countries whose checksum catches a
+/// month moved by one.
+fn month_field(country: Country, nid: &[u8]) -> Option<MonthField> {
+    match country {
+        Country::Latvia if nid.starts_with(b"32") => None,
+        Country::Albania
+        | Country::Serbia
+        | Country::NorthMacedonia
+        | Country::Poland
+        | Country::Finland
+        | Country::Latvia
+        | Country::SouthKorea
+        | Country::Bahrain => Some(MonthField::Digits(2)),
+        Country::France | Country::Egypt => Some(MonthField::Digits(3)),
+        Country::Austria => Some(MonthField::Digits(6)),
+        Country::Italy => Some(MonthField::Letter(8)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn samples(country: Country) -> impl Iterator<Item = String> {
+        let fixtures = crate::fixtures::for_country(country)
+            .filter(|fixture| fixture.is_valid())
+            .map(|fixture| fixture.nid.to_owned());
+        let generated =
+            (0..200).map(move |index| crate::generator::generate_seeded(country, 5, index));
+        fixtures.chain(generated)
+    }
+
+    #[test]
+    fn every_near_miss_is_rejected_as_labelled() {
+        for &country in Country::ALL {
+            for nid in samples(country) {
+                for miss in near_misses(country, &nid).unwrap() {
+                    let error = crate::decode(country, &miss.nid).unwrap_err();
+                    assert!(
+                        miss.expected.matches(&error),
+                        "{country}: {nid} -> {} ({:?}) gave {}",
+                        miss.nid,
+                        miss.corruption,
+                        error.error_code()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn covers_each_corruption() {
+        let misses = near_misses(Country::Albania, "J00101999W").unwrap();
+        let count =
+            |f: fn(&Corruption) -> bool| misses.iter().filter(|miss| f(&miss.corruption)).count();
+        assert_eq!(count(|c| *c == Corruption::CheckCharacter), 22);
+        assert_eq!(count(|c| matches!(c, Corruption::Transposition { .. })), 5);
+        let months: Vec<&str> = misses
+            .iter()
+            .filter(|miss| matches!(miss.corruption, Corruption::Month { .. }))
+            .map(|miss| miss.nid.as_str())
+            .collect();
+        assert_eq!(months, ["J00001999W", "J00201999W"]);
+    }
+
+    #[test]
+    fn italian_months_move_by_letter() {
+        let misses = near_misses(Country::Italy, "RSSMRA85T10A562S").unwrap();
+        let months: Vec<&str> = misses
+            .iter()
+            .filter(|miss| matches!(miss.corruption, Corruption::Month { .. }))
+            .map(|miss| miss.nid.as_str())
+            .collect();
+        assert_eq!(months, ["RSSMRA85S10A562S"]);
+    }
+
+    #[test]
+    fn rejects_invalid_input() {
+        let error = near_misses(Country::Albania, "J00101999A").unwrap_err();
+        assert_eq!(error.error_code(), "AL_CHECKSUM");
+        assert!(near_misses(Country::Kenya, "12345678").unwrap().is_empty());
+    }
+}
@@ -0,0 +1,203 @@
+use std::borrow::Cow;
+
+use crate::CenturyPolicy;
+
+/// Options controlling how input is pre-processed before validation.
+///
+/// The default is strict: the input must already be in the exact format the
+/// country expects. Use [`ParseOptions::lenient`] for user-entered input that
+/// may contain spaces, hyphens or trailing newlines.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::ParseOptions;
+///
+/// let opts = ParseOptions::lenient();
+/// assert!(nidx::albania::decode_with("J0 0101 999W\n", &opts).is_ok());
+/// assert!(nidx::albania::decode_with("J0 0101 999W\n", &ParseOptions::new()).is_err());
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct ParseOptions {
+    /// Remove whitespace and the separators `-`, `.` and `/` anywhere in the
+    /// input before validating it.
+    ///
+    /// Formats in which one of these characters carries meaning, such as the
+    /// century sign of a Finnish HETU or the hyphen before a Colombian NIT
+    /// check digit, keep that character.
+    pub strip_separators: bool,
+    /// How two-digit birth years are expanded, for formats that record
+    /// only the last two digits of the year.
+    pub century: CenturyPolicy,
+    /// Reject IDs that pass validation but are obviously placeholders or
+    /// test data, such as all-zero or sequential digits and published sample
+    /// numbers, with the country's `Placeholder` error.
+    ///
+    /// Albania and Kosovo have their own rules, applied by their
+    /// `decode_with` and `validate_with` functions. For every other country
+    /// the crate-level [`decode_with`](crate::decode_with) applies a generic
+    /// rule and returns [`Error::Placeholder`](crate::Error::Placeholder);
+    /// their module-level `decode_with` functions have no placeholder error.
+    pub reject_placeholders: bool,
+}
+
+impl ParseOptions {
+    /// Strict options: the input is validated exactly as given.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        ParseOptions {
+            strip_separators: false,
+            century: CenturyPolicy::DEFAULT,
+            reject_placeholders: false,
+        }
+    }
+
+    /// Lenient options: whitespace and common separators are stripped.
+    #[inline]
+    #[must_use]
+    pub const fn lenient() -> Self {
+        ParseOptions {
+            strip_separators: true,
+            century: CenturyPolicy::DEFAULT,
+            reject_placeholders: false,
+        }
+    }
+
+    /// Set whether whitespace and separators are stripped.
+    #[inline]
+    #[must_use]
+    pub const fn strip_separators(mut self, strip: bool) -> Self {
+        self.strip_separators = strip;
+        self
+    }
+
+    /// Set how two-digit birth years are expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::{CenturyPolicy, ParseOptions};
+    ///
+    /// assert_eq!(nidx::bahrain::decode("850612349").unwrap().birth_year, 1985);
+    ///
+    /// let options = ParseOptions::new().century(CenturyPolicy::Pivot(90));
+    /// let info = nidx::bahrain::decode_with("850612349", &options).unwrap();
+    /// assert_eq!(info.birth_year, 2085);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn century(mut self, policy: CenturyPolicy) -> Self {
+        self.century = policy;
+        self
+    }
+
+    /// Set whether obvious placeholder and sample IDs are rejected.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::ParseOptions;
+    ///
+    /// let options = ParseOptions::new().reject_placeholders(true);
+    /// assert!(nidx::kosovo::validate("0000000000").is_ok());
+    /// assert_eq!(
+    ///     nidx::kosovo::validate_with("0000000000", &options),
+    ///     Err(nidx::kosovo::NidError::Placeholder)
+    /// );
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn reject_placeholders(mut self, reject: bool) -> Self {
+        self.reject_placeholders = reject;
+        self
+    }
+
+    /// Apply the pre-processing steps to `nid`, borrowing when nothing changes.
+    pub(crate) fn prepare<'a>(&self, nid: &'a str) -> Cow<'a, str> {
+        if self.strip_separators && nid.chars().any(is_separator) {
+            Cow::Owned(nid.chars().filter(|&c| !is_separator(c)).collect())
+        } else {
+            Cow::Borrowed(nid)
+        }
+    }
+
+    /// Like [`prepare`](Self::prepare), but only strip whitespace, for formats
+    /// in which `-`, `.` or `/` carry meaning.
+    pub(crate) fn prepare_whitespace<'a>(&self, nid: &'a str) -> Cow<'a, str> {
+        if self.strip_separators && nid.chars().any(char::is_whitespace) {
+            Cow::Owned(nid.chars().filter(|c| !c.is_whitespace()).collect())
+        } else {
+            Cow::Borrowed(nid)
+        }
+    }
+
+    /// Like [`prepare`](Self::prepare), but keep `-`, for formats in which
+    /// only the hyphen carries meaning.
+    pub(crate) fn prepare_keep_hyphen<'a>(&self, nid: &'a str) -> Cow<'a, str> {
+        let strip = |c: char| c != '-' && is_separator(c);
+        if self.strip_separators && nid.chars().any(strip) {
+            Cow::Owned(nid.chars().filter(|&c| !strip(c)).collect())
+        } else {
+            Cow::Borrowed(nid)
+        }
+    }
+}
+
+#[inline]
+fn is_separator(c: char) -> bool {
+    c.is_whitespace() || matches!(c, '-' | '.' | '/')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn strict_borrows_input_unchanged() {
+        let prepared = ParseOptions::new().prepare(" J0-01 ");
+        assert!(matches!(prepared, Cow::Borrowed(" J0-01 ")));
+    }
+
+    #[test]
+    fn lenient_strips_separators() {
+        let opts = ParseOptions::lenient();
+        assert_eq!(opts.prepare("J0 0101 999W\r\n"), "J00101999W");
+        assert_eq!(opts.prepare("123-456.789/2"), "1234567892");
+        assert_eq!(opts.prepare("\u{a0}J00101999W\t"), "J00101999W");
+        assert!(matches!(opts.prepare("J00101999W"), Cow::Borrowed(_)));
+    }
+
+    #[test]
+    fn prepare_whitespace_keeps_separators() {
+        let opts = ParseOptions::lenient();
+        assert_eq!(opts.prepare_whitespace(" 131052-308T\n"), "131052-308T");
+        assert!(matches!(
+            ParseOptions::new().prepare_whitespace(" 131052-308T "),
+            Cow::Borrowed(" 131052-308T ")
+        ));
+    }
+
+    #[test]
+    fn prepare_keep_hyphen_strips_other_separators() {
+        let opts = ParseOptions::lenient();
+        assert_eq!(opts.prepare_keep_hyphen(" 79.555.123-7\n"), "79555123-7");
+        assert!(matches!(
+            opts.prepare_keep_hyphen("79555123-7"),
+            Cow::Borrowed(_)
+        ));
+    }
+
+    #[test]
+    fn builder_toggles_stripping() {
+        assert_eq!(
+            ParseOptions::new().strip_separators(true),
+            ParseOptions::lenient()
+        );
+        assert_eq!(
+            ParseOptions::lenient().strip_separators(false),
+            ParseOptions::default()
+        );
+    }
+}
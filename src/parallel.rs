@@ -0,0 +1,60 @@
+//! Parallel batch validation, behind the `rayon` feature.
+
+use rayon::prelude::*;
+
+use crate::{Country, Error};
+
+/// Validate a batch of IDs issued by the given country on the rayon thread
+/// pool, yielding one result per ID in input order.
+///
+/// Each result equals what [`validate`](crate::validate) returns for that
+/// ID. The iterator is indexed, so `collect` into a `Vec` keeps results
+/// aligned with `nids`, and adaptors such as `enumerate` give each result's
+/// row.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Country;
+/// use rayon::prelude::*;
+///
+/// let nids = ["J00101999W", "J00101999A", "H55312123U"];
+/// let invalid: Vec<usize> = nidx::par_validate_iter(Country::Albania, &nids)
+///     .enumerate()
+///     .filter_map(|(row, result)| result.is_err().then_some(row))
+///     .collect();
+/// assert_eq!(invalid, [1]);
+/// ```
+pub fn par_validate_iter<S>(
+    country: Country,
+    nids: &[S],
+) -> impl IndexedParallelIterator<Item = Result<(), Error>>
+where
+    S: AsRef<str> + Sync,
+{
+    nids.par_iter()
+        .map(move |nid| crate::validate(country, nid.as_ref()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_sequential_validation() {
+        for &country in Country::ALL {
+            let mut nids: Vec<String> = (0..300)
+                .map(|index| crate::generator::generate_seeded(country, 11, index))
+                .collect();
+            for nid in nids.iter_mut().step_by(3) {
+                nid.pop();
+            }
+            let results: Vec<_> = par_validate_iter(country, &nids).collect();
+            let expected: Vec<_> = nids
+                .iter()
+                .map(|nid| crate::validate(country, nid))
+                .collect();
+            assert_eq!(results, expected, "{country}");
+        }
+    }
+}
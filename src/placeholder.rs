@@ -0,0 +1,57 @@
+/// `true` if the ASCII digits in `digits` are all the same (`0000…`) or run
+/// up or down by one at each step (`1234…`, `9876…`).
+///
+/// Used by the country modules' placeholder rules, see
+/// [`ParseOptions::reject_placeholders`](crate::ParseOptions::reject_placeholders).
+pub(crate) fn is_trivial_sequence(digits: &[u8]) -> bool {
+    let [first, second, ..] = digits else {
+        return false;
+    };
+    let step = i16::from(*second) - i16::from(*first);
+    (-1..=1).contains(&step)
+        && digits
+            .windows(2)
+            .all(|pair| i16::from(pair[1]) - i16::from(pair[0]) == step)
+}
+
+/// The fewest payload digits [`has_trivial_payload`] looks at; shorter
+/// numbers are too easily a run by chance.
+const MIN_PAYLOAD_DIGITS: usize = 6;
+
+/// `true` if the digits of `nid`, without a trailing check digit, are a
+/// trivial sequence of at least [`MIN_PAYLOAD_DIGITS`] digits.
+///
+/// This is the placeholder rule [`decode_with`](crate::decode_with) applies
+/// to countries without rules of their own. Separators and letters are
+/// skipped, so it works on any written form of an ID.
+pub(crate) fn has_trivial_payload(nid: &str) -> bool {
+    let mut digits: Vec<u8> = nid.bytes().filter(u8::is_ascii_digit).collect();
+    if nid.ends_with(|c: char| c.is_ascii_digit()) {
+        digits.pop();
+    }
+    digits.len() >= MIN_PAYLOAD_DIGITS && is_trivial_sequence(&digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn trivial_sequences() {
+        assert!(is_trivial_sequence(b"000000000"));
+        assert!(is_trivial_sequence(b"123456789"));
+        assert!(is_trivial_sequence(b"98765432"));
+        assert!(!is_trivial_sequence(b"123456788"));
+        assert!(!is_trivial_sequence(b"13579"));
+        assert!(!is_trivial_sequence(b"1"));
+    }
+
+    #[test]
+    fn trivial_payloads() {
+        assert!(has_trivial_payload("12345678"));
+        assert!(has_trivial_payload("111.111.111-K"));
+        assert!(has_trivial_payload("00000000T"));
+        assert!(!has_trivial_payload("31415926"));
+        assert!(!has_trivial_payload("12345"));
+    }
+}
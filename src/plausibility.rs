@@ -0,0 +1,263 @@
+use std::fmt;
+
+use crate::{Date, Summary};
+
+/// Sanity checks on a decoded birthday relative to a reference date,
+/// usually today.
+///
+/// Decoding only checks that a birthday is a real calendar date. A
+/// checksum-valid ID can still encode a birth in the future or more than a
+/// lifetime ago, which is almost always a data-entry error or a forgery.
+/// `Plausibility` rejects both. The crate has no clock, so the reference
+/// date is supplied by the caller.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::{Date, Plausibility, PlausibilityError};
+///
+/// let today = Date { year: 2026, month: 10, day: 16 };
+/// let check = Plausibility::new(today);
+///
+/// let info = nidx::albania::decode("J00101999W").unwrap();
+/// assert!(check.check(info.birthday).is_ok());
+///
+/// // Born in 2090.
+/// let future = nidx::albania::complete("T00101001").unwrap();
+/// let info = nidx::albania::decode(&future).unwrap();
+/// assert!(matches!(
+///     check.check(info.birthday),
+///     Err(PlausibilityError::FutureBirth { .. })
+/// ));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Plausibility {
+    reference: Date,
+    max_age: u16,
+}
+
+impl Plausibility {
+    /// The maximum age used by [`Plausibility::new`].
+    pub const DEFAULT_MAX_AGE: u16 = 120;
+
+    /// Check birthdays against `reference`, allowing ages up to
+    /// [`DEFAULT_MAX_AGE`](Self::DEFAULT_MAX_AGE).
+    #[inline]
+    #[must_use]
+    pub const fn new(reference: Date) -> Self {
+        Plausibility {
+            reference,
+            max_age: Self::DEFAULT_MAX_AGE,
+        }
+    }
+
+    /// Set the greatest age, in completed years on the reference date, that
+    /// is still plausible.
+    #[inline]
+    #[must_use]
+    pub const fn max_age(mut self, years: u16) -> Self {
+        self.max_age = years;
+        self
+    }
+
+    /// The reference date birthdays are checked against.
+    #[inline]
+    #[must_use]
+    pub const fn reference(&self) -> Date {
+        self.reference
+    }
+
+    /// Check a decoded birthday.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`PlausibilityError::FutureBirth`] if `birthday` is after the
+    /// reference date, and [`PlausibilityError::TooOld`] if the holder would
+    /// be older than the maximum age on the reference date.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::{Date, Plausibility, PlausibilityError};
+    ///
+    /// let check = Plausibility::new(Date { year: 2026, month: 1, day: 1 }).max_age(100);
+    /// let birthday = Date { year: 1924, month: 6, day: 1 };
+    /// assert_eq!(
+    ///     check.check(birthday),
+    ///     Err(PlausibilityError::TooOld { birthday, max_age: 100 })
+    /// );
+    /// ```
+    pub const fn check(&self, birthday: Date) -> Result<(), PlausibilityError> {
+        let reference = self.reference;
+        let before_anniversary = reference.month < birthday.month
+            || (reference.month == birthday.month && reference.day < birthday.day);
+        if birthday.year > reference.year || (birthday.year == reference.year && before_anniversary)
+        {
+            return Err(PlausibilityError::FutureBirth {
+                birthday,
+                reference,
+            });
+        }
+        let age = reference.year - birthday.year - before_anniversary as u16;
+        if age > self.max_age {
+            return Err(PlausibilityError::TooOld {
+                birthday,
+                max_age: self.max_age,
+            });
+        }
+        Ok(())
+    }
+
+    /// Check the birthday of a decoded ID, if it encodes one.
+    ///
+    /// IDs without a birthday always pass.
+    ///
+    /// # Errors
+    ///
+    /// Returns the same errors as [`check`](Self::check).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::{Country, Date, Plausibility};
+    ///
+    /// let check = Plausibility::new(Date { year: 2026, month: 10, day: 16 });
+    /// let id = nidx::decode(Country::Albania, "J00101999W").unwrap();
+    /// assert!(check.check_summary(&id.summary()).is_ok());
+    /// ```
+    #[inline]
+    pub const fn check_summary(&self, summary: &Summary) -> Result<(), PlausibilityError> {
+        match summary.birthday {
+            Some(birthday) => self.check(birthday),
+            None => Ok(()),
+        }
+    }
+}
+
+/// Reason a decoded birthday was rejected by [`Plausibility`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum PlausibilityError {
+    /// The birthday is after the reference date.
+    FutureBirth {
+        /// The decoded birthday.
+        birthday: Date,
+        /// The reference date it was checked against.
+        reference: Date,
+    },
+    /// The holder would be older than the maximum age on the reference date.
+    TooOld {
+        /// The decoded birthday.
+        birthday: Date,
+        /// The maximum age that was exceeded.
+        max_age: u16,
+    },
+}
+
+impl PlausibilityError {
+    /// A stable, machine-readable code identifying the error.
+    ///
+    /// | Error | Code |
+    /// |-------|------|
+    /// | [`PlausibilityError::FutureBirth`] | `PLAUSIBILITY_FUTURE_BIRTH` |
+    /// | [`PlausibilityError::TooOld`] | `PLAUSIBILITY_TOO_OLD` |
+    #[must_use]
+    pub const fn error_code(&self) -> &'static str {
+        match self {
+            PlausibilityError::FutureBirth { .. } => "PLAUSIBILITY_FUTURE_BIRTH",
+            PlausibilityError::TooOld { .. } => "PLAUSIBILITY_TOO_OLD",
+        }
+    }
+}
+
+impl fmt::Display for PlausibilityError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PlausibilityError::FutureBirth {
+                birthday,
+                reference,
+            } => write!(f, "birthday {birthday} is after {reference}"),
+            PlausibilityError::TooOld { birthday, max_age } => {
+                write!(f, "birthday {birthday} implies an age over {max_age}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for PlausibilityError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const REFERENCE: Date = Date {
+        year: 2026,
+        month: 10,
+        day: 16,
+    };
+
+    const fn date(year: u16, month: u8, day: u8) -> Date {
+        Date { year, month, day }
+    }
+
+    #[test]
+    fn future_birth_is_rejected_from_the_next_day() {
+        let check = Plausibility::new(REFERENCE);
+        assert!(check.check(REFERENCE).is_ok());
+        assert!(check.check(date(2026, 10, 15)).is_ok());
+        for birthday in [date(2026, 10, 17), date(2026, 11, 1), date(2027, 1, 1)] {
+            assert_eq!(
+                check.check(birthday),
+                Err(PlausibilityError::FutureBirth {
+                    birthday,
+                    reference: REFERENCE,
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn max_age_counts_completed_years() {
+        let check = Plausibility::new(REFERENCE).max_age(100);
+        // Turns 101 tomorrow.
+        assert!(check.check(date(1925, 10, 17)).is_ok());
+        // Turned 101 today.
+        assert_eq!(
+            check.check(date(1925, 10, 16)),
+            Err(PlausibilityError::TooOld {
+                birthday: date(1925, 10, 16),
+                max_age: 100,
+            })
+        );
+        assert!(
+            Plausibility::new(REFERENCE)
+                .check(date(1906, 10, 16))
+                .is_ok()
+        );
+        assert!(
+            Plausibility::new(REFERENCE)
+                .check(date(1905, 10, 16))
+                .is_err()
+        );
+    }
+
+    #[test]
+    fn summary_without_birthday_passes() {
+        let summary = Summary {
+            birthday: None,
+            sex: None,
+            is_national: None,
+        };
+        assert!(Plausibility::new(REFERENCE).check_summary(&summary).is_ok());
+    }
+
+    #[test]
+    fn error_display_and_codes() {
+        let err = Plausibility::new(REFERENCE)
+            .check(date(2030, 1, 1))
+            .unwrap_err();
+        assert_eq!(err.to_string(), "birthday 2030-01-01 is after 2026-10-16");
+        assert_eq!(err.error_code(), "PLAUSIBILITY_FUTURE_BIRTH");
+    }
+}
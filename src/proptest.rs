@@ -0,0 +1,103 @@
+//! [proptest](https://docs.rs/proptest) strategies producing valid IDs.
+//!
+//! Downstream crates can use these to property-test their own parsers,
+//! storage and forms with realistic valid inputs. Albanian and Kosovo
+//! strategies are built from their fields and shrink towards the smallest
+//! birthday, serial and digits; [`valid_nid`] covers every country through
+//! [`generate_seeded`](crate::generator::generate_seeded) and shrinks towards
+//! seed `0`.
+//!
+//! This module requires the `proptest` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::default();
+//! runner
+//!     .run(&nidx::proptest::valid_albania_nid(), |nid| {
+//!         assert!(nidx::albania::is_valid(&nid));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+
+use ::proptest::prelude::*;
+
+use crate::date::{civil_from_days, days_from_civil};
+use crate::{Country, Date, Sex, albania, kosovo};
+
+/// Earliest birthday an Albanian NID can encode.
+const ALBANIA_EARLIEST: Date = Date {
+    year: 1800,
+    month: 1,
+    day: 1,
+};
+
+/// Latest birthday an Albanian NID can encode.
+const ALBANIA_LATEST: Date = Date {
+    year: 2099,
+    month: 12,
+    day: 31,
+};
+
+/// Valid Albanian NIDs, in canonical upper-case form.
+///
+/// Birthdays cover the whole encodable range, 1800-01-01 to 2099-12-31; sex,
+/// national status and serial are drawn uniformly.
+pub fn valid_albania_nid() -> impl Strategy<Value = String> {
+    (
+        days_from_civil(ALBANIA_EARLIEST)..=days_from_civil(ALBANIA_LATEST),
+        any::<bool>(),
+        any::<bool>(),
+        0u16..1000,
+    )
+        .prop_map(|(days, female, foreign, serial)| {
+            let sex = if female { Sex::Female } else { Sex::Male };
+            albania::encode(civil_from_days(days), sex, !foreign, serial)
+                .expect("the birthday range is encodable")
+        })
+}
+
+/// Valid Kosovo personal numbers with a computed check digit.
+///
+/// Numbers starting with `9`, whose check digit is not verified, are not
+/// produced.
+pub fn valid_kosovo_number() -> impl Strategy<Value = String> {
+    "[0-8][0-9]{8}".prop_map(|partial| {
+        let digits: &[u8; 9] = partial.as_bytes().try_into().expect("9 digits");
+        format!("{partial}{}", kosovo::check_digit(digits))
+    })
+}
+
+/// Valid IDs for `country`, as produced by
+/// [`generate_seeded`](crate::generator::generate_seeded).
+pub fn valid_nid(country: Country) -> impl Strategy<Value = String> {
+    any::<u64>().prop_map(move |seed| crate::generator::generate_seeded(country, seed, 0))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    proptest! {
+        #[test]
+        fn albania_nids_are_valid(nid in valid_albania_nid()) {
+            prop_assert!(albania::is_valid(&nid));
+        }
+
+        #[test]
+        fn kosovo_numbers_are_valid(nid in valid_kosovo_number()) {
+            prop_assert!(kosovo::validate_strict(&nid).is_ok());
+        }
+
+        #[test]
+        fn nids_are_valid_for_every_country(
+            (country, nid) in ::proptest::sample::select(Country::ALL)
+                .prop_flat_map(|country| (Just(country), valid_nid(country)))
+        ) {
+            prop_assert!(crate::is_valid(country, &nid), "{}: {}", country, nid);
+        }
+    }
+}
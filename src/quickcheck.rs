@@ -0,0 +1,82 @@
+//! [quickcheck](https://docs.rs/quickcheck) newtypes holding valid IDs.
+//!
+//! The [`Arbitrary`] implementations draw a seed from the [`Gen`] and pass it
+//! to [`generate_seeded`], so the IDs are as varied as those of the
+//! [`proptest`](https://docs.rs/proptest) strategies behind the `proptest`
+//! feature. They do not shrink: a smaller valid ID is not a simpler one.
+//!
+//! This module requires the `quickcheck` feature.
+//!
+//! # Examples
+//!
+//! ```
+//! use nidx::quickcheck::ValidAlbaniaNid;
+//! use quickcheck::{QuickCheck, TestResult};
+//!
+//! fn accepts_valid_nids(nid: ValidAlbaniaNid) -> TestResult {
+//!     TestResult::from_bool(nidx::albania::is_valid(&nid.0))
+//! }
+//!
+//! QuickCheck::new().quickcheck(accepts_valid_nids as fn(ValidAlbaniaNid) -> TestResult);
+//! ```
+
+use ::quickcheck::{Arbitrary, Gen};
+
+use crate::Country;
+use crate::generator::generate_seeded;
+
+/// A valid Albanian NID, in canonical upper-case form.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidAlbaniaNid(pub String);
+
+/// A valid Kosovo personal number whose check digit is verified, i.e. one
+/// that does not start with `9`.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidKosovoNumber(pub String);
+
+/// A valid ID of any supported country.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidNid {
+    /// The issuing country.
+    pub country: Country,
+    /// The ID, in canonical form.
+    pub nid: String,
+}
+
+impl Arbitrary for ValidAlbaniaNid {
+    fn arbitrary(g: &mut Gen) -> Self {
+        ValidAlbaniaNid(generate_seeded(Country::Albania, u64::arbitrary(g), 0))
+    }
+}
+
+impl Arbitrary for ValidKosovoNumber {
+    fn arbitrary(g: &mut Gen) -> Self {
+        ValidKosovoNumber(generate_seeded(Country::Kosovo, u64::arbitrary(g), 0))
+    }
+}
+
+impl Arbitrary for ValidNid {
+    fn arbitrary(g: &mut Gen) -> Self {
+        let country = *g.choose(Country::ALL).expect("there are countries");
+        ValidNid {
+            country,
+            nid: generate_seeded(country, u64::arbitrary(g), 0),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ::quickcheck::QuickCheck;
+
+    #[test]
+    fn newtypes_hold_valid_ids() {
+        fn check(albania: ValidAlbaniaNid, kosovo: ValidKosovoNumber, any: ValidNid) -> bool {
+            crate::albania::is_valid(&albania.0)
+                && crate::kosovo::validate_strict(&kosovo.0).is_ok()
+                && crate::is_valid(any.country, &any.nid)
+        }
+        QuickCheck::new().quickcheck(check as fn(_, _, _) -> bool);
+    }
+}
@@ -0,0 +1,320 @@
+//! Runtime lookup of decoders by ISO 3166-1 alpha-2 code.
+
+use std::any::Any;
+use std::fmt;
+
+use crate::{
+    Country, NationalId, albania, australia, austria, bahrain, belarus, botswana, chile, colombia,
+    costa_rica, cyprus, czechia, ecuador, egypt, finland, france, georgia, germany, ireland, italy,
+    kenya, kosovo, latvia, malaysia, north_macedonia, peru, poland, portugal, russia, serbia,
+    south_korea, spain, switzerland, turkey, uae, ukraine, venezuela, vietnam, zimbabwe,
+};
+
+/// Decoded information behind dynamic dispatch.
+///
+/// Downcast to the concrete `NidInfo` type with [`Box::downcast`] or
+/// [`<dyn Any>::downcast_ref`](Any::downcast_ref).
+pub type BoxedInfo = Box<dyn Any + Send + Sync>;
+
+/// A country module's error behind dynamic dispatch.
+pub type BoxedError = Box<dyn std::error::Error + Send + Sync>;
+
+/// Object-safe counterpart of [`NationalId`], used by [`Registry`].
+///
+/// Every [`NationalId`] implementor whose `Info` and `Error` types are
+/// `'static + Send + Sync` gets this trait for free; implement it directly
+/// only for decoders that do not fit [`NationalId`].
+pub trait Decoder: Send + Sync {
+    /// Validate and decode an ID, boxing the result.
+    ///
+    /// # Errors
+    ///
+    /// Returns the boxed country error if the ID is invalid.
+    fn decode(&self, nid: &str) -> Result<BoxedInfo, BoxedError>;
+
+    /// Validate an ID without returning the decoded information.
+    ///
+    /// # Errors
+    ///
+    /// Returns the boxed country error if the ID is invalid.
+    #[inline]
+    fn validate(&self, nid: &str) -> Result<(), BoxedError> {
+        self.decode(nid).map(|_| ())
+    }
+}
+
+impl<T> Decoder for T
+where
+    T: NationalId + Send + Sync,
+    T::Info: Send + Sync + 'static,
+    T::Error: Send + Sync + 'static,
+{
+    #[inline]
+    fn decode(&self, nid: &str) -> Result<BoxedInfo, BoxedError> {
+        match T::decode(nid) {
+            Ok(info) => Ok(Box::new(info)),
+            Err(e) => Err(Box::new(e)),
+        }
+    }
+
+    #[inline]
+    fn validate(&self, nid: &str) -> Result<(), BoxedError> {
+        T::validate(nid).map_err(|e| Box::new(e) as BoxedError)
+    }
+}
+
+/// Errors returned by [`Registry`] lookups.
+#[derive(Debug)]
+#[non_exhaustive]
+pub enum RegistryError {
+    /// No decoder is registered under the requested code.
+    UnknownCode,
+    /// The decoder rejected the ID.
+    Invalid(BoxedError),
+}
+
+impl fmt::Display for RegistryError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            RegistryError::UnknownCode => write!(f, "no decoder registered for this code"),
+            RegistryError::Invalid(e) => write!(f, "{e}"),
+        }
+    }
+}
+
+impl std::error::Error for RegistryError {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            RegistryError::Invalid(e) => Some(&**e),
+            _ => None,
+        }
+    }
+}
+
+/// A set of decoders keyed by country code, chosen at runtime.
+///
+/// [`Registry::default`] contains every built-in country under its ISO
+/// 3166-1 alpha-2 code (see [`Country::alpha2`]). Codes are matched ignoring
+/// ASCII case, and [`register`](Registry::register) can add or replace
+/// entries, e.g. to plug in a decoder defined outside this crate.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Registry;
+///
+/// let registry = Registry::default();
+/// assert!(registry.validate("XK", "1234567892").is_ok());
+///
+/// let info = registry.decode("al", "J00101999W").unwrap();
+/// let info = info.downcast_ref::<nidx::albania::NidInfo>().unwrap();
+/// assert_eq!(info.birthday.to_string(), "1990-01-01");
+///
+/// assert!(registry.decode("ZZ", "J00101999W").is_err());
+/// ```
+pub struct Registry {
+    entries: Vec<(String, Box<dyn Decoder>)>,
+}
+
+impl Registry {
+    /// Create an empty registry.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Registry {
+            entries: Vec::new(),
+        }
+    }
+
+    /// Register a decoder under `code`, replacing any existing entry for it.
+    pub fn register<D: Decoder + 'static>(&mut self, code: &str, decoder: D) {
+        let decoder: Box<dyn Decoder> = Box::new(decoder);
+        match self.position(code) {
+            Some(i) => self.entries[i].1 = decoder,
+            None => self.entries.push((code.to_ascii_uppercase(), decoder)),
+        }
+    }
+
+    /// The decoder registered under `code`, if any.
+    #[must_use]
+    pub fn get(&self, code: &str) -> Option<&dyn Decoder> {
+        self.position(code).map(|i| &*self.entries[i].1)
+    }
+
+    /// Every registered code, uppercased, in registration order.
+    pub fn codes(&self) -> impl Iterator<Item = &str> {
+        self.entries.iter().map(|(code, _)| code.as_str())
+    }
+
+    /// Decode `nid` with the decoder registered under `code`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::UnknownCode`] if nothing is registered under
+    /// `code`, or [`RegistryError::Invalid`] if the decoder rejects the ID.
+    pub fn decode(&self, code: &str, nid: &str) -> Result<BoxedInfo, RegistryError> {
+        self.get(code)
+            .ok_or(RegistryError::UnknownCode)?
+            .decode(nid)
+            .map_err(RegistryError::Invalid)
+    }
+
+    /// Validate `nid` with the decoder registered under `code`.
+    ///
+    /// # Errors
+    ///
+    /// Returns [`RegistryError::UnknownCode`] if nothing is registered under
+    /// `code`, or [`RegistryError::Invalid`] if the decoder rejects the ID.
+    pub fn validate(&self, code: &str, nid: &str) -> Result<(), RegistryError> {
+        self.get(code)
+            .ok_or(RegistryError::UnknownCode)?
+            .validate(nid)
+            .map_err(RegistryError::Invalid)
+    }
+
+    /// Check whether `nid` is valid for the decoder registered under `code`.
+    ///
+    /// Returns `false` if nothing is registered under `code`.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self, code: &str, nid: &str) -> bool {
+        self.validate(code, nid).is_ok()
+    }
+
+    fn position(&self, code: &str) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|(c, _)| c.eq_ignore_ascii_case(code))
+    }
+}
+
+impl Default for Registry {
+    /// A registry with every built-in country under its ISO alpha-2 code.
+    fn default() -> Self {
+        let mut registry = Registry::new();
+        for &country in Country::ALL {
+            match country {
+                Country::Albania => registry.register(country.alpha2(), albania::Albania),
+                Country::Kosovo => registry.register(country.alpha2(), kosovo::Kosovo),
+                Country::Serbia => registry.register(country.alpha2(), serbia::Serbia),
+                Country::NorthMacedonia => {
+                    registry.register(country.alpha2(), north_macedonia::NorthMacedonia)
+                }
+                Country::Italy => registry.register(country.alpha2(), italy::Italy),
+                Country::Spain => registry.register(country.alpha2(), spain::Spain),
+                Country::Portugal => registry.register(country.alpha2(), portugal::Portugal),
+                Country::France => registry.register(country.alpha2(), france::France),
+                Country::Germany => registry.register(country.alpha2(), germany::Germany),
+                Country::Switzerland => {
+                    registry.register(country.alpha2(), switzerland::Switzerland)
+                }
+                Country::Austria => registry.register(country.alpha2(), austria::Austria),
+                Country::Poland => registry.register(country.alpha2(), poland::Poland),
+                Country::Czechia => registry.register(country.alpha2(), czechia::Czechia),
+                Country::Ukraine => registry.register(country.alpha2(), ukraine::Ukraine),
+                Country::Turkey => registry.register(country.alpha2(), turkey::Turkey),
+                Country::Finland => registry.register(country.alpha2(), finland::Finland),
+                Country::Latvia => registry.register(country.alpha2(), latvia::Latvia),
+                Country::Ireland => registry.register(country.alpha2(), ireland::Ireland),
+                Country::Chile => registry.register(country.alpha2(), chile::Chile),
+                Country::SouthKorea => registry.register(country.alpha2(), south_korea::SouthKorea),
+                Country::Malaysia => registry.register(country.alpha2(), malaysia::Malaysia),
+                Country::Egypt => registry.register(country.alpha2(), egypt::Egypt),
+                Country::Australia => registry.register(country.alpha2(), australia::Australia),
+                Country::Belarus => registry.register(country.alpha2(), belarus::Belarus),
+                Country::Russia => registry.register(country.alpha2(), russia::Russia),
+                Country::Georgia => registry.register(country.alpha2(), georgia::Georgia),
+                Country::Cyprus => registry.register(country.alpha2(), cyprus::Cyprus),
+                Country::Uae => registry.register(country.alpha2(), uae::Uae),
+                Country::Bahrain => registry.register(country.alpha2(), bahrain::Bahrain),
+                Country::Kenya => registry.register(country.alpha2(), kenya::Kenya),
+                Country::Zimbabwe => registry.register(country.alpha2(), zimbabwe::Zimbabwe),
+                Country::Botswana => registry.register(country.alpha2(), botswana::Botswana),
+                Country::Peru => registry.register(country.alpha2(), peru::Peru),
+                Country::Ecuador => registry.register(country.alpha2(), ecuador::Ecuador),
+                Country::Venezuela => registry.register(country.alpha2(), venezuela::Venezuela),
+                Country::Colombia => registry.register(country.alpha2(), colombia::Colombia),
+                Country::CostaRica => registry.register(country.alpha2(), costa_rica::CostaRica),
+                Country::Vietnam => registry.register(country.alpha2(), vietnam::Vietnam),
+            }
+        }
+        registry
+    }
+}
+
+impl fmt::Debug for Registry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Registry")
+            .field("codes", &self.codes().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn default_registers_every_country() {
+        let registry = Registry::default();
+        let codes: Vec<_> = registry.codes().collect();
+        let expected: Vec<_> = Country::ALL.iter().map(|c| c.alpha2()).collect();
+        assert_eq!(codes, expected);
+    }
+
+    #[test]
+    fn decode_downcasts_to_country_info() {
+        let registry = Registry::default();
+        let info = registry.decode("AL", "J00101999W").unwrap();
+        assert_eq!(
+            *info.downcast::<albania::NidInfo>().unwrap(),
+            albania::decode("J00101999W").unwrap()
+        );
+        let info = registry.decode("xk", "1234567892").unwrap();
+        assert!(info.downcast_ref::<()>().is_some());
+    }
+
+    #[test]
+    fn invalid_id_wraps_country_error() {
+        let registry = Registry::default();
+        let err = registry.validate("XK", "1234567890").unwrap_err();
+        let RegistryError::Invalid(inner) = err else {
+            panic!("expected Invalid, got {err:?}");
+        };
+        assert_eq!(
+            inner.downcast_ref::<kosovo::NidError>(),
+            Some(&kosovo::NidError::Checksum)
+        );
+    }
+
+    #[test]
+    fn unknown_code() {
+        let registry = Registry::default();
+        assert!(matches!(
+            registry.validate("ZZ", "1234567892"),
+            Err(RegistryError::UnknownCode)
+        ));
+        assert!(!registry.is_valid("", "1234567892"));
+        assert!(Registry::new().get("AL").is_none());
+    }
+
+    #[test]
+    fn register_replaces_existing_entry() {
+        let mut registry = Registry::default();
+        registry.register("al", kosovo::Kosovo);
+        assert_eq!(registry.codes().count(), Country::ALL.len());
+        assert!(registry.is_valid("AL", "1234567892"));
+        assert!(!registry.is_valid("AL", "J00101999W"));
+    }
+
+    #[test]
+    fn error_display() {
+        assert_eq!(
+            RegistryError::UnknownCode.to_string(),
+            "no decoder registered for this code"
+        );
+        let err = RegistryError::Invalid(Box::new(kosovo::NidError::Checksum));
+        assert_eq!(err.to_string(), "checksum validation failed");
+    }
+}
@@ -0,0 +1,92 @@
+/// Every problem found in an ID, as returned by the country modules'
+/// `validate_all` functions.
+///
+/// Unlike `validate`, which stops at the first problem, a report lists all
+/// issues that can be detected independently, in the order `validate` would
+/// check them. The first issue is therefore the error `validate` returns.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::albania::{FormatKind, NidError};
+///
+/// // Bad decade character *and* an unknown month code.
+/// let report = nidx::albania::validate_all("Z0130112A3");
+/// assert!(!report.is_valid());
+/// assert!(report
+///     .issues()
+///     .contains(&NidError::Format(FormatKind::InvalidDecadeChar { found: b'Z' })));
+/// assert!(report
+///     .issues()
+///     .contains(&NidError::Format(FormatKind::InvalidMonthCode { code: 13 })));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ValidationReport<E> {
+    issues: Vec<E>,
+}
+
+impl<E> ValidationReport<E> {
+    #[inline]
+    pub(crate) const fn new() -> Self {
+        ValidationReport { issues: Vec::new() }
+    }
+
+    #[inline]
+    pub(crate) fn push(&mut self, issue: E) {
+        self.issues.push(issue);
+    }
+
+    /// `true` if no issues were found.
+    #[inline]
+    #[must_use]
+    pub fn is_valid(&self) -> bool {
+        self.issues.is_empty()
+    }
+
+    /// The issues found, in check order.
+    #[inline]
+    #[must_use]
+    pub fn issues(&self) -> &[E] {
+        &self.issues
+    }
+
+    /// The first issue found, i.e. the error `validate` would return.
+    #[inline]
+    #[must_use]
+    pub fn first(&self) -> Option<&E> {
+        self.issues.first()
+    }
+
+    /// Iterate over the issues found.
+    #[inline]
+    pub fn iter(&self) -> std::slice::Iter<'_, E> {
+        self.issues.iter()
+    }
+}
+
+impl<E> Default for ValidationReport<E> {
+    #[inline]
+    fn default() -> Self {
+        ValidationReport::new()
+    }
+}
+
+impl<E> IntoIterator for ValidationReport<E> {
+    type Item = E;
+    type IntoIter = std::vec::IntoIter<E>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.into_iter()
+    }
+}
+
+impl<'a, E> IntoIterator for &'a ValidationReport<E> {
+    type Item = &'a E;
+    type IntoIter = std::slice::Iter<'a, E>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        self.issues.iter()
+    }
+}
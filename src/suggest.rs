@@ -0,0 +1,124 @@
+use std::fmt;
+
+/// The single edit that turns a rejected ID into a [`Suggestion`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Correction {
+    /// The character at `position` (0-based) was replaced.
+    Substitution {
+        /// 0-based position of the replaced character.
+        position: usize,
+        /// The character found in the input.
+        found: char,
+        /// The character that makes the ID valid.
+        replacement: char,
+    },
+    /// The characters at `position` and `position + 1` (0-based) were swapped.
+    Transposition {
+        /// 0-based position of the first swapped character.
+        position: usize,
+    },
+}
+
+impl fmt::Display for Correction {
+    /// Formats with 1-based positions, e.g. `"character 3: 'O' -> '0'"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Correction::Substitution {
+                position,
+                found,
+                replacement,
+            } => write!(
+                f,
+                "character {}: {found:?} -> {replacement:?}",
+                position + 1
+            ),
+            Correction::Transposition { position } => {
+                write!(f, "swap characters {} and {}", position + 1, position + 2)
+            }
+        }
+    }
+}
+
+/// A valid ID one edit away from a rejected input, as returned by the
+/// country modules' `suggest_corrections` functions.
+///
+/// # Examples
+///
+/// ```
+/// use nidx::Correction;
+///
+/// // The last two digits were swapped.
+/// let suggestions = nidx::kosovo::suggest_corrections("1234567829");
+/// assert!(suggestions.iter().any(|s| s.nid == "1234567892"
+///     && s.correction == Correction::Transposition { position: 8 }));
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Suggestion {
+    /// The corrected, valid ID in canonical form.
+    pub nid: String,
+    /// The edit applied to the (normalized) input.
+    pub correction: Correction,
+}
+
+impl fmt::Display for Suggestion {
+    /// Formats as `"<nid> (<correction>)"`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.nid, self.correction)
+    }
+}
+
+/// Every ID accepted by `is_valid` that differs from `nid` by one
+/// substitution from `alphabet` or one swap of adjacent characters.
+///
+/// Returns nothing if `nid` is already valid or is not ASCII. Substitutions
+/// come first, ordered by position and then by `alphabet`, followed by
+/// transpositions ordered by position.
+pub(crate) fn suggest(
+    nid: &str,
+    alphabet: &[u8],
+    is_valid: impl Fn(&str) -> bool,
+) -> Vec<Suggestion> {
+    let mut suggestions = Vec::new();
+    if !nid.is_ascii() || is_valid(nid) {
+        return suggestions;
+    }
+    let mut candidate = nid.as_bytes().to_vec();
+    let mut try_candidate = |candidate: &[u8], correction: Correction| {
+        // Only ASCII bytes are ever written, so the candidate is valid UTF-8.
+        let candidate = std::str::from_utf8(candidate).expect("ASCII input");
+        if is_valid(candidate) {
+            suggestions.push(Suggestion {
+                nid: candidate.to_owned(),
+                correction,
+            });
+        }
+    };
+
+    for position in 0..candidate.len() {
+        let found = candidate[position];
+        for &replacement in alphabet.iter().filter(|&&c| c != found) {
+            candidate[position] = replacement;
+            try_candidate(
+                &candidate,
+                Correction::Substitution {
+                    position,
+                    found: char::from(found),
+                    replacement: char::from(replacement),
+                },
+            );
+        }
+        candidate[position] = found;
+    }
+
+    for position in 0..candidate.len().saturating_sub(1) {
+        if candidate[position] == candidate[position + 1] {
+            continue;
+        }
+        candidate.swap(position, position + 1);
+        try_candidate(&candidate, Correction::Transposition { position });
+        candidate.swap(position, position + 1);
+    }
+
+    suggestions
+}
@@ -1,5 +1,12 @@
 use std::fmt;
 
+use crate::{
+    Metadata, albania, australia, austria, bahrain, belarus, botswana, chile, colombia, costa_rica,
+    cyprus, czechia, ecuador, egypt, finland, france, georgia, germany, ireland, italy, kenya,
+    kosovo, latvia, malaysia, north_macedonia, peru, poland, portugal, russia, serbia, south_korea,
+    spain, switzerland, turkey, uae, ukraine, venezuela, vietnam, zimbabwe,
+};
+
 /// Biological sex as encoded in a national ID.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[non_exhaustive]
@@ -7,6 +14,12 @@ use std::fmt;
 pub enum Sex {
     Male,
     Female,
+    /// A non-binary or third-gender marker, such as `X` in German or ICAO
+    /// travel documents or `O` in Nepalese ones.
+    Other,
+    /// Sex not recorded or withheld, such as the `<` filler of a
+    /// machine-readable zone.
+    Unspecified,
 }
 
 impl fmt::Display for Sex {
@@ -15,6 +28,8 @@ impl fmt::Display for Sex {
         match self {
             Sex::Male => write!(f, "M"),
             Sex::Female => write!(f, "F"),
+            Sex::Other => write!(f, "X"),
+            Sex::Unspecified => write!(f, "U"),
         }
     }
 }
@@ -34,3 +49,308 @@ impl fmt::Display for Date {
         write!(f, "{:04}-{:02}-{:02}", self.year, self.month, self.day)
     }
 }
+
+/// Formats an offending input byte for error messages: quoted if it is a
+/// printable ASCII character (`'O'`), in hexadecimal otherwise (`0xC3`).
+pub(crate) struct FoundByte(pub(crate) u8);
+
+impl fmt::Display for FoundByte {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.0.is_ascii_graphic() {
+            write!(f, "'{}'", self.0 as char)
+        } else {
+            write!(f, "{:#04X}", self.0)
+        }
+    }
+}
+
+/// A country whose national ID format is supported by this crate.
+///
+/// The [`Display`](fmt::Display) and [`FromStr`](std::str::FromStr)
+/// representations use the lowercase module name (`"albania"`, `"kosovo"`),
+/// which makes the value convenient to store in a text column.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "snake_case"))]
+pub enum Country {
+    Albania,
+    Kosovo,
+    Serbia,
+    NorthMacedonia,
+    Italy,
+    Spain,
+    Portugal,
+    France,
+    Germany,
+    Switzerland,
+    Austria,
+    Poland,
+    Czechia,
+    Ukraine,
+    Turkey,
+    Finland,
+    Latvia,
+    Ireland,
+    Chile,
+    SouthKorea,
+    Malaysia,
+    Egypt,
+    Australia,
+    Belarus,
+    Russia,
+    Georgia,
+    Cyprus,
+    Uae,
+    Bahrain,
+    Kenya,
+    Zimbabwe,
+    Botswana,
+    Peru,
+    Ecuador,
+    Venezuela,
+    Colombia,
+    CostaRica,
+    Vietnam,
+}
+
+impl Country {
+    /// Every supported country, in declaration order.
+    pub const ALL: &'static [Country] = &[
+        Country::Albania,
+        Country::Kosovo,
+        Country::Serbia,
+        Country::NorthMacedonia,
+        Country::Italy,
+        Country::Spain,
+        Country::Portugal,
+        Country::France,
+        Country::Germany,
+        Country::Switzerland,
+        Country::Austria,
+        Country::Poland,
+        Country::Czechia,
+        Country::Ukraine,
+        Country::Turkey,
+        Country::Finland,
+        Country::Latvia,
+        Country::Ireland,
+        Country::Chile,
+        Country::SouthKorea,
+        Country::Malaysia,
+        Country::Egypt,
+        Country::Australia,
+        Country::Belarus,
+        Country::Russia,
+        Country::Georgia,
+        Country::Cyprus,
+        Country::Uae,
+        Country::Bahrain,
+        Country::Kenya,
+        Country::Zimbabwe,
+        Country::Botswana,
+        Country::Peru,
+        Country::Ecuador,
+        Country::Venezuela,
+        Country::Colombia,
+        Country::CostaRica,
+        Country::Vietnam,
+    ];
+
+    /// The lowercase name of the country, matching its module name.
+    #[inline]
+    #[must_use]
+    pub const fn name(self) -> &'static str {
+        match self {
+            Country::Albania => "albania",
+            Country::Kosovo => "kosovo",
+            Country::Serbia => "serbia",
+            Country::NorthMacedonia => "north_macedonia",
+            Country::Italy => "italy",
+            Country::Spain => "spain",
+            Country::Portugal => "portugal",
+            Country::France => "france",
+            Country::Germany => "germany",
+            Country::Switzerland => "switzerland",
+            Country::Austria => "austria",
+            Country::Poland => "poland",
+            Country::Czechia => "czechia",
+            Country::Ukraine => "ukraine",
+            Country::Turkey => "turkey",
+            Country::Finland => "finland",
+            Country::Latvia => "latvia",
+            Country::Ireland => "ireland",
+            Country::Chile => "chile",
+            Country::SouthKorea => "south_korea",
+            Country::Malaysia => "malaysia",
+            Country::Egypt => "egypt",
+            Country::Australia => "australia",
+            Country::Belarus => "belarus",
+            Country::Russia => "russia",
+            Country::Georgia => "georgia",
+            Country::Cyprus => "cyprus",
+            Country::Uae => "uae",
+            Country::Bahrain => "bahrain",
+            Country::Kenya => "kenya",
+            Country::Zimbabwe => "zimbabwe",
+            Country::Botswana => "botswana",
+            Country::Peru => "peru",
+            Country::Ecuador => "ecuador",
+            Country::Venezuela => "venezuela",
+            Country::Colombia => "colombia",
+            Country::CostaRica => "costa_rica",
+            Country::Vietnam => "vietnam",
+        }
+    }
+
+    /// Static facts about the country's ID format.
+    #[inline]
+    #[must_use]
+    pub const fn metadata(self) -> &'static Metadata {
+        match self {
+            Country::Albania => &albania::METADATA,
+            Country::Kosovo => &kosovo::METADATA,
+            Country::Serbia => &serbia::METADATA,
+            Country::NorthMacedonia => &north_macedonia::METADATA,
+            Country::Italy => &italy::METADATA,
+            Country::Spain => &spain::METADATA,
+            Country::Portugal => &portugal::METADATA,
+            Country::France => &france::METADATA,
+            Country::Germany => &germany::METADATA,
+            Country::Switzerland => &switzerland::METADATA,
+            Country::Austria => &austria::METADATA,
+            Country::Poland => &poland::METADATA,
+            Country::Czechia => &czechia::METADATA,
+            Country::Ukraine => &ukraine::METADATA,
+            Country::Turkey => &turkey::METADATA,
+            Country::Finland => &finland::METADATA,
+            Country::Latvia => &latvia::METADATA,
+            Country::Ireland => &ireland::METADATA,
+            Country::Chile => &chile::METADATA,
+            Country::SouthKorea => &south_korea::METADATA,
+            Country::Malaysia => &malaysia::METADATA,
+            Country::Egypt => &egypt::METADATA,
+            Country::Australia => &australia::METADATA,
+            Country::Belarus => &belarus::METADATA,
+            Country::Russia => &russia::METADATA,
+            Country::Georgia => &georgia::METADATA,
+            Country::Cyprus => &cyprus::METADATA,
+            Country::Uae => &uae::METADATA,
+            Country::Bahrain => &bahrain::METADATA,
+            Country::Kenya => &kenya::METADATA,
+            Country::Zimbabwe => &zimbabwe::METADATA,
+            Country::Botswana => &botswana::METADATA,
+            Country::Peru => &peru::METADATA,
+            Country::Ecuador => &ecuador::METADATA,
+            Country::Venezuela => &venezuela::METADATA,
+            Country::Colombia => &colombia::METADATA,
+            Country::CostaRica => &costa_rica::METADATA,
+            Country::Vietnam => &vietnam::METADATA,
+        }
+    }
+
+    /// The ISO 3166-1 alpha-2 code of the country.
+    ///
+    /// Kosovo has no officially assigned code and uses the user-assigned `XK`,
+    /// as is common practice (e.g. in the EU and on passports).
+    #[inline]
+    #[must_use]
+    pub const fn alpha2(self) -> &'static str {
+        self.metadata().alpha2
+    }
+
+    /// The ISO 3166-1 alpha-3 code of the country (`XKX` for Kosovo).
+    #[inline]
+    #[must_use]
+    pub const fn alpha3(self) -> &'static str {
+        self.metadata().alpha3
+    }
+
+    /// Look up a country by its ISO 3166-1 alpha-2 code, ignoring ASCII case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use nidx::Country;
+    ///
+    /// assert_eq!(Country::from_alpha2("al"), Some(Country::Albania));
+    /// assert_eq!(Country::from_alpha2("XK"), Some(Country::Kosovo));
+    /// assert_eq!(Country::from_alpha2("ZZ"), None);
+    /// ```
+    #[must_use]
+    pub fn from_alpha2(code: &str) -> Option<Country> {
+        Country::ALL
+            .iter()
+            .copied()
+            .find(|c| c.alpha2().eq_ignore_ascii_case(code))
+    }
+}
+
+impl fmt::Display for Country {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl std::str::FromStr for Country {
+    type Err = ParseCountryError;
+
+    /// Parses a country from its module name, ignoring ASCII case.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Country::ALL
+            .iter()
+            .copied()
+            .find(|c| c.name().eq_ignore_ascii_case(s))
+            .ok_or(ParseCountryError)
+    }
+}
+
+/// Error returned when a string does not name a supported [`Country`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseCountryError;
+
+impl fmt::Display for ParseCountryError {
+    #[inline]
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown or unsupported country")
+    }
+}
+
+impl std::error::Error for ParseCountryError {}
+
+/// The personal data common to decoded IDs, independent of the country.
+///
+/// Fields are `None` when the country's format does not encode them. The
+/// [`Display`](fmt::Display) form lists the known fields separated by spaces,
+/// e.g. `"1990-01-01 M national"`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Summary {
+    /// Date of birth.
+    pub birthday: Option<Date>,
+    /// Biological sex.
+    pub sex: Option<Sex>,
+    /// Whether the holder is a national of the issuing country.
+    pub is_national: Option<bool>,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut sep = "";
+        if let Some(birthday) = self.birthday {
+            write!(f, "{birthday}")?;
+            sep = " ";
+        }
+        if let Some(sex) = self.sex {
+            write!(f, "{sep}{sex}")?;
+            sep = " ";
+        }
+        if let Some(is_national) = self.is_national {
+            let status = if is_national { "national" } else { "foreign" };
+            write!(f, "{sep}{status}")?;
+        }
+        Ok(())
+    }
+}
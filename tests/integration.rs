@@ -14,6 +14,8 @@ fn public_api_decode_returns_expected_info() {
             },
             sex: Sex::Male,
             is_national: true,
+            serial: 999,
+            month_code: 1,
         }
     );
 }
@@ -175,3 +177,1324 @@ fn kosovo_error_type_implements_std_error() {
     fn assert_error<T: std::error::Error>() {}
     assert_error::<nidx::kosovo::NidError>();
 }
+
+// ── Serbia ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn serbia_is_valid_accepts_valid() {
+    assert!(nidx::serbia::is_valid("0101990710008"));
+}
+
+#[test]
+fn serbia_is_valid_rejects_invalid() {
+    assert!(!nidx::serbia::is_valid(""));
+    assert!(!nidx::serbia::is_valid("0101990710009"));
+}
+
+#[test]
+fn serbia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Serbia, "0101990710008").unwrap();
+    assert_eq!(id.country(), Country::Serbia);
+    assert!(nidx::detect("0101990710008").contains(&Country::Serbia));
+    assert!(
+        nidx::Registry::default()
+            .validate("RS", "0101990710008")
+            .is_ok()
+    );
+}
+
+#[test]
+fn serbia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::serbia::NidError>();
+}
+
+// ── North Macedonia ─────────────────────────────────────────────────────────
+
+#[test]
+fn north_macedonia_is_valid_accepts_valid() {
+    assert!(nidx::north_macedonia::is_valid("0101990450006"));
+}
+
+#[test]
+fn north_macedonia_is_valid_rejects_invalid() {
+    assert!(!nidx::north_macedonia::is_valid(""));
+    assert!(!nidx::north_macedonia::is_valid("0101990450007"));
+}
+
+#[test]
+fn north_macedonia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::NorthMacedonia, "0101990450006").unwrap();
+    assert_eq!(id.country(), Country::NorthMacedonia);
+    assert!(nidx::detect("0101990450006").contains(&Country::NorthMacedonia));
+    assert!(
+        nidx::Registry::default()
+            .validate("MK", "0101990450006")
+            .is_ok()
+    );
+}
+
+#[test]
+fn north_macedonia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::north_macedonia::NidError>();
+}
+
+// ── Italy ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn italy_is_valid_accepts_valid() {
+    assert!(nidx::italy::is_valid("RSSMRA85T10A562S"));
+}
+
+#[test]
+fn italy_is_valid_rejects_invalid() {
+    assert!(!nidx::italy::is_valid(""));
+    assert!(!nidx::italy::is_valid("RSSMRA85T10A562A"));
+}
+
+#[test]
+fn italy_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Italy, "RSSMRA85T10A562S").unwrap();
+    assert_eq!(id.country(), Country::Italy);
+    assert!(nidx::detect("RSSMRA85T10A562S").contains(&Country::Italy));
+    assert!(
+        nidx::Registry::default()
+            .validate("IT", "RSSMRA85T10A562S")
+            .is_ok()
+    );
+}
+
+#[test]
+fn italy_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::italy::NidError>();
+}
+
+// ── Spain ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn spain_is_valid_accepts_valid() {
+    assert!(nidx::spain::is_valid("12345678Z"));
+}
+
+#[test]
+fn spain_is_valid_rejects_invalid() {
+    assert!(!nidx::spain::is_valid(""));
+    assert!(!nidx::spain::is_valid("12345678T"));
+}
+
+#[test]
+fn spain_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Spain, "12345678Z").unwrap();
+    assert_eq!(id.country(), Country::Spain);
+    assert!(nidx::detect("12345678Z").contains(&Country::Spain));
+    assert!(
+        nidx::Registry::default()
+            .validate("ES", "12345678Z")
+            .is_ok()
+    );
+}
+
+#[test]
+fn spain_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::spain::NidError>();
+}
+
+// ── Portugal ────────────────────────────────────────────────────────────────
+
+#[test]
+fn portugal_is_valid_accepts_valid() {
+    assert!(nidx::portugal::is_valid("123456789ZZ1"));
+}
+
+#[test]
+fn portugal_is_valid_rejects_invalid() {
+    assert!(!nidx::portugal::is_valid(""));
+    assert!(!nidx::portugal::is_valid("123456789ZZ2"));
+}
+
+#[test]
+fn portugal_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Portugal, "123456789ZZ1").unwrap();
+    assert_eq!(id.country(), Country::Portugal);
+    assert!(nidx::detect("123456789ZZ1").contains(&Country::Portugal));
+    assert!(
+        nidx::Registry::default()
+            .validate("PT", "123456789ZZ1")
+            .is_ok()
+    );
+}
+
+#[test]
+fn portugal_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::portugal::NidError>();
+}
+
+// ── France ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn france_is_valid_accepts_valid() {
+    assert!(nidx::france::is_valid("185057800608491"));
+}
+
+#[test]
+fn france_is_valid_rejects_invalid() {
+    assert!(!nidx::france::is_valid(""));
+    assert!(!nidx::france::is_valid("185057800608492"));
+}
+
+#[test]
+fn france_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::France, "185057800608491").unwrap();
+    assert_eq!(id.country(), Country::France);
+    assert!(nidx::detect("185057800608491").contains(&Country::France));
+    assert!(
+        nidx::Registry::default()
+            .validate("FR", "185057800608491")
+            .is_ok()
+    );
+}
+
+#[test]
+fn france_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::france::NidError>();
+}
+
+// ── Germany ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn germany_is_valid_accepts_valid() {
+    assert!(nidx::germany::is_valid("86095742719"));
+}
+
+#[test]
+fn germany_is_valid_rejects_invalid() {
+    assert!(!nidx::germany::is_valid(""));
+    assert!(!nidx::germany::is_valid("86095742718"));
+}
+
+#[test]
+fn germany_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Germany, "86095742719").unwrap();
+    assert_eq!(id.country(), Country::Germany);
+    assert!(nidx::detect("86095742719").contains(&Country::Germany));
+    assert!(
+        nidx::Registry::default()
+            .validate("DE", "86095742719")
+            .is_ok()
+    );
+}
+
+#[test]
+fn germany_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::germany::NidError>();
+}
+
+// ── Switzerland ─────────────────────────────────────────────────────────────
+
+#[test]
+fn switzerland_is_valid_accepts_valid() {
+    assert!(nidx::switzerland::is_valid("756.9217.0769.85"));
+}
+
+#[test]
+fn switzerland_is_valid_rejects_invalid() {
+    assert!(!nidx::switzerland::is_valid(""));
+    assert!(!nidx::switzerland::is_valid("756.9217.0769.86"));
+}
+
+#[test]
+fn switzerland_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Switzerland, "756.9217.0769.85").unwrap();
+    assert_eq!(id.country(), Country::Switzerland);
+    assert!(nidx::detect("756.9217.0769.85").contains(&Country::Switzerland));
+    assert!(
+        nidx::Registry::default()
+            .validate("CH", "756.9217.0769.85")
+            .is_ok()
+    );
+}
+
+#[test]
+fn switzerland_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::switzerland::NidError>();
+}
+
+// ── Austria ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn austria_is_valid_accepts_valid() {
+    assert!(nidx::austria::is_valid("1237010180"));
+}
+
+#[test]
+fn austria_is_valid_rejects_invalid() {
+    assert!(!nidx::austria::is_valid(""));
+    assert!(!nidx::austria::is_valid("1238010180"));
+}
+
+#[test]
+fn austria_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Austria, "1237010180").unwrap();
+    assert_eq!(id.country(), Country::Austria);
+    assert!(nidx::detect("1237010180").contains(&Country::Austria));
+    assert!(
+        nidx::Registry::default()
+            .validate("AT", "1237010180")
+            .is_ok()
+    );
+}
+
+#[test]
+fn austria_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::austria::NidError>();
+}
+
+// ── Poland ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn poland_is_valid_accepts_valid() {
+    assert!(nidx::poland::is_valid("44051401359"));
+}
+
+#[test]
+fn poland_is_valid_rejects_invalid() {
+    assert!(!nidx::poland::is_valid(""));
+    assert!(!nidx::poland::is_valid("44051401358"));
+}
+
+#[test]
+fn poland_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Poland, "44051401359").unwrap();
+    assert_eq!(id.country(), Country::Poland);
+    assert!(nidx::detect("44051401359").contains(&Country::Poland));
+    assert!(
+        nidx::Registry::default()
+            .validate("PL", "44051401359")
+            .is_ok()
+    );
+}
+
+#[test]
+fn poland_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::poland::NidError>();
+}
+
+// ── Czechia ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn czechia_is_valid_accepts_valid() {
+    assert!(nidx::czechia::is_valid("7801231230"));
+}
+
+#[test]
+fn czechia_is_valid_rejects_invalid() {
+    assert!(!nidx::czechia::is_valid(""));
+    assert!(!nidx::czechia::is_valid("7801231231"));
+}
+
+#[test]
+fn czechia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Czechia, "7801231230").unwrap();
+    assert_eq!(id.country(), Country::Czechia);
+    assert!(nidx::detect("7801231230").contains(&Country::Czechia));
+    assert!(
+        nidx::Registry::default()
+            .validate("CZ", "7801231230")
+            .is_ok()
+    );
+}
+
+#[test]
+fn czechia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::czechia::NidError>();
+}
+
+// ── Ukraine ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn ukraine_is_valid_accepts_valid() {
+    assert!(nidx::ukraine::is_valid("3287301233"));
+}
+
+#[test]
+fn ukraine_is_valid_rejects_invalid() {
+    assert!(!nidx::ukraine::is_valid(""));
+    assert!(!nidx::ukraine::is_valid("3287301234"));
+}
+
+#[test]
+fn ukraine_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Ukraine, "3287301233").unwrap();
+    assert_eq!(id.country(), Country::Ukraine);
+    assert!(nidx::detect("3287301233").contains(&Country::Ukraine));
+    assert!(
+        nidx::Registry::default()
+            .validate("UA", "3287301233")
+            .is_ok()
+    );
+}
+
+#[test]
+fn ukraine_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::ukraine::NidError>();
+}
+
+// ── Turkey ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn turkey_is_valid_accepts_valid() {
+    assert!(nidx::turkey::is_valid("10000000146"));
+}
+
+#[test]
+fn turkey_is_valid_rejects_invalid() {
+    assert!(!nidx::turkey::is_valid(""));
+    assert!(!nidx::turkey::is_valid("10000000147"));
+}
+
+#[test]
+fn turkey_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Turkey, "10000000146").unwrap();
+    assert_eq!(id.country(), Country::Turkey);
+    assert!(nidx::detect("10000000146").contains(&Country::Turkey));
+    assert!(
+        nidx::Registry::default()
+            .validate("TR", "10000000146")
+            .is_ok()
+    );
+}
+
+#[test]
+fn turkey_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::turkey::NidError>();
+}
+
+// ── Finland ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn finland_is_valid_accepts_valid() {
+    assert!(nidx::finland::is_valid("131052-308T"));
+}
+
+#[test]
+fn finland_is_valid_rejects_invalid() {
+    assert!(!nidx::finland::is_valid(""));
+    assert!(!nidx::finland::is_valid("131052-308U"));
+}
+
+#[test]
+fn finland_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Finland, "131052-308T").unwrap();
+    assert_eq!(id.country(), Country::Finland);
+    assert!(nidx::detect("131052-308T").contains(&Country::Finland));
+    assert!(
+        nidx::Registry::default()
+            .validate("FI", "131052-308T")
+            .is_ok()
+    );
+}
+
+#[test]
+fn finland_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::finland::NidError>();
+}
+
+// ── Latvia ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn latvia_is_valid_accepts_valid() {
+    assert!(nidx::latvia::is_valid("16117519997"));
+}
+
+#[test]
+fn latvia_is_valid_rejects_invalid() {
+    assert!(!nidx::latvia::is_valid(""));
+    assert!(!nidx::latvia::is_valid("16117519996"));
+}
+
+#[test]
+fn latvia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Latvia, "16117519997").unwrap();
+    assert_eq!(id.country(), Country::Latvia);
+    assert!(nidx::detect("16117519997").contains(&Country::Latvia));
+    assert!(
+        nidx::Registry::default()
+            .validate("LV", "16117519997")
+            .is_ok()
+    );
+}
+
+#[test]
+fn latvia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::latvia::NidError>();
+}
+
+// ── Ireland ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn ireland_is_valid_accepts_valid() {
+    assert!(nidx::ireland::is_valid("6433435F"));
+}
+
+#[test]
+fn ireland_is_valid_rejects_invalid() {
+    assert!(!nidx::ireland::is_valid(""));
+    assert!(!nidx::ireland::is_valid("6433435E"));
+}
+
+#[test]
+fn ireland_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Ireland, "6433435F").unwrap();
+    assert_eq!(id.country(), Country::Ireland);
+    assert!(nidx::detect("6433435F").contains(&Country::Ireland));
+    assert!(nidx::Registry::default().validate("IE", "6433435F").is_ok());
+}
+
+#[test]
+fn ireland_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::ireland::NidError>();
+}
+
+// ── Chile ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn chile_is_valid_accepts_valid() {
+    assert!(nidx::chile::is_valid("12345678-5"));
+}
+
+#[test]
+fn chile_is_valid_rejects_invalid() {
+    assert!(!nidx::chile::is_valid(""));
+    assert!(!nidx::chile::is_valid("12345678-4"));
+}
+
+#[test]
+fn chile_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Chile, "12345678-5").unwrap();
+    assert_eq!(id.country(), Country::Chile);
+    assert!(nidx::detect("12345678-5").contains(&Country::Chile));
+    assert!(
+        nidx::Registry::default()
+            .validate("CL", "12345678-5")
+            .is_ok()
+    );
+}
+
+#[test]
+fn chile_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::chile::NidError>();
+}
+
+// ── South Korea ─────────────────────────────────────────────────────────────
+
+#[test]
+fn south_korea_is_valid_accepts_valid() {
+    assert!(nidx::south_korea::is_valid("9001011234568"));
+}
+
+#[test]
+fn south_korea_is_valid_rejects_invalid() {
+    assert!(!nidx::south_korea::is_valid(""));
+    assert!(!nidx::south_korea::is_valid("9001011234567"));
+}
+
+#[test]
+fn south_korea_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::SouthKorea, "9001011234568").unwrap();
+    assert_eq!(id.country(), Country::SouthKorea);
+    assert!(nidx::detect("9001011234568").contains(&Country::SouthKorea));
+    assert!(
+        nidx::Registry::default()
+            .validate("KR", "9001011234568")
+            .is_ok()
+    );
+}
+
+#[test]
+fn south_korea_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::south_korea::NidError>();
+}
+
+// ── Malaysia ────────────────────────────────────────────────────────────────
+
+#[test]
+fn malaysia_is_valid_accepts_valid() {
+    assert!(nidx::malaysia::is_valid("850615101235"));
+}
+
+#[test]
+fn malaysia_is_valid_rejects_invalid() {
+    assert!(!nidx::malaysia::is_valid(""));
+    assert!(!nidx::malaysia::is_valid("900101695678"));
+}
+
+#[test]
+fn malaysia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Malaysia, "850615101235").unwrap();
+    assert_eq!(id.country(), Country::Malaysia);
+    assert!(nidx::detect("850615101235").contains(&Country::Malaysia));
+    assert!(
+        nidx::Registry::default()
+            .validate("MY", "850615101235")
+            .is_ok()
+    );
+}
+
+#[test]
+fn malaysia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::malaysia::NidError>();
+}
+
+// ── Egypt ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn egypt_is_valid_accepts_valid() {
+    assert!(nidx::egypt::is_valid("29001010100211"));
+}
+
+#[test]
+fn egypt_is_valid_rejects_invalid() {
+    assert!(!nidx::egypt::is_valid(""));
+    assert!(!nidx::egypt::is_valid("29001010100212"));
+}
+
+#[test]
+fn egypt_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Egypt, "29001010100211").unwrap();
+    assert_eq!(id.country(), Country::Egypt);
+    assert!(nidx::detect("29001010100211").contains(&Country::Egypt));
+    assert!(
+        nidx::Registry::default()
+            .validate("EG", "29001010100211")
+            .is_ok()
+    );
+}
+
+#[test]
+fn egypt_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::egypt::NidError>();
+}
+
+// ── Australia ───────────────────────────────────────────────────────────────
+
+#[test]
+fn australia_is_valid_accepts_valid() {
+    assert!(nidx::australia::is_valid("123456782"));
+}
+
+#[test]
+fn australia_is_valid_rejects_invalid() {
+    assert!(!nidx::australia::is_valid(""));
+    assert!(!nidx::australia::is_valid("123456789"));
+}
+
+#[test]
+fn australia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Australia, "123456782").unwrap();
+    assert_eq!(id.country(), Country::Australia);
+    assert!(nidx::detect("123456782").contains(&Country::Australia));
+    assert!(
+        nidx::Registry::default()
+            .validate("AU", "123456782")
+            .is_ok()
+    );
+}
+
+#[test]
+fn australia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::australia::NidError>();
+}
+
+// ── Belarus ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn belarus_is_valid_accepts_valid() {
+    assert!(nidx::belarus::is_valid("3140391A001PB8"));
+}
+
+#[test]
+fn belarus_is_valid_rejects_invalid() {
+    assert!(!nidx::belarus::is_valid(""));
+    assert!(!nidx::belarus::is_valid("3140391A001PB7"));
+}
+
+#[test]
+fn belarus_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Belarus, "3140391A001PB8").unwrap();
+    assert_eq!(id.country(), Country::Belarus);
+    assert!(nidx::detect("3140391A001PB8").contains(&Country::Belarus));
+    assert!(
+        nidx::Registry::default()
+            .validate("BY", "3140391A001PB8")
+            .is_ok()
+    );
+}
+
+#[test]
+fn belarus_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::belarus::NidError>();
+}
+
+// ── Russia ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn russia_is_valid_accepts_valid() {
+    assert!(nidx::russia::inn::is_valid("500100732259"));
+}
+
+#[test]
+fn russia_is_valid_rejects_invalid() {
+    assert!(!nidx::russia::inn::is_valid(""));
+    assert!(!nidx::russia::inn::is_valid("500100732258"));
+}
+
+#[test]
+fn russia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Russia, "500100732259").unwrap();
+    assert_eq!(id.country(), Country::Russia);
+    assert!(nidx::detect("500100732259").contains(&Country::Russia));
+    assert!(
+        nidx::Registry::default()
+            .validate("RU", "500100732259")
+            .is_ok()
+    );
+    // Organisation numbers are only accepted by `inn::decode_any`.
+    assert!(nidx::decode(Country::Russia, "7707083893").is_err());
+}
+
+#[test]
+fn russia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::russia::inn::InnError>();
+}
+
+// ── Georgia ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn georgia_is_valid_accepts_valid() {
+    assert!(nidx::georgia::is_valid("01001011234"));
+}
+
+#[test]
+fn georgia_is_valid_rejects_invalid() {
+    assert!(!nidx::georgia::is_valid(""));
+    assert!(!nidx::georgia::is_valid("00001011234"));
+}
+
+#[test]
+fn georgia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Georgia, "01001011234").unwrap();
+    assert_eq!(id.country(), Country::Georgia);
+    assert!(nidx::detect("01001011234").contains(&Country::Georgia));
+    assert!(
+        nidx::Registry::default()
+            .validate("GE", "01001011234")
+            .is_ok()
+    );
+}
+
+#[test]
+fn georgia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::georgia::NidError>();
+}
+
+// ── Cyprus ──────────────────────────────────────────────────────────────────
+
+#[test]
+fn cyprus_is_valid_accepts_valid() {
+    assert!(nidx::cyprus::is_valid("1234567"));
+}
+
+#[test]
+fn cyprus_is_valid_rejects_invalid() {
+    assert!(!nidx::cyprus::is_valid(""));
+    assert!(!nidx::cyprus::is_valid("12345X7"));
+}
+
+#[test]
+fn cyprus_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Cyprus, "1234567").unwrap();
+    assert_eq!(id.country(), Country::Cyprus);
+    assert!(nidx::detect("1234567").contains(&Country::Cyprus));
+    assert!(nidx::Registry::default().validate("CY", "1234567").is_ok());
+}
+
+#[test]
+fn cyprus_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::cyprus::NidError>();
+}
+
+// ── United Arab Emirates ────────────────────────────────────────────────────
+
+#[test]
+fn uae_is_valid_accepts_valid() {
+    assert!(nidx::uae::is_valid("784199012345676"));
+}
+
+#[test]
+fn uae_is_valid_rejects_invalid() {
+    assert!(!nidx::uae::is_valid(""));
+    assert!(!nidx::uae::is_valid("784199012345677"));
+}
+
+#[test]
+fn uae_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Uae, "784199012345676").unwrap();
+    assert_eq!(id.country(), Country::Uae);
+    assert!(nidx::detect("784199012345676").contains(&Country::Uae));
+    assert!(
+        nidx::Registry::default()
+            .validate("AE", "784199012345676")
+            .is_ok()
+    );
+}
+
+#[test]
+fn uae_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::uae::NidError>();
+}
+
+// ── Bahrain ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn bahrain_is_valid_accepts_valid() {
+    assert!(nidx::bahrain::is_valid("850612349"));
+}
+
+#[test]
+fn bahrain_is_valid_rejects_invalid() {
+    assert!(!nidx::bahrain::is_valid(""));
+    assert!(!nidx::bahrain::is_valid("850612348"));
+}
+
+#[test]
+fn bahrain_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Bahrain, "850612349").unwrap();
+    assert_eq!(id.country(), Country::Bahrain);
+    assert!(nidx::detect("850612349").contains(&Country::Bahrain));
+    assert!(
+        nidx::Registry::default()
+            .validate("BH", "850612349")
+            .is_ok()
+    );
+}
+
+#[test]
+fn bahrain_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::bahrain::NidError>();
+}
+
+// ── Kenya ───────────────────────────────────────────────────────────────────
+
+#[test]
+fn kenya_is_valid_accepts_valid() {
+    assert!(nidx::kenya::is_valid("12345678"));
+}
+
+#[test]
+fn kenya_is_valid_rejects_invalid() {
+    assert!(!nidx::kenya::is_valid(""));
+    assert!(!nidx::kenya::is_valid("123456"));
+}
+
+#[test]
+fn kenya_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Kenya, "12345678").unwrap();
+    assert_eq!(id.country(), Country::Kenya);
+    assert!(nidx::detect("12345678").contains(&Country::Kenya));
+    assert!(nidx::Registry::default().validate("KE", "12345678").is_ok());
+}
+
+#[test]
+fn kenya_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::kenya::NidError>();
+}
+
+// ── Zimbabwe ────────────────────────────────────────────────────────────────
+
+#[test]
+fn zimbabwe_is_valid_accepts_valid() {
+    assert!(nidx::zimbabwe::is_valid("63123456B42"));
+}
+
+#[test]
+fn zimbabwe_is_valid_rejects_invalid() {
+    assert!(!nidx::zimbabwe::is_valid(""));
+    assert!(!nidx::zimbabwe::is_valid("63123456C42"));
+}
+
+#[test]
+fn zimbabwe_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Zimbabwe, "63123456B42").unwrap();
+    assert_eq!(id.country(), Country::Zimbabwe);
+    assert!(nidx::detect("63123456B42").contains(&Country::Zimbabwe));
+    assert!(
+        nidx::Registry::default()
+            .validate("ZW", "63123456B42")
+            .is_ok()
+    );
+}
+
+#[test]
+fn zimbabwe_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::zimbabwe::NidError>();
+}
+
+// ── Botswana ────────────────────────────────────────────────────────────────
+
+#[test]
+fn botswana_is_valid_accepts_valid() {
+    assert!(nidx::botswana::is_valid("123412345"));
+}
+
+#[test]
+fn botswana_is_valid_rejects_invalid() {
+    assert!(!nidx::botswana::is_valid(""));
+    assert!(!nidx::botswana::is_valid("123432345"));
+}
+
+#[test]
+fn botswana_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Botswana, "123412345").unwrap();
+    assert_eq!(id.country(), Country::Botswana);
+    assert!(nidx::detect("123412345").contains(&Country::Botswana));
+    assert!(
+        nidx::Registry::default()
+            .validate("BW", "123412345")
+            .is_ok()
+    );
+}
+
+#[test]
+fn botswana_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::botswana::NidError>();
+}
+
+// ── Peru ────────────────────────────────────────────────────────────────────
+
+#[test]
+fn peru_is_valid_accepts_valid() {
+    assert!(nidx::peru::is_valid("12345678-1"));
+}
+
+#[test]
+fn peru_is_valid_rejects_invalid() {
+    assert!(!nidx::peru::is_valid(""));
+    assert!(!nidx::peru::is_valid("12345678-2"));
+}
+
+#[test]
+fn peru_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Peru, "12345678-1").unwrap();
+    assert_eq!(id.country(), Country::Peru);
+    assert!(nidx::detect("12345678-1").contains(&Country::Peru));
+    assert!(
+        nidx::Registry::default()
+            .validate("PE", "12345678-1")
+            .is_ok()
+    );
+}
+
+#[test]
+fn peru_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::peru::NidError>();
+}
+
+// ── Ecuador ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn ecuador_is_valid_accepts_valid() {
+    assert!(nidx::ecuador::is_valid("1710034065"));
+}
+
+#[test]
+fn ecuador_is_valid_rejects_invalid() {
+    assert!(!nidx::ecuador::is_valid(""));
+    assert!(!nidx::ecuador::is_valid("1710034066"));
+}
+
+#[test]
+fn ecuador_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Ecuador, "1710034065").unwrap();
+    assert_eq!(id.country(), Country::Ecuador);
+    assert!(nidx::detect("1710034065").contains(&Country::Ecuador));
+    assert!(
+        nidx::Registry::default()
+            .validate("EC", "1710034065")
+            .is_ok()
+    );
+}
+
+#[test]
+fn ecuador_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::ecuador::NidError>();
+}
+
+// ── Venezuela ───────────────────────────────────────────────────────────────
+
+#[test]
+fn venezuela_is_valid_accepts_valid() {
+    assert!(nidx::venezuela::is_valid("V-12345678"));
+}
+
+#[test]
+fn venezuela_is_valid_rejects_invalid() {
+    assert!(!nidx::venezuela::is_valid(""));
+    assert!(!nidx::venezuela::is_valid("12345678"));
+}
+
+#[test]
+fn venezuela_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Venezuela, "V-12345678").unwrap();
+    assert_eq!(id.country(), Country::Venezuela);
+    assert!(nidx::detect("V-12345678").contains(&Country::Venezuela));
+    assert!(
+        nidx::Registry::default()
+            .validate("VE", "V-12345678")
+            .is_ok()
+    );
+}
+
+#[test]
+fn venezuela_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::venezuela::NidError>();
+}
+
+// ── Colombia ────────────────────────────────────────────────────────────────
+
+#[test]
+fn colombia_is_valid_accepts_valid() {
+    assert!(nidx::colombia::is_valid("79555123"));
+}
+
+#[test]
+fn colombia_is_valid_rejects_invalid() {
+    assert!(!nidx::colombia::is_valid(""));
+    assert!(!nidx::colombia::is_valid("79555123-8"));
+}
+
+#[test]
+fn colombia_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Colombia, "79555123").unwrap();
+    assert_eq!(id.country(), Country::Colombia);
+    assert!(nidx::detect("79555123").contains(&Country::Colombia));
+    assert!(nidx::Registry::default().validate("CO", "79555123").is_ok());
+}
+
+#[test]
+fn colombia_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::colombia::NidError>();
+}
+
+// ── Costa Rica ──────────────────────────────────────────────────────────────
+
+#[test]
+fn costa_rica_is_valid_accepts_valid() {
+    assert!(nidx::costa_rica::is_valid("1-0234-0567"));
+}
+
+#[test]
+fn costa_rica_is_valid_rejects_invalid() {
+    assert!(!nidx::costa_rica::is_valid(""));
+    assert!(!nidx::costa_rica::is_valid("1-0234-056"));
+}
+
+#[test]
+fn costa_rica_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::CostaRica, "1-0234-0567").unwrap();
+    assert_eq!(id.country(), Country::CostaRica);
+    assert!(nidx::detect("1-0234-0567").contains(&Country::CostaRica));
+    assert!(
+        nidx::Registry::default()
+            .validate("CR", "1-0234-0567")
+            .is_ok()
+    );
+}
+
+#[test]
+fn costa_rica_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::costa_rica::NidError>();
+}
+
+// ── Vietnam ─────────────────────────────────────────────────────────────────
+
+#[test]
+fn vietnam_is_valid_accepts_valid() {
+    assert!(nidx::vietnam::is_valid("001090012345"));
+}
+
+#[test]
+fn vietnam_is_valid_rejects_invalid() {
+    assert!(!nidx::vietnam::is_valid(""));
+    assert!(!nidx::vietnam::is_valid("003090012345"));
+}
+
+#[test]
+fn vietnam_dispatch_and_detect() {
+    use nidx::Country;
+
+    let id = nidx::decode(Country::Vietnam, "001090012345").unwrap();
+    assert_eq!(id.country(), Country::Vietnam);
+    assert!(nidx::detect("001090012345").contains(&Country::Vietnam));
+    assert!(
+        nidx::Registry::default()
+            .validate("VN", "001090012345")
+            .is_ok()
+    );
+}
+
+#[test]
+fn vietnam_error_type_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::vietnam::NidError>();
+}
+
+// ── Country dispatch ────────────────────────────────────────────────────────
+
+#[test]
+fn dispatch_decode_matches_country_module() {
+    use nidx::{Country, DecodedId};
+
+    let id = nidx::decode(Country::Albania, "J00101999W").unwrap();
+    assert_eq!(
+        id,
+        DecodedId::Albania(nidx::albania::decode("J00101999W").unwrap())
+    );
+
+    let id = nidx::decode(Country::Kosovo, "1234567892").unwrap();
+    assert_eq!(id, DecodedId::Kosovo);
+}
+
+#[test]
+fn dispatch_country_parsed_from_string() {
+    let country: nidx::Country = "kosovo".parse().unwrap();
+    assert!(nidx::is_valid(country, "1234567892"));
+    assert!(matches!(
+        nidx::validate(country, "1234567890"),
+        Err(nidx::Error::Kosovo(nidx::kosovo::NidError::Checksum))
+    ));
+}
+
+#[test]
+fn dispatch_error_implements_std_error() {
+    fn assert_error<T: std::error::Error>() {}
+    assert_error::<nidx::Error>();
+    assert_error::<nidx::ParseCountryError>();
+}
+
+#[test]
+fn detect_returns_matching_countries() {
+    use nidx::Country;
+
+    assert_eq!(nidx::detect("J00101999W"), vec![Country::Albania]);
+    assert_eq!(nidx::detect("9000000001"), vec![Country::Kosovo]);
+    assert_eq!(nidx::detect("not an id"), Vec::<Country>::new());
+}
+
+// ── NationalId trait ────────────────────────────────────────────────────────
+
+fn first_valid<T: nidx::NationalId>(ids: &[&str]) -> Option<T::Info> {
+    ids.iter().find_map(|id| T::decode(id).ok())
+}
+
+#[test]
+fn national_id_generic_over_countries() {
+    use nidx::NationalId;
+    use nidx::albania::Albania;
+    use nidx::kosovo::Kosovo;
+
+    let ids = ["short", "1234567892", "J00101999W"];
+    let info = first_valid::<Albania>(&ids).unwrap();
+    assert_eq!(info.birthday.to_string(), "1990-01-01");
+    assert_eq!(first_valid::<Kosovo>(&ids), Some(()));
+
+    assert_eq!(Albania::COUNTRY, nidx::Country::Albania);
+    assert_eq!(Kosovo::COUNTRY, nidx::Country::Kosovo);
+}
+
+// ── Registry ────────────────────────────────────────────────────────────────
+
+#[test]
+fn registry_selects_decoder_from_config_string() {
+    let registry = nidx::Registry::default();
+    for (code, nid) in [("AL", "J00101999W"), ("XK", "1234567892")] {
+        assert!(registry.validate(code, nid).is_ok(), "{code} {nid}");
+    }
+    let err = registry.validate("AL", "J00101999A").unwrap_err();
+    assert!(matches!(err, nidx::RegistryError::Invalid(_)));
+    assert_eq!(err.to_string(), "checksum validation failed");
+}
+
+#[test]
+fn registry_accepts_custom_decoders() {
+    struct AlwaysValid;
+
+    impl nidx::Decoder for AlwaysValid {
+        fn decode(&self, _nid: &str) -> Result<nidx::BoxedInfo, nidx::BoxedError> {
+            Ok(Box::new(()))
+        }
+    }
+
+    let mut registry = nidx::Registry::new();
+    registry.register("ZZ", AlwaysValid);
+    assert!(registry.is_valid("zz", "anything"));
+    assert!(!registry.is_valid("AL", "J00101999W"));
+}
+
+// ── Unified error ───────────────────────────────────────────────────────────
+
+fn validate_pair(al: &str, xk: &str) -> Result<(), nidx::Error> {
+    nidx::albania::validate(al)?;
+    nidx::kosovo::validate(xk)?;
+    Ok(())
+}
+
+#[test]
+fn unified_error_converts_with_question_mark() {
+    use std::error::Error as _;
+
+    assert!(validate_pair("J00101999W", "1234567892").is_ok());
+
+    let err = validate_pair("short", "1234567892").unwrap_err();
+    assert_eq!(err.country(), nidx::Country::Albania);
+    assert!(err.source().is_some());
+
+    let err = validate_pair("J00101999W", "12345").unwrap_err();
+    assert_eq!(
+        err,
+        nidx::Error::Kosovo(nidx::kosovo::NidError::Format(
+            nidx::kosovo::FormatKind::InvalidLength
+        ))
+    );
+}
+
+// ── Normalization ───────────────────────────────────────────────────────────
+
+#[test]
+fn normalize_yields_single_canonical_form() {
+    let inputs = ["J00101999W", "j00101999w", " J00101999w\n"];
+    for input in inputs {
+        assert_eq!(nidx::albania::normalize(input), "J00101999W");
+        assert_eq!(nidx::normalize(nidx::Country::Albania, input), "J00101999W");
+    }
+    assert_eq!(nidx::kosovo::normalize("\t1234567892 "), "1234567892");
+}
+
+// ── Parse options ───────────────────────────────────────────────────────────
+
+#[test]
+fn lenient_options_accept_formatted_input() {
+    use nidx::ParseOptions;
+
+    let opts = ParseOptions::lenient();
+    let strict = nidx::albania::decode("J00101999W").unwrap();
+    assert_eq!(
+        nidx::albania::decode_with("J0 0101 999W\n", &opts),
+        Ok(strict)
+    );
+    assert!(nidx::kosovo::validate_with("1234-5678-92", &opts).is_ok());
+    assert!(nidx::albania::validate_with("J0 0101 999W", &ParseOptions::default()).is_err());
+}
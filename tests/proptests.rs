@@ -13,6 +13,18 @@ proptest! {
         assert_eq!(nidx::albania::is_valid(&s), nidx::albania::decode(&s).is_ok());
     }
 
+    #[test]
+    fn albania_decode_bytes_never_panics(bytes in proptest::collection::vec(any::<u8>(), 0..20)) {
+        let _ = nidx::albania::decode_bytes(&bytes);
+    }
+
+    #[test]
+    fn albania_validate_all_first_issue_matches_validate(s in "[0-9A-Za-z]{10}") {
+        let report = nidx::albania::validate_all(&s);
+        assert_eq!(report.first().copied(), nidx::albania::validate(&s).err());
+        assert_eq!(report.is_valid(), nidx::albania::is_valid(&s));
+    }
+
     #[test]
     fn albania_valid_nids_roundtrip(
         decade in 0usize..30,
@@ -69,6 +81,25 @@ proptest! {
         }
     }
 
+    #[test]
+    fn albania_encode_decode_roundtrip(
+        year in 1800u16..2100,
+        month in 1u8..13,
+        day in 1u8..29,
+        female: bool,
+        is_national: bool,
+        serial in 0u16..1000,
+    ) {
+        let birthday = nidx::Date { year, month, day };
+        let sex = if female { nidx::Sex::Female } else { nidx::Sex::Male };
+        let nid = nidx::albania::encode(birthday, sex, is_national, serial).unwrap();
+        let info = nidx::albania::decode(&nid).unwrap();
+        assert_eq!(info.birthday, birthday);
+        assert_eq!(info.sex, sex);
+        assert_eq!(info.is_national, is_national);
+        assert_eq!(info.serial, serial);
+    }
+
     // ── Kosovo ──────────────────────────────────────────────────────────
 
     #[test]
@@ -81,6 +112,17 @@ proptest! {
         assert_eq!(nidx::kosovo::is_valid(&s), nidx::kosovo::validate(&s).is_ok());
     }
 
+    #[test]
+    fn kosovo_validate_bytes_never_panics(bytes in proptest::collection::vec(any::<u8>(), 0..20)) {
+        let _ = nidx::kosovo::validate_bytes(&bytes);
+    }
+
+    #[test]
+    fn kosovo_validate_all_agrees_with_validate(s in "\\PC{0,20}") {
+        let report = nidx::kosovo::validate_all(&s);
+        assert_eq!(report.first().copied(), nidx::kosovo::validate(&s).err());
+    }
+
     #[test]
     fn kosovo_valid_nids_roundtrip(
         digits in proptest::collection::vec(0u8..10, 9),
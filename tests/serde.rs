@@ -28,3 +28,55 @@ fn nid_info_roundtrip_json() {
     let back: nidx::albania::NidInfo = serde_json::from_str(&json).unwrap();
     assert_eq!(info, back);
 }
+
+#[test]
+fn country_serializes_as_lowercase_name() {
+    let json = serde_json::to_string(&nidx::Country::Kosovo).unwrap();
+    assert_eq!(json, "\"kosovo\"");
+    let back: nidx::Country = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, nidx::Country::Kosovo);
+}
+
+#[test]
+fn decoded_id_roundtrip_json() {
+    let id = nidx::decode(nidx::Country::Albania, "J00101999W").unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    let back: nidx::DecodedId = serde_json::from_str(&json).unwrap();
+    assert_eq!(id, back);
+}
+
+#[test]
+fn albania_nid_serializes_as_string() {
+    let nid: nidx::albania::AlbaniaNid = "j00101999w".parse().unwrap();
+    let json = serde_json::to_string(&nid).unwrap();
+    assert_eq!(json, "\"J00101999W\"");
+    let back: nidx::albania::AlbaniaNid = serde_json::from_str(&json).unwrap();
+    assert_eq!(nid, back);
+}
+
+#[test]
+fn newtypes_reject_invalid_on_deserialize() {
+    assert!(serde_json::from_str::<nidx::albania::AlbaniaNid>("\"J00101999A\"").is_err());
+    assert!(serde_json::from_str::<nidx::kosovo::KosovoNid>("\"1234567890\"").is_err());
+    assert!(serde_json::from_str::<nidx::kosovo::KosovoNid>("\"1234567892\"").is_ok());
+}
+
+#[test]
+fn multi_word_country_serializes_as_module_name() {
+    let json = serde_json::to_string(&nidx::Country::NorthMacedonia).unwrap();
+    assert_eq!(json, "\"north_macedonia\"");
+    let back: nidx::Country = serde_json::from_str(&json).unwrap();
+    assert_eq!(back, nidx::Country::NorthMacedonia);
+}
+
+#[test]
+fn multi_word_decoded_id_tag_matches_country() {
+    for country in [nidx::Country::NorthMacedonia, nidx::Country::SouthKorea] {
+        let nid = nidx::generator::generate_seeded(country, 1, 0);
+        let id = nidx::decode(country, &nid).unwrap();
+        let json: serde_json::Value = serde_json::to_value(id).unwrap();
+        assert_eq!(json["country"], serde_json::to_value(country).unwrap());
+        let back: nidx::DecodedId = serde_json::from_value(json).unwrap();
+        assert_eq!(back, id);
+    }
+}